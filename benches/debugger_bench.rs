@@ -0,0 +1,63 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use soroban_debugger::runtime::executor::ContractExecutor;
+use soroban_debugger::runtime::parser;
+use soroban_debugger::utils::wasm;
+use std::fs;
+use std::path::PathBuf;
+
+fn read_fixture(name: &str) -> Vec<u8> {
+    let mut wasm_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    wasm_path.push(format!("tests/fixtures/wasm/{name}.wasm"));
+    fs::read(wasm_path).unwrap_or_else(|_| panic!("Failed to read {name}.wasm"))
+}
+
+fn bench_signature_cache(c: &mut Criterion) {
+    let wasm_bytes = read_fixture("echo");
+    let executor = ContractExecutor::new(wasm_bytes.clone()).unwrap();
+    let signatures = wasm::parse_function_signatures(&wasm_bytes).unwrap();
+
+    let mut group = c.benchmark_group("signature_cache");
+
+    // What every execute() did before signatures were cached on the executor.
+    group.bench_function("parse_args_fresh", |b| {
+        b.iter(|| {
+            let exported = wasm::parse_functions(black_box(&wasm_bytes)).unwrap();
+            let args =
+                parser::parse_args(executor.env(), black_box(&wasm_bytes), "echo", "[42]").unwrap();
+            black_box((exported, args));
+        })
+    });
+
+    group.bench_function("parse_args_cached", |b| {
+        b.iter(|| {
+            let exported = executor.exported_functions().unwrap();
+            let args = parser::parse_args_with_signatures(
+                executor.env(),
+                black_box(&signatures),
+                "echo",
+                "[42]",
+            )
+            .unwrap();
+            black_box((exported, args));
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_repeated_execute(c: &mut Criterion) {
+    let mut executor = ContractExecutor::new(read_fixture("echo")).unwrap();
+
+    c.bench_function("repeated_execute_echo", |b| {
+        b.iter(|| {
+            executor.env().cost_estimate().budget().reset_unlimited();
+            let result = executor
+                .execute(black_box("echo"), black_box(Some("[42]")))
+                .unwrap();
+            black_box(result);
+        })
+    });
+}
+
+criterion_group!(benches, bench_signature_cache, bench_repeated_execute);
+criterion_main!(benches);
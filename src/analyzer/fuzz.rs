@@ -0,0 +1,512 @@
+//! Random, type-valid input generation for `soroban-debug fuzz`.
+//!
+//! Arguments are generated from the function's spec signature and executed
+//! against a fresh contract instance each iteration. Inputs that make the call
+//! abort, or return a contract error code that was not declared expected, are
+//! reported once per distinct outcome together with the first input that hit it.
+
+use crate::analyzer::symbolic::lcg_next;
+use crate::runtime::executor::ContractExecutor;
+use crate::utils::wasm::{parse_function_signatures, FunctionParam};
+use crate::{DebuggerError, Result};
+use serde::Serialize;
+use serde_json::{json, Value as JsonValue};
+
+/// Addresses handed out for `Address` parameters; all are valid strkeys.
+const ADDRESS_POOL: &[&str] = &[
+    "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+    "GD7777777777777777777777777777777777777777777777777773DB",
+    "GAAACAQDAQCQMBYIBEFAWDANBYHRAEISCMKBKFQXDAMRUGY4DUPB7JZX",
+    "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4",
+    "CAAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQC526",
+];
+
+const U256_MAX: &str =
+    "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+const I256_MIN: &str =
+    "-57896044618658097711785492504343953926634992332820282019728792003956564819968";
+const I256_MAX: &str =
+    "57896044618658097711785492504343953926634992332820282019728792003956564819967";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzConfig {
+    pub iterations: usize,
+    pub seed: u64,
+    pub timeout_secs: u64,
+    /// Contract error codes that are part of the function's contract and
+    /// should not be reported as findings.
+    pub expected_codes: Vec<u32>,
+    /// Initial storage JSON applied before every iteration.
+    pub storage: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FuzzFindingKind {
+    /// The call trapped or aborted (`InvokeError::Abort`).
+    Abort,
+    /// The call returned a contract error code not listed as expected.
+    ContractError,
+    /// Any other execution failure, such as budget exhaustion or a timeout.
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FuzzFinding {
+    pub kind: FuzzFindingKind,
+    pub code: Option<u32>,
+    pub name: Option<String>,
+    pub message: String,
+    /// 1-based iteration that first produced this outcome.
+    pub first_iteration: usize,
+    /// JSON argument array that first produced this outcome.
+    pub inputs: String,
+    pub occurrences: usize,
+}
+
+impl FuzzFinding {
+    /// Short label such as `contract error #2 (InvalidPrice)` or `abort`.
+    pub fn label(&self) -> String {
+        match (self.kind, self.code, &self.name) {
+            (FuzzFindingKind::ContractError, Some(code), Some(name)) => {
+                format!("contract error #{} ({})", code, name)
+            }
+            (FuzzFindingKind::ContractError, Some(code), None) => {
+                format!("contract error #{}", code)
+            }
+            (FuzzFindingKind::Abort, ..) => "abort".to_string(),
+            _ => self.message.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FuzzReport {
+    pub function: String,
+    pub seed: u64,
+    pub iterations: usize,
+    pub succeeded: usize,
+    pub expected_errors: usize,
+    pub findings: Vec<FuzzFinding>,
+}
+
+/// Small seedable generator so a `--seed` always replays the same inputs.
+///
+/// Steps the same 64-bit LCG as the symbolic analyzer's shuffle.
+#[derive(Debug, Clone)]
+pub struct FuzzRng {
+    state: u64,
+}
+
+impl FuzzRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u32(&mut self) -> u32 {
+        (lcg_next(&mut self.state) >> 32) as u32
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        ((self.next_u32() as u64) << 32) | self.next_u32() as u64
+    }
+
+    fn next_u128(&mut self) -> u128 {
+        ((self.next_u64() as u128) << 64) | self.next_u64() as u128
+    }
+
+    /// Uniform-enough value in `0..bound`; `bound` must be non-zero.
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// True roughly once in `n` calls.
+    fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+}
+
+/// Generate one JSON argument array for `params`.
+pub fn generate_args(params: &[FunctionParam], rng: &mut FuzzRng) -> Result<String> {
+    let values = params
+        .iter()
+        .map(|param| {
+            generate_value(&param.type_name, rng).ok_or_else(|| {
+                DebuggerError::InvalidArguments(format!(
+                    "Cannot fuzz parameter '{}': unsupported type {}",
+                    param.name, param.type_name
+                ))
+                .into()
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(JsonValue::Array(values).to_string())
+}
+
+/// A typed argument annotation for `type_name`, or `None` if the type is unsupported.
+fn generate_value(type_name: &str, rng: &mut FuzzRng) -> Option<JsonValue> {
+    if let Some((annotation, value)) = generate_scalar(type_name, rng) {
+        return Some(json!({"type": annotation, "value": value}));
+    }
+    if type_name == "Void" {
+        return Some(JsonValue::Null);
+    }
+    if type_name == "Val" {
+        let any = ["Bool", "U32", "I64", "I128", "String", "Symbol", "Bytes"];
+        return generate_value(any[rng.below(any.len())], rng);
+    }
+    if let Some(size) = type_name
+        .strip_prefix("BytesN<")
+        .and_then(|t| t.strip_suffix('>'))
+        .and_then(|t| t.trim().parse::<usize>().ok())
+    {
+        return Some(json!({"type": "bytesn", "size": size, "value": random_hex(rng, size)}));
+    }
+    if let Some(inner) = type_name
+        .strip_prefix("Option<")
+        .and_then(|t| t.strip_suffix('>'))
+    {
+        if rng.one_in(4) {
+            return Some(json!({"type": "option", "value": null}));
+        }
+        return Some(json!({"type": "option", "value": generate_value(inner, rng)?}));
+    }
+    if let Some(inner) = type_name
+        .strip_prefix("Vec<")
+        .and_then(|t| t.strip_suffix('>'))
+    {
+        // The parser only accepts a single element type annotation, so stick to scalars.
+        let (annotation, _) = generate_scalar(inner, rng)?;
+        let items = (0..rng.below(4))
+            .map(|_| generate_scalar(inner, rng).map(|(_, value)| value))
+            .collect::<Option<Vec<_>>>()?;
+        return Some(json!({"type": "vec", "element_type": annotation, "value": items}));
+    }
+    None
+}
+
+/// Raw JSON value plus the parser annotation for scalar spec types.
+///
+/// One value in four is drawn from the type's boundary values; the rest are random.
+fn generate_scalar(type_name: &str, rng: &mut FuzzRng) -> Option<(&'static str, JsonValue)> {
+    let edge = rng.one_in(4);
+    let value = match type_name {
+        "Bool" => return Some(("bool", JsonValue::Bool(rng.one_in(2)))),
+        "U32" => pick_int(rng, edge, &[0, 1, u32::MAX as i64], |r| r.next_u32() as i64),
+        "I32" => pick_int(
+            rng,
+            edge,
+            &[0, 1, -1, i32::MIN as i64, i32::MAX as i64],
+            |r| r.next_u32() as i32 as i64,
+        ),
+        "U64" | "Timepoint" | "Duration" => {
+            let value = if edge {
+                [0, 1, u64::MAX][rng.below(3)]
+            } else {
+                rng.next_u64()
+            };
+            JsonValue::from(value)
+        }
+        "I64" => pick_int(rng, edge, &[0, 1, -1, i64::MIN, i64::MAX], |r| {
+            r.next_u64() as i64
+        }),
+        // JSON numbers stop at 64 bits, so 128-bit values go as decimal strings.
+        "U128" => JsonValue::String(if edge {
+            [0, 1, u128::MAX][rng.below(3)].to_string()
+        } else {
+            rng.next_u128().to_string()
+        }),
+        "I128" => JsonValue::String(if edge {
+            [0, 1, -1, i128::MIN, i128::MAX][rng.below(5)].to_string()
+        } else {
+            (rng.next_u128() as i128).to_string()
+        }),
+        "U256" => JsonValue::String(if edge {
+            ["0", "1", U256_MAX][rng.below(3)].to_string()
+        } else {
+            rng.next_u128().to_string()
+        }),
+        "I256" => JsonValue::String(if edge {
+            ["0", "1", "-1", I256_MIN, I256_MAX][rng.below(5)].to_string()
+        } else {
+            // Full-width magnitude below 2^255 as hex, with a random sign.
+            let sign = if rng.one_in(2) { "-" } else { "" };
+            let hi = rng.next_u128() >> 1;
+            let lo = rng.next_u128();
+            format!("{sign}0x{hi:032x}{lo:032x}")
+        }),
+        "String" => {
+            let len = rng.below(17);
+            JsonValue::String(
+                (0..len)
+                    .map(|_| (b' ' + rng.below(95) as u8) as char)
+                    .collect(),
+            )
+        }
+        "Symbol" => {
+            const SYMBOL_CHARS: &[u8] =
+                b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789_";
+            let len = rng.below(11);
+            JsonValue::String(
+                (0..len)
+                    .map(|_| SYMBOL_CHARS[rng.below(SYMBOL_CHARS.len())] as char)
+                    .collect(),
+            )
+        }
+        "Address" => JsonValue::String(ADDRESS_POOL[rng.below(ADDRESS_POOL.len())].to_string()),
+        "Bytes" => {
+            let len = rng.below(17);
+            JsonValue::String(random_hex(rng, len))
+        }
+        _ => return None,
+    };
+    let annotation = match type_name {
+        "U32" => "u32",
+        "I32" => "i32",
+        "U64" => "u64",
+        "I64" => "i64",
+        "U128" => "u128",
+        "I128" => "i128",
+        "U256" => "u256",
+        "I256" => "i256",
+        "Timepoint" => "timepoint",
+        "Duration" => "duration",
+        "String" => "string",
+        "Symbol" => "symbol",
+        "Address" => "address",
+        _ => "bytes",
+    };
+    Some((annotation, value))
+}
+
+fn pick_int(
+    rng: &mut FuzzRng,
+    edge: bool,
+    edges: &[i64],
+    random: impl FnOnce(&mut FuzzRng) -> i64,
+) -> JsonValue {
+    let value = if edge {
+        edges[rng.below(edges.len())]
+    } else {
+        random(rng)
+    };
+    JsonValue::from(value)
+}
+
+fn random_hex(rng: &mut FuzzRng, len: usize) -> String {
+    let bytes: Vec<u8> = (0..len).map(|_| rng.next_u32() as u8).collect();
+    format!("0x{}", hex::encode(bytes))
+}
+
+#[derive(Default)]
+pub struct ContractFuzzer;
+
+impl ContractFuzzer {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn fuzz(&self, wasm: &[u8], function: &str, config: &FuzzConfig) -> Result<FuzzReport> {
+        let signature = parse_function_signatures(wasm)?
+            .into_iter()
+            .find(|sig| sig.name == function)
+            .ok_or_else(|| {
+                DebuggerError::InvalidArguments(format!(
+                    "Function '{}' has no spec entry; fuzzing needs its parameter types",
+                    function
+                ))
+            })?;
+
+        let mut rng = FuzzRng::new(config.seed);
+        let mut report = FuzzReport {
+            function: function.to_string(),
+            seed: config.seed,
+            iterations: config.iterations,
+            succeeded: 0,
+            expected_errors: 0,
+            findings: Vec::new(),
+        };
+
+        for iteration in 1..=config.iterations {
+            let inputs = generate_args(&signature.params, &mut rng)?;
+
+            let mut executor = ContractExecutor::new(wasm.to_vec())?;
+            executor.enable_mock_all_auths();
+            executor.set_timeout(config.timeout_secs);
+            if let Some(storage) = &config.storage {
+                executor.set_initial_storage(storage.clone())?;
+            }
+
+            let err = match executor.execute(function, Some(&inputs)) {
+                Ok(_) => {
+                    report.succeeded += 1;
+                    continue;
+                }
+                Err(err) => err,
+            };
+
+            let (kind, code, name) = match err.downcast_ref::<DebuggerError>() {
+                Some(DebuggerError::ContractError { code, .. })
+                    if config.expected_codes.contains(code) =>
+                {
+                    report.expected_errors += 1;
+                    continue;
+                }
+                Some(DebuggerError::ContractError { code, name, .. }) => {
+                    (FuzzFindingKind::ContractError, Some(*code), name.clone())
+                }
+                Some(DebuggerError::ExecutionError(msg))
+                    if msg.starts_with("Contract execution was aborted") =>
+                {
+                    (FuzzFindingKind::Abort, None, None)
+                }
+                _ => (FuzzFindingKind::Error, None, None),
+            };
+            let message = err.to_string();
+
+            let existing = report.findings.iter_mut().find(|f| {
+                f.kind == kind
+                    && f.code == code
+                    && (kind != FuzzFindingKind::Error || f.message == message)
+            });
+            match existing {
+                Some(finding) => finding.occurrences += 1,
+                None => report.findings.push(FuzzFinding {
+                    kind,
+                    code,
+                    name,
+                    message,
+                    first_iteration: iteration,
+                    inputs,
+                    occurrences: 1,
+                }),
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::ArgumentParser;
+    use soroban_sdk::Env;
+
+    fn param(name: &str, type_name: &str) -> FunctionParam {
+        FunctionParam {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+        }
+    }
+
+    #[test]
+    fn same_seed_generates_same_args() {
+        let params = vec![param("asset", "String"), param("price", "I128")];
+        let mut a = FuzzRng::new(42);
+        let mut b = FuzzRng::new(42);
+        for _ in 0..20 {
+            assert_eq!(
+                generate_args(&params, &mut a).unwrap(),
+                generate_args(&params, &mut b).unwrap()
+            );
+        }
+        let mut c = FuzzRng::new(43);
+        assert_ne!(
+            generate_args(&params, &mut FuzzRng::new(42)).unwrap(),
+            generate_args(&params, &mut c).unwrap()
+        );
+    }
+
+    #[test]
+    fn generated_args_parse_for_every_supported_type() {
+        let params: Vec<FunctionParam> = [
+            "Bool",
+            "U32",
+            "I32",
+            "U64",
+            "I64",
+            "U128",
+            "I128",
+            "U256",
+            "I256",
+            "Timepoint",
+            "Duration",
+            "String",
+            "Symbol",
+            "Address",
+            "Bytes",
+            "BytesN<32>",
+            "Option<U32>",
+            "Vec<Symbol>",
+            "Val",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(i, t)| param(&format!("p{}", i), t))
+        .collect();
+
+        let parser = ArgumentParser::new(Env::default());
+        let mut rng = FuzzRng::new(7);
+        for _ in 0..50 {
+            let args = generate_args(&params, &mut rng).unwrap();
+            let vals = parser
+                .parse_args_string(&args)
+                .unwrap_or_else(|e| panic!("{args}: {e}"));
+            assert_eq!(vals.len(), params.len());
+        }
+    }
+
+    #[test]
+    fn i256_values_cover_bounds_and_full_width() {
+        let mut rng = FuzzRng::new(11);
+        let values: Vec<String> = (0..200)
+            .map(|_| match generate_scalar("I256", &mut rng) {
+                Some(("i256", JsonValue::String(value))) => value,
+                other => panic!("unexpected I256 value {other:?}"),
+            })
+            .collect();
+        assert!(values.iter().any(|v| v == I256_MIN));
+        assert!(values.iter().any(|v| v == I256_MAX));
+        // Beyond i128: the upper 128 bits of the magnitude are in use.
+        assert!(values.iter().any(|v| v
+            .trim_start_matches('-')
+            .strip_prefix("0x")
+            .is_some_and(|hex| u128::from_str_radix(&hex[..32], 16).unwrap() > 0)));
+    }
+
+    #[test]
+    fn i128_and_u128_values_cover_bounds_and_full_width() {
+        let mut rng = FuzzRng::new(13);
+        let mut draw = |type_name: &str| -> Vec<String> {
+            (0..200)
+                .map(|_| match generate_scalar(type_name, &mut rng) {
+                    Some((_, JsonValue::String(value))) => value,
+                    other => panic!("unexpected {type_name} value {other:?}"),
+                })
+                .collect()
+        };
+
+        let unsigned: Vec<u128> = draw("U128").iter().map(|v| v.parse().unwrap()).collect();
+        assert!(unsigned.contains(&u128::MAX));
+        assert!(unsigned
+            .iter()
+            .any(|&v| v > u64::MAX as u128 && v != u128::MAX));
+
+        let signed: Vec<i128> = draw("I128").iter().map(|v| v.parse().unwrap()).collect();
+        assert!(signed.contains(&i128::MIN));
+        assert!(signed.contains(&i128::MAX));
+        assert!(signed
+            .iter()
+            .any(|&v| i64::try_from(v).is_err() && v != i128::MIN && v != i128::MAX));
+    }
+
+    #[test]
+    fn unsupported_parameter_type_is_an_error() {
+        let err = generate_args(&[param("key", "DataKey")], &mut FuzzRng::new(1))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("key"), "{err}");
+        assert!(err.contains("DataKey"), "{err}");
+    }
+}
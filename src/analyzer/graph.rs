@@ -1,3 +1,5 @@
+use crate::runtime::mocking::MockCallLogEntry;
+use crate::utils::wasm::{CrossContractCall, EXTERNAL_CONTRACT_TARGET};
 use std::collections::BTreeSet;
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -17,6 +19,35 @@ impl DependencyGraph {
         Self::default()
     }
 
+    /// Static call graph from the call sites found in a WASM module.
+    pub fn from_cross_contract_calls(calls: &[CrossContractCall]) -> Self {
+        let mut graph = Self::new();
+        for call in calls {
+            graph.add_edge(call.caller.as_str(), call.target.as_str());
+        }
+        graph
+    }
+
+    /// Replace `caller`'s placeholder edge with the calls observed at runtime,
+    /// one edge per `CONTRACT_ID::function`.
+    pub fn add_runtime_calls(&mut self, caller: &str, log: &[MockCallLogEntry]) {
+        if log.is_empty() {
+            return;
+        }
+        self.edges
+            .retain(|edge| edge.from != caller || edge.to != EXTERNAL_CONTRACT_TARGET);
+        if !self
+            .edges
+            .iter()
+            .any(|edge| edge.to == EXTERNAL_CONTRACT_TARGET)
+        {
+            self.nodes.remove(EXTERNAL_CONTRACT_TARGET);
+        }
+        for entry in log {
+            self.add_edge(caller, format!("{}::{}", entry.contract_id, entry.function));
+        }
+    }
+
     pub fn add_node(&mut self, node: impl Into<String>) {
         self.nodes.insert(node.into());
     }
@@ -102,6 +133,30 @@ mod tests {
         assert!(dot.contains("\"contract_a\" -> \"token_contract\";"));
     }
 
+    #[test]
+    fn runtime_calls_replace_static_placeholder() {
+        let mut graph = DependencyGraph::from_cross_contract_calls(&[CrossContractCall {
+            caller: "update".to_string(),
+            target: EXTERNAL_CONTRACT_TARGET.to_string(),
+            host_function: "call".to_string(),
+        }]);
+        graph.add_runtime_calls(
+            "update",
+            &[MockCallLogEntry {
+                contract_id: "CORACLE".to_string(),
+                function: "get_price".to_string(),
+                args_count: 0,
+                args: Vec::new(),
+                mocked: true,
+                returned: Some("100".to_string()),
+            }],
+        );
+
+        let dot = graph.to_dot();
+        assert!(dot.contains("\"update\" -> \"CORACLE::get_price\";"));
+        assert!(!dot.contains(EXTERNAL_CONTRACT_TARGET));
+    }
+
     #[test]
     fn mermaid_export_contains_edges() {
         let mut graph = DependencyGraph::new();
@@ -0,0 +1,152 @@
+use super::upgrade::{BreakingChange, UpgradeAnalyzer};
+use crate::utils::wasm::ContractFunctionSignature;
+use crate::{DebuggerError, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+
+/// How one contract measures up against a declared interface
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterfaceConformance {
+    pub contract: String,
+    pub conforms: bool,
+    /// Interface functions that are missing or whose signature differs.
+    pub violations: Vec<BreakingChange>,
+}
+
+impl fmt::Display for InterfaceConformance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = if self.conforms { "CONFORMS" } else { "DRIFTED" };
+        write!(f, "[{}] {}", status, self.contract)?;
+        for violation in &self.violations {
+            match violation {
+                BreakingChange::FunctionRemoved { name } => write!(f, "\n  [MISSING] {}", name)?,
+                other => write!(f, "\n  {}", other)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Conformance of several contracts to one shared interface
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InterfaceReport {
+    pub interface_path: String,
+    pub interface: Vec<ContractFunctionSignature>,
+    pub contracts: Vec<InterfaceConformance>,
+}
+
+impl InterfaceReport {
+    pub fn all_conform(&self) -> bool {
+        self.contracts.iter().all(|c| c.conforms)
+    }
+}
+
+pub struct InterfaceChecker;
+
+impl InterfaceChecker {
+    /// Load an interface file: a JSON array of function signatures, in the same
+    /// shape as `exported_functions` from `inspect --functions --format json`.
+    pub fn load_interface<P: AsRef<Path>>(path: P) -> Result<Vec<ContractFunctionSignature>> {
+        let contents = std::fs::read_to_string(path.as_ref()).map_err(|e| {
+            DebuggerError::FileError(format!(
+                "Failed to read interface file {:?}: {}",
+                path.as_ref(),
+                e
+            ))
+        })?;
+        let interface = serde_json::from_str(&contents).map_err(|e| {
+            DebuggerError::InvalidArguments(format!(
+                "Invalid interface file {:?} (expected a JSON array of function signatures): {}",
+                path.as_ref(),
+                e
+            ))
+        })?;
+        Ok(interface)
+    }
+
+    /// Check a contract's exported functions against `interface`.
+    ///
+    /// Functions the contract exports beyond the interface are ignored.
+    pub fn check(
+        interface: &[ContractFunctionSignature],
+        contract: &str,
+        functions: &[ContractFunctionSignature],
+    ) -> InterfaceConformance {
+        let (violations, _extra) =
+            UpgradeAnalyzer::diff_signatures(interface, functions).into_changes();
+        InterfaceConformance {
+            contract: contract.to_string(),
+            conforms: violations.is_empty(),
+            violations,
+        }
+    }
+
+    /// Check a WASM contract against `interface`
+    pub fn check_wasm(
+        interface: &[ContractFunctionSignature],
+        contract: &str,
+        wasm_bytes: &[u8],
+    ) -> Result<InterfaceConformance> {
+        let functions = crate::utils::wasm::parse_function_signatures(wasm_bytes)?;
+        Ok(Self::check(interface, contract, &functions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::wasm::FunctionParam;
+
+    fn sig(name: &str, params: &[&str], ret: Option<&str>) -> ContractFunctionSignature {
+        ContractFunctionSignature {
+            name: name.to_string(),
+            params: params
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| FunctionParam {
+                    name: format!("p{}", i),
+                    type_name: ty.to_string(),
+                })
+                .collect(),
+            return_type: ret.map(str::to_string),
+        }
+    }
+
+    fn token_interface() -> Vec<ContractFunctionSignature> {
+        vec![
+            sig("transfer", &["Address", "Address", "i128"], None),
+            sig("balance", &["Address"], Some("i128")),
+        ]
+    }
+
+    #[test]
+    fn test_conforming_contract_may_export_extra_functions() {
+        let mut functions = token_interface();
+        functions.push(sig("mint", &["Address", "i128"], None));
+
+        let result = InterfaceChecker::check(&token_interface(), "a.wasm", &functions);
+        assert!(result.conforms);
+        assert!(result.violations.is_empty());
+    }
+
+    #[test]
+    fn test_missing_and_changed_functions_are_violations() {
+        let functions = vec![sig("transfer", &["Address", "i128"], None)];
+
+        let result = InterfaceChecker::check(&token_interface(), "b.wasm", &functions);
+        assert!(!result.conforms);
+        assert!(result.violations.iter().any(|v| matches!(
+            v,
+            BreakingChange::FunctionRemoved { name } if name == "balance"
+        )));
+        assert!(result.violations.iter().any(|v| matches!(
+            v,
+            BreakingChange::ParameterCountChanged { name, .. } if name == "transfer"
+        )));
+
+        let text = result.to_string();
+        assert!(text.starts_with("[DRIFTED] b.wasm"));
+        assert!(text.contains("[MISSING] balance"));
+    }
+}
@@ -1,4 +1,6 @@
+pub mod fuzz;
 pub mod graph;
+pub mod interface;
 pub mod security;
 pub mod symbolic;
 pub mod upgrade;
@@ -112,6 +112,15 @@ struct GeneratedInputs {
     truncated_by_input_cap: bool,
 }
 
+/// Advance a simple 64-bit LCG and return the new state.
+pub(crate) fn lcg_next(state: &mut u64) -> u64 {
+    // Knuth multiplicative hash constants (same as used in many RNG implementations).
+    *state = state
+        .wrapping_mul(6_364_136_223_846_793_005)
+        .wrapping_add(1_442_695_040_888_963_407);
+    *state
+}
+
 /// Shuffles `items` in-place using a seeded Fisher-Yates algorithm backed by a
 /// simple 64-bit LCG.  Given the same seed and the same input slice the result
 /// is always identical, which is the property we rely on for `--replay`.
@@ -122,12 +131,8 @@ fn seeded_shuffle(items: &mut [String], seed: u64) {
     }
     let mut state = seed;
     for i in (1..n).rev() {
-        // Knuth multiplicative hash constants (same as used in many RNG implementations).
-        state = state
-            .wrapping_mul(6_364_136_223_846_793_005)
-            .wrapping_add(1_442_695_040_888_963_407);
         // Use the high 31 bits to avoid modulo bias on small ranges.
-        let j = ((state >> 33) as usize) % (i + 1);
+        let j = ((lcg_next(&mut state) >> 33) as usize) % (i + 1);
         items.swap(i, j);
     }
 }
@@ -75,6 +75,21 @@ pub enum BreakingChange {
         old_types: Vec<WasmType>,
         new_types: Vec<WasmType>,
     },
+    /// Same named, typed parameters in a different order.
+    ParametersReordered {
+        name: String,
+        old_order: Vec<String>,
+        new_order: Vec<String>,
+    },
+    ErrorRemoved {
+        name: String,
+        code: u32,
+    },
+    ErrorCodeChanged {
+        name: String,
+        old_code: u32,
+        new_code: u32,
+    },
 }
 
 impl fmt::Display for BreakingChange {
@@ -121,6 +136,33 @@ impl fmt::Display for BreakingChange {
                     new.join(", ")
                 )
             }
+            BreakingChange::ErrorRemoved { name, code } => {
+                write!(f, "[ERROR_REMOVED] {} = {}", name, code)
+            }
+            BreakingChange::ParametersReordered {
+                name,
+                old_order,
+                new_order,
+            } => {
+                write!(
+                    f,
+                    "[PARAMS_REORDERED] {}: ({}) -> ({})",
+                    name,
+                    old_order.join(", "),
+                    new_order.join(", ")
+                )
+            }
+            BreakingChange::ErrorCodeChanged {
+                name,
+                old_code,
+                new_code,
+            } => {
+                write!(
+                    f,
+                    "[ERROR_CODE_CHANGED] {}: {} -> {}",
+                    name, old_code, new_code
+                )
+            }
         }
     }
 }
@@ -130,16 +172,151 @@ impl fmt::Display for BreakingChange {
 #[serde(tag = "type")]
 pub enum NonBreakingChange {
     FunctionAdded { name: String },
+    ErrorAdded { name: String, code: u32 },
 }
 
 impl fmt::Display for NonBreakingChange {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             NonBreakingChange::FunctionAdded { name } => write!(f, "[ADDED] {}", name),
+            NonBreakingChange::ErrorAdded { name, code } => {
+                write!(f, "[ERROR_ADDED] {} = {}", name, code)
+            }
+        }
+    }
+}
+
+/// Function-level differences between two sets of contract signatures
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SignatureDiff {
+    /// Functions only the new version exports.
+    pub added: Vec<String>,
+    /// Functions only the old version exports.
+    pub removed: Vec<String>,
+    /// Parameter or return type changes to functions present in both.
+    pub changed: Vec<BreakingChange>,
+}
+
+impl SignatureDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Split into report entries: removals and changes are breaking, additions are not.
+    pub fn into_changes(self) -> (Vec<BreakingChange>, Vec<NonBreakingChange>) {
+        let mut breaking: Vec<BreakingChange> = self
+            .removed
+            .into_iter()
+            .map(|name| BreakingChange::FunctionRemoved { name })
+            .collect();
+        breaking.extend(self.changed);
+        let non_breaking = self
+            .added
+            .into_iter()
+            .map(|name| NonBreakingChange::FunctionAdded { name })
+            .collect();
+        (breaking, non_breaking)
+    }
+}
+
+/// A change to a `#[contracttype]` definition that affects persisted storage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum StorageLayoutChange {
+    DataKeyRemoved {
+        key_type: String,
+        variant: String,
+    },
+    DataKeyVariantChanged {
+        key_type: String,
+        variant: String,
+        old_fields: Vec<String>,
+        new_fields: Vec<String>,
+    },
+    DataKeyAdded {
+        key_type: String,
+        variant: String,
+    },
+    StructLayoutChanged {
+        name: String,
+        old_fields: Vec<String>,
+        new_fields: Vec<String>,
+    },
+    StructRemoved {
+        name: String,
+    },
+}
+
+impl StorageLayoutChange {
+    /// Whether existing ledger entries become unreadable or orphaned after the upgrade
+    pub fn is_breaking(&self) -> bool {
+        !matches!(self, StorageLayoutChange::DataKeyAdded { .. })
+    }
+}
+
+impl fmt::Display for StorageLayoutChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageLayoutChange::DataKeyRemoved { key_type, variant } => {
+                write!(f, "[KEY_REMOVED] {}::{}", key_type, variant)
+            }
+            StorageLayoutChange::DataKeyVariantChanged {
+                key_type,
+                variant,
+                old_fields,
+                new_fields,
+            } => write!(
+                f,
+                "[KEY_CHANGED] {}::{}({}) -> {}::{}({})",
+                key_type,
+                variant,
+                old_fields.join(", "),
+                key_type,
+                variant,
+                new_fields.join(", ")
+            ),
+            StorageLayoutChange::DataKeyAdded { key_type, variant } => {
+                write!(f, "[KEY_ADDED] {}::{}", key_type, variant)
+            }
+            StorageLayoutChange::StructLayoutChanged {
+                name,
+                old_fields,
+                new_fields,
+            } => write!(
+                f,
+                "[STRUCT_CHANGED] {}: {{{}}} -> {{{}}}",
+                name,
+                old_fields.join(", "),
+                new_fields.join(", ")
+            ),
+            StorageLayoutChange::StructRemoved { name } => {
+                write!(f, "[STRUCT_REMOVED] {}", name)
+            }
         }
     }
 }
 
+/// A parameter or return type that could not be resolved on either side, so an
+/// unchanged type name may still hide a change. Reported under `--strict-types`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnresolvedTypeWarning {
+    pub name: String,
+    /// `param[N]` or `return`.
+    pub location: String,
+    pub old_type: String,
+    pub new_type: String,
+}
+
+impl fmt::Display for UnresolvedTypeWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[NEEDS_REVIEW] {} {}: {} -> {} (type could not be resolved)",
+            self.name, self.location, self.old_type, self.new_type
+        )
+    }
+}
+
 /// Execution result comparison when --test-inputs is provided
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutionDiff {
@@ -158,6 +335,14 @@ pub struct CompatibilityReport {
     pub new_wasm_path: String,
     pub breaking_changes: Vec<BreakingChange>,
     pub non_breaking_changes: Vec<NonBreakingChange>,
+    #[serde(default)]
+    pub storage_changes: Vec<StorageLayoutChange>,
+    /// Types needing manual review; only filled in strict mode.
+    #[serde(default)]
+    pub type_warnings: Vec<UnresolvedTypeWarning>,
+    /// Set when the two binaries were built with different soroban-sdk major versions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sdk_warning: Option<String>,
     pub old_functions: Vec<crate::utils::wasm::ContractFunctionSignature>,
     pub new_functions: Vec<crate::utils::wasm::ContractFunctionSignature>,
     pub execution_diffs: Vec<ExecutionDiff>,
@@ -166,22 +351,54 @@ pub struct CompatibilityReport {
 pub struct UpgradeAnalyzer;
 
 impl UpgradeAnalyzer {
-    /// Analyze two WASM binaries and produce a compatibility report
+    /// Analyze two WASM binaries and produce a compatibility report.
+    ///
+    /// With `strict_types`, matching types that cannot be resolved are
+    /// reported as warnings instead of being treated as unchanged.
     pub fn analyze(
         old_wasm: &[u8],
         new_wasm: &[u8],
         old_path: &str,
         new_path: &str,
         execution_diffs: Vec<ExecutionDiff>,
+        strict_types: bool,
     ) -> Result<CompatibilityReport> {
         let old_functions = crate::utils::wasm::parse_function_signatures(old_wasm)?;
         let new_functions = crate::utils::wasm::parse_function_signatures(new_wasm)?;
 
-        let (breaking_changes, non_breaking_changes) =
-            Self::diff_signatures(&old_functions, &new_functions);
+        let (mut breaking_changes, mut non_breaking_changes) =
+            Self::diff_signatures(&old_functions, &new_functions).into_changes();
+
+        let type_warnings = if strict_types {
+            Self::unresolved_types(&old_functions, &new_functions)
+        } else {
+            Vec::new()
+        };
+
+        let (breaking_errors, added_errors) = Self::diff_errors(
+            &crate::utils::wasm::parse_custom_errors(old_wasm)?,
+            &crate::utils::wasm::parse_custom_errors(new_wasm)?,
+        );
+        breaking_changes.extend(breaking_errors);
+        non_breaking_changes.extend(added_errors);
+
+        let storage_changes = Self::diff_storage_layout(
+            &crate::utils::wasm::parse_contract_unions(old_wasm)?,
+            &crate::utils::wasm::parse_contract_unions(new_wasm)?,
+            &crate::utils::wasm::parse_contract_structs(old_wasm)?,
+            &crate::utils::wasm::parse_contract_structs(new_wasm)?,
+        );
+
+        let sdk_warning = Self::sdk_mismatch(
+            &crate::utils::wasm::parse_metadata(old_wasm)?,
+            &crate::utils::wasm::parse_metadata(new_wasm)?,
+        );
 
         let has_execution_mismatches = execution_diffs.iter().any(|d| !d.outputs_match);
-        let is_compatible = breaking_changes.is_empty() && !has_execution_mismatches;
+        let has_breaking_storage_changes = storage_changes.iter().any(|c| c.is_breaking());
+        let is_compatible = breaking_changes.is_empty()
+            && !has_breaking_storage_changes
+            && !has_execution_mismatches;
 
         Ok(CompatibilityReport {
             is_compatible,
@@ -189,17 +406,35 @@ impl UpgradeAnalyzer {
             new_wasm_path: new_path.to_string(),
             breaking_changes,
             non_breaking_changes,
+            storage_changes,
+            type_warnings,
+            sdk_warning,
             old_functions,
             new_functions,
             execution_diffs,
         })
     }
 
-    /// Compute breaking and non-breaking changes between two sets of function signatures
-    fn diff_signatures(
+    /// Describe a soroban-sdk major version mismatch between two `contractmetav0` maps.
+    pub(crate) fn sdk_mismatch(
+        old: &std::collections::BTreeMap<String, String>,
+        new: &std::collections::BTreeMap<String, String>,
+    ) -> Option<String> {
+        let old_major = crate::utils::wasm::sdk_major_version(old)?;
+        let new_major = crate::utils::wasm::sdk_major_version(new)?;
+        (old_major != new_major).then(|| {
+            format!(
+                "Built with different soroban-sdk major versions: {} -> {}",
+                old["rssdkver"], new["rssdkver"]
+            )
+        })
+    }
+
+    /// Compute added, removed and changed functions between two sets of signatures
+    pub fn diff_signatures(
         old: &[crate::utils::wasm::ContractFunctionSignature],
         new: &[crate::utils::wasm::ContractFunctionSignature],
-    ) -> (Vec<BreakingChange>, Vec<NonBreakingChange>) {
+    ) -> SignatureDiff {
         use std::collections::BTreeMap;
 
         let old_by_name: BTreeMap<&str, &crate::utils::wasm::ContractFunctionSignature> =
@@ -207,22 +442,17 @@ impl UpgradeAnalyzer {
         let new_by_name: BTreeMap<&str, &crate::utils::wasm::ContractFunctionSignature> =
             new.iter().map(|sig| (sig.name.as_str(), sig)).collect();
 
-        let mut breaking = Vec::new();
-        let mut non_breaking = Vec::new();
+        let mut diff = SignatureDiff::default();
 
         for name in old_by_name.keys() {
             if !new_by_name.contains_key(name) {
-                breaking.push(BreakingChange::FunctionRemoved {
-                    name: (*name).to_string(),
-                });
+                diff.removed.push((*name).to_string());
             }
         }
 
         for name in new_by_name.keys() {
             if !old_by_name.contains_key(name) {
-                non_breaking.push(NonBreakingChange::FunctionAdded {
-                    name: (*name).to_string(),
-                });
+                diff.added.push((*name).to_string());
             }
         }
 
@@ -232,7 +462,7 @@ impl UpgradeAnalyzer {
             };
 
             if old_sig.params.len() != new_sig.params.len() {
-                breaking.push(BreakingChange::ParameterCountChanged {
+                diff.changed.push(BreakingChange::ParameterCountChanged {
                     name: (*name).to_string(),
                     old_count: old_sig.params.len(),
                     new_count: new_sig.params.len(),
@@ -240,21 +470,29 @@ impl UpgradeAnalyzer {
                 continue;
             }
 
-            for (idx, (old_param, new_param)) in
-                old_sig.params.iter().zip(new_sig.params.iter()).enumerate()
-            {
-                if old_param.type_name != new_param.type_name {
-                    breaking.push(BreakingChange::ParameterTypeChanged {
-                        name: (*name).to_string(),
-                        index: idx,
-                        old_type: parse_contract_type_to_wasm_type(&old_param.type_name),
-                        new_type: parse_contract_type_to_wasm_type(&new_param.type_name),
-                    });
+            if is_reordering(&old_sig.params, &new_sig.params) {
+                diff.changed.push(BreakingChange::ParametersReordered {
+                    name: (*name).to_string(),
+                    old_order: old_sig.params.iter().map(|p| p.name.clone()).collect(),
+                    new_order: new_sig.params.iter().map(|p| p.name.clone()).collect(),
+                });
+            } else {
+                for (idx, (old_param, new_param)) in
+                    old_sig.params.iter().zip(new_sig.params.iter()).enumerate()
+                {
+                    if old_param.type_name != new_param.type_name {
+                        diff.changed.push(BreakingChange::ParameterTypeChanged {
+                            name: (*name).to_string(),
+                            index: idx,
+                            old_type: parse_contract_type_to_wasm_type(&old_param.type_name),
+                            new_type: parse_contract_type_to_wasm_type(&new_param.type_name),
+                        });
+                    }
                 }
             }
 
             if old_sig.return_type != new_sig.return_type {
-                breaking.push(BreakingChange::ReturnTypeChanged {
+                diff.changed.push(BreakingChange::ReturnTypeChanged {
                     name: (*name).to_string(),
                     old_types: old_sig
                         .return_type
@@ -270,8 +508,160 @@ impl UpgradeAnalyzer {
             }
         }
 
+        diff
+    }
+
+    /// Find params and returns of functions present in both versions whose
+    /// type names match but resolve to [`WasmType::Unknown`] on either side.
+    fn unresolved_types(
+        old: &[crate::utils::wasm::ContractFunctionSignature],
+        new: &[crate::utils::wasm::ContractFunctionSignature],
+    ) -> Vec<UnresolvedTypeWarning> {
+        let mut warnings = Vec::new();
+        let mut check = |name: &str, location: String, old_type: &str, new_type: &str| {
+            if old_type == new_type
+                && (is_unresolved_type(old_type) || is_unresolved_type(new_type))
+            {
+                warnings.push(UnresolvedTypeWarning {
+                    name: name.to_string(),
+                    location,
+                    old_type: old_type.to_string(),
+                    new_type: new_type.to_string(),
+                });
+            }
+        };
+
+        for old_sig in old {
+            let Some(new_sig) = new.iter().find(|s| s.name == old_sig.name) else {
+                continue;
+            };
+            if old_sig.params.len() == new_sig.params.len() {
+                for (idx, (old_param, new_param)) in
+                    old_sig.params.iter().zip(new_sig.params.iter()).enumerate()
+                {
+                    check(
+                        &old_sig.name,
+                        format!("param[{}]", idx),
+                        &old_param.type_name,
+                        &new_param.type_name,
+                    );
+                }
+            }
+            if let (Some(old_ret), Some(new_ret)) = (&old_sig.return_type, &new_sig.return_type) {
+                check(&old_sig.name, "return".to_string(), old_ret, new_ret);
+            }
+        }
+        warnings
+    }
+
+    /// Compute `#[contracterror]` changes; clients matching on numeric codes break
+    /// when a variant is removed or renumbered
+    fn diff_errors(
+        old: &[crate::utils::wasm::CustomError],
+        new: &[crate::utils::wasm::CustomError],
+    ) -> (Vec<BreakingChange>, Vec<NonBreakingChange>) {
+        let mut breaking = Vec::new();
+        let mut non_breaking = Vec::new();
+
+        for old_err in old {
+            match new.iter().find(|e| e.name == old_err.name) {
+                None => breaking.push(BreakingChange::ErrorRemoved {
+                    name: old_err.name.clone(),
+                    code: old_err.code,
+                }),
+                Some(new_err) if new_err.code != old_err.code => {
+                    breaking.push(BreakingChange::ErrorCodeChanged {
+                        name: old_err.name.clone(),
+                        old_code: old_err.code,
+                        new_code: new_err.code,
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+
+        for new_err in new {
+            if !old.iter().any(|e| e.name == new_err.name) {
+                non_breaking.push(NonBreakingChange::ErrorAdded {
+                    name: new_err.name.clone(),
+                    code: new_err.code,
+                });
+            }
+        }
+
         (breaking, non_breaking)
     }
+
+    /// Compute storage layout changes between two sets of `#[contracttype]` definitions
+    fn diff_storage_layout(
+        old_unions: &[crate::utils::wasm::ContractUnion],
+        new_unions: &[crate::utils::wasm::ContractUnion],
+        old_structs: &[crate::utils::wasm::ContractStruct],
+        new_structs: &[crate::utils::wasm::ContractStruct],
+    ) -> Vec<StorageLayoutChange> {
+        let mut changes = Vec::new();
+
+        for old_union in old_unions {
+            let new_union = new_unions.iter().find(|u| u.name == old_union.name);
+            for old_case in &old_union.cases {
+                let new_case =
+                    new_union.and_then(|u| u.cases.iter().find(|c| c.name == old_case.name));
+                match new_case {
+                    None => changes.push(StorageLayoutChange::DataKeyRemoved {
+                        key_type: old_union.name.clone(),
+                        variant: old_case.name.clone(),
+                    }),
+                    Some(new_case) if new_case.fields != old_case.fields => {
+                        changes.push(StorageLayoutChange::DataKeyVariantChanged {
+                            key_type: old_union.name.clone(),
+                            variant: old_case.name.clone(),
+                            old_fields: old_case.fields.clone(),
+                            new_fields: new_case.fields.clone(),
+                        })
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        for new_union in new_unions {
+            let old_union = old_unions.iter().find(|u| u.name == new_union.name);
+            for new_case in &new_union.cases {
+                let existed =
+                    old_union.is_some_and(|u| u.cases.iter().any(|c| c.name == new_case.name));
+                if !existed {
+                    changes.push(StorageLayoutChange::DataKeyAdded {
+                        key_type: new_union.name.clone(),
+                        variant: new_case.name.clone(),
+                    });
+                }
+            }
+        }
+
+        let describe = |s: &crate::utils::wasm::ContractStruct| -> Vec<String> {
+            s.fields
+                .iter()
+                .map(|f| format!("{}: {}", f.name, f.type_name))
+                .collect()
+        };
+        for old_struct in old_structs {
+            let Some(new_struct) = new_structs.iter().find(|s| s.name == old_struct.name) else {
+                changes.push(StorageLayoutChange::StructRemoved {
+                    name: old_struct.name.clone(),
+                });
+                continue;
+            };
+            if old_struct.fields != new_struct.fields {
+                changes.push(StorageLayoutChange::StructLayoutChanged {
+                    name: old_struct.name.clone(),
+                    old_fields: describe(old_struct),
+                    new_fields: describe(new_struct),
+                });
+            }
+        }
+
+        changes
+    }
 }
 
 fn parse_contract_type_to_wasm_type(type_name: &str) -> WasmType {
@@ -287,6 +677,93 @@ fn parse_contract_type_to_wasm_type(type_name: &str) -> WasmType {
     }
 }
 
+/// A type is unresolved when it maps to [`WasmType::Unknown`] and is not a
+/// Soroban spec type either: `Val` (accepts anything), unreadable UDT names,
+/// or generics wrapping such a type.
+fn is_unresolved_type(type_name: &str) -> bool {
+    const SPEC_TYPES: &[&str] = &[
+        "Bool",
+        "Void",
+        "Error",
+        "U32",
+        "I32",
+        "U64",
+        "I64",
+        "Timepoint",
+        "Duration",
+        "U128",
+        "I128",
+        "U256",
+        "I256",
+        "Bytes",
+        "String",
+        "Symbol",
+        "Address",
+    ];
+    let type_name = type_name.trim();
+    if parse_contract_type_to_wasm_type(type_name) != WasmType::Unknown
+        || SPEC_TYPES.contains(&type_name)
+    {
+        return false;
+    }
+    if let Some((outer, inner)) = type_name.strip_suffix('>').and_then(|t| t.split_once('<')) {
+        if outer == "BytesN" {
+            return inner.parse::<u32>().is_err();
+        }
+        return !matches!(outer, "Option" | "Result" | "Vec" | "Map" | "Tuple")
+            || split_generic_args(inner)
+                .into_iter()
+                .any(is_unresolved_type);
+    }
+    // Anything else should be a user-defined type name.
+    matches!(type_name, "Val" | "Udt" | "")
+        || !type_name.starts_with(|c: char| c.is_ascii_uppercase())
+        || !type_name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn split_generic_args(args: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in args.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&args[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&args[start..]);
+    parts
+}
+
+/// Whether `new` holds exactly the same `name: type` params as `old` in a different order.
+///
+/// A rename or a genuine type change leaves the sorted lists unequal, so only a
+/// pure permutation qualifies.
+fn is_reordering(
+    old: &[crate::utils::wasm::FunctionParam],
+    new: &[crate::utils::wasm::FunctionParam],
+) -> bool {
+    if old == new {
+        return false;
+    }
+    fn sorted(params: &[crate::utils::wasm::FunctionParam]) -> Vec<(&str, &str)> {
+        let mut pairs: Vec<(&str, &str)> = params
+            .iter()
+            .map(|p| (p.name.as_str(), p.type_name.as_str()))
+            .collect();
+        pairs.sort_unstable();
+        pairs
+    }
+    sorted(old) == sorted(new)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -302,12 +779,11 @@ mod tests {
     #[test]
     fn test_diff_signatures_no_changes() {
         let sig = sig("test");
-        let (breaking, non_breaking) = UpgradeAnalyzer::diff_signatures(
+        let diff = UpgradeAnalyzer::diff_signatures(
             std::slice::from_ref(&sig),
             std::slice::from_ref(&sig),
         );
-        assert!(breaking.is_empty());
-        assert!(non_breaking.is_empty());
+        assert!(diff.is_empty());
     }
 
     #[test]
@@ -315,8 +791,12 @@ mod tests {
         let sig1 = sig("foo");
         let sig2 = sig("bar");
 
-        let (breaking, non_breaking) = UpgradeAnalyzer::diff_signatures(&[sig1], &[sig2]);
+        let diff = UpgradeAnalyzer::diff_signatures(&[sig1], &[sig2]);
+        assert_eq!(diff.removed, ["foo"]);
+        assert_eq!(diff.added, ["bar"]);
+        assert!(diff.changed.is_empty());
 
+        let (breaking, non_breaking) = diff.into_changes();
         assert!(breaking.iter().any(|change| matches!(
             change,
             BreakingChange::FunctionRemoved { name } if name == "foo"
@@ -346,9 +826,10 @@ mod tests {
             return_type: Some("i32".to_string()),
         };
 
-        let (breaking, non_breaking) = UpgradeAnalyzer::diff_signatures(&[old], &[new]);
+        let diff = UpgradeAnalyzer::diff_signatures(&[old], &[new]);
 
-        assert!(non_breaking.is_empty());
+        assert!(diff.added.is_empty() && diff.removed.is_empty());
+        let breaking = diff.changed;
         assert!(breaking.iter().any(|change| matches!(
             change,
             BreakingChange::ParameterTypeChanged { name, index, .. } if name == "transfer" && *index == 0
@@ -359,6 +840,146 @@ mod tests {
         )));
     }
 
+    fn union(name: &str, cases: &[(&str, &[&str])]) -> crate::utils::wasm::ContractUnion {
+        crate::utils::wasm::ContractUnion {
+            name: name.to_string(),
+            cases: cases
+                .iter()
+                .map(|(case, fields)| crate::utils::wasm::UnionCase {
+                    name: case.to_string(),
+                    fields: fields.iter().map(|f| f.to_string()).collect(),
+                })
+                .collect(),
+        }
+    }
+
+    /// `DataKey` from the oracle example.
+    fn oracle_v1_keys() -> Vec<crate::utils::wasm::ContractUnion> {
+        vec![union(
+            "DataKey",
+            &[("Admin", &[]), ("Price", &["String"]), ("Decimals", &[])],
+        )]
+    }
+
+    /// The same contract after `Price` switched to `Symbol` and `Decimals` was dropped.
+    fn oracle_v2_keys() -> Vec<crate::utils::wasm::ContractUnion> {
+        vec![union(
+            "DataKey",
+            &[("Admin", &[]), ("Price", &["Symbol"]), ("Updater", &[])],
+        )]
+    }
+
+    #[test]
+    fn test_diff_storage_layout_flags_key_changes() {
+        let changes =
+            UpgradeAnalyzer::diff_storage_layout(&oracle_v1_keys(), &oracle_v2_keys(), &[], &[]);
+
+        assert!(changes.iter().any(|change| matches!(
+            change,
+            StorageLayoutChange::DataKeyVariantChanged { key_type, variant, old_fields, new_fields }
+                if key_type == "DataKey"
+                    && variant == "Price"
+                    && old_fields == &["String"]
+                    && new_fields == &["Symbol"]
+        )));
+        assert!(changes.iter().any(|change| matches!(
+            change,
+            StorageLayoutChange::DataKeyRemoved { variant, .. } if variant == "Decimals"
+        )));
+        let added: Vec<_> = changes.iter().filter(|c| !c.is_breaking()).collect();
+        assert_eq!(added.len(), 1);
+        assert!(matches!(
+            added[0],
+            StorageLayoutChange::DataKeyAdded { variant, .. } if variant == "Updater"
+        ));
+    }
+
+    #[test]
+    fn test_diff_storage_layout_identical_specs() {
+        let changes =
+            UpgradeAnalyzer::diff_storage_layout(&oracle_v1_keys(), &oracle_v1_keys(), &[], &[]);
+        assert!(changes.is_empty());
+    }
+
+    #[test]
+    fn test_diff_storage_layout_struct_field_change() {
+        let field = |name: &str, type_name: &str| crate::utils::wasm::StructField {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+        };
+        let old = crate::utils::wasm::ContractStruct {
+            name: "PriceData".to_string(),
+            fields: vec![field("price", "I128"), field("timestamp", "U64")],
+        };
+        let new = crate::utils::wasm::ContractStruct {
+            name: "PriceData".to_string(),
+            fields: vec![field("price", "I128")],
+        };
+
+        let changes =
+            UpgradeAnalyzer::diff_storage_layout(&[], &[], std::slice::from_ref(&old), &[new]);
+        assert_eq!(changes.len(), 1);
+        assert!(changes[0].is_breaking());
+        assert_eq!(
+            changes[0].to_string(),
+            "[STRUCT_CHANGED] PriceData: {price: I128, timestamp: U64} -> {price: I128}"
+        );
+
+        let changes = UpgradeAnalyzer::diff_storage_layout(&[], &[], &[old], &[]);
+        assert_eq!(changes.len(), 1);
+        assert!(changes[0].is_breaking());
+        assert_eq!(changes[0].to_string(), "[STRUCT_REMOVED] PriceData");
+    }
+
+    fn error(name: &str, code: u32) -> crate::utils::wasm::CustomError {
+        crate::utils::wasm::CustomError {
+            code,
+            name: name.to_string(),
+            doc: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_diff_errors_oracle_renumbered_error() {
+        let old = vec![error("NotInitialized", 1), error("InvalidPrice", 2)];
+        let new = vec![error("NotInitialized", 1), error("InvalidPrice", 6)];
+
+        let (breaking, non_breaking) = UpgradeAnalyzer::diff_errors(&old, &new);
+
+        assert!(non_breaking.is_empty());
+        assert_eq!(breaking.len(), 1);
+        assert!(matches!(
+            &breaking[0],
+            BreakingChange::ErrorCodeChanged { name, old_code, new_code }
+                if name == "InvalidPrice" && *old_code == 2 && *new_code == 6
+        ));
+        assert_eq!(
+            breaking[0].to_string(),
+            "[ERROR_CODE_CHANGED] InvalidPrice: 2 -> 6"
+        );
+    }
+
+    #[test]
+    fn test_diff_errors_removed_and_added() {
+        let old = vec![error("NotInitialized", 1), error("StalePrice", 3)];
+        let new = vec![error("NotInitialized", 1), error("Unauthorized", 4)];
+
+        let (breaking, non_breaking) = UpgradeAnalyzer::diff_errors(&old, &new);
+
+        assert!(matches!(
+            &breaking[..],
+            [BreakingChange::ErrorRemoved { name, code }] if name == "StalePrice" && *code == 3
+        ));
+        assert!(matches!(
+            &non_breaking[..],
+            [NonBreakingChange::ErrorAdded { name, code }] if name == "Unauthorized" && *code == 4
+        ));
+
+        let json = serde_json::to_value(&breaking[0]).unwrap();
+        assert_eq!(json["type"], "ErrorRemoved");
+        assert_eq!(json["code"], 3);
+    }
+
     #[test]
     fn test_diff_signatures_param_count_changed() {
         let old = crate::utils::wasm::ContractFunctionSignature {
@@ -384,7 +1005,7 @@ mod tests {
             return_type: None,
         };
 
-        let (breaking, _) = UpgradeAnalyzer::diff_signatures(&[old], &[new]);
+        let breaking = UpgradeAnalyzer::diff_signatures(&[old], &[new]).changed;
 
         assert!(breaking.iter().any(|change| matches!(
             change,
@@ -392,4 +1013,114 @@ mod tests {
                 if name == "mint" && *old_count == 1 && *new_count == 2
         )));
     }
+
+    fn sig_with(
+        name: &str,
+        params: &[&str],
+        ret: Option<&str>,
+    ) -> crate::utils::wasm::ContractFunctionSignature {
+        crate::utils::wasm::ContractFunctionSignature {
+            name: name.to_string(),
+            params: params
+                .iter()
+                .enumerate()
+                .map(|(i, ty)| crate::utils::wasm::FunctionParam {
+                    name: format!("p{}", i),
+                    type_name: ty.to_string(),
+                })
+                .collect(),
+            return_type: ret.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_unknown_types_on_both_sides_warn_in_strict_mode() {
+        let old = sig_with("relay", &["Address", "Val"], Some("Val"));
+        let new = old.clone();
+
+        // Default mode: identical names look unchanged.
+        let diff = UpgradeAnalyzer::diff_signatures(
+            std::slice::from_ref(&old),
+            std::slice::from_ref(&new),
+        );
+        assert!(diff.is_empty());
+
+        let warnings = UpgradeAnalyzer::unresolved_types(&[old], &[new]);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].location, "param[1]");
+        assert_eq!(warnings[1].location, "return");
+        assert_eq!(
+            warnings[0].to_string(),
+            "[NEEDS_REVIEW] relay param[1]: Val -> Val (type could not be resolved)"
+        );
+    }
+
+    #[test]
+    fn test_resolved_spec_types_do_not_warn() {
+        for ty in [
+            "I128",
+            "Option<Address>",
+            "Map<Symbol, Vec<U32>>",
+            "BytesN<32>",
+            "DataKey",
+        ] {
+            assert!(!is_unresolved_type(ty), "{ty}");
+        }
+        for ty in ["Val", "Vec<Val>", "Udt", "Map<Symbol, Val>"] {
+            assert!(is_unresolved_type(ty), "{ty}");
+        }
+    }
+
+    #[test]
+    fn test_sdk_major_mismatch_is_reported() {
+        let meta = |ver: &str| {
+            std::collections::BTreeMap::from([("rssdkver".to_string(), ver.to_string())])
+        };
+
+        let warning = UpgradeAnalyzer::sdk_mismatch(&meta("21.7.1#abc"), &meta("22.0.11#def"));
+        assert_eq!(
+            warning.as_deref(),
+            Some("Built with different soroban-sdk major versions: 21.7.1#abc -> 22.0.11#def")
+        );
+        assert!(UpgradeAnalyzer::sdk_mismatch(&meta("22.0.1"), &meta("22.0.11")).is_none());
+        assert!(
+            UpgradeAnalyzer::sdk_mismatch(&meta("22.0.1"), &std::collections::BTreeMap::new())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_swapped_params_are_reported_as_reordered() {
+        // Same types: positional types still match, so only the names reveal the swap.
+        let old = sig_with("transfer", &["String", "String"], None);
+        let mut new = old.clone();
+        new.params.swap(0, 1);
+        let changed = UpgradeAnalyzer::diff_signatures(&[old], &[new]).changed;
+        assert_eq!(changed.len(), 1);
+        assert_eq!(
+            changed[0].to_string(),
+            "[PARAMS_REORDERED] transfer: (p0, p1) -> (p1, p0)"
+        );
+
+        // Different types: reported once as a reorder rather than as two type changes.
+        let old = sig_with("deposit", &["Address", "I128"], None);
+        let mut new = old.clone();
+        new.params.swap(0, 1);
+        let changed = UpgradeAnalyzer::diff_signatures(&[old], &[new]).changed;
+        assert!(matches!(
+            &changed[..],
+            [BreakingChange::ParametersReordered { name, .. }] if name == "deposit"
+        ));
+    }
+
+    #[test]
+    fn test_type_change_is_not_reported_as_reorder() {
+        let old = sig_with("deposit", &["Address", "I128"], None);
+        let new = sig_with("deposit", &["I128", "Address"], None);
+        let changed = UpgradeAnalyzer::diff_signatures(&[old], &[new]).changed;
+        assert_eq!(changed.len(), 2);
+        assert!(changed
+            .iter()
+            .all(|c| matches!(c, BreakingChange::ParameterTypeChanged { .. })));
+    }
 }
@@ -1,4 +1,5 @@
 use crate::runtime::executor::ContractExecutor;
+use crate::runtime::metrics::SessionMetrics;
 use crate::DebuggerError;
 use crate::Result;
 use rayon::prelude::*;
@@ -7,7 +8,7 @@ use serde_json::Value;
 use std::cell::RefCell;
 use std::fs;
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::thread_local;
 use std::time::Instant;
 
@@ -70,6 +71,8 @@ pub struct BatchSummary {
 pub struct BatchExecutor {
     wasm_bytes: Arc<Vec<u8>>,
     function: String,
+    /// Metrics of every item run so far, across all worker threads.
+    metrics: Mutex<SessionMetrics>,
 }
 
 // Thread-local storage for executors to avoid re-initialization
@@ -83,9 +86,38 @@ impl BatchExecutor {
         Ok(Self {
             wasm_bytes: Arc::new(wasm_bytes),
             function,
+            metrics: Mutex::new(SessionMetrics::new()),
         })
     }
 
+    /// Metrics of the items executed so far, for `--metrics-out`.
+    pub fn metrics(&self) -> SessionMetrics {
+        self.metrics
+            .lock()
+            .map(|metrics| metrics.clone())
+            .unwrap_or_default()
+    }
+
+    /// Execute the batch function once on `executor`, counting it in the metrics.
+    fn execute_item(
+        &self,
+        executor: &mut ContractExecutor,
+        item: &BatchItem,
+    ) -> (String, bool, Option<String>) {
+        let result = executor.execute(&self.function, Some(&item.args));
+        if let Ok(mut metrics) = self.metrics.lock() {
+            let budget = match &result {
+                Ok(_) => executor.last_execution().map(|record| &record.budget),
+                Err(_) => None,
+            };
+            metrics.record(&self.function, budget, result.as_ref().err());
+        }
+        match result {
+            Ok(result) => (result, true, None),
+            Err(e) => (String::new(), false, Some(format!("{:#}", e))),
+        }
+    }
+
     /// Load batch items from a JSON file
     pub fn load_batch_file<P: AsRef<Path>>(path: P) -> Result<Vec<BatchItem>> {
         let content = fs::read_to_string(path.as_ref()).map_err(|e| {
@@ -135,10 +167,7 @@ impl BatchExecutor {
                 if Arc::ptr_eq(wasm_bytes, &self.wasm_bytes) {
                     // Reuse existing executor
                     if let Some(executor) = executor_ref.as_mut() {
-                        return match executor.1.execute(&self.function, Some(&item.args)) {
-                            Ok(result) => (result, true, None),
-                            Err(e) => (String::new(), false, Some(format!("{:#}", e))),
-                        };
+                        return self.execute_item(&mut executor.1, item);
                     }
                 }
             }
@@ -146,10 +175,7 @@ impl BatchExecutor {
             // Create new executor
             match ContractExecutor::new((*self.wasm_bytes).clone()) {
                 Ok(mut executor) => {
-                    let result = match executor.execute(&self.function, Some(&item.args)) {
-                        Ok(result) => (result, true, None),
-                        Err(e) => (String::new(), false, Some(format!("{:#}", e))),
-                    };
+                    let result = self.execute_item(&mut executor, item);
                     *executor_ref = Some((Arc::clone(&self.wasm_bytes), executor));
                     result
                 }
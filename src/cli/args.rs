@@ -12,6 +12,9 @@ pub const DEPRECATED_FLAGS: &[(&str, &str)] = &[
     ("--snapshot", "--network-snapshot"),
 ];
 
+/// Result display limit used on a terminal when `--max-output-len` is not given.
+pub const DEFAULT_TTY_OUTPUT_LEN: usize = 2000;
+
 /// Get a deprecation warning message for a deprecated flag
 /// Returns None if the flag is not deprecated
 pub fn get_deprecation_warning(deprecated_flag: &str) -> Option<String> {
@@ -41,6 +44,19 @@ pub enum OutputFormat {
     Json,
 }
 
+/// Result format for `run --output`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
+pub enum RunOutputFormat {
+    /// Human-readable sections
+    #[default]
+    #[value(name = "text", alias = "pretty")]
+    Pretty,
+    Json,
+    Yaml,
+    /// `section,key,before,after` rows for the result, storage diff and events
+    Csv,
+}
+
 /// Export format for profiler output (issue #502).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
 pub enum ProfileExportFormat {
@@ -50,6 +66,40 @@ pub enum ProfileExportFormat {
     Json,
 }
 
+/// Layout of the file written by `--export-storage`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
+pub enum StorageExportFormat {
+    /// Stringified keys, re-importable with `--import-storage`
+    #[default]
+    Flat,
+    /// Decoded values nested by `#[contracttype]` enum variant
+    Typed,
+}
+
+/// How `run` reports storage changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
+pub enum StorageDiffFormat {
+    /// Colour-coded list of added, modified and deleted keys
+    #[default]
+    #[value(name = "text", alias = "human")]
+    Text,
+    /// RFC 6902 JSON Patch over the typed (DataKey-grouped) storage view
+    #[value(name = "jsonpatch", alias = "json-patch")]
+    JsonPatch,
+}
+
+/// Which host diagnostic events `run --show-diagnostics` prints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
+pub enum DiagnosticsLevel {
+    /// Every diagnostic event, including `fn_call`/`fn_return`
+    #[default]
+    All,
+    /// `log` and `error` events
+    Log,
+    /// Only `error` events
+    Error,
+}
+
 /// Format for dependency graph output.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 pub enum GraphFormat {
@@ -57,6 +107,14 @@ pub enum GraphFormat {
     Mermaid,
 }
 
+/// Rendering for the upgrade-check compatibility report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum UpgradeReportFormat {
+    Text,
+    Json,
+    Markdown,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
 pub enum SymbolicProfile {
     Fast,
@@ -93,6 +151,10 @@ pub struct Cli {
     #[arg(long, global = true)]
     pub no_banner: bool,
 
+    /// On failure, print the error to stdout as JSON (`error_kind`, `message`, `contract_code`)
+    #[arg(long, global = true)]
+    pub json_errors: bool,
+
     /// Override the history file location (useful for CI, sandboxes, and per-project isolation)
     ///
     /// Equivalent to setting `SOROBAN_DEBUG_HISTORY_FILE`.
@@ -170,6 +232,15 @@ pub enum Commands {
     /// Check compatibility between two contract versions
     UpgradeCheck(UpgradeCheckArgs),
 
+    /// Compare the exported function signatures of two contract versions
+    Diff(DiffArgs),
+
+    /// Check that arguments parse for a function's spec without executing it
+    ValidateArgs(ValidateArgsArgs),
+
+    /// Check that several contracts implement a shared interface
+    CheckInterface(CheckInterfaceArgs),
+
     /// Generate shell completion scripts
     Completions(CompletionsArgs),
 
@@ -188,18 +259,27 @@ pub enum Commands {
     /// Run symbolic execution to explore contract input space
     Symbolic(SymbolicArgs),
 
+    /// Call a function with random, type-valid arguments and report failing inputs
+    Fuzz(FuzzArgs),
+
     /// Start debug server for remote connections
     Server(ServerArgs),
 
     /// Connect to remote debug server
     Remote(RemoteArgs),
 
+    /// Start a Debug Adapter Protocol server for editor integration
+    Dap(DapArgs),
+
     /// Analyze contract for security vulnerabilities
     Analyze(AnalyzeArgs),
 
     /// Run a multi-step scenario from a TOML file
     Scenario(ScenarioArgs),
 
+    /// Run one function per line of a script file against shared contract storage
+    Script(ScriptArgs),
+
     /// Prune or compact run history according to a retention policy
     HistoryPrune(HistoryPruneArgs),
 
@@ -210,7 +290,7 @@ pub enum Commands {
 
 #[derive(Parser)]
 pub struct RunArgs {
-    /// Path to the contract WASM file
+    /// Path to the contract WASM file, `-` to read it from stdin, or an http(s) URL
     #[arg(
         short,
         long,
@@ -234,10 +314,27 @@ pub struct RunArgs {
     #[arg(short, long)]
     pub args: Option<String>,
 
+    /// Read the JSON argument array from a file instead of --args
+    #[arg(long, value_name = "FILE", conflicts_with = "args")]
+    pub args_file: Option<PathBuf>,
+
+    /// Expand ${VAR} and ${VAR:-default} in the arguments from the environment
+    #[arg(long)]
+    pub expand_env: bool,
+
     /// Initial storage state as JSON object
     #[arg(short, long)]
     pub storage: Option<String>,
 
+    /// Write an instance-storage entry keyed by a spec enum variant before the call,
+    /// typed from the spec (repeatable), e.g. StaleTtl=60
+    #[arg(long, value_name = "KEY=VALUE")]
+    pub set_instance: Vec<String>,
+
+    /// Allow require_auth only for this address (repeatable); other addresses fail
+    #[arg(long = "auth", value_name = "STRKEY")]
+    pub auth: Vec<String>,
+
     /// Set breakpoint at function name
     #[arg(short, long)]
     pub breakpoint: Vec<String>,
@@ -250,6 +347,22 @@ pub struct RunArgs {
     #[arg(long, hide = true, alias = "snapshot")]
     pub snapshot: Option<PathBuf>,
 
+    /// Start from a ledger snapshot written by --snapshot-out instead of an empty ledger
+    #[arg(long, value_name = "FILE", conflicts_with = "network_snapshot")]
+    pub snapshot_in: Option<PathBuf>,
+
+    /// Register the contract at this id (C... strkey or hex hash) instead of a generated one
+    #[arg(long, value_name = "STRKEY", conflicts_with = "snapshot_in")]
+    pub contract_id: Option<String>,
+
+    /// Arguments for the contract's __constructor as a JSON array, checked against its spec
+    #[arg(long, value_name = "JSON", conflicts_with_all = ["snapshot_in", "batch_args"])]
+    pub constructor_args: Option<String>,
+
+    /// Write the ledger after execution as a JSON snapshot, for a later --snapshot-in
+    #[arg(long, value_name = "FILE")]
+    pub snapshot_out: Option<PathBuf>,
+
     /// Enable verbose output
     #[arg(short, long)]
     pub verbose: bool,
@@ -277,13 +390,17 @@ pub struct RunArgs {
     /// Path to TLS key file
     #[arg(long)]
     pub tls_key: Option<std::path::PathBuf>,
+
+    /// TLS CA certificate file for `--remote` (optional, for self-signed certs)
+    #[arg(long)]
+    pub tls_ca: Option<std::path::PathBuf>,
     /// Output format (text, json)
     #[arg(long)]
     pub format: Option<String>,
 
-    /// Output mode for command result rendering (pretty, json)
-    #[arg(long = "output", value_enum, default_value_t = OutputFormat::Pretty)]
-    pub output_format: OutputFormat,
+    /// Output mode for command result rendering (text, json, yaml, csv)
+    #[arg(long = "output", value_enum, default_value_t = RunOutputFormat::Pretty)]
+    pub output_format: RunOutputFormat,
 
     /// Show contract events emitted during execution
     #[arg(long)]
@@ -293,6 +410,15 @@ pub struct RunArgs {
     #[arg(long)]
     pub show_auth: bool,
 
+    /// Show the host's diagnostic events (fn_call, fn_return, log, error) with decoded
+    /// topics and data; printed even when the call fails
+    #[arg(long)]
+    pub show_diagnostics: bool,
+
+    /// Limit --show-diagnostics to `log` and `error` events, or to `error` events only
+    #[arg(long, value_enum, value_name = "LEVEL", requires = "show_diagnostics")]
+    pub diagnostics_level: Option<DiagnosticsLevel>,
+
     /// Output format as JSON
     #[arg(long)]
     pub json: bool,
@@ -301,7 +427,7 @@ pub struct RunArgs {
     #[arg(long)]
     pub filter_topic: Option<String>,
 
-    /// Filter events by topic pattern (repeatable)
+    /// Filter events by first topic: `set*` (prefix), `re:^set` (regex) or exact name (repeatable)
     #[arg(long, value_name = "PATTERN")]
     pub event_filter: Vec<String>,
 
@@ -309,10 +435,21 @@ pub struct RunArgs {
     #[arg(long)]
     pub repeat: Option<u32>,
 
-    /// Mock cross-contract return: CONTRACT_ID.function=return_value (repeatable)
+    /// Mock cross-contract return: CONTRACT_ID.function=return_value, or
+    /// CONTRACT_ID.function=>[v1,v2,...] for one value per call, or =>err:CODE / =>abort
+    /// to fail the call (repeatable)
     #[arg(long, value_name = "CONTRACT_ID.function=return_value")]
     pub mock: Vec<String>,
 
+    /// Assert a mocked contract received a call: CONTRACT_ID:function(arg, name=value) (repeatable)
+    #[arg(long, value_name = "CONTRACT_ID:function(ARGS)", requires = "mock")]
+    pub expect_mock_call: Vec<String>,
+
+    /// Forward each event with this first topic to another contract, with the
+    /// event data as arguments: TOPIC => CONTRACT_ID.function (repeatable)
+    #[arg(long, value_name = "TOPIC => CONTRACT_ID.function")]
+    pub on_event: Vec<String>,
+
     /// Filter storage output by key pattern (repeatable). Supports:
     ///   prefix*       — match keys starting with prefix
     ///   re:<regex>    — match keys by regex
@@ -320,6 +457,10 @@ pub struct RunArgs {
     #[arg(long, value_name = "PATTERN")]
     pub storage_filter: Vec<String>,
 
+    /// Group changed storage keys by the capture groups of a `re:` storage filter
+    #[arg(long, requires = "storage_filter")]
+    pub group_by_capture: bool,
+
     /// Enable instruction-level debugging
     #[arg(long)]
     pub instruction_debug: bool,
@@ -331,7 +472,8 @@ pub struct RunArgs {
     /// Step mode for instruction debugging (into, over, out, block)
     #[arg(long, default_value = "into")]
     pub step_mode: String,
-    /// Execute contract in dry-run mode: simulate execution without persisting storage changes
+    /// Run the call and show its storage diff and events, then roll storage back;
+    /// --record is skipped and --export-storage sees the state from before the call
     #[arg(long)]
     pub dry_run: bool,
 
@@ -339,10 +481,22 @@ pub struct RunArgs {
     #[arg(long)]
     pub export_storage: Option<PathBuf>,
 
+    /// Layout for --export-storage: flat (default) or typed
+    #[arg(long, value_enum, default_value_t = StorageExportFormat::Flat)]
+    pub export_storage_format: StorageExportFormat,
+
     /// Import storage state from JSON file before execution
     #[arg(long)]
     pub import_storage: Option<PathBuf>,
 
+    /// Report storage changes as text (default) or an RFC 6902 JSON Patch
+    #[arg(long, value_enum, default_value_t = StorageDiffFormat::Text)]
+    pub diff_format: StorageDiffFormat,
+
+    /// Decode storage with the contract spec and show typed before/after values of changed keys
+    #[arg(long)]
+    pub decode_storage: bool,
+
     /// Path to JSON file containing array of argument sets for batch execution
     #[arg(long)]
     pub batch_args: Option<PathBuf>,
@@ -351,6 +505,41 @@ pub struct RunArgs {
     #[arg(long, value_name = "FILE")]
     pub generate_test: Option<PathBuf>,
 
+    /// Record the invocation (function, args, prior storage, result) for `replay`
+    #[arg(long, value_name = "FILE")]
+    pub record: Option<PathBuf>,
+
+    /// Append one JSON line per invocation (args, result, budget, memory) to this file
+    #[arg(long, value_name = "FILE")]
+    pub log_json: Option<PathBuf>,
+
+    /// Write Prometheus metrics (invocations, failures by kind, cpu and memory totals,
+    /// per-function average cpu) for the whole session to this file
+    #[arg(long, value_name = "FILE")]
+    pub metrics_out: Option<PathBuf>,
+
+    /// Only allow invoking these functions (comma-separated or repeatable)
+    #[arg(long, value_name = "FUNCTIONS", value_delimiter = ',')]
+    pub allow_functions: Vec<String>,
+
+    /// Reject invoking these functions, even if allowed (comma-separated or repeatable)
+    #[arg(long, value_name = "FUNCTIONS", value_delimiter = ',')]
+    pub deny_functions: Vec<String>,
+
+    /// Compare the function's cpu-insn and mem-byte budget with this baseline file and fail
+    /// on growth beyond --tolerance; the file is created (or the function added) on first run
+    #[arg(long, value_name = "FILE", conflicts_with = "batch_args")]
+    pub baseline: Option<PathBuf>,
+
+    /// Allowed budget growth over the --baseline, in percent
+    #[arg(
+        long,
+        value_name = "PERCENT",
+        default_value_t = 0.0,
+        requires = "baseline"
+    )]
+    pub tolerance: f64,
+
     /// Overwrite the test file if it already exists (default: append)
 
     #[arg(long)]
@@ -360,6 +549,14 @@ pub struct RunArgs {
     #[arg(long, default_value = "30")]
     pub timeout: u64,
 
+    /// Fail the call once it consumes more than this many CPU instructions
+    #[arg(long, value_name = "INSNS")]
+    pub max_insns: Option<u64>,
+
+    /// Fail the call once it allocates more than this many bytes of memory
+    #[arg(long, value_name = "BYTES")]
+    pub max_mem: Option<u64>,
+
     /// Trigger a prominent alert when a critical storage key is modified (repeatable)
     #[arg(long, value_name = "KEY_PATTERN")]
     pub alert_on_change: Vec<String>,
@@ -372,10 +569,61 @@ pub struct RunArgs {
     #[arg(long)]
     pub show_ledger: bool,
 
+    /// Print the read-only/read-write ledger keys as a base64 XDR LedgerFootprint (stellar-cli compatible)
+    #[arg(long)]
+    pub dump_footprint_xdr: bool,
+
+    /// List the contract calls the host dispatched, in order, with their arguments and results
+    #[arg(long)]
+    pub trace_host_functions: bool,
+
+    /// Write the calls and host costs as a Chrome trace (chrome://tracing, Perfetto) to FILE
+    #[arg(long, value_name = "FILE")]
+    pub trace: Option<PathBuf>,
+
+    /// Print the cross-contract call graph as DOT after execution, with the calls actually made
+    #[arg(long)]
+    pub call_graph: bool,
+
+    /// Hide the budget and memory summary while keeping results, events and storage output
+    #[arg(long)]
+    pub quiet_budget: bool,
+
+    /// After the run, estimate which contract functions consumed the CPU budget
+    #[arg(long)]
+    pub profile: bool,
+
+    /// Number of hotspots --profile lists
+    #[arg(long, value_name = "N", default_value_t = 10, requires = "profile")]
+    pub profile_top: usize,
+
+    /// Truncate the displayed result to N characters (0 for no limit). Defaults to 2000 on a
+    /// terminal and no limit when piped; JSON and recorded output always hold the full value
+    #[arg(long, value_name = "N")]
+    pub max_output_len: Option<usize>,
+
     /// TTL warning threshold in ledger sequence numbers (default: 1000)
     #[arg(long, default_value = "1000")]
     pub ttl_warning_threshold: u32,
 
+    /// Advance the ledger sequence by N before the call, then report each storage entry's
+    /// durability and remaining TTL, flagging expired entries the call read (0 reports without ageing)
+    #[arg(long, value_name = "N")]
+    pub advance_ledgers: Option<u32>,
+
+    /// Ledger timestamp in UNIX seconds (default: current wall-clock time, or the
+    /// --snapshot-in ledger's)
+    #[arg(long, value_name = "SECONDS")]
+    pub ledger_timestamp: Option<u64>,
+
+    /// Ledger sequence number
+    #[arg(long, value_name = "SEQ")]
+    pub ledger_sequence: Option<u32>,
+
+    /// Network ID as 64 hex characters, or a network passphrase to hash
+    #[arg(long, value_name = "ID_OR_PASSPHRASE")]
+    pub ledger_network_id: Option<String>,
+
     /// Export execution trace to JSON file
     #[arg(long)]
     pub trace_output: Option<PathBuf>,
@@ -389,14 +637,31 @@ pub struct RunArgs {
 }
 
 impl RunArgs {
+    /// Effective `--output` format, honouring the legacy `--json` and `--format` flags.
+    pub fn result_format(&self) -> RunOutputFormat {
+        if self.json {
+            return RunOutputFormat::Json;
+        }
+        match self
+            .format
+            .as_deref()
+            .map(str::to_ascii_lowercase)
+            .as_deref()
+        {
+            Some("json") => RunOutputFormat::Json,
+            Some("yaml") => RunOutputFormat::Yaml,
+            Some("csv") => RunOutputFormat::Csv,
+            _ => self.output_format,
+        }
+    }
+
     pub fn is_json_output(&self) -> bool {
-        self.output_format == OutputFormat::Json
-            || self.json
-            || self
-                .format
-                .as_deref()
-                .map(|f| f.eq_ignore_ascii_case("json"))
-                .unwrap_or(false)
+        self.result_format() == RunOutputFormat::Json
+    }
+
+    /// Whether results are emitted as data (JSON, YAML or CSV) rather than text.
+    pub fn is_structured_output(&self) -> bool {
+        self.result_format() != RunOutputFormat::Pretty
     }
 
     pub fn merge_config(&mut self, config: &Config) {
@@ -466,7 +731,9 @@ pub struct InteractiveArgs {
     #[arg(short, long)]
     pub breakpoint: Vec<String>,
 
-    /// Mock cross-contract return: CONTRACT_ID.function=return_value (repeatable)
+    /// Mock cross-contract return: CONTRACT_ID.function=return_value, or
+    /// CONTRACT_ID.function=>[v1,v2,...] for one value per call, or =>err:CODE / =>abort
+    /// to fail the call (repeatable)
     #[arg(long, value_name = "CONTRACT_ID.function=return_value")]
     pub mock: Vec<String>,
 
@@ -489,6 +756,11 @@ pub struct InteractiveArgs {
     /// Expected SHA-256 hash of the WASM file. If provided, loading will fail if the computed hash does not match.
     #[arg(long)]
     pub expected_hash: Option<String>,
+
+    /// Do not load or save command history (stored in ~/.config/soroban-debug/history,
+    /// or $SOROBAN_DEBUG_COMMAND_HISTORY)
+    #[arg(long)]
+    pub no_history: bool,
 }
 
 impl InteractiveArgs {
@@ -523,6 +795,14 @@ pub struct ReplArgs {
     /// Expected SHA-256 hash of the WASM file. If provided, loading will fail if the computed hash does not match.
     #[arg(long)]
     pub expected_hash: Option<String>,
+
+    /// Append one JSON line per invocation (args, result, budget, memory) to this file
+    #[arg(long, value_name = "FILE")]
+    pub log_json: Option<PathBuf>,
+
+    /// Derive address aliases (e.g. `acc1`) from this seed so they resolve to the same StrKey every run
+    #[arg(long, value_name = "SEED")]
+    pub account_seed: Option<u64>,
 }
 
 impl ReplArgs {
@@ -555,9 +835,22 @@ pub struct HistoryPruneArgs {
 
 #[derive(Parser)]
 pub struct InspectArgs {
-    /// Path to the contract WASM file
-    #[arg(short, long)]
-    pub contract: PathBuf,
+    /// Path to the contract WASM file, `-` to read it from stdin, or an http(s) URL
+    #[arg(short, long, required_unless_present = "rpc")]
+    pub contract: Option<PathBuf>,
+
+    /// Soroban RPC endpoint to download the deployed contract named by --contract-id from
+    #[arg(
+        long,
+        value_name = "URL",
+        requires = "contract_id",
+        conflicts_with = "contract"
+    )]
+    pub rpc: Option<String>,
+
+    /// Deployed contract to inspect with --rpc (C... strkey or hex contract hash)
+    #[arg(long, value_name = "CONTRACT_ID", requires = "rpc")]
+    pub contract_id: Option<String>,
 
     /// Deprecated: use --contract instead
     #[arg(long, hide = true, alias = "wasm", alias = "contract-path")]
@@ -571,6 +864,10 @@ pub struct InspectArgs {
     #[arg(long)]
     pub metadata: bool,
 
+    /// Print the full contract spec (functions, types, errors, metadata) as JSON
+    #[arg(long)]
+    pub spec: bool,
+
     /// Output format: pretty (default) or json
     #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
     pub format: OutputFormat,
@@ -590,6 +887,39 @@ pub struct InspectArgs {
     /// Show cross-contract dependency graph in specified format
     #[arg(long, value_enum)]
     pub dependency_graph: Option<GraphFormat>,
+
+    /// Print the cross-contract call graph (exported function -> callee) as DOT
+    #[arg(long, conflicts_with = "dependency_graph")]
+    pub call_graph: bool,
+
+    /// List price-feed assets from a typed storage export
+    /// (`run --export-storage --export-storage-format typed`)
+    #[arg(long, value_name = "STORAGE_FILE")]
+    pub list_assets: Option<PathBuf>,
+
+    /// Staleness TTL in seconds for --list-assets, if not in the export
+    #[arg(long, requires = "list_assets")]
+    pub stale_ttl: Option<u64>,
+
+    /// Print a histogram of the WASM opcodes used across all function bodies
+    #[arg(long)]
+    pub opcodes: bool,
+
+    /// Number of most frequent opcodes to print with --opcodes
+    #[arg(long, default_value_t = 20, requires = "opcodes")]
+    pub opcode_limit: usize,
+}
+
+impl InspectArgs {
+    /// How the inspected contract is named in output: the `--contract` path,
+    /// or the `--contract-id` fetched with `--rpc`.
+    pub fn contract_label(&self) -> String {
+        match (&self.contract_id, &self.contract) {
+            (Some(contract_id), _) => contract_id.clone(),
+            (None, Some(contract)) => contract.display().to_string(),
+            (None, None) => String::new(),
+        }
+    }
 }
 
 #[derive(Parser)]
@@ -606,6 +936,10 @@ pub struct UpgradeCheckArgs {
     #[arg(long, default_value = "text")]
     pub output: String,
 
+    /// Report format; takes precedence over --output when given
+    #[arg(long, value_enum)]
+    pub format: Option<UpgradeReportFormat>,
+
     /// Write report to file instead of stdout
     #[arg(long)]
     pub output_file: Option<PathBuf>,
@@ -614,6 +948,79 @@ pub struct UpgradeCheckArgs {
     /// e.g. '{"vote": [1, true], "create_proposal": ["title", "desc"]}'
     #[arg(long)]
     pub test_inputs: Option<String>,
+
+    /// Stop running test inputs at the first output mismatch
+    #[arg(long, conflicts_with = "collect_all")]
+    pub fail_fast: bool,
+
+    /// Run every test input and report all mismatches (default)
+    #[arg(long)]
+    pub collect_all: bool,
+
+    /// Maximum number of test inputs to run in parallel (default: one per CPU)
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(usize))]
+    pub jobs: Option<usize>,
+
+    /// Flag matching types that cannot be resolved (e.g. `Val`) for manual review
+    #[arg(long)]
+    pub strict_types: bool,
+}
+
+#[derive(Parser)]
+pub struct CheckInterfaceArgs {
+    /// JSON array of function signatures every contract must implement
+    #[arg(long, value_name = "ABI_FILE")]
+    pub interface: PathBuf,
+
+    /// Contract WASM files to check (comma-separated or repeatable)
+    #[arg(long, value_delimiter = ',', required = true, num_args = 1..)]
+    pub contracts: Vec<PathBuf>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+    pub format: OutputFormat,
+}
+
+#[derive(Parser)]
+pub struct DiffArgs {
+    /// Path to the old contract WASM file
+    #[arg(long)]
+    pub old: PathBuf,
+
+    /// Path to the new contract WASM file
+    #[arg(long)]
+    pub new: PathBuf,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+    pub format: OutputFormat,
+}
+
+#[derive(Parser)]
+pub struct ValidateArgsArgs {
+    /// Path to the contract WASM file
+    #[arg(short, long)]
+    pub contract: PathBuf,
+
+    /// Function whose spec the arguments are checked against
+    #[arg(short, long)]
+    pub function: String,
+
+    /// Function arguments as JSON array (e.g., '["arg1", "arg2"]')
+    #[arg(short, long)]
+    pub args: Option<String>,
+
+    /// Read the JSON argument array from a file instead of --args
+    #[arg(long, value_name = "FILE", conflicts_with = "args")]
+    pub args_file: Option<PathBuf>,
+
+    /// Expand ${VAR} and ${VAR:-default} in the arguments from the environment, as `run` does
+    #[arg(long)]
+    pub expand_env: bool,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+    pub format: OutputFormat,
 }
 
 #[derive(Parser)]
@@ -638,6 +1045,14 @@ pub struct OptimizeArgs {
     #[arg(short, long)]
     pub output: Option<PathBuf>,
 
+    /// Write a copy of the WASM with non-essential custom sections removed to this file
+    #[arg(long, value_name = "FILE")]
+    pub strip_custom_sections: Option<PathBuf>,
+
+    /// Fail when the WASM exports functions that are missing from the contract spec
+    #[arg(long)]
+    pub fail_on_extra_exports: bool,
+
     /// Initial storage state as JSON object
     #[arg(short, long)]
     pub storage: Option<String>,
@@ -657,7 +1072,7 @@ pub struct OptimizeArgs {
 
 #[cfg(test)]
 mod tests {
-    use super::{Cli, Commands, OutputFormat, SymbolicProfile};
+    use super::{Cli, Commands, OutputFormat, RunOutputFormat, SymbolicProfile};
     use clap::Parser;
 
     #[test]
@@ -675,7 +1090,7 @@ mod tests {
             panic!("run command expected");
         };
 
-        assert_eq!(args.output_format, OutputFormat::Pretty);
+        assert_eq!(args.output_format, RunOutputFormat::Pretty);
         assert!(!args.is_json_output());
     }
 
@@ -696,10 +1111,36 @@ mod tests {
             panic!("run command expected");
         };
 
-        assert_eq!(args.output_format, OutputFormat::Json);
+        assert_eq!(args.output_format, RunOutputFormat::Json);
         assert!(args.is_json_output());
     }
 
+    #[test]
+    fn run_output_accepts_yaml_csv_and_text() {
+        let parse = |format: &str| {
+            let cli = Cli::parse_from([
+                "soroban-debug",
+                "run",
+                "--contract",
+                "contract.wasm",
+                "--function",
+                "increment",
+                "--output",
+                format,
+            ]);
+            let Commands::Run(args) = cli.command.expect("run command expected") else {
+                panic!("run command expected");
+            };
+            args
+        };
+
+        assert_eq!(parse("yaml").result_format(), RunOutputFormat::Yaml);
+        assert_eq!(parse("csv").result_format(), RunOutputFormat::Csv);
+        assert!(parse("csv").is_structured_output());
+        assert!(!parse("csv").is_json_output());
+        assert_eq!(parse("text").output_format, RunOutputFormat::Pretty);
+    }
+
     #[test]
     fn legacy_json_flag_still_enables_json_mode() {
         let cli = Cli::parse_from([
@@ -784,6 +1225,30 @@ mod tests {
         assert_eq!(args.timeout, None);
     }
 
+    #[test]
+    fn fuzz_defaults_to_random_seed_and_collects_expected_codes() {
+        let cli = Cli::parse_from([
+            "soroban-debug",
+            "fuzz",
+            "--contract",
+            "oracle.wasm",
+            "--function",
+            "set_price",
+            "--expect-code",
+            "2",
+            "--expect-code",
+            "5",
+        ]);
+
+        let Commands::Fuzz(args) = cli.command.expect("fuzz command expected") else {
+            panic!("fuzz command expected");
+        };
+
+        assert_eq!(args.iterations, 100);
+        assert_eq!(args.seed, None);
+        assert_eq!(args.expect_code, vec![2, 5]);
+    }
+
     #[test]
     fn symbolic_accepts_explicit_caps_and_profile() {
         let cli = Cli::parse_from([
@@ -855,6 +1320,26 @@ mod tests {
         assert_eq!(args.source_map_limit, 5);
         assert_eq!(args.format, OutputFormat::Json);
     }
+
+    #[test]
+    fn inspect_accepts_rpc_with_contract_id() {
+        let cli = Cli::parse_from([
+            "soroban-debug",
+            "inspect",
+            "--rpc",
+            "https://rpc.example",
+            "--contract-id",
+            "CABC",
+        ]);
+
+        let Commands::Inspect(args) = cli.command.expect("inspect command expected") else {
+            panic!("inspect command expected");
+        };
+
+        assert_eq!(args.contract, None);
+        assert_eq!(args.rpc.as_deref(), Some("https://rpc.example"));
+        assert_eq!(args.contract_label(), "CABC");
+    }
 }
 
 #[derive(Parser)]
@@ -880,6 +1365,17 @@ pub struct CompareArgs {
     /// Repeatable. Useful for timestamps, sequence numbers, and similar metadata.
     #[arg(long, value_name = "FIELD")]
     pub ignore_field: Vec<String>,
+
+    /// Ignore a named field inside event data when comparing events. Repeatable.
+    /// For example: --compare-events-ignoring timestamp
+    #[arg(long, value_name = "FIELD")]
+    pub compare_events_ignoring: Vec<String>,
+
+    /// Treat TRACE_A and TRACE_B as `run --record` recordings and diff their
+    /// result, storage after the call, and emitted events. Exits non-zero if
+    /// anything differs.
+    #[arg(long)]
+    pub compare_runs: bool,
 }
 
 /// Arguments for the TUI dashboard subcommand
@@ -1007,9 +1503,46 @@ pub struct SymbolicArgs {
     pub format: OutputFormat,
 }
 
+#[derive(Parser)]
+pub struct FuzzArgs {
+    /// Path to the contract WASM file
+    #[arg(short, long)]
+    pub contract: PathBuf,
+
+    /// Function name to fuzz
+    #[arg(short, long)]
+    pub function: String,
+
+    /// Number of random inputs to try
+    #[arg(long, default_value = "100")]
+    pub iterations: usize,
+
+    /// Seed for input generation; the same seed replays the same inputs.
+    /// A random seed is chosen and printed when omitted.
+    #[arg(long, value_name = "N")]
+    pub seed: Option<u64>,
+
+    /// Contract error code the function is expected to return for some inputs;
+    /// these are not reported as findings (repeatable)
+    #[arg(long = "expect-code", value_name = "CODE")]
+    pub expect_code: Vec<u32>,
+
+    /// Initial storage state as JSON object, applied before every iteration
+    #[arg(long)]
+    pub storage: Option<String>,
+
+    /// Execution timeout in seconds for each invocation
+    #[arg(long, default_value = "30")]
+    pub timeout: u64,
+
+    /// Output format for the report (pretty/text or json)
+    #[arg(long, value_enum, default_value_t = OutputFormat::Pretty)]
+    pub format: OutputFormat,
+}
+
 #[derive(Parser)]
 pub struct ReplayArgs {
-    /// Path to the trace JSON file to replay
+    /// Path to the trace JSON file, or a `run --record` recording, to replay
     #[arg(value_name = "TRACE_FILE")]
     pub trace_file: PathBuf,
 
@@ -1057,6 +1590,17 @@ pub struct ServerArgs {
     pub storage_filter: Vec<String>,
 }
 
+#[derive(Parser)]
+pub struct DapArgs {
+    /// Default contract WASM file, used when the DAP `launch` request omits one
+    #[arg(short, long)]
+    pub contract: Option<PathBuf>,
+
+    /// Listen on this TCP port instead of using stdin/stdout
+    #[arg(short, long)]
+    pub port: Option<u16>,
+}
+
 #[derive(Parser)]
 pub struct RemoteArgs {
     /// Remote server address (e.g., localhost:9229)
@@ -1149,4 +1693,48 @@ pub struct ScenarioArgs {
     /// Use 0 to disable the timeout entirely.
     #[arg(long)]
     pub timeout: Option<u64>,
+
+    /// Advance the ledger clock by this many seconds before every step after
+    /// the first. A step's `ledger_advance` takes precedence.
+    #[arg(long, value_name = "SECS")]
+    pub step_advance_secs: Option<u64>,
+}
+
+#[derive(Parser)]
+pub struct ScriptArgs {
+    /// Script file with one `function [json-args]` invocation per line
+    pub script: PathBuf,
+
+    /// Path to the contract WASM file
+    #[arg(short, long)]
+    pub contract: PathBuf,
+
+    /// Initial storage state as JSON object
+    #[arg(long)]
+    pub storage: Option<String>,
+
+    /// Execution timeout in seconds for each invocation
+    #[arg(long, default_value = "30")]
+    pub timeout: u64,
+
+    /// Keep running the remaining lines after a failed invocation
+    #[arg(long)]
+    pub continue_on_error: bool,
+
+    /// Append one JSON line per invocation (args, result, budget, memory) to this file
+    #[arg(long, value_name = "FILE")]
+    pub log_json: Option<PathBuf>,
+
+    /// Write Prometheus metrics (invocations, failures by kind, cpu and memory totals,
+    /// per-function average cpu) for the whole session to this file
+    #[arg(long, value_name = "FILE")]
+    pub metrics_out: Option<PathBuf>,
+
+    /// Only allow invoking these functions (comma-separated or repeatable)
+    #[arg(long, value_name = "FUNCTIONS", value_delimiter = ',')]
+    pub allow_functions: Vec<String>,
+
+    /// Reject invoking these functions, even if allowed (comma-separated or repeatable)
+    #[arg(long, value_name = "FUNCTIONS", value_delimiter = ',')]
+    pub deny_functions: Vec<String>,
 }
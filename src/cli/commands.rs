@@ -1,17 +1,22 @@
+use crate::analyzer::fuzz::{ContractFuzzer, FuzzConfig};
+use crate::analyzer::graph::DependencyGraph;
 use crate::analyzer::symbolic::SymbolicConfig;
 use crate::analyzer::upgrade::{CompatibilityReport, ExecutionDiff, UpgradeAnalyzer};
 use crate::analyzer::{security::SecurityAnalyzer, symbolic::SymbolicAnalyzer};
 use crate::cli::args::{
-    AnalyzeArgs, CompareArgs, HistoryPruneArgs, InspectArgs, InteractiveArgs, OptimizeArgs,
-    OutputFormat, ProfileArgs, RemoteArgs, ReplArgs, ReplayArgs, RunArgs, ScenarioArgs, ServerArgs,
-    SymbolicArgs, SymbolicProfile, TuiArgs, UpgradeCheckArgs, Verbosity,
+    AnalyzeArgs, CheckInterfaceArgs, CompareArgs, DapArgs, DiffArgs, FuzzArgs, GraphFormat,
+    HistoryPruneArgs, InspectArgs, InteractiveArgs, OptimizeArgs, OutputFormat, ProfileArgs,
+    RemoteArgs, ReplArgs, ReplayArgs, RunArgs, RunOutputFormat, ScenarioArgs, ScriptArgs,
+    ServerArgs, StorageDiffFormat, StorageExportFormat, SymbolicArgs, SymbolicProfile, TuiArgs,
+    UpgradeCheckArgs, UpgradeReportFormat, ValidateArgsArgs, Verbosity,
 };
 use crate::debugger::engine::DebuggerEngine;
 use crate::debugger::instruction_pointer::StepMode;
 use crate::history::{HistoryManager, RunHistory};
 use crate::inspector::events::{ContractEvent, EventInspector};
+use crate::inspector::host_calls::HostCallInspector;
 use crate::logging;
-use crate::output::OutputWriter;
+use crate::output::{OutputWriter, WarningCollector};
 use crate::repeat::RepeatRunner;
 use crate::repl::ReplConfig;
 use crate::runtime::executor::ContractExecutor;
@@ -21,6 +26,7 @@ use crate::ui::{run_dashboard, DebuggerUI};
 use crate::{DebuggerError, Result};
 use miette::WrapErr;
 use std::fs;
+use std::io::IsTerminal;
 
 fn print_info(message: impl AsRef<str>) {
     if !Formatter::is_quiet() {
@@ -156,6 +162,38 @@ fn render_symbolic_report(report: &crate::analyzer::symbolic::SymbolicReport) ->
     lines.join("\n")
 }
 
+fn render_fuzz_report(report: &crate::analyzer::fuzz::FuzzReport) -> String {
+    let mut lines = vec![
+        format!("Function: {}", report.function),
+        format!("Seed: {}", report.seed),
+        format!(
+            "Iterations: {} (succeeded={}, expected_errors={})",
+            report.iterations, report.succeeded, report.expected_errors
+        ),
+        format!("Findings: {}", report.findings.len()),
+    ];
+
+    for (idx, finding) in report.findings.iter().enumerate() {
+        lines.push(format!(
+            "  {}. {} x{} (first at iteration {})",
+            idx + 1,
+            finding.label(),
+            finding.occurrences,
+            finding.first_iteration
+        ));
+        lines.push(format!("     inputs={}", finding.inputs));
+    }
+
+    if !report.findings.is_empty() {
+        lines.push(format!(
+            "Reproduce with: --seed {} --iterations {}",
+            report.seed, report.iterations
+        ));
+    }
+
+    lines.join("\n")
+}
+
 fn symbolic_profile_config(profile: SymbolicProfile) -> SymbolicConfig {
     match profile {
         SymbolicProfile::Fast => SymbolicConfig::fast(),
@@ -448,9 +486,7 @@ fn run_batch(args: &RunArgs, batch_file: &std::path::Path) -> Result<()> {
     print_info(format!("Loading contract: {:?}", contract));
     logging::log_loading_contract(&contract.to_string_lossy());
 
-    let wasm_bytes = fs::read(contract).map_err(|e| {
-        DebuggerError::WasmLoadError(format!("Failed to read WASM file at {:?}: {}", contract, e))
-    })?;
+    let wasm_bytes = crate::utils::wasm::load_wasm(contract)?.bytes;
 
     print_success(format!(
         "Contract loaded successfully ({} bytes)",
@@ -477,9 +513,13 @@ fn run_batch(args: &RunArgs, batch_file: &std::path::Path) -> Result<()> {
     ));
     logging::log_execution_start(function, None);
 
+    function_guard(args).check(function)?;
     let executor = crate::batch::BatchExecutor::new(wasm_bytes, function.clone())?;
     let results = executor.execute_batch(batch_items)?;
     let summary = crate::batch::BatchExecutor::summarize(&results);
+    if let Some(metrics_path) = &args.metrics_out {
+        executor.metrics().write_to(metrics_path)?;
+    }
 
     crate::batch::BatchExecutor::display_results(&results, &summary);
 
@@ -509,9 +549,58 @@ fn run_batch(args: &RunArgs, batch_file: &std::path::Path) -> Result<()> {
     Ok(())
 }
 
+/// The `--allow-functions` / `--deny-functions` guard.
+fn function_guard(args: &RunArgs) -> crate::runtime::function_guard::FunctionGuard {
+    crate::runtime::function_guard::FunctionGuard::new(
+        args.allow_functions.clone(),
+        args.deny_functions.clone(),
+    )
+}
+
+/// Whether `--diagnostics-level` keeps a diagnostic event of `kind`.
+fn diagnostics_level_includes(
+    level: crate::cli::args::DiagnosticsLevel,
+    kind: crate::inspector::events::DiagnosticKind,
+) -> bool {
+    use crate::cli::args::DiagnosticsLevel;
+    use crate::inspector::events::DiagnosticKind;
+    match level {
+        DiagnosticsLevel::All => true,
+        DiagnosticsLevel::Log => matches!(kind, DiagnosticKind::Log | DiagnosticKind::Error),
+        DiagnosticsLevel::Error => kind == DiagnosticKind::Error,
+    }
+}
+
+/// Ledger state requested by `--ledger-timestamp`, `--ledger-sequence` and `--ledger-network-id`.
+///
+/// Without `--ledger-timestamp` a fresh ledger gets the wall-clock time, while
+/// a `--snapshot-in` ledger keeps the snapshot's.
+fn ledger_seed(args: &RunArgs) -> crate::runtime::loader::LedgerSeed {
+    crate::runtime::loader::LedgerSeed {
+        timestamp: args.ledger_timestamp.or_else(|| {
+            args.snapshot_in
+                .is_none()
+                .then(crate::runtime::loader::LedgerSeed::wall_clock_timestamp)
+        }),
+        sequence: args.ledger_sequence,
+        network_id: args
+            .ledger_network_id
+            .as_deref()
+            .map(crate::runtime::loader::LedgerSeed::parse_network_id),
+    }
+}
+
 /// Execute the run command.
 #[tracing::instrument(skip_all, fields(contract = ?args.contract, function = args.function))]
-pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
+pub fn run(mut args: RunArgs, verbosity: Verbosity) -> Result<()> {
+    if let Some(args_json) = crate::utils::arguments::prepare_args(
+        args.args.as_deref(),
+        args.args_file.take().as_deref(),
+        args.expand_env,
+    )? {
+        args.args = Some(args_json);
+    }
+
     // Start debug server if requested
     if args.server {
         return server(ServerArgs {
@@ -519,6 +608,8 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
             token: args.token,
             tls_cert: args.tls_cert,
             tls_key: args.tls_key,
+            repeat: args.repeat,
+            storage_filter: args.storage_filter,
         });
     }
 
@@ -530,6 +621,9 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
                 token: args.token.clone(),
                 contract: args.contract.clone(),
                 function: args.function.clone(),
+                tls_cert: args.tls_cert.clone(),
+                tls_key: args.tls_key.clone(),
+                tls_ca: args.tls_ca.clone(),
                 args: args.args.clone(),
             },
             verbosity,
@@ -544,9 +638,7 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
         return run_batch(&args, batch_file);
     }
 
-    if args.dry_run {
-        return run_dry_run(&args);
-    }
+    WarningCollector::clear();
 
     let contract = args
         .contract
@@ -646,20 +738,75 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
     }
     logging::log_execution_start(function, parsed_args.as_deref());
 
-    let mut executor = ContractExecutor::new(wasm_bytes.clone())?;
+    let mut executor = match &args.snapshot_in {
+        Some(snapshot_path) => {
+            print_info(format!("Loading ledger snapshot: {:?}", snapshot_path));
+            let snapshot = crate::runtime::loader::read_ledger_snapshot(snapshot_path)?;
+            ledger_seed(&args).check_snapshot_network(&snapshot)?;
+            ContractExecutor::from_ledger_snapshot(wasm_bytes.clone(), snapshot)?
+        }
+        None => match (&args.constructor_args, &args.contract_id) {
+            (Some(constructor_args), contract_id) => ContractExecutor::new_with_constructor(
+                wasm_bytes.clone(),
+                contract_id.as_deref(),
+                constructor_args,
+            )?,
+            (None, Some(contract_id)) => ContractExecutor::new_at(wasm_bytes.clone(), contract_id)?,
+            (None, None) => ContractExecutor::new(wasm_bytes.clone())?,
+        },
+    };
     executor.set_timeout(args.timeout);
+    executor.set_budget_limits(args.max_insns, args.max_mem);
+    if let Some(log_path) = &args.log_json {
+        executor.set_invocation_log(log_path)?;
+    }
+    if let Some(metrics_path) = &args.metrics_out {
+        executor.set_metrics_out(metrics_path)?;
+    }
+    executor.set_function_guard(function_guard(&args));
+    executor.set_quiet_budget(args.quiet_budget);
+    executor.apply_ledger_seed(&ledger_seed(&args));
+    if args.verbose || verbosity == Verbosity::Verbose {
+        print_info(format!(
+            "Ledger: timestamp={}, sequence={}",
+            executor.ledger_timestamp(),
+            executor.env().ledger().sequence()
+        ));
+    }
 
     if let Some(storage) = initial_storage {
         executor.set_initial_storage(storage)?;
     }
+    for entry in &args.set_instance {
+        let (key, value) = entry.split_once('=').ok_or_else(|| {
+            DebuggerError::InvalidArguments(format!(
+                "Invalid --set-instance '{}': expected KEY=VALUE",
+                entry
+            ))
+        })?;
+        executor.set_instance_entry(key.trim(), value.trim())?;
+    }
+    if !args.auth.is_empty() {
+        executor.require_auth_from(&args.auth)?;
+    }
     if !args.mock.is_empty() {
         executor.set_mock_specs(&args.mock)?;
     }
+    let mock_expectations = args
+        .expect_mock_call
+        .iter()
+        .map(|spec| crate::runtime::mocking::MockCallExpectation::parse(spec))
+        .collect::<Result<Vec<_>>>()?;
+    let event_routes = args
+        .on_event
+        .iter()
+        .map(|spec| crate::runtime::event_routes::EventRoute::parse(spec))
+        .collect::<Result<Vec<_>>>()?;
 
     let mut engine = DebuggerEngine::new(executor, args.breakpoint.clone());
 
     // Execute locally with debugging
-    if !args.is_json_output() {
+    if !args.is_structured_output() && !Formatter::is_quiet() {
         println!("\n--- Execution Start ---\n");
     }
     if args.instruction_debug {
@@ -681,14 +828,205 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
     print_info("\n--- Execution Start ---\n");
     output_writer.write("\n--- Execution Start ---\n")?;
     let storage_before = engine.executor().get_storage_snapshot()?;
-    let result = engine.execute(function, parsed_args.as_deref())?;
+    let entries_before = match &args.record {
+        Some(_) => Some(engine.executor().contract_data_entries()?),
+        None => None,
+    };
+    let storage_decoder = args
+        .decode_storage
+        .then(|| crate::inspector::decoded_storage::StorageDecoder::from_wasm(&wasm_bytes));
+    let decoded_before = match &storage_decoder {
+        Some(decoder) => Some(decoder.decode_entries(&engine.executor().contract_data_entries()?)),
+        None => None,
+    };
+    let typed_before = if args.diff_format == StorageDiffFormat::JsonPatch {
+        Some(crate::inspector::storage::typed_storage_json(
+            &engine.executor().contract_data_entries()?,
+        ))
+    } else {
+        None
+    };
+    let dry_run_snapshot = if args.dry_run {
+        Some(engine.executor().snapshot_storage()?)
+    } else {
+        None
+    };
+    // Age the ledger before the call, so it runs against entries that may have
+    // expired, and isolate the footprint to see which of those it reads.
+    let expiry_watch = match args.advance_ledgers {
+        Some(count) => {
+            engine.executor_mut().advance_ledgers(count);
+            let expired = engine.executor().expired_entry_keys()?;
+            Some((expired, engine.executor().take_footprint()?))
+        }
+        None => None,
+    };
+    let execution = engine.execute(function, parsed_args.as_deref());
+    // Reported before propagating the error: reading an archived entry aborts the call.
+    let storage_ttls = match expiry_watch {
+        Some((expired, earlier)) => {
+            let touched = engine.executor().restore_footprint(earlier)?;
+            let read_after_expiry: Vec<_> = expired
+                .into_iter()
+                .filter(|key| touched.contains(key))
+                .collect();
+            let decoder = crate::inspector::decoded_storage::StorageDecoder::from_wasm(&wasm_bytes);
+            let ttls = engine
+                .executor()
+                .storage_ttls(&decoder, &read_after_expiry)?;
+            if !args.is_structured_output() {
+                print_info(format!(
+                    "\n--- Storage TTL (ledger {}, advanced {}) ---",
+                    engine.executor().ledger_sequence(),
+                    args.advance_ledgers.unwrap_or_default()
+                ));
+                if ttls.is_empty() {
+                    print_info("(no storage entries)");
+                }
+                for entry in &ttls {
+                    if entry.expired {
+                        print_warning(entry.to_string());
+                    } else {
+                        print_info(entry.to_string());
+                    }
+                    output_writer.write(&entry.to_string())?;
+                }
+            }
+            Some(ttls)
+        }
+        None => None,
+    };
+    let json_diagnostics = if args.show_diagnostics {
+        // Shown before propagating the error: the diagnostics explain why a call aborted.
+        let level = args.diagnostics_level.unwrap_or_default();
+        let raw_diagnostics = engine.executor().get_diagnostic_events()?;
+        let diagnostics: Vec<_> = EventInspector::decode_diagnostic_events(&raw_diagnostics)
+            .into_iter()
+            .filter(|event| diagnostics_level_includes(level, event.kind))
+            .collect();
+        if !args.is_structured_output() {
+            print_info("\n--- Diagnostic Events ---");
+            if diagnostics.is_empty() {
+                print_info("(none)");
+            }
+            for event in &diagnostics {
+                print_info(event.to_string());
+            }
+        }
+        Some(diagnostics)
+    } else {
+        None
+    };
+    let host_calls = if args.trace_host_functions || args.trace.is_some() {
+        let state = engine.state();
+        let calls = state
+            .lock()
+            .map(|s| HostCallInspector::from_boundaries(s.call_boundaries()))
+            .unwrap_or_default();
+        Some(calls)
+    } else {
+        None
+    };
+    if let (Some(trace_path), Some(calls)) = (&args.trace, &host_calls) {
+        // Written before propagating a contract error so failed runs can be profiled too.
+        let rows = crate::inspector::budget::BudgetInspector::breakdown(engine.executor().host());
+        HostCallInspector::write_chrome_trace(trace_path, calls, &rows)?;
+        if !args.is_structured_output() {
+            print_info(format!("Chrome trace written to {:?}", trace_path));
+        }
+    }
+    let result = execution?;
     let storage_after = engine.executor().get_storage_snapshot()?;
     print_success("\n--- Execution Complete ---\n");
     output_writer.write("\n--- Execution Complete ---\n")?;
-    print_result(format!("Result: {:?}", result));
-    output_writer.write(&format!("Result: {:?}", result))?;
+    // Structured output and `--max-output-len 0` never truncate; without the
+    // flag only a terminal truncates.
+    let display_limit = match args.max_output_len {
+        _ if args.is_structured_output() => None,
+        Some(0) => None,
+        Some(limit) => Some(limit),
+        None => std::io::stdout()
+            .is_terminal()
+            .then_some(crate::cli::args::DEFAULT_TTY_OUTPUT_LEN),
+    };
+    let returned = engine
+        .executor()
+        .last_execution()
+        .and_then(|record| record.result.as_ref().ok())
+        .cloned();
+    // Spec-typed JSON when the contract declares a return type, else the debug form.
+    let typed_result = returned.as_ref().and_then(|val| {
+        crate::utils::scval::decode_typed_return_value(&wasm_bytes, function, val)
+            .map(|decoded| crate::utils::scval::display_decoded(val, &decoded))
+    });
+    let shown = typed_result.as_deref().unwrap_or(result.as_str());
+    let line = format!("Result: {}", shown);
+    print_result(Formatter::truncate_output(&line, display_limit));
+    output_writer.write(&line)?;
+    let timing = engine
+        .executor()
+        .last_execution()
+        .map(|record| (record.duration, record.cpu_insns, record.timing_summary()));
+    if let Some((_, _, summary)) = &timing {
+        print_info(summary);
+        output_writer.write(summary)?;
+    }
     logging::log_execution_complete(&result);
 
+    let decoded_result = returned
+        .as_ref()
+        .map(|val| crate::utils::scval::decode_return_value(&wasm_bytes, function, val));
+    if let Some(decoded) = decoded_result
+        .as_ref()
+        .filter(|decoded| typed_result.is_none() && decoded.is_compound())
+    {
+        let line = format!("Decoded result: {}", decoded);
+        print_result(Formatter::truncate_output(&line, display_limit));
+        output_writer.write(&line)?;
+    }
+
+    // Forwarded before the mock call log is read, so downstream mocks show up in it.
+    let forwarded_events = engine.executor_mut().forward_events(&event_routes)?;
+    if !event_routes.is_empty() && !args.is_structured_output() {
+        print_info("\n--- Forwarded Events ---");
+        if forwarded_events.is_empty() {
+            print_info("(no matching events)");
+        }
+        for call in &forwarded_events {
+            print_info(call.to_string());
+            output_writer.write(&call.to_string())?;
+        }
+    }
+
+    let hotspots = if args.profile {
+        let cpu_insns = timing.as_ref().map_or(0, |(_, cpu_insns, _)| *cpu_insns);
+        Some(crate::profiler::hotspots::attribute_hotspots(
+            &wasm_bytes,
+            function,
+            cpu_insns,
+        )?)
+    } else {
+        None
+    };
+    if let Some(hotspots) = hotspots.as_ref().filter(|_| !args.is_structured_output()) {
+        print_info("\n--- Hotspots (by code size) ---");
+        for line in crate::profiler::hotspots::format_hotspots(hotspots, args.profile_top) {
+            print_info(&line);
+            output_writer.write(&line)?;
+        }
+    }
+
+    if let Some(calls) = host_calls
+        .as_ref()
+        .filter(|_| args.trace_host_functions && !args.is_structured_output())
+    {
+        print_info("\n--- Host Calls ---");
+        for line in HostCallInspector::format_lines(calls) {
+            print_info(&line);
+            output_writer.write(&line)?;
+        }
+    }
+
     // Generate test if requested
     if let Some(test_path) = &args.generate_test {
         if let Some(record) = engine.executor().last_execution() {
@@ -704,28 +1042,140 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
         }
     }
 
+    if args.dry_run {
+        if let Some(record_path) = &args.record {
+            print_warning(format!("[DRY RUN] Not recording to {:?}", record_path));
+        }
+    } else if let (Some(record_path), Some(entries_before)) = (&args.record, entries_before) {
+        if let Some(record) = engine.executor().last_execution() {
+            let recording = crate::runtime::recording::ExecutionRecording::new(
+                Some(contract.to_string_lossy().to_string()),
+                record.clone(),
+                entries_before,
+            )
+            .with_events(
+                engine
+                    .executor()
+                    .step_feed()
+                    .last()
+                    .map(|step| step.events.clone())
+                    .unwrap_or_default(),
+            );
+            recording.save(record_path)?;
+            print_success(format!("Execution recorded to {:?}", record_path));
+        } else {
+            print_warning("No execution record found to save.");
+        }
+    }
+
     let storage_diff = crate::inspector::storage::StorageInspector::compute_diff(
         &storage_before,
         &storage_after,
         &args.alert_on_change,
     );
-    if !storage_diff.is_empty() || !args.alert_on_change.is_empty() {
-        print_info("\n--- Storage Changes ---");
-        crate::inspector::storage::StorageInspector::display_diff(&storage_diff);
+    let storage_patch = match typed_before {
+        Some(before) => {
+            let after = crate::inspector::storage::typed_storage_json(
+                &engine.executor().contract_data_entries()?,
+            );
+            Some(crate::inspector::storage::storage_json_patch(
+                &before, &after,
+            ))
+        }
+        None => None,
+    };
+    let decoded_diff = match (&storage_decoder, &decoded_before) {
+        (Some(decoder), Some(before)) => {
+            let after = decoder.decode_entries(&engine.executor().contract_data_entries()?);
+            Some(crate::inspector::decoded_storage::DecodedStorageDiff::compute(before, &after))
+        }
+        _ => None,
+    };
+    match &storage_patch {
+        Some(patch) if !args.is_structured_output() => {
+            print_info("\n--- Storage Changes (JSON Patch) ---");
+            let rendered = serde_json::to_string_pretty(patch).unwrap_or_else(|_| "[]".to_string());
+            print_result(&rendered);
+            output_writer.write(&rendered)?;
+        }
+        Some(_) => {}
+        None if decoded_diff.is_some() => {
+            if let Some(diff) = decoded_diff
+                .as_ref()
+                .filter(|_| !args.is_structured_output())
+            {
+                print_info("\n--- Storage Changes (decoded) ---");
+                diff.display();
+                for line in diff.format_lines() {
+                    output_writer.write(&line)?;
+                }
+            }
+        }
+        None if !storage_diff.is_empty() || !args.alert_on_change.is_empty() => {
+            print_info("\n--- Storage Changes ---");
+            crate::inspector::storage::StorageInspector::display_diff(&storage_diff);
+        }
+        None => {}
     }
-
-    if let Some(export_path) = &args.export_storage {
-        print_info(format!("\nExporting storage to: {:?}", export_path));
-        crate::inspector::storage::StorageState::export_to_file(&storage_after, export_path)?;
+    // Roll back once the would-be changes have been computed, so --export-storage
+    // and anything after it see the state from before the call.
+    if let Some(snapshot) = &dry_run_snapshot {
+        engine.executor_mut().restore_storage(snapshot)?;
+    }
+    if let Some(snapshot_path) = &args.snapshot_out {
+        engine.executor().save_ledger_snapshot(snapshot_path)?;
+        print_success(format!("Ledger snapshot written to {:?}", snapshot_path));
+    }
+    if args.group_by_capture && !args.is_structured_output() {
+        let filter = crate::inspector::storage::StorageFilter::new(&args.storage_filter)
+            .map_err(|e| DebuggerError::StorageError(format!("Invalid storage filter: {}", e)))?;
+        let changed_keys: Vec<&str> = match &decoded_diff {
+            Some(diff) => diff.changes.iter().map(|c| c.key.as_str()).collect(),
+            None => storage_diff
+                .added
+                .keys()
+                .chain(storage_diff.modified.keys())
+                .chain(storage_diff.deleted.iter())
+                .map(String::as_str)
+                .collect(),
+        };
+        let groups = filter.group_by_capture(changed_keys);
+        print_info("\n--- Storage Changes by Capture ---");
+        if groups.is_empty() {
+            print_info("(no changed keys matched a capturing filter)");
+        }
+        for (group, keys) in &groups {
+            print_info(format!("{}: {} changed key(s)", group, keys.len()));
+            for key in keys {
+                print_info(format!("  {}", key));
+            }
+        }
     }
+
     let mock_calls = engine.executor().get_mock_call_log();
     if !args.mock.is_empty() {
         display_mock_call_log(&mock_calls);
     }
+    if args.call_graph && !args.is_structured_output() {
+        let static_calls = crate::utils::wasm::parse_cross_contract_calls(&wasm_bytes)?;
+        let mut graph = DependencyGraph::from_cross_contract_calls(&static_calls);
+        graph.add_runtime_calls(function, &mock_calls);
+        print_info("\n--- Call Graph ---");
+        println!("{}", graph.to_dot());
+    }
+    if !mock_expectations.is_empty() {
+        crate::runtime::mocking::MockCallExpectation::check_all(&mock_expectations, &mock_calls)
+            .map_err(|msg| miette::miette!("{}", msg))?;
+        print_success(format!(
+            "All {} expected mock call(s) matched",
+            mock_expectations.len()
+        ));
+    }
 
     // Save budget info to history
     let host = engine.executor().host();
     let budget = crate::inspector::budget::BudgetInspector::get_cpu_usage(host);
+    let budget_breakdown = crate::inspector::budget::BudgetInspector::breakdown(host);
     if let Ok(manager) = HistoryManager::new() {
         let record = RunHistory {
             date: chrono::Utc::now().to_rfc3339(),
@@ -738,15 +1188,36 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
     }
     let _json_memory_summary = engine.executor().last_memory_summary().cloned();
 
+    if (args.verbose || verbosity == Verbosity::Verbose) && !args.is_structured_output() {
+        print_info("\n--- Budget Breakdown ---");
+        for line in crate::inspector::budget::BudgetInspector::format_breakdown(&budget_breakdown) {
+            print_info(&line);
+        }
+    }
+
     // Export storage if specified
     if let Some(export_path) = &args.export_storage {
         print_info(format!("Exporting storage to: {:?}", export_path));
-        let storage_snapshot = engine.executor().get_storage_snapshot()?;
-        crate::inspector::storage::StorageState::export_to_file(&storage_snapshot, export_path)?;
-        print_success(format!(
-            "Exported {} storage entries",
-            storage_snapshot.len()
-        ));
+        match args.export_storage_format {
+            StorageExportFormat::Flat => {
+                let storage_snapshot = engine.executor().get_storage_snapshot()?;
+                crate::inspector::storage::StorageState::export_to_file(
+                    &storage_snapshot,
+                    export_path,
+                )?;
+                print_success(format!(
+                    "Exported {} storage entries",
+                    storage_snapshot.len()
+                ));
+            }
+            StorageExportFormat::Typed => {
+                let typed = crate::inspector::storage::typed_storage_json(
+                    &engine.executor().contract_data_entries()?,
+                );
+                crate::inspector::storage::StorageState::export_typed_to_file(&typed, export_path)?;
+                print_success("Exported storage grouped by DataKey variant");
+            }
+        }
     }
 
     let mut json_events = None;
@@ -771,6 +1242,7 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
                     let fallback: Vec<ContractEvent> = raw_events
                         .into_iter()
                         .map(|r| ContractEvent {
+                            index: r.index,
                             contract_id: None,
                             topics: vec![],
                             data: format!("{:?}", r),
@@ -780,14 +1252,14 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
                 }
             };
 
-        // Determine filter: prefer repeatable --event-filter, fallback to legacy --filter-topic
-        let filter_opt = if !args.event_filter.is_empty() {
-            Some(args.event_filter.join(","))
-        } else {
-            args.filter_topic.clone()
-        };
-
-        let filtered_events = if let Some(ref filt) = filter_opt {
+        // Prefer repeatable --event-filter (first topic), fallback to legacy --filter-topic
+        let filtered_events = if !args.event_filter.is_empty() {
+            let event_filter = crate::inspector::events::EventFilter::new(&args.event_filter)
+                .map_err(|e| {
+                    DebuggerError::InvalidArguments(format!("Invalid event filter: {}", e))
+                })?;
+            EventInspector::apply_filter(&converted_events, &event_filter)
+        } else if let Some(ref filt) = args.filter_topic {
             EventInspector::filter_events(&converted_events, filt)
         } else {
             converted_events.clone()
@@ -831,6 +1303,25 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
         json_auth = Some(auth_tree);
     }
 
+    // Read before `--show-ledger` finalizes the host.
+    let footprint_xdr = if args.dump_footprint_xdr {
+        let footprint = engine.executor().ledger_footprint()?;
+        let xdr = crate::runtime::footprint::encode_footprint(&footprint)?;
+        if !args.is_structured_output() {
+            print_info(format!(
+                "\n--- Ledger Footprint (XDR) --- read-only: {}, read-write: {}",
+                footprint.read_only.len(),
+                footprint.read_write.len()
+            ));
+            // Uncoloured so it can be pasted into stellar-cli as is.
+            println!("{}", xdr);
+            output_writer.write(&xdr)?;
+        }
+        Some(xdr)
+    } else {
+        None
+    };
+
     let mut json_ledger = None;
     if args.show_ledger {
         print_info("\n--- Ledger Entries ---");
@@ -889,20 +1380,40 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
         json_ledger = Some(ledger_inspector);
     }
 
-    if args.is_json_output() {
+    let warnings = WarningCollector::take();
+    if !warnings.is_empty() && !args.is_structured_output() {
+        print_info("\n--- Warnings ---");
+        for line in WarningCollector::format_lines(&warnings) {
+            print_warning(&line);
+            output_writer.write(&line)?;
+        }
+    }
+
+    if args.is_structured_output() {
         let mut result_obj = serde_json::json!({
             "result": result,
             "sha256": wasm_hash,
             "budget": {
                 "cpu_instructions": budget.cpu_instructions,
                 "memory_bytes": budget.memory_bytes,
+                "breakdown": crate::inspector::budget::BudgetInspector::breakdown_to_json(
+                    &budget_breakdown
+                ),
             },
             "storage_diff": storage_diff,
         });
 
+        if args.dry_run {
+            result_obj["dry_run"] = serde_json::Value::Bool(true);
+        }
         if let Some(ref events) = json_events {
             result_obj["events"] = EventInspector::to_json_value(events);
         }
+        if let Some(ref diagnostics) = json_diagnostics {
+            result_obj["diagnostics"] = serde_json::to_value(diagnostics).map_err(|e| {
+                DebuggerError::FileError(format!("Failed to serialize diagnostics: {}", e))
+            })?;
+        }
         if let Some(auth_tree) = json_auth {
             result_obj["auth"] = crate::inspector::auth::AuthInspector::to_json_value(&auth_tree);
         }
@@ -925,6 +1436,36 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
         if let Some(ref ledger) = json_ledger {
             result_obj["ledger_entries"] = ledger.to_json();
         }
+        if let Some(ref ttls) = storage_ttls {
+            result_obj["storage_ttl"] = serde_json::to_value(ttls).unwrap_or_default();
+        }
+        if let Some(ref xdr) = footprint_xdr {
+            result_obj["footprint_xdr"] = serde_json::Value::String(xdr.clone());
+        }
+        if let Some(decoded) = decoded_result {
+            result_obj["decoded_result"] = decoded.into();
+        }
+        if !event_routes.is_empty() {
+            result_obj["forwarded_events"] =
+                forwarded_events.iter().map(|call| call.to_json()).collect();
+        }
+        if let Some(hotspots) = &hotspots {
+            result_obj["hotspots"] = serde_json::json!(hotspots);
+        }
+        if let Some(calls) = host_calls.as_ref().filter(|_| args.trace_host_functions) {
+            result_obj["host_calls"] = serde_json::to_value(calls).unwrap_or_default();
+        }
+        if let Some((duration, cpu_insns, _)) = timing {
+            result_obj["duration_ms"] = serde_json::json!(duration.as_secs_f64() * 1000.0);
+            result_obj["cpu_insns"] = serde_json::json!(cpu_insns);
+        }
+        if let Some(ref patch) = storage_patch {
+            result_obj["storage_patch"] = serde_json::Value::Array(patch.clone());
+        }
+        if let Some(ref diff) = decoded_diff {
+            result_obj["decoded_storage_diff"] =
+                serde_json::to_value(&diff.changes).unwrap_or_default();
+        }
 
         let output = serde_json::json!({
             "schema_version": "1.0",
@@ -937,28 +1478,54 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
                 "memory_bytes": budget.memory_bytes,
             },
             "storage_diff": storage_diff,
+            "warnings": warnings,
             "error": serde_json::Value::Null
         });
 
-        match serde_json::to_string_pretty(&output) {
-            Ok(json) => println!("{}", json),
-            Err(e) => {
-                let err_output = serde_json::json!({
-                    "schema_version": "1.0",
-                    "command": "run",
-                    "status": "error",
-                    "result": serde_json::Value::Null,
-                    "error": {
-                        "message": format!("Failed to serialize output: {}", e)
+        match args.result_format() {
+            RunOutputFormat::Yaml => {
+                let yaml = serde_norway::to_string(&output).map_err(|e| {
+                    DebuggerError::FileError(format!("Failed to serialize output: {}", e))
+                })?;
+                print!("{}", yaml);
+            }
+            RunOutputFormat::Csv => {
+                let events = json_events.as_deref().unwrap_or_default();
+                let csv = run_output_csv(function, &result, &storage_diff, events);
+                print!("{}", csv);
+            }
+            RunOutputFormat::Pretty | RunOutputFormat::Json => {
+                match serde_json::to_string_pretty(&output) {
+                    Ok(json) => println!("{}", json),
+                    Err(e) => {
+                        let err_output = serde_json::json!({
+                            "schema_version": "1.0",
+                            "command": "run",
+                            "status": "error",
+                            "result": serde_json::Value::Null,
+                            "error": {
+                                "message": format!("Failed to serialize output: {}", e)
+                            }
+                        });
+                        if let Ok(err_json) = serde_json::to_string_pretty(&err_output) {
+                            println!("{}", err_json);
+                        }
                     }
-                });
-                if let Ok(err_json) = serde_json::to_string_pretty(&err_output) {
-                    println!("{}", err_json);
                 }
             }
         }
     }
 
+    if let Some(baseline_path) = &args.baseline {
+        check_budget_baseline(
+            baseline_path,
+            function,
+            &budget,
+            args.tolerance,
+            args.is_structured_output(),
+        )?;
+    }
+
     if let Some(trace_path) = &args.trace_output {
         print_info(format!("\nExporting execution trace to: {:?}", trace_path));
 
@@ -990,9 +1557,112 @@ pub fn run(args: RunArgs, verbosity: Verbosity) -> Result<()> {
         }
     }
 
+    if args.dry_run && !args.is_structured_output() {
+        print_warning("\nDRY RUN — no state committed");
+    }
+
+    Ok(())
+}
+
+/// Gate `run` on the `--baseline` file: record `function`'s budget the first time
+/// it is seen, otherwise fail when it grew by more than `tolerance` percent.
+fn check_budget_baseline(
+    path: &std::path::Path,
+    function: &str,
+    budget: &crate::inspector::budget::BudgetInfo,
+    tolerance: f64,
+    structured: bool,
+) -> Result<()> {
+    use crate::runtime::baseline::BudgetBaseline;
+
+    let mut baseline = BudgetBaseline::load(path)?.unwrap_or_default();
+    let Some(deltas) = baseline.compare(function, budget, tolerance) else {
+        baseline.insert(function, budget);
+        baseline.save(path)?;
+        if !structured {
+            print_success(format!(
+                "Recorded budget baseline for '{}' in {:?}",
+                function, path
+            ));
+        }
+        return Ok(());
+    };
+
+    if !structured {
+        print_info(format!("\n--- Budget Baseline ({}) ---", function));
+        for delta in &deltas {
+            if delta.regressed {
+                print_warning(delta.to_string());
+            } else {
+                print_info(delta.to_string());
+            }
+        }
+    }
+    let regressed: Vec<String> = deltas
+        .iter()
+        .filter(|delta| delta.regressed)
+        .map(|delta| format!("{} grew {:+.2}%", delta.metric, delta.percent))
+        .collect();
+    if !regressed.is_empty() {
+        return Err(DebuggerError::BudgetExceeded(format!(
+            "'{}' regressed against baseline {:?}: {} (tolerance {}%)",
+            function,
+            path,
+            regressed.join(", "),
+            tolerance
+        ))
+        .into());
+    }
     Ok(())
 }
 
+/// Flatten a run into `section,key,before,after` CSV rows: one `result` row,
+/// one `storage` row per changed key and one `event` row per event.
+fn run_output_csv(
+    function: &str,
+    result: &str,
+    storage_diff: &crate::inspector::storage::StorageDiff,
+    events: &[ContractEvent],
+) -> String {
+    let row = |section: &str, key: &str, before: &str, after: &str| {
+        vec![
+            section.to_string(),
+            key.to_string(),
+            before.to_string(),
+            after.to_string(),
+        ]
+    };
+
+    let mut rows = vec![
+        row("section", "key", "before", "after"),
+        row("result", function, "", result),
+    ];
+
+    let mut keys: Vec<&String> = storage_diff
+        .added
+        .keys()
+        .chain(storage_diff.modified.keys())
+        .chain(storage_diff.deleted.iter())
+        .collect();
+    keys.sort();
+    for key in keys {
+        let (before, after) = match (storage_diff.added.get(key), storage_diff.modified.get(key)) {
+            (Some(after), _) => ("", after.as_str()),
+            (None, Some((before, after))) => (before.as_str(), after.as_str()),
+            (None, None) => ("", ""),
+        };
+        rows.push(row("storage", key, before, after));
+    }
+
+    for event in events {
+        let after = format!("[{}] {}", event.topics.join(", "), event.data);
+        let index = event.index.map(|i| i.to_string()).unwrap_or_default();
+        rows.push(row("event", &index, "", &after));
+    }
+
+    crate::output::to_csv(&rows)
+}
+
 #[allow(clippy::too_many_arguments)]
 fn build_execution_trace(
     function: &str,
@@ -1078,46 +1748,6 @@ fn build_execution_trace(
     }
 }
 
-/// Execute run command in dry-run mode.
-fn run_dry_run(args: &RunArgs) -> Result<()> {
-    let contract = args
-        .contract
-        .as_ref()
-        .expect("contract is required for dry-run");
-    print_info(format!("[DRY RUN] Loading contract: {:?}", contract));
-
-    let wasm_file = crate::utils::wasm::load_wasm(contract)
-        .with_context(|| format!("Failed to read WASM file: {:?}", contract))?;
-    let wasm_bytes = wasm_file.bytes;
-    let wasm_hash = wasm_file.sha256_hash;
-
-    if let Some(expected) = &args.expected_hash {
-        if expected.to_lowercase() != wasm_hash {
-            return Err((crate::DebuggerError::ChecksumMismatch(
-                expected.clone(),
-                wasm_hash.clone(),
-            ))
-            .into());
-        }
-    }
-
-    print_success(format!(
-        "[DRY RUN] Contract loaded successfully ({} bytes)",
-        wasm_bytes.len()
-    ));
-
-    if args.verbose {
-        print_verbose(format!("[DRY RUN] SHA-256: {}", wasm_hash));
-        if args.expected_hash.is_some() {
-            print_verbose("[DRY RUN] Checksum verified ✓");
-        }
-    }
-
-    print_info("[DRY RUN] Skipping execution");
-
-    Ok(())
-}
-
 /// Get instruction counts from the debugger engine
 #[allow(dead_code)]
 fn get_instruction_counts(
@@ -1202,7 +1832,7 @@ pub fn upgrade_check(args: UpgradeCheckArgs) -> Result<()> {
 
     // Optionally run test inputs against both versions
     let execution_diffs = if let Some(inputs_json) = &args.test_inputs {
-        run_test_inputs(inputs_json, &old_wasm, &new_wasm)?
+        run_test_inputs(inputs_json, &old_wasm, &new_wasm, args.fail_fast, args.jobs)?
     } else {
         Vec::new()
     };
@@ -1210,16 +1840,24 @@ pub fn upgrade_check(args: UpgradeCheckArgs) -> Result<()> {
     let old_path = args.old.to_string_lossy().to_string();
     let new_path = args.new.to_string_lossy().to_string();
 
-    let report =
-        UpgradeAnalyzer::analyze(&old_wasm, &new_wasm, &old_path, &new_path, execution_diffs)?;
+    let report = UpgradeAnalyzer::analyze(
+        &old_wasm,
+        &new_wasm,
+        &old_path,
+        &new_path,
+        execution_diffs,
+        args.strict_types,
+    )?;
 
-    let output = match args.output.as_str() {
-        "json" => {
-            let envelope = crate::output::VersionedOutput::success("upgrade-check", &report);
-            serde_json::to_string_pretty(&envelope)
-                .map_err(|e| miette::miette!("Failed to serialize report: {}", e))?
-        }
-        _ => format_text_report(&report),
+    let format = args.format.unwrap_or(match args.output.as_str() {
+        "json" => UpgradeReportFormat::Json,
+        "markdown" | "md" => UpgradeReportFormat::Markdown,
+        _ => UpgradeReportFormat::Text,
+    });
+    let output = match format {
+        UpgradeReportFormat::Json => format_json_report(&report)?,
+        UpgradeReportFormat::Markdown => format_markdown_report(&report),
+        UpgradeReportFormat::Text => format_text_report(&report),
     };
 
     if let Some(out_file) = &args.output_file {
@@ -1230,23 +1868,227 @@ pub fn upgrade_check(args: UpgradeCheckArgs) -> Result<()> {
         println!("{}", output);
     }
 
+    if report.is_compatible && !report.type_warnings.is_empty() {
+        print_warning(format!(
+            "{} type(s) could not be resolved and need manual review",
+            report.type_warnings.len()
+        ));
+    }
+    if let Some(sdk_warning) = &report.sdk_warning {
+        print_warning(sdk_warning);
+    }
+
     if !report.is_compatible {
+        let breaking_storage = report
+            .storage_changes
+            .iter()
+            .filter(|c| c.is_breaking())
+            .count();
         return Err(miette::miette!(
             "Contracts are not compatible: {} breaking change(s) detected",
-            report.breaking_changes.len()
+            report.breaking_changes.len() + breaking_storage
         ));
     }
 
     Ok(())
 }
 
+/// Compare the function signatures of two contracts without executing them
+pub fn diff(args: DiffArgs) -> Result<()> {
+    let old_wasm = fs::read(&args.old)
+        .map_err(|e| miette::miette!("Failed to read old WASM file {:?}: {}", args.old, e))?;
+    let new_wasm = fs::read(&args.new)
+        .map_err(|e| miette::miette!("Failed to read new WASM file {:?}: {}", args.new, e))?;
+
+    let old_functions = crate::utils::wasm::parse_function_signatures(&old_wasm)?;
+    let new_functions = crate::utils::wasm::parse_function_signatures(&new_wasm)?;
+    let (breaking, non_breaking) =
+        UpgradeAnalyzer::diff_signatures(&old_functions, &new_functions).into_changes();
+
+    if args.format == OutputFormat::Json {
+        let report = serde_json::json!({
+            "old": args.old.to_string_lossy(),
+            "new": args.new.to_string_lossy(),
+            "breaking_changes": breaking,
+            "non_breaking_changes": non_breaking,
+        });
+        let envelope = crate::output::VersionedOutput::success("diff", &report);
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&envelope).map_err(|e| {
+                DebuggerError::FileError(format!("Failed to serialize diff report: {}", e))
+            })?
+        );
+        return Ok(());
+    }
+
+    if breaking.is_empty() && non_breaking.is_empty() {
+        print_success("No function signature changes");
+        return Ok(());
+    }
+    for change in &breaking {
+        println!("{}", change);
+    }
+    for change in &non_breaking {
+        println!("{}", change);
+    }
+    print_info(format!(
+        "{} breaking, {} non-breaking change(s)",
+        breaking.len(),
+        non_breaking.len()
+    ));
+    Ok(())
+}
+
+/// Parse a function's arguments against the contract spec without invoking it
+pub fn validate_args(args: ValidateArgsArgs) -> Result<()> {
+    use soroban_env_host::xdr::ScVal;
+    use soroban_sdk::TryFromVal;
+
+    let wasm = fs::read(&args.contract)
+        .map_err(|e| miette::miette!("Failed to read WASM file {:?}: {}", args.contract, e))?;
+    if !crate::utils::wasm::parse_functions(&wasm)?
+        .iter()
+        .any(|name| name == &args.function)
+    {
+        return Err(DebuggerError::InvalidFunction(args.function.clone()).into());
+    }
+    // Prepared exactly as `run` prepares them, so both see the same JSON.
+    let args_json = crate::utils::arguments::prepare_args(
+        args.args.as_deref(),
+        args.args_file.as_deref(),
+        args.expand_env,
+    )?
+    .unwrap_or_else(|| "[]".to_string());
+
+    let signatures = crate::utils::wasm::parse_function_signatures(&wasm)?;
+    let env = soroban_sdk::Env::default();
+    let vals =
+        crate::runtime::parser::parse_args_checked(&env, &signatures, &args.function, &args_json)?;
+    let scvals = vals
+        .iter()
+        .map(|val| ScVal::try_from_val(&env, val))
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|e| {
+            DebuggerError::InvalidArguments(format!("Failed to convert argument: {:?}", e))
+        })?;
+    let decoded = crate::utils::scval::decode_args(&wasm, &args.function, &scvals);
+
+    let params = signatures
+        .iter()
+        .find(|sig| sig.name == args.function)
+        .map(|sig| sig.params.as_slice())
+        .unwrap_or_default();
+    let resolved: Vec<serde_json::Value> = decoded
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let param = params.get(index);
+            serde_json::json!({
+                "name": param.map(|p| p.name.as_str()),
+                "type": param.map(|p| p.type_name.as_str()),
+                "value": value,
+            })
+        })
+        .collect();
+
+    if args.format == OutputFormat::Json {
+        let report = serde_json::json!({
+            "function": args.function,
+            "args": resolved,
+        });
+        let envelope = crate::output::VersionedOutput::success("validate-args", &report);
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&envelope).map_err(|e| {
+                DebuggerError::FileError(format!("Failed to serialize arguments: {}", e))
+            })?
+        );
+        return Ok(());
+    }
+
+    for (index, (scval, value)) in scvals.iter().zip(&decoded).enumerate() {
+        let shown = crate::utils::scval::display_decoded(scval, value);
+        match params.get(index) {
+            Some(param) => println!("{} ({}) = {}", param.name, param.type_name, shown),
+            None => println!("#{} = {}", index, shown),
+        }
+    }
+    print_success(format!(
+        "{} argument(s) valid for '{}'",
+        decoded.len(),
+        args.function
+    ));
+    Ok(())
+}
+
+/// Check that each contract implements the functions of a shared interface
+pub fn check_interface(args: CheckInterfaceArgs) -> Result<()> {
+    use crate::analyzer::interface::{InterfaceChecker, InterfaceReport};
+
+    let interface = InterfaceChecker::load_interface(&args.interface)?;
+    let mut contracts = Vec::with_capacity(args.contracts.len());
+    for path in &args.contracts {
+        let wasm = fs::read(path)
+            .map_err(|e| miette::miette!("Failed to read WASM file {:?}: {}", path, e))?;
+        contracts.push(InterfaceChecker::check_wasm(
+            &interface,
+            &path.to_string_lossy(),
+            &wasm,
+        )?);
+    }
+
+    let report = InterfaceReport {
+        interface_path: args.interface.to_string_lossy().to_string(),
+        interface,
+        contracts,
+    };
+
+    if args.format == OutputFormat::Json {
+        let envelope = crate::output::VersionedOutput::success("check-interface", &report);
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&envelope).map_err(|e| {
+                DebuggerError::FileError(format!("Failed to serialize interface report: {}", e))
+            })?
+        );
+    } else {
+        print_info(format!(
+            "Interface: {} ({} function(s))",
+            report.interface_path,
+            report.interface.len()
+        ));
+        for conformance in &report.contracts {
+            println!("{}", conformance);
+        }
+    }
+
+    let drifted = report.contracts.iter().filter(|c| !c.conforms).count();
+    if drifted > 0 {
+        return Err(miette::miette!(
+            "{} of {} contract(s) do not conform to the interface",
+            drifted,
+            report.contracts.len()
+        ));
+    }
+
+    print_success(format!(
+        "All {} contract(s) conform to the interface",
+        report.contracts.len()
+    ));
+    Ok(())
+}
+
 /// Run test inputs against both WASM versions and collect diffs
 fn run_test_inputs(
     inputs_json: &str,
     old_wasm: &[u8],
     new_wasm: &[u8],
+    fail_fast: bool,
+    jobs: Option<usize>,
 ) -> Result<Vec<ExecutionDiff>> {
-    let inputs: serde_json::Map<String, serde_json::Value> = serde_json
+    // Parsed into an ordered map so inputs run in file order.
+    let inputs: indexmap::IndexMap<String, serde_json::Value> = serde_json
         ::from_str(inputs_json)
         .map_err(|e|
             miette::miette!(
@@ -1255,13 +2097,37 @@ fn run_test_inputs(
             )
         )?;
 
+    let invoke = |func_name: &str, args_str: &str| {
+        (
+            invoke_wasm(old_wasm, func_name, args_str),
+            invoke_wasm(new_wasm, func_name, args_str),
+        )
+    };
+
+    // --fail-fast stays sequential so inputs after the first mismatch never run.
+    if fail_fast || jobs == Some(1) {
+        Ok(collect_execution_diffs(&inputs, fail_fast, invoke))
+    } else {
+        collect_execution_diffs_parallel(&inputs, jobs, invoke)
+    }
+}
+
+/// Compare old/new results for each input in order.
+///
+/// With `fail_fast` the remaining inputs are skipped after the first mismatch.
+fn collect_execution_diffs<F>(
+    inputs: &indexmap::IndexMap<String, serde_json::Value>,
+    fail_fast: bool,
+    mut invoke: F,
+) -> Vec<ExecutionDiff>
+where
+    F: FnMut(&str, &str) -> (String, String),
+{
     let mut diffs = Vec::new();
 
-    for (func_name, args_val) in &inputs {
+    for (func_name, args_val) in inputs {
         let args_str = args_val.to_string();
-
-        let old_result = invoke_wasm(old_wasm, func_name, &args_str);
-        let new_result = invoke_wasm(new_wasm, func_name, &args_str);
+        let (old_result, new_result) = invoke(func_name, &args_str);
 
         let outputs_match = old_result == new_result;
         diffs.push(ExecutionDiff {
@@ -1271,9 +2137,61 @@ fn run_test_inputs(
             new_result,
             outputs_match,
         });
+
+        if fail_fast && !outputs_match {
+            print_warning(format!(
+                "Stopping at first mismatch in '{}' (--fail-fast); {} input(s) skipped",
+                func_name,
+                inputs.len() - diffs.len()
+            ));
+            break;
+        }
     }
 
-    Ok(diffs)
+    diffs
+}
+
+/// Compare old/new results for every input on a rayon pool of `jobs` threads.
+///
+/// Each invocation builds its own executors; the diffs keep the input order.
+fn collect_execution_diffs_parallel<F>(
+    inputs: &indexmap::IndexMap<String, serde_json::Value>,
+    jobs: Option<usize>,
+    invoke: F,
+) -> Result<Vec<ExecutionDiff>>
+where
+    F: Fn(&str, &str) -> (String, String) + Sync,
+{
+    use rayon::prelude::*;
+
+    if jobs == Some(0) {
+        return Err(miette::miette!("--jobs must be at least 1"));
+    }
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs.unwrap_or(0))
+        .build()
+        .map_err(|e| miette::miette!("Failed to start test input thread pool: {}", e))?;
+
+    let cases: Vec<(&String, String)> = inputs
+        .iter()
+        .map(|(func_name, args_val)| (func_name, args_val.to_string()))
+        .collect();
+
+    Ok(pool.install(|| {
+        cases
+            .into_par_iter()
+            .map(|(func_name, args_str)| {
+                let (old_result, new_result) = invoke(func_name, &args_str);
+                ExecutionDiff {
+                    function: func_name.clone(),
+                    args: args_str,
+                    outputs_match: old_result == new_result,
+                    old_result,
+                    new_result,
+                }
+            })
+            .collect()
+    }))
 }
 
 /// Invoke a function on a WASM contract and return a string representation of the result
@@ -1295,6 +2213,146 @@ fn invoke_wasm(wasm: &[u8], function: &str, args: &str) -> String {
     }
 }
 
+/// Serialize a compatibility report inside the versioned JSON envelope
+fn format_json_report(report: &CompatibilityReport) -> Result<String> {
+    let envelope = crate::output::VersionedOutput::success("upgrade-check", report);
+    serde_json::to_string_pretty(&envelope)
+        .map_err(|e| miette::miette!("Failed to serialize report: {}", e))
+}
+
+/// Render a contract function signature as `name(param: Type, ...) -> Ret`
+fn format_contract_signature(sig: &crate::utils::wasm::ContractFunctionSignature) -> String {
+    let params: Vec<String> = sig
+        .params
+        .iter()
+        .map(|p| format!("{}: {}", p.name, p.type_name))
+        .collect();
+    match &sig.return_type {
+        Some(ret) => format!("{}({}) -> {}", sig.name, params.join(", "), ret),
+        None => format!("{}({})", sig.name, params.join(", ")),
+    }
+}
+
+/// Format a compatibility report as Markdown, e.g. for a PR comment or CI summary
+fn format_markdown_report(report: &CompatibilityReport) -> String {
+    let mut out = String::new();
+
+    out.push_str("# Contract Upgrade Compatibility Report\n\n");
+    let status = if !report.is_compatible {
+        "INCOMPATIBLE"
+    } else if report.type_warnings.is_empty() {
+        "COMPATIBLE"
+    } else {
+        "COMPATIBLE (manual review required)"
+    };
+    out.push_str(&format!("**Status:** {}\n\n", status));
+    if let Some(sdk_warning) = &report.sdk_warning {
+        out.push_str(&format!("> **Warning:** {}\n\n", sdk_warning));
+    }
+    out.push_str(&format!("- Old: `{}`\n", report.old_wasm_path));
+    out.push_str(&format!("- New: `{}`\n\n", report.new_wasm_path));
+
+    let breaking_storage = report
+        .storage_changes
+        .iter()
+        .filter(|c| c.is_breaking())
+        .count();
+    let mismatches = report
+        .execution_diffs
+        .iter()
+        .filter(|d| !d.outputs_match)
+        .count();
+    out.push_str("| Category | Breaking | Non-breaking |\n");
+    out.push_str("|---|---|---|\n");
+    out.push_str(&format!(
+        "| Interface | {} | {} |\n",
+        report.breaking_changes.len(),
+        report.non_breaking_changes.len()
+    ));
+    out.push_str(&format!(
+        "| Storage layout | {} | {} |\n",
+        breaking_storage,
+        report.storage_changes.len() - breaking_storage
+    ));
+    out.push_str(&format!(
+        "| Execution | {} | {} |\n",
+        mismatches,
+        report.execution_diffs.len() - mismatches
+    ));
+
+    let sections: [(&str, Vec<String>); 4] = [
+        (
+            "Breaking Changes",
+            report
+                .breaking_changes
+                .iter()
+                .map(|c| c.to_string())
+                .collect(),
+        ),
+        (
+            "Non-Breaking Changes",
+            report
+                .non_breaking_changes
+                .iter()
+                .map(|c| c.to_string())
+                .collect(),
+        ),
+        (
+            "Storage Layout Changes",
+            report
+                .storage_changes
+                .iter()
+                .map(|c| c.to_string())
+                .collect(),
+        ),
+        (
+            "Needs Manual Review",
+            report.type_warnings.iter().map(|w| w.to_string()).collect(),
+        ),
+    ];
+    for (title, items) in sections {
+        if items.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("\n## {}\n\n", title));
+        for item in items {
+            out.push_str(&format!("- `{}`\n", item));
+        }
+    }
+
+    if !report.execution_diffs.is_empty() {
+        out.push_str("\n## Execution Diffs\n\n");
+        out.push_str("| Function | Args | Old | New | Match |\n");
+        out.push_str("|---|---|---|---|---|\n");
+        for diff in &report.execution_diffs {
+            out.push_str(&format!(
+                "| `{}` | `{}` | `{}` | `{}` | {} |\n",
+                diff.function,
+                diff.args,
+                diff.old_result,
+                diff.new_result,
+                if diff.outputs_match { "yes" } else { "no" }
+            ));
+        }
+    }
+
+    out.push_str("\n<details>\n<summary>Function signatures</summary>\n\n");
+    for (label, functions) in [
+        ("Old", &report.old_functions),
+        ("New", &report.new_functions),
+    ] {
+        out.push_str(&format!("### {} ({})\n\n```text\n", label, functions.len()));
+        for sig in functions {
+            out.push_str(&format_contract_signature(sig));
+            out.push('\n');
+        }
+        out.push_str("```\n\n");
+    }
+    out.push_str("</details>\n");
+
+    out
+}
+
 /// Format a compatibility report as human-readable text
 fn format_text_report(report: &CompatibilityReport) -> String {
     let mut out = String::new();
@@ -1305,12 +2363,17 @@ fn format_text_report(report: &CompatibilityReport) -> String {
     out.push_str(&format!("New: {}\n", report.new_wasm_path));
     out.push('\n');
 
-    let status = if report.is_compatible {
+    let status = if !report.is_compatible {
+        "INCOMPATIBLE"
+    } else if report.type_warnings.is_empty() {
         "COMPATIBLE"
     } else {
-        "INCOMPATIBLE"
+        "COMPATIBLE (manual review required)"
     };
     out.push_str(&format!("Status: {}\n", status));
+    if let Some(sdk_warning) = &report.sdk_warning {
+        out.push_str(&format!("Warning: {}\n", sdk_warning));
+    }
 
     out.push('\n');
     out.push_str(&format!(
@@ -1338,6 +2401,33 @@ fn format_text_report(report: &CompatibilityReport) -> String {
         }
     }
 
+    if !report.storage_changes.is_empty() {
+        out.push('\n');
+        out.push_str(&format!(
+            "Storage Layout Changes ({}):\n",
+            report.storage_changes.len()
+        ));
+        for change in &report.storage_changes {
+            let severity = if change.is_breaking() {
+                "BREAKING"
+            } else {
+                "OK"
+            };
+            out.push_str(&format!("  {} [{}]\n", change, severity));
+        }
+    }
+
+    if !report.type_warnings.is_empty() {
+        out.push('\n');
+        out.push_str(&format!(
+            "Needs Manual Review ({}):\n",
+            report.type_warnings.len()
+        ));
+        for warning in &report.type_warnings {
+            out.push_str(&format!("  {}\n", warning));
+        }
+    }
+
     if !report.execution_diffs.is_empty() {
         out.push('\n');
         out.push_str(&format!(
@@ -1469,6 +2559,32 @@ pub fn optimize(args: OptimizeArgs, _verbosity: Verbosity) -> Result<()> {
         args.function.clone()
     };
 
+    let size_analysis = crate::profiler::size::SizeAnalysis::analyze(&wasm_bytes, &args.function)?;
+    for name in &size_analysis.extra_exports {
+        print_warning(format!(
+            "Export `{}` is not part of the contract interface (no contractspecv0 entry)",
+            name
+        ));
+    }
+
+    if let Some(stripped_path) = &args.strip_custom_sections {
+        let (stripped, removed) = crate::profiler::size::strip_custom_sections(&wasm_bytes)?;
+        crate::utils::wasm::get_module_info(&stripped)?;
+        fs::write(stripped_path, &stripped).map_err(|e| {
+            DebuggerError::FileError(format!(
+                "Failed to write stripped WASM to {:?}: {}",
+                stripped_path, e
+            ))
+        })?;
+        print_success(format!(
+            "Stripped {} custom section(s) ({}), saving {} bytes: {:?}",
+            removed.len(),
+            removed.join(", "),
+            wasm_bytes.len() - stripped.len(),
+            stripped_path
+        ));
+    }
+
     let mut executor = ContractExecutor::new(wasm_bytes)?;
     if let Some(storage_json) = &args.storage {
         let storage = parse_storage(storage_json)?;
@@ -1512,7 +2628,9 @@ pub fn optimize(args: OptimizeArgs, _verbosity: Verbosity) -> Result<()> {
 
     let contract_path_str = args.contract.to_string_lossy().to_string();
     let report = optimizer.generate_report(&contract_path_str);
-    let markdown = optimizer.generate_markdown_report(&report);
+    let mut markdown = optimizer.generate_markdown_report(&report);
+    markdown.push('\n');
+    markdown.push_str(&size_analysis.to_markdown());
 
     if let Some(output_path) = &args.output {
         fs::write(output_path, &markdown).map_err(|e| {
@@ -1530,6 +2648,14 @@ pub fn optimize(args: OptimizeArgs, _verbosity: Verbosity) -> Result<()> {
         logging::log_display(&markdown, logging::LogLevel::Info);
     }
 
+    if args.fail_on_extra_exports && !size_analysis.extra_exports.is_empty() {
+        return Err(miette::miette!(
+            "{} exported function(s) are not part of the contract interface: {}",
+            size_analysis.extra_exports.len(),
+            size_analysis.extra_exports.join(", ")
+        ));
+    }
+
     Ok(())
 }
 
@@ -1644,6 +2770,10 @@ pub fn profile(args: ProfileArgs) -> Result<()> {
 
 /// Execute the compare command.
 pub fn compare(args: CompareArgs) -> Result<()> {
+    if args.compare_runs {
+        return compare_runs(&args);
+    }
+
     print_info(format!("Loading trace A: {:?}", args.trace_a));
     let trace_a = crate::compare::ExecutionTrace::from_file(&args.trace_a)?;
 
@@ -1654,7 +2784,8 @@ pub fn compare(args: CompareArgs) -> Result<()> {
     let filters = crate::compare::engine::CompareFilters::new(
         args.ignore_path.clone(),
         args.ignore_field.clone(),
-    )?;
+    )?
+    .with_ignored_event_fields(args.compare_events_ignoring.clone());
     let report = crate::compare::CompareEngine::compare_with_filters(&trace_a, &trace_b, &filters);
     let rendered = crate::compare::CompareEngine::render_report(&report);
 
@@ -1673,9 +2804,57 @@ pub fn compare(args: CompareArgs) -> Result<()> {
     Ok(())
 }
 
+/// `compare --compare-runs`: diff two `run --record` recordings.
+fn compare_runs(args: &CompareArgs) -> Result<()> {
+    use crate::runtime::recording::{ExecutionRecording, RunComparison};
+
+    print_info(format!("Loading run A: {:?}", args.trace_a));
+    let run_a = ExecutionRecording::load(&args.trace_a)?;
+    print_info(format!("Loading run B: {:?}", args.trace_b));
+    let run_b = ExecutionRecording::load(&args.trace_b)?;
+
+    let comparison = RunComparison::compare(&run_a, &run_b);
+    let mut lines = Vec::new();
+    if run_a.record.function != run_b.record.function {
+        lines.push(format!(
+            "Run A called '{}', run B called '{}'",
+            run_a.record.function, run_b.record.function
+        ));
+    }
+    lines.extend(comparison.format_lines());
+    let rendered = lines.join("\n");
+
+    if let Some(output_path) = &args.output {
+        fs::write(output_path, &rendered).map_err(|e| {
+            DebuggerError::FileError(format!(
+                "Failed to write report to {:?}: {}",
+                output_path, e
+            ))
+        })?;
+        print_success(format!("Comparison report written to: {:?}", output_path));
+    } else {
+        println!("{}", rendered);
+    }
+
+    let differing = comparison.differing_sections();
+    if !differing.is_empty() {
+        return Err(miette::miette!("Runs differ in {}", differing.join(", ")));
+    }
+    print_success("Runs match");
+    Ok(())
+}
+
 /// Execute the replay command.
 /// Execute the replay command.
 pub fn replay(args: ReplayArgs, verbosity: Verbosity) -> Result<()> {
+    let is_recording = fs::read_to_string(&args.trace_file)
+        .ok()
+        .and_then(|contents| serde_json::from_str::<serde_json::Value>(&contents).ok())
+        .is_some_and(|value| crate::runtime::recording::ExecutionRecording::is_recording(&value));
+    if is_recording {
+        return replay_recording(&args);
+    }
+
     print_info(format!("Loading trace file: {:?}", args.trace_file));
     let original_trace = crate::compare::ExecutionTrace::from_file(&args.trace_file)?;
 
@@ -1813,6 +2992,91 @@ pub fn replay(args: ReplayArgs, verbosity: Verbosity) -> Result<()> {
     Ok(())
 }
 
+/// Re-run a `run --record` recording and compare its result with the recorded one.
+fn replay_recording(args: &ReplayArgs) -> Result<()> {
+    print_info(format!("Loading recording: {:?}", args.trace_file));
+    let recording = crate::runtime::recording::ExecutionRecording::load(&args.trace_file)?;
+
+    let contract_path = match (&args.contract, &recording.contract) {
+        (Some(path), _) => path.clone(),
+        (None, Some(path)) => std::path::PathBuf::from(path),
+        (None, None) => {
+            return Err(DebuggerError::ExecutionError(
+                "No contract path specified and recording does not contain contract path"
+                    .to_string(),
+            )
+            .into())
+        }
+    };
+    print_info(format!("Loading contract: {:?}", contract_path));
+    let wasm_bytes = fs::read(&contract_path).map_err(|e| {
+        DebuggerError::WasmLoadError(format!(
+            "Failed to read WASM file at {:?}: {}",
+            contract_path, e
+        ))
+    })?;
+
+    let function = &recording.record.function;
+    let mut executor = ContractExecutor::new(wasm_bytes)?;
+    executor.restore_contract_data(&recording.entries_before)?;
+    print_info(format!(
+        "Restored {} storage entries",
+        recording.entries_before.len()
+    ));
+
+    print_info(format!("\n--- Replaying {} ---\n", function));
+    executor.execute_scvals(function, &recording.record.args)?;
+    let replayed = executor.last_execution().cloned();
+    let replayed_result = match &replayed {
+        Some(record) => record.result.clone(),
+        None => Err("Replayed invocation failed".to_string()),
+    };
+
+    let diff = recording.diff_result(&replayed_result);
+    let mut lines = vec![
+        format!("Function: {}", diff.function),
+        format!("Arguments: {}", diff.args),
+        format!("Recorded result: {}", diff.old_result),
+        format!("Replayed result: {}", diff.new_result),
+    ];
+    if let Some(record) = &replayed {
+        let replayed_storage = crate::inspector::storage::StorageInspector::compute_diff(
+            &record.storage_before,
+            &record.storage_after,
+            &[],
+        );
+        if replayed_storage.added != recording.storage_diff.added
+            || replayed_storage.modified != recording.storage_diff.modified
+        {
+            lines.push("Storage changes differ from the recording".to_string());
+        }
+    }
+    let rendered = lines.join("\n");
+
+    if let Some(output_path) = &args.output {
+        fs::write(output_path, &rendered).map_err(|e| {
+            DebuggerError::FileError(format!(
+                "Failed to write report to {:?}: {}",
+                output_path, e
+            ))
+        })?;
+        print_success(format!("\nReplay report written to: {:?}", output_path));
+    } else {
+        println!("{}", rendered);
+    }
+
+    if !diff.outputs_match {
+        return Err(miette::miette!(
+            "Replay mismatch: '{}' returned {} but the recording has {}",
+            function,
+            diff.new_result,
+            diff.old_result
+        ));
+    }
+    print_success("Replay matches the recording");
+    Ok(())
+}
+
 /// Start debug server for remote connections
 pub fn server(args: ServerArgs) -> Result<()> {
     print_info(format!(
@@ -1852,6 +3116,19 @@ pub fn server(args: ServerArgs) -> Result<()> {
         .and_then(|rt| rt.block_on(server.run(args.port)))
 }
 
+/// Start a Debug Adapter Protocol server
+pub fn dap(args: DapArgs) -> Result<()> {
+    let server = crate::protocol::server::DapServer::new(args.contract);
+    match args.port {
+        Some(port) => {
+            print_info(format!("Starting DAP server on 127.0.0.1:{}", port));
+            server.run_tcp(port)
+        }
+        // stdout carries the protocol itself, so nothing else may be printed there.
+        None => server.run_stdio(),
+    }
+}
+
 /// Connect to remote debug server
 pub fn remote(args: RemoteArgs, _verbosity: Verbosity) -> Result<()> {
     print_info(format!("Connecting to remote debugger at {}", args.remote));
@@ -1956,6 +3233,9 @@ pub fn interactive(args: InteractiveArgs, _verbosity: Verbosity) -> Result<()> {
 
     print_info("Starting interactive session (type 'help' for commands)");
     let mut ui = DebuggerUI::new(engine)?;
+    if !args.no_history {
+        ui.set_history_path(crate::ui::command_history::default_history_path());
+    }
     ui.queue_execution(args.function.clone(), parsed_args);
     ui.run()
 }
@@ -2006,8 +3286,20 @@ pub fn tui(args: TuiArgs, _verbosity: Verbosity) -> Result<()> {
 
 /// Inspect a WASM contract
 pub fn inspect(args: InspectArgs, _verbosity: Verbosity) -> Result<()> {
-    let wasm_file = crate::utils::wasm::load_wasm(&args.contract)
-        .with_context(|| format!("Failed to read WASM file: {:?}", args.contract))?;
+    let contract = match (&args.rpc, &args.contract_id, &args.contract) {
+        (Some(rpc), Some(contract_id), _) => {
+            crate::utils::rpc::fetch_contract_wasm(rpc, contract_id)?
+        }
+        (_, _, Some(contract)) => contract.clone(),
+        _ => {
+            return Err(DebuggerError::InvalidArguments(
+                "inspect needs --contract, or --rpc with --contract-id".to_string(),
+            )
+            .into())
+        }
+    };
+    let wasm_file = crate::utils::wasm::load_wasm(&contract)
+        .with_context(|| format!("Failed to read WASM file: {:?}", contract))?;
     if let Some(expected) = &args.expected_hash {
         if !wasm_file.sha256_hash.eq_ignore_ascii_case(expected) {
             return Err(crate::DebuggerError::ChecksumMismatch(
@@ -2024,20 +3316,74 @@ pub fn inspect(args: InspectArgs, _verbosity: Verbosity) -> Result<()> {
         return inspect_source_map_diagnostics(&args, &bytes);
     }
 
+    if let Some(storage_path) = &args.list_assets {
+        return inspect_list_assets(&args, storage_path);
+    }
+
+    if args.opcodes {
+        return inspect_opcodes(&args, &bytes);
+    }
+
+    if args.spec {
+        let spec = crate::utils::wasm::parse_contract_spec(&bytes)?;
+        let rendered = if args.format == OutputFormat::Json {
+            serde_json::to_string_pretty(&crate::output::VersionedOutput::success("inspect", spec))
+        } else {
+            serde_json::to_string_pretty(&spec)
+        };
+        println!(
+            "{}",
+            rendered.map_err(|e| {
+                DebuggerError::FileError(format!("Failed to serialize contract spec: {}", e))
+            })?
+        );
+        return Ok(());
+    }
+
+    let graph_format = if args.call_graph {
+        Some(GraphFormat::Dot)
+    } else {
+        args.dependency_graph
+    };
+    if let Some(format) = graph_format {
+        let calls = crate::utils::wasm::parse_cross_contract_calls(&bytes)?;
+        let graph = DependencyGraph::from_cross_contract_calls(&calls);
+        match format {
+            GraphFormat::Dot => println!("{}", graph.to_dot()),
+            GraphFormat::Mermaid => println!("{}", graph.to_mermaid()),
+        }
+        return Ok(());
+    }
+
     let info = crate::utils::wasm::get_module_info(&bytes)?;
+    let contract_meta = crate::utils::wasm::parse_metadata(&bytes).unwrap_or_default();
+    let extra_exports = crate::utils::wasm::exports_missing_from_spec(&bytes).unwrap_or_default();
     if args.format == OutputFormat::Json {
         let exported_functions = if args.functions {
-            Some(crate::utils::wasm::parse_function_signatures(&bytes)?)
+            let read_only = crate::utils::wasm::read_only_functions(&bytes).unwrap_or_default();
+            let sigs = crate::utils::wasm::parse_function_signatures(&bytes)?;
+            Some(
+                sigs.into_iter()
+                    .map(|sig| {
+                        let is_read_only = read_only.contains(&sig.name);
+                        let mut value = serde_json::to_value(&sig).unwrap_or_default();
+                        value["read_only"] = serde_json::Value::Bool(is_read_only);
+                        value
+                    })
+                    .collect::<Vec<_>>(),
+            )
         } else {
             None
         };
         let result = serde_json::json!({
-            "contract": args.contract.display().to_string(),
+            "contract": args.contract_label(),
             "size_bytes": info.total_size,
             "types": info.type_count,
             "functions": info.function_count,
             "exports": info.export_count,
+            "contract_meta": contract_meta,
             "exported_functions": exported_functions,
+            "extra_exports": extra_exports,
         });
         let envelope = crate::output::VersionedOutput::success("inspect", result);
         println!(
@@ -2049,13 +3395,17 @@ pub fn inspect(args: InspectArgs, _verbosity: Verbosity) -> Result<()> {
         return Ok(());
     }
 
-    println!("Contract: {:?}", args.contract);
+    println!("Contract: {:?}", args.contract_label());
     println!("Size: {} bytes", info.total_size);
+    if let Some(built_with) = built_with(&contract_meta) {
+        println!("Built with: {}", built_with);
+    }
     println!("Types: {}", info.type_count);
     println!("Functions: {}", info.function_count);
     println!("Exports: {}", info.export_count);
     if args.functions {
         let sigs = crate::utils::wasm::parse_function_signatures(&bytes)?;
+        let read_only = crate::utils::wasm::read_only_functions(&bytes).unwrap_or_default();
         println!("Exported functions:");
         for sig in &sigs {
             let params: Vec<String> = sig
@@ -2064,12 +3414,135 @@ pub fn inspect(args: InspectArgs, _verbosity: Verbosity) -> Result<()> {
                 .map(|p| format!("{}: {}", p.name, p.type_name))
                 .collect();
             let ret = sig.return_type.as_deref().unwrap_or("()");
-            println!("  {}({}) -> {}", sig.name, params.join(", "), ret);
+            let marker = if read_only.contains(&sig.name) {
+                " [read-only]"
+            } else {
+                ""
+            };
+            println!("  {}({}) -> {}{}", sig.name, params.join(", "), ret, marker);
+        }
+    }
+    if !extra_exports.is_empty() {
+        print_warning(format!(
+            "Exported but not part of the contract interface: {}",
+            extra_exports.join(", ")
+        ));
+    }
+    Ok(())
+}
+
+/// Summarise the toolchain recorded in `contractmetav0`, e.g. `soroban-sdk 22.0.11, rustc 1.92.0`.
+fn built_with(contract_meta: &std::collections::BTreeMap<String, String>) -> Option<String> {
+    let parts: Vec<String> = [("rssdkver", "soroban-sdk"), ("rsver", "rustc")]
+        .iter()
+        .filter_map(|(key, label)| {
+            // The SDK version carries a `#<commit>` suffix that is noise here.
+            let version = contract_meta.get(*key)?.split('#').next()?;
+            Some(format!("{label} {version}"))
+        })
+        .collect();
+    if parts.is_empty() {
+        None
+    } else {
+        Some(parts.join(", "))
+    }
+}
+
+fn inspect_list_assets(args: &InspectArgs, storage_path: &std::path::Path) -> Result<()> {
+    let contents = fs::read_to_string(storage_path).map_err(|e| {
+        DebuggerError::FileError(format!(
+            "Failed to read storage file {:?}: {}",
+            storage_path, e
+        ))
+    })?;
+    let typed: serde_json::Value = serde_json::from_str(&contents).map_err(|e| {
+        DebuggerError::StorageError(format!("Invalid storage JSON in {:?}: {}", storage_path, e))
+    })?;
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let assets = crate::inspector::assets::list_assets(&typed, args.stale_ttl, now);
+
+    if args.format == OutputFormat::Json {
+        let result = serde_json::json!({
+            "contract": args.contract_label(),
+            "layout_matched": assets.is_some(),
+            "assets": assets.unwrap_or_default(),
+        });
+        let envelope = crate::output::VersionedOutput::success("inspect", result);
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&envelope).map_err(|e| {
+                DebuggerError::FileError(format!("Failed to serialize inspect JSON output: {}", e))
+            })?
+        );
+        return Ok(());
+    }
+
+    match assets {
+        None => print_warning(
+            "Storage has no Price(asset) entries; --list-assets expects an oracle-style \
+             Price/Timestamp key layout in a typed storage export",
+        ),
+        Some(assets) if assets.is_empty() => print_info("No assets tracked"),
+        Some(assets) => {
+            for line in crate::inspector::assets::format_asset_table(&assets) {
+                println!("{}", line);
+            }
         }
     }
     Ok(())
 }
 
+fn inspect_opcodes(args: &InspectArgs, wasm_bytes: &[u8]) -> Result<()> {
+    let histogram = crate::runtime::instruction::InstructionParser::opcode_histogram(wasm_bytes);
+    let total: u64 = histogram.values().sum();
+
+    if args.format == OutputFormat::Json {
+        let result = serde_json::json!({
+            "contract": args.contract_label(),
+            "total_instructions": total,
+            "opcodes": histogram,
+        });
+        let envelope = crate::output::VersionedOutput::success("inspect", result);
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&envelope).map_err(|e| {
+                DebuggerError::FileError(format!("Failed to serialize inspect JSON output: {}", e))
+            })?
+        );
+        return Ok(());
+    }
+
+    if total == 0 {
+        print_warning("No function bodies could be read from the code section");
+        return Ok(());
+    }
+
+    let mut ranked: Vec<(&String, &u64)> = histogram.iter().collect();
+    ranked.sort_by(|(a_name, a_count), (b_name, b_count)| {
+        b_count.cmp(a_count).then(a_name.cmp(b_name))
+    });
+    let shown = ranked.len().min(args.opcode_limit);
+    println!(
+        "Opcodes: {} instructions, {} distinct (top {} shown)",
+        total,
+        ranked.len(),
+        shown
+    );
+    for (name, count) in ranked.into_iter().take(shown) {
+        println!(
+            "  {:<20} {:>8} {:>6.2}%",
+            name,
+            count,
+            *count as f64 / total as f64 * 100.0
+        );
+    }
+    Ok(())
+}
+
 fn inspect_source_map_diagnostics(args: &InspectArgs, wasm_bytes: &[u8]) -> Result<()> {
     let report =
         crate::debugger::source_map::SourceMap::inspect_wasm(wasm_bytes, args.source_map_limit)?;
@@ -2077,7 +3550,7 @@ fn inspect_source_map_diagnostics(args: &InspectArgs, wasm_bytes: &[u8]) -> Resu
     match args.format {
         OutputFormat::Json => {
             let output = SourceMapDiagnosticsCommandOutput {
-                contract: args.contract.display().to_string(),
+                contract: args.contract_label(),
                 source_map: report,
             };
             let pretty = serde_json::to_string_pretty(&output).map_err(|e| {
@@ -2089,7 +3562,7 @@ fn inspect_source_map_diagnostics(args: &InspectArgs, wasm_bytes: &[u8]) -> Resu
         }
         OutputFormat::Pretty => {
             println!("Source Map Diagnostics");
-            println!("Contract: {}", args.contract.display());
+            println!("Contract: {}", args.contract_label());
             println!("Resolved mappings: {}", report.mappings_count);
             println!("Fallback mode: {}", report.fallback_mode);
             println!("Fallback behavior: {}", report.fallback_message);
@@ -2180,6 +3653,45 @@ pub fn symbolic(args: SymbolicArgs, _verbosity: Verbosity) -> Result<()> {
     Ok(())
 }
 
+/// Fuzz a contract function with random, type-valid arguments
+pub fn fuzz(args: FuzzArgs) -> Result<()> {
+    print_info(format!("Loading contract: {:?}", args.contract));
+    let wasm_file = crate::utils::wasm::load_wasm(&args.contract)
+        .with_context(|| format!("Failed to read WASM file: {:?}", args.contract))?;
+
+    let seed = args.seed.unwrap_or_else(|| {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0)
+    });
+    let config = FuzzConfig {
+        iterations: args.iterations,
+        seed,
+        timeout_secs: args.timeout,
+        expected_codes: args.expect_code.clone(),
+        storage: args.storage.clone(),
+    };
+    let report = ContractFuzzer::new().fuzz(&wasm_file.bytes, &args.function, &config)?;
+
+    match args.format {
+        OutputFormat::Pretty => {
+            println!("{}", render_fuzz_report(&report));
+        }
+        OutputFormat::Json => {
+            let envelope = crate::output::VersionedOutput::success("fuzz", &report);
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&envelope).map_err(|e| {
+                    DebuggerError::FileError(format!("Failed to serialize fuzz report: {}", e))
+                })?
+            );
+        }
+    }
+
+    Ok(())
+}
+
 /// Analyze a contract
 pub fn analyze(args: AnalyzeArgs, _verbosity: Verbosity) -> Result<()> {
     print_info(format!("Loading contract: {:?}", args.contract));
@@ -2274,6 +3786,11 @@ pub fn scenario(args: ScenarioArgs, _verbosity: Verbosity) -> Result<()> {
     crate::scenario::run_scenario(args, _verbosity)
 }
 
+/// Run a script
+pub fn script(args: ScriptArgs, verbosity: Verbosity) -> Result<()> {
+    crate::script::run_script(args, verbosity)
+}
+
 /// Launch the REPL
 pub async fn repl(args: ReplArgs) -> Result<()> {
     print_info(format!("Loading contract: {:?}", args.contract));
@@ -2289,6 +3806,8 @@ pub async fn repl(args: ReplArgs) -> Result<()> {
         contract_path: args.contract,
         network_snapshot: args.network_snapshot,
         storage: args.storage,
+        log_json: args.log_json,
+        account_seed: args.account_seed,
     })
     .await
 }
@@ -2428,6 +3947,114 @@ pub fn history_prune(args: HistoryPruneArgs) -> Result<()> {
 mod tests {
     use super::*;
 
+    fn diverging_second_input(function: &str, _args: &str) -> (String, String) {
+        match function {
+            "transfer" => ("Ok(0)".to_string(), "Ok(1)".to_string()),
+            _ => ("Ok(7)".to_string(), "Ok(7)".to_string()),
+        }
+    }
+
+    fn three_inputs() -> indexmap::IndexMap<String, serde_json::Value> {
+        serde_json::from_str(r#"{"balance": [1], "transfer": [1, 2], "supply": []}"#).unwrap()
+    }
+
+    #[test]
+    fn markdown_report_has_summary_table_and_signatures() {
+        let sig = crate::utils::wasm::ContractFunctionSignature {
+            name: "set_price".to_string(),
+            params: vec![crate::utils::wasm::FunctionParam {
+                name: "price".to_string(),
+                type_name: "I128".to_string(),
+            }],
+            return_type: None,
+        };
+        let report = CompatibilityReport {
+            is_compatible: false,
+            old_wasm_path: "old.wasm".to_string(),
+            new_wasm_path: "new.wasm".to_string(),
+            breaking_changes: vec![crate::analyzer::upgrade::BreakingChange::FunctionRemoved {
+                name: "get_price".to_string(),
+            }],
+            non_breaking_changes: Vec::new(),
+            storage_changes: Vec::new(),
+            type_warnings: Vec::new(),
+            sdk_warning: None,
+            old_functions: vec![sig.clone()],
+            new_functions: vec![sig],
+            execution_diffs: Vec::new(),
+        };
+
+        let md = format_markdown_report(&report);
+        assert!(md.contains("**Status:** INCOMPATIBLE"));
+        assert!(md.contains("| Interface | 1 | 0 |"));
+        assert!(md.contains("- `[REMOVED] get_price`"));
+        assert!(md.contains("<details>"));
+        assert!(md.contains("set_price(price: I128)"));
+    }
+
+    #[test]
+    fn fail_fast_stops_at_first_mismatch() {
+        let mut calls = Vec::new();
+        let diffs = collect_execution_diffs(&three_inputs(), true, |f, a| {
+            calls.push(f.to_string());
+            diverging_second_input(f, a)
+        });
+
+        assert_eq!(calls, vec!["balance", "transfer"]);
+        assert_eq!(diffs.len(), 2);
+        assert!(diffs[0].outputs_match);
+        assert!(!diffs[1].outputs_match);
+    }
+
+    #[test]
+    fn collect_all_includes_inputs_after_mismatch() {
+        let diffs = collect_execution_diffs(&three_inputs(), false, diverging_second_input);
+
+        let functions: Vec<&str> = diffs.iter().map(|d| d.function.as_str()).collect();
+        assert_eq!(functions, vec!["balance", "transfer", "supply"]);
+        assert!(!diffs[1].outputs_match);
+        assert!(diffs[2].outputs_match);
+    }
+
+    #[test]
+    fn parallel_diffs_match_sequential_order() {
+        let inputs: indexmap::IndexMap<String, serde_json::Value> = serde_json::from_str(
+            r#"{"a": [1], "b": [2], "c": [3], "d": [4], "e": [5], "f": [6], "g": [7], "h": [8]}"#,
+        )
+        .unwrap();
+        // Earlier inputs sleep longer, so they finish last on the pool.
+        let invoke = |f: &str, a: &str| {
+            let delay = 8 - a
+                .trim_matches(|c| c == '[' || c == ']')
+                .parse::<u64>()
+                .unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(delay * 5));
+            let new = if f == "c" { "Ok(0)" } else { a };
+            (a.to_string(), new.to_string())
+        };
+
+        let sequential = collect_execution_diffs(&inputs, false, invoke);
+        let parallel = collect_execution_diffs_parallel(&inputs, Some(4), invoke).unwrap();
+
+        assert_eq!(parallel.len(), sequential.len());
+        for (p, s) in parallel.iter().zip(&sequential) {
+            assert_eq!(p.function, s.function);
+            assert_eq!(p.args, s.args);
+            assert_eq!(p.old_result, s.old_result);
+            assert_eq!(p.new_result, s.new_result);
+            assert_eq!(p.outputs_match, s.outputs_match);
+        }
+        assert!(!parallel[2].outputs_match);
+    }
+
+    #[test]
+    fn parallel_diffs_reject_zero_jobs() {
+        let err =
+            collect_execution_diffs_parallel(&three_inputs(), Some(0), diverging_second_input)
+                .unwrap_err();
+        assert!(err.to_string().contains("--jobs"));
+    }
+
     #[test]
     fn budget_trend_stats_or_err_returns_error_instead_of_panicking() {
         let empty: Vec<RunHistory> = Vec::new();
@@ -48,6 +48,7 @@ impl Default for RetryPolicy {
     }
 }
 
+#[derive(Debug)]
 pub struct RemoteClientConfig {
     pub timeouts: RequestTimeouts,
     pub retry: RetryPolicy,
@@ -194,11 +195,11 @@ impl RemoteClient {
                 }
             }
 
-            let mut client_config = ClientConfig::builder()
+            let builder = ClientConfig::builder()
                 .with_safe_defaults()
                 .with_root_certificates(root_store);
 
-            if let (Some(ref cert_path), Some(ref key_path)) = (&config.tls_cert, &config.tls_key) {
+            let client_config = if let (Some(ref cert_path), Some(ref key_path)) = (&config.tls_cert, &config.tls_key) {
                 let cert_file = std::fs::File::open(cert_path).map_err(|e| {
                     DebuggerError::FileError(format!("Failed to open client cert {:?}: {}", cert_path, e))
                 })?;
@@ -216,14 +217,15 @@ impl RemoteClient {
                 let keys = rustls_pemfile::pkcs8_private_keys(&mut key_reader)
                     .map_err(|e| DebuggerError::FileError(format!("Failed to parse client key: {}", e)))?;
                 
-                if let Some(key) = keys.into_iter().next() {
-                    client_config = client_config.with_client_auth_cert(certs, PrivateKey(key)).map_err(|e| {
-                        DebuggerError::FileError(format!("Failed to set client certificate: {}", e))
-                    })?;
-                }
+                let key = keys.into_iter().next().ok_or_else(|| {
+                    DebuggerError::FileError(format!("No PKCS#8 private key found in {:?}", key_path))
+                })?;
+                builder.with_client_auth_cert(certs, PrivateKey(key)).map_err(|e| {
+                    DebuggerError::FileError(format!("Failed to set client certificate: {}", e))
+                })?
             } else {
-                client_config = client_config.with_no_client_auth();
-            }
+                builder.with_no_client_auth()
+            };
 
             let host = addr.split(':').next().unwrap_or("localhost");
             let server_name = ServerName::try_from(host).map_err(|e| {
@@ -381,6 +383,30 @@ impl RemoteClient {
         }
     }
 
+    /// Step to the next contract call boundary, entering cross-contract callees
+    pub fn step_into_call(&mut self) -> Result<Option<crate::debugger::CallBoundary>> {
+        self.step_call(DebugRequest::StepInto)
+    }
+
+    /// Step to the next contract call boundary, running cross-contract calls as one step
+    pub fn step_over_call(&mut self) -> Result<Option<crate::debugger::CallBoundary>> {
+        self.step_call(DebugRequest::StepOver)
+    }
+
+    fn step_call(
+        &mut self,
+        request: DebugRequest,
+    ) -> Result<Option<crate::debugger::CallBoundary>> {
+        match self.send_request(request)? {
+            DebugResponse::CallStepResult { boundary, .. } => Ok(boundary),
+            DebugResponse::Error { message } => Err(DebuggerError::ExecutionError(message).into()),
+            _ => Err(
+                DebuggerError::ExecutionError("Unexpected response to call step".to_string())
+                    .into(),
+            ),
+        }
+    }
+
     /// Step out of current function
     pub fn step_out(&mut self) -> Result<(bool, Option<String>, u64)> {
         let response = self.send_request(DebugRequest::StepOut)?;
@@ -1038,6 +1064,7 @@ mod tests {
             },
             heartbeat_interval_ms: None,
             idle_timeout_ms: None,
+            ..RemoteClientConfig::default()
         };
 
         let mut client =
@@ -1128,6 +1155,7 @@ mod tests {
             },
             heartbeat_interval_ms: None,
             idle_timeout_ms: None,
+            ..RemoteClientConfig::default()
         };
 
         let mut client =
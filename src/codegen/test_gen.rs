@@ -140,6 +140,8 @@ mod tests {
             },
             storage_before: HashMap::new(),
             storage_after: HashMap::new(),
+            duration: std::time::Duration::default(),
+            cpu_insns: 0,
         };
         let wasm_path = Path::new("test.wasm");
         let code = TestGenerator::generate(&record, wasm_path).unwrap();
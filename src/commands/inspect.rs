@@ -1,7 +1,7 @@
 use std::path::Path;
 use crate::{
     cli::args::OutputFormat,
-    utils::wasm::{extract_contract_metadata, get_module_info, parse_function_signatures, parse_functions},
+    utils::wasm::{exports_missing_from_spec, extract_contract_metadata, get_module_info, parse_function_signatures, parse_functions, parse_metadata, read_only_functions},
     InspectArgs, Result,
 };
 use colored::Colorize;
@@ -46,6 +46,7 @@ struct FunctionSignatureJson {
     #[serde(skip_serializing_if = "Option::is_none")]
     return_type: Option<String>,
     has_source_debug: bool,
+    read_only: bool,
 }
 
 #[derive(Serialize)]
@@ -68,6 +69,7 @@ struct FullReport {
     functions: Vec<String>,
     signatures: Vec<crate::utils::wasm::FunctionSignature>,
     metadata: crate::utils::wasm::ContractMetadata,
+    contract_meta: std::collections::BTreeMap<String, String>,
     source_map: SourceMapReport,
 }
 
@@ -75,6 +77,7 @@ fn output_functions(path: &Path, wasm_bytes: &[u8], format: OutputFormat) -> Res
     let signatures = parse_function_signatures(wasm_bytes)?;
     let mut source_map = crate::debugger::source_map::SourceMap::new();
     let _ = source_map.load(wasm_bytes);
+    let read_only = read_only_functions(wasm_bytes).unwrap_or_default();
 
     match format {
         OutputFormat::Json => {
@@ -82,6 +85,7 @@ fn output_functions(path: &Path, wasm_bytes: &[u8], format: OutputFormat) -> Res
                 .into_iter()
                 .map(|sig| {
                     let has_debug = source_map.function_has_source_mapped(wasm_bytes, &sig.name);
+                    let is_read_only = read_only.contains(&sig.name);
                     FunctionSignatureJson {
                         name: sig.name,
                         params: sig.params.into_iter().map(|p| FunctionParam {
@@ -90,6 +94,7 @@ fn output_functions(path: &Path, wasm_bytes: &[u8], format: OutputFormat) -> Res
                         }).collect(),
                         return_type: sig.return_type.filter(|r| r != "Void"),
                         has_source_debug: has_debug,
+                        read_only: is_read_only,
                     }
                 })
                 .collect();
@@ -102,11 +107,11 @@ fn output_functions(path: &Path, wasm_bytes: &[u8], format: OutputFormat) -> Res
             println!("{}", serde_json::to_string_pretty(&listing)?);
             Ok(())
         }
-        OutputFormat::Pretty => print_pretty_functions(&signatures, wasm_bytes, &source_map),
+        OutputFormat::Pretty => print_pretty_functions(&signatures, wasm_bytes, &source_map, &read_only),
     }
 }
 
-fn print_pretty_functions(signatures: &[crate::utils::wasm::FunctionSignature], wasm_bytes: &[u8], source_map: &crate::debugger::source_map::SourceMap) -> Result<()> {
+fn print_pretty_functions(signatures: &[crate::utils::wasm::FunctionSignature], wasm_bytes: &[u8], source_map: &crate::debugger::source_map::SourceMap, read_only: &std::collections::BTreeSet<String>) -> Result<()> {
     if signatures.is_empty() {
         let functions = parse_functions(wasm_bytes).unwrap_or_default();
         if functions.is_empty() {
@@ -121,8 +126,8 @@ fn print_pretty_functions(signatures: &[crate::utils::wasm::FunctionSignature],
         }
     } else {
         let name_w = std::cmp::max(signatures.iter().map(|s| s.name.len()).max().unwrap_or(8), 8);
-        println!("{:<name_w$}  {:<45}  Source/Debug", "Function", "Signature", name_w = name_w);
-        println!("{}  {}  {}", "─".repeat(name_w), "─".repeat(45), "─".repeat(12));
+        println!("{:<name_w$}  {:<45}  {:<12}  Read-only", "Function", "Signature", "Source/Debug", name_w = name_w);
+        println!("{}  {}  {}  {}", "─".repeat(name_w), "─".repeat(45), "─".repeat(12), "─".repeat(9));
 
         for sig in signatures {
             let params = sig.params.iter()
@@ -137,18 +142,34 @@ fn print_pretty_functions(signatures: &[crate::utils::wasm::FunctionSignature],
             let sig_str = format!("({}){}", params, ret);
             let has_debug = source_map.function_has_source_mapped(wasm_bytes, &sig.name);
             let debug_str = if has_debug { "Yes" } else { "No" };
+            let read_only_str = if read_only.contains(&sig.name) { "Yes" } else { "No" };
             
-            println!("{:<name_w$}  {:<45}  {}", sig.name, sig_str, debug_str, name_w = name_w);
+            println!("{:<name_w$}  {:<45}  {:<12}  {}", sig.name, sig_str, debug_str, read_only_str, name_w = name_w);
+        }
+
+        let extra = exports_missing_from_spec(wasm_bytes).unwrap_or_default();
+        if !extra.is_empty() {
+            println!("\n⚠  Exported but not part of the contract interface: {}", extra.join(", "));
         }
     }
     Ok(())
 }
 
+/// Summarise the toolchain recorded in `contractmetav0`, e.g. `soroban-sdk 22.0.11, rustc 1.92.0`.
+fn built_with(contract_meta: &std::collections::BTreeMap<String, String>) -> Option<String> {
+    let parts: Vec<String> = [("rssdkver", "soroban-sdk"), ("rsver", "rustc")]
+        .iter()
+        .filter_map(|(key, label)| contract_meta.get(*key).map(|v| format!("{label} {v}")))
+        .collect();
+    if parts.is_empty() { None } else { Some(parts.join(", ")) }
+}
+
 fn print_json_report(path: &Path, wasm_bytes: &[u8]) -> Result<()> {
     let info = get_module_info(wasm_bytes)?;
     let functions = parse_functions(wasm_bytes)?;
     let signatures = parse_function_signatures(wasm_bytes)?;
     let metadata = extract_contract_metadata(wasm_bytes)?;
+    let contract_meta = parse_metadata(wasm_bytes)?;
 
     let mut source_map = crate::debugger::source_map::SourceMap::new();
     let _ = source_map.load(wasm_bytes);
@@ -160,6 +181,7 @@ fn print_json_report(path: &Path, wasm_bytes: &[u8]) -> Result<()> {
         functions,
         signatures,
         metadata,
+        contract_meta,
         source_map: SourceMapReport {
             mappings_count: source_map.len(),
             diagnostics: source_map.diagnostics.clone(),
@@ -177,6 +199,7 @@ fn print_report(path: &Path, wasm_bytes: &[u8]) -> Result<()> {
     let info = get_module_info(wasm_bytes)?;
     let signatures = parse_function_signatures(wasm_bytes)?;
     let metadata = extract_contract_metadata(wasm_bytes)?;
+    let contract_meta = parse_metadata(wasm_bytes)?;
 
     let separator = "═".repeat(BAR_WIDTH);
     let size_kb = wasm_bytes.len() as f64 / 1024.0;
@@ -186,8 +209,12 @@ fn print_report(path: &Path, wasm_bytes: &[u8]) -> Result<()> {
     log_both(&separator);
     log_both("");
     log_both(&format!("  File : {}", path.display().to_string().bright_white()));
-    log_both(&format!("  Size : {} ({:.2} KB)\n", 
+    log_both(&format!("  Size : {} ({:.2} KB)", 
         format!("{} bytes", wasm_bytes.len()).bright_white(), size_kb));
+    if let Some(built_with) = built_with(&contract_meta) {
+        log_both(&format!("  Built with : {}", built_with.bright_white()));
+    }
+    log_both("");
 
     print_section("Module Statistics", || {
         log_both(&format!("  Types      : {}", info.type_count.to_string().bright_white()));
@@ -243,6 +270,11 @@ fn print_report(path: &Path, wasm_bytes: &[u8]) -> Result<()> {
                     .unwrap_or_default();
                 log_both(&format!("  {:<name_w$}  ({}){ret}", sig.name, params, name_w = name_w));
             }
+
+            let extra = exports_missing_from_spec(wasm_bytes).unwrap_or_default();
+            if !extra.is_empty() {
+                log_both(&format!("  ⚠  Exported but not part of the contract interface: {}", extra.join(", ")));
+            }
         }
     });
 
@@ -395,11 +427,15 @@ mod tests {
                         type_name: "Address".to_string(),
                     }],
                     return_type: None,
+                    has_source_debug: false,
+                    read_only: false,
                 },
                 FunctionSignatureJson {
                     name: "get_value".to_string(),
                     params: vec![],
                     return_type: Some("i64".to_string()),
+                    has_source_debug: false,
+                    read_only: true,
                 },
             ],
         };
@@ -88,6 +88,7 @@ pub enum DiffLine {
 pub struct CompareFilters {
     ignore_paths: Vec<Vec<String>>,
     ignore_fields: BTreeSet<String>,
+    ignore_event_fields: BTreeSet<String>,
 }
 
 impl CompareFilters {
@@ -100,9 +101,16 @@ impl CompareFilters {
         Ok(Self {
             ignore_paths: parsed_paths,
             ignore_fields: ignore_fields.into_iter().collect(),
+            ignore_event_fields: BTreeSet::new(),
         })
     }
 
+    /// Exclude named fields inside event data payloads from event equality.
+    pub fn with_ignored_event_fields(mut self, fields: Vec<String>) -> Self {
+        self.ignore_event_fields = fields.into_iter().collect();
+        self
+    }
+
     fn parse_path(path: &str) -> crate::Result<Vec<String>> {
         let trimmed = path.trim();
         if trimmed.is_empty() {
@@ -393,10 +401,87 @@ impl CompareEngine {
         entry: &EventEntry,
         filters: &CompareFilters,
     ) -> Option<serde_json::Value> {
-        let value = serde_json::to_value(entry).ok()?;
+        let mut value = serde_json::to_value(entry).ok()?;
+        if !filters.ignore_event_fields.is_empty() {
+            if let Some(serde_json::Value::String(data)) = value.get("data") {
+                let stripped = Self::strip_event_data_fields(data, &filters.ignore_event_fields);
+                value["data"] = stripped;
+            }
+        }
         Self::normalize_value(&value, &["events"], filters)
     }
 
+    /// Drop ignored fields from an event payload.
+    ///
+    /// JSON payloads are parsed and stripped structurally; anything else is
+    /// treated as a debug-formatted `ScVal` and matching symbol-keyed map
+    /// entries are cut out of the text.
+    fn strip_event_data_fields(data: &str, fields: &BTreeSet<String>) -> serde_json::Value {
+        if let Ok(mut parsed) = serde_json::from_str::<serde_json::Value>(data) {
+            Self::remove_fields(&mut parsed, fields);
+            return parsed;
+        }
+
+        let mut text = data.to_string();
+        for field in fields {
+            text = Self::strip_debug_map_entry(&text, field);
+        }
+        serde_json::Value::String(text)
+    }
+
+    fn remove_fields(value: &mut serde_json::Value, fields: &BTreeSet<String>) {
+        match value {
+            serde_json::Value::Object(map) => {
+                map.retain(|key, _| !fields.contains(key));
+                map.values_mut()
+                    .for_each(|child| Self::remove_fields(child, fields));
+            }
+            serde_json::Value::Array(items) => items
+                .iter_mut()
+                .for_each(|child| Self::remove_fields(child, fields)),
+            _ => {}
+        }
+    }
+
+    /// Remove `ScMapEntry { key: Symbol(..field..), val: .. }` from a debug-formatted map.
+    fn strip_debug_map_entry(data: &str, field: &str) -> String {
+        let needle = format!(
+            "ScMapEntry {{ key: Symbol(ScSymbol(StringM({}))), val: ",
+            field
+        );
+        let mut out = data.to_string();
+
+        while let Some(mut start) = out.find(&needle) {
+            let mut depth = 0usize;
+            let mut end = None;
+            for (offset, ch) in out[start..].char_indices() {
+                match ch {
+                    '{' | '(' | '[' => depth += 1,
+                    '}' | ')' | ']' => {
+                        depth = depth.saturating_sub(1);
+                        if depth == 0 {
+                            end = Some(start + offset + 1);
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            let Some(mut end) = end else {
+                break;
+            };
+
+            if out[end..].starts_with(", ") {
+                end += 2;
+            } else if out[..start].ends_with(", ") {
+                start -= 2;
+            }
+            out.replace_range(start..end, "");
+        }
+
+        out
+    }
+
     fn normalize_value(
         value: &serde_json::Value,
         path: &[&str],
@@ -934,6 +1019,60 @@ mod tests {
         assert!(report.return_value_diff.equal);
     }
 
+    #[test]
+    fn test_ignored_event_field_makes_events_equal() {
+        let mut a = make_trace_a();
+        let mut b = make_trace_a();
+        let price_event = |data: &str| EventEntry {
+            contract_id: Some("ORACLE".to_string()),
+            topics: vec!["price_updated".to_string()],
+            data: Some(data.to_string()),
+        };
+        a.events = vec![price_event(
+            r#"{"asset":"XLM","price":1100000,"timestamp":5000}"#,
+        )];
+        b.events = vec![price_event(
+            r#"{"asset":"XLM","price":1100000,"timestamp":6120}"#,
+        )];
+
+        let strict = CompareEngine::compare(&a, &b);
+        assert!(!strict.event_diff.identical);
+
+        let tolerant = filters(&[], &[]).with_ignored_event_fields(vec!["timestamp".to_string()]);
+        let report = CompareEngine::compare_with_filters(&a, &b, &tolerant);
+        assert!(report.event_diff.identical);
+    }
+
+    #[test]
+    fn test_ignored_event_field_in_debug_scval_map() {
+        let entry = |field: &str, val: &str| {
+            format!(
+                "ScMapEntry {{ key: Symbol(ScSymbol(StringM({}))), val: {} }}",
+                field, val
+            )
+        };
+        let map = |ts: &str| {
+            format!(
+                "Map(Some(ScMap(VecM([{}, {}]))))",
+                entry("price", "I128(Int128Parts { hi: 0, lo: 1100000 })"),
+                entry("timestamp", ts)
+            )
+        };
+
+        let fields = BTreeSet::from(["timestamp".to_string()]);
+        let a = CompareEngine::strip_event_data_fields(&map("U64(5000)"), &fields);
+        let b = CompareEngine::strip_event_data_fields(&map("U64(6120)"), &fields);
+
+        assert_eq!(a, b);
+        assert_eq!(
+            a,
+            serde_json::Value::String(format!(
+                "Map(Some(ScMap(VecM([{}]))))",
+                entry("price", "I128(Int128Parts { hi: 0, lo: 1100000 })")
+            ))
+        );
+    }
+
     #[test]
     fn test_ignore_field_suppresses_flow_diff_noise() {
         let mut a = make_trace_a();
@@ -1,5 +1,9 @@
+use crate::inspector::decoded_storage::StorageDecoder;
+use crate::runtime::recording::RecordedEntry;
+use ethnum::I256;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
 
 /// Represents a single breakpoint with optional conditions and logging
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,6 +20,9 @@ pub struct Breakpoint {
     pub log_message: Option<String>,
     /// Number of times this breakpoint has been hit
     pub hit_count: usize,
+    /// Only break once the function has been reached this many times
+    #[serde(default)]
+    pub hit_threshold: Option<u32>,
 }
 
 impl Breakpoint {
@@ -28,6 +35,7 @@ impl Breakpoint {
             hit_condition: None,
             log_message: None,
             hit_count: 0,
+            hit_threshold: None,
         }
     }
 
@@ -40,6 +48,7 @@ impl Breakpoint {
             hit_condition: None,
             log_message: None,
             hit_count: 0,
+            hit_threshold: None,
         }
     }
 
@@ -52,6 +61,7 @@ impl Breakpoint {
             hit_condition: Some(hit_condition),
             log_message: None,
             hit_count: 0,
+            hit_threshold: None,
         }
     }
 
@@ -64,6 +74,7 @@ impl Breakpoint {
             hit_condition: None,
             log_message: Some(log_message),
             hit_count: 0,
+            hit_threshold: None,
         }
     }
 
@@ -76,6 +87,28 @@ impl Breakpoint {
     pub fn is_log_point(&self) -> bool {
         self.log_message.is_some()
     }
+
+    /// Whether the condition refers to `result`, so it can only be checked once
+    /// the call has returned rather than at function entry.
+    pub fn breaks_after_call(&self) -> bool {
+        self.condition
+            .as_deref()
+            .and_then(|condition| BreakpointManager::parse_condition(condition).ok())
+            .is_some_and(|condition| condition.is_post_call())
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.function)?;
+        if let Some(condition) = &self.condition {
+            write!(f, " (if {})", condition)?;
+        }
+        if let Some(threshold) = self.hit_threshold {
+            write!(f, " (hit {})", threshold)?;
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -93,9 +126,129 @@ pub struct BreakpointHit {
     pub log_messages: Vec<String>,
 }
 
+/// A storage change reported by a watchpoint
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchHit {
+    /// The watch pattern that matched (e.g. `Price(*)`).
+    pub pattern: String,
+    /// The storage key that changed.
+    pub key: String,
+    /// Value before the invocation; `None` if the key was created.
+    pub old_value: Option<String>,
+    /// Value after the invocation; `None` if the key was removed.
+    pub new_value: Option<String>,
+}
+
+impl fmt::Display for WatchHit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} changed: {} -> {}",
+            self.key,
+            self.old_value.as_deref().unwrap_or("<unset>"),
+            self.new_value.as_deref().unwrap_or("<unset>")
+        )
+    }
+}
+
+/// Comparison operator used by a breakpoint condition predicate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Operator {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl Operator {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Operator::Eq => "==",
+            Operator::Ne => "!=",
+            Operator::Lt => "<",
+            Operator::Le => "<=",
+            Operator::Gt => ">",
+            Operator::Ge => ">=",
+        }
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A parsed breakpoint condition
+///
+/// Leaf predicates compare a storage entry (`storage[key] > 10`), a named
+/// argument (`amount >= 500`) or the decoded return value (`result > 1000000`).
+/// Predicates can be combined with `&&` and `||`, where `&&` binds tighter
+/// than `||`.
+///
+/// A condition that mentions `result` is a post-condition: it is evaluated
+/// after the call completes, and the debugger pauses once the call has
+/// returned instead of at function entry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Condition {
+    Storage {
+        key: String,
+        operator: Operator,
+        value: String,
+    },
+    Argument {
+        name: String,
+        operator: Operator,
+        value: String,
+    },
+    Result {
+        operator: Operator,
+        value: String,
+    },
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+}
+
+impl Condition {
+    /// Whether any predicate compares the return value.
+    pub fn is_post_call(&self) -> bool {
+        match self {
+            Condition::Result { .. } => true,
+            Condition::And(lhs, rhs) | Condition::Or(lhs, rhs) => {
+                lhs.is_post_call() || rhs.is_post_call()
+            }
+            Condition::Storage { .. } | Condition::Argument { .. } => false,
+        }
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Condition::Storage {
+                key,
+                operator,
+                value,
+            } => write!(f, "storage[{}] {} {}", key, operator, value),
+            Condition::Argument {
+                name,
+                operator,
+                value,
+            } => write!(f, "{} {} {}", name, operator, value),
+            Condition::Result { operator, value } => write!(f, "result {} {}", operator, value),
+            Condition::And(lhs, rhs) => write!(f, "({} && {})", lhs, rhs),
+            Condition::Or(lhs, rhs) => write!(f, "({} || {})", lhs, rhs),
+        }
+    }
+}
+
 /// Manages breakpoints during debugging
 pub struct BreakpointManager {
     breakpoints: HashMap<String, Breakpoint>,
+    /// Storage key patterns that break whenever a matching value changes
+    watches: Vec<String>,
 }
 
 impl BreakpointManager {
@@ -103,6 +256,7 @@ impl BreakpointManager {
     pub fn new() -> Self {
         Self {
             breakpoints: HashMap::new(),
+            watches: Vec::new(),
         }
     }
 
@@ -121,6 +275,19 @@ impl BreakpointManager {
         self.add(function);
     }
 
+    /// Add a breakpoint that only fires once `function` has been hit `hit_count` times
+    pub fn add_with_hit_count(
+        &mut self,
+        function: &str,
+        condition: Option<String>,
+        hit_count: u32,
+    ) {
+        let mut breakpoint = Breakpoint::simple(function.to_string());
+        breakpoint.condition = condition;
+        breakpoint.hit_threshold = Some(hit_count);
+        self.set(breakpoint);
+    }
+
     pub fn add_spec(&mut self, spec: BreakpointSpec) {
         self.set(Breakpoint {
             id: spec.id,
@@ -129,6 +296,7 @@ impl BreakpointManager {
             hit_condition: spec.hit_condition,
             log_message: spec.log_message,
             hit_count: 0,
+            hit_threshold: None,
         });
     }
 
@@ -208,8 +376,31 @@ impl BreakpointManager {
     }
 
     /// Simplified check for backward compatibility
-    pub fn should_break(&self, function: &str) -> bool {
-        self.breakpoints.contains_key(function)
+    ///
+    /// Without storage or arguments the condition cannot be checked, so every
+    /// call counts as a hit; [`on_hit`](Self::on_hit) counts only calls where
+    /// the condition holds. Breakpoints on the return value never fire here;
+    /// they are checked after the call returns.
+    pub fn should_break(&mut self, function: &str) -> bool {
+        let Some(bp) = self.breakpoints.get_mut(function) else {
+            return false;
+        };
+        if bp.breaks_after_call() {
+            return false;
+        }
+
+        bp.increment_hit();
+        match bp.hit_threshold {
+            Some(threshold) => bp.hit_count >= threshold as usize,
+            None => true,
+        }
+    }
+
+    /// Reset all hit counters, e.g. before re-running a scenario
+    pub fn reset_hit_counts(&mut self) {
+        for bp in self.breakpoints.values_mut() {
+            bp.hit_count = 0;
+        }
     }
 
     /// List all breakpoints
@@ -225,13 +416,56 @@ impl BreakpointManager {
     pub fn on_hit(
         &mut self,
         function: &str,
-        _storage: &HashMap<String, String>,
-        _args: Option<&str>,
+        storage: &HashMap<String, String>,
+        args: Option<&str>,
+    ) -> crate::Result<Option<BreakpointHit>> {
+        let Some(bp) = self.breakpoints.get_mut(function) else {
+            return Ok(None);
+        };
+        if bp.breaks_after_call() {
+            return Ok(None);
+        }
+
+        // Only calls where the condition holds count towards the hit count.
+        if let Some(condition) = &bp.condition {
+            let condition = Self::parse_condition(condition)?;
+            if !evaluate_condition(&condition, storage, args) {
+                return Ok(None);
+            }
+        }
+
+        Self::count_hit(bp)
+    }
+
+    /// Like [`on_hit`](Self::on_hit), for breakpoints whose condition reads
+    /// `result` and so can only be checked once the call has returned.
+    pub fn on_return(
+        &mut self,
+        function: &str,
+        storage: &HashMap<String, String>,
+        args: Option<&str>,
+        result: &serde_json::Value,
     ) -> crate::Result<Option<BreakpointHit>> {
         let Some(bp) = self.breakpoints.get_mut(function) else {
             return Ok(None);
         };
+        if !bp.breaks_after_call() {
+            return Ok(None);
+        }
+
+        if let Some(condition) = &bp.condition {
+            let condition = Self::parse_condition(condition)?;
+            if !evaluate_post_call_condition(&condition, storage, args, Some(result)) {
+                return Ok(None);
+            }
+        }
 
+        Self::count_hit(bp)
+    }
+
+    /// Count a hit whose condition held, then apply the hit condition, the
+    /// hit threshold and the log message.
+    fn count_hit(bp: &mut Breakpoint) -> crate::Result<Option<BreakpointHit>> {
         bp.increment_hit();
 
         if let Some(hit_cond) = &bp.hit_condition {
@@ -240,6 +474,13 @@ impl BreakpointManager {
             }
         }
 
+        if bp
+            .hit_threshold
+            .is_some_and(|threshold| bp.hit_count < threshold as usize)
+        {
+            return Ok(None);
+        }
+
         let log_messages = bp.log_message.clone().into_iter().collect();
         Ok(Some(BreakpointHit {
             should_pause: !bp.is_log_point(),
@@ -247,6 +488,63 @@ impl BreakpointManager {
         }))
     }
 
+    /// Watch a storage key; `*` matches any run of characters, so `Price(*)` watches all assets
+    pub fn add_watch(&mut self, key: &str) {
+        if !self.watches.iter().any(|w| w == key) {
+            self.watches.push(key.to_string());
+        }
+    }
+
+    /// Remove a watch by its exact pattern
+    pub fn remove_watch(&mut self, key: &str) -> bool {
+        let before = self.watches.len();
+        self.watches.retain(|w| w != key);
+        self.watches.len() != before
+    }
+
+    /// All watched key patterns
+    pub fn watches(&self) -> &[String] {
+        &self.watches
+    }
+
+    /// Compare storage around an invocation and report every watched key whose
+    /// value was created, changed or removed. A non-empty result means break.
+    ///
+    /// Patterns are matched against keys as `decoder` renders them, so
+    /// `Price(*)` matches the `DataKey::Price("XLM")` entry.
+    pub fn check_watches(
+        &self,
+        decoder: &StorageDecoder,
+        storage_before: &[RecordedEntry],
+        storage_after: &[RecordedEntry],
+    ) -> Vec<WatchHit> {
+        if self.watches.is_empty() {
+            return Vec::new();
+        }
+
+        let before = decoder.decode_entries(storage_before);
+        let after = decoder.decode_entries(storage_after);
+        let keys: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+
+        let mut hits = Vec::new();
+        for key in keys {
+            let old_value = before.get(key).map(|v| v.rendered.clone());
+            let new_value = after.get(key).map(|v| v.rendered.clone());
+            if old_value == new_value {
+                continue;
+            }
+            if let Some(pattern) = self.watches.iter().find(|w| glob_matches(w, key)) {
+                hits.push(WatchHit {
+                    pattern: pattern.clone(),
+                    key: key.clone(),
+                    old_value,
+                    new_value,
+                });
+            }
+        }
+        hits
+    }
+
     /// Clear all breakpoints
     pub fn clear(&mut self) {
         self.breakpoints.clear();
@@ -262,9 +560,10 @@ impl BreakpointManager {
         self.breakpoints.len()
     }
 
-    /// Parse a condition string into a validated Condition
-    /// This validates syntax but doesn't evaluate it
-    pub fn parse_condition(s: &str) -> crate::Result<String> {
+    /// Parse a condition string into a Condition
+    ///
+    /// Supports `&&`, `||` and parentheses; `&&` binds tighter than `||`.
+    pub fn parse_condition(s: &str) -> crate::Result<Condition> {
         let s = s.trim();
         if s.is_empty() {
             return Err(crate::DebuggerError::BreakpointError(
@@ -273,17 +572,18 @@ impl BreakpointManager {
             .into());
         }
 
-        // Basic syntax validation - check for supported operators
-        if !contains_comparison_operator(s) {
+        let tokens = tokenize_condition(s);
+        let mut pos = 0;
+        let condition = parse_or(&tokens, &mut pos, s)?;
+        if pos != tokens.len() {
             return Err(crate::DebuggerError::BreakpointError(format!(
-                "Invalid condition '{}': must contain a comparison operator (==, !=, <, >, <=, >=)",
+                "Invalid condition '{}': unexpected trailing input",
                 s
             ))
             .into());
         }
 
-        // Additional validation could be added here
-        Ok(s.to_string())
+        Ok(condition)
     }
 
     /// Parse a hit condition string
@@ -415,14 +715,355 @@ fn evaluate_hit_condition(hit_condition: &str, hit_count: usize) -> crate::Resul
     .into())
 }
 
-/// Check if a string contains a comparison operator
-fn contains_comparison_operator(s: &str) -> bool {
-    s.contains(">=")
-        || s.contains("<=")
-        || s.contains("==")
-        || s.contains("!=")
-        || s.contains('>')
-        || s.contains('<')
+/// Match `text` against a pattern where `*` matches any run of characters
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or("");
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let remaining: Vec<&str> = parts.collect();
+    let Some((last, middle)) = remaining.split_last() else {
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+/// Evaluate a parsed condition against storage and JSON call arguments.
+///
+/// `And` and `Or` short-circuit, so the right-hand side is only evaluated
+/// when it can change the result. `result` predicates are false here; use
+/// [`evaluate_post_call_condition`] once the call has returned.
+pub fn evaluate_condition(
+    condition: &Condition,
+    storage: &HashMap<String, String>,
+    args: Option<&str>,
+) -> bool {
+    evaluate_post_call_condition(condition, storage, args, None)
+}
+
+/// Evaluate a condition after a call, with `result` as the spec-decoded return value.
+pub fn evaluate_post_call_condition(
+    condition: &Condition,
+    storage: &HashMap<String, String>,
+    args: Option<&str>,
+    result: Option<&serde_json::Value>,
+) -> bool {
+    match condition {
+        Condition::Storage {
+            key,
+            operator,
+            value,
+        } => storage
+            .get(key)
+            .is_some_and(|actual| compare_values(actual, *operator, value)),
+        Condition::Argument {
+            name,
+            operator,
+            value,
+        } => {
+            let Some(parsed) = args.and_then(|a| serde_json::from_str::<serde_json::Value>(a).ok())
+            else {
+                return false;
+            };
+            lookup_json_path(&parsed, name)
+                .is_some_and(|actual| compare_json_value(actual, *operator, value))
+        }
+        Condition::Result { operator, value } => {
+            result.is_some_and(|actual| compare_json_value(actual, *operator, value))
+        }
+        Condition::And(lhs, rhs) => {
+            evaluate_post_call_condition(lhs, storage, args, result)
+                && evaluate_post_call_condition(rhs, storage, args, result)
+        }
+        Condition::Or(lhs, rhs) => {
+            evaluate_post_call_condition(lhs, storage, args, result)
+                || evaluate_post_call_condition(rhs, storage, args, result)
+        }
+    }
+}
+
+/// Compare a decoded JSON value, using strings as-is so large integers
+/// decoded to strings still compare numerically.
+fn compare_json_value(actual: &serde_json::Value, operator: Operator, expected: &str) -> bool {
+    match actual {
+        serde_json::Value::String(actual) => compare_values(actual, operator, expected),
+        actual => compare_values(&actual.to_string(), operator, expected),
+    }
+}
+
+/// One step of an argument path such as `user.balances[0]`
+#[derive(Debug, Clone, PartialEq)]
+enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// Split a dotted/indexed argument path into segments
+fn parse_json_path(path: &str) -> Option<Vec<PathSegment>> {
+    let mut segments = Vec::new();
+    for part in path.split('.') {
+        let (key, mut rest) = match part.find('[') {
+            Some(pos) => (&part[..pos], &part[pos..]),
+            None => (part, ""),
+        };
+        if key.is_empty() && (segments.is_empty() || rest.is_empty()) {
+            return None;
+        }
+        if !key.is_empty() {
+            segments.push(PathSegment::Key(key.to_string()));
+        }
+        while !rest.is_empty() {
+            let close = rest.find(']')?;
+            let index = rest.get(1..close)?.trim().parse::<usize>().ok()?;
+            segments.push(PathSegment::Index(index));
+            rest = &rest[close + 1..];
+            if !rest.is_empty() && !rest.starts_with('[') {
+                return None;
+            }
+        }
+    }
+    Some(segments)
+}
+
+/// Resolve a dotted/indexed path against parsed argument JSON
+fn lookup_json_path<'a>(value: &'a serde_json::Value, path: &str) -> Option<&'a serde_json::Value> {
+    parse_json_path(path)?
+        .iter()
+        .try_fold(value, |current, segment| match segment {
+            PathSegment::Key(key) => current.get(key),
+            PathSegment::Index(index) => current.get(*index),
+        })
+}
+
+/// Replace the value at an existing dotted/indexed path.
+///
+/// Returns `None` when the path is malformed or does not already exist.
+pub(crate) fn set_json_path(
+    value: &mut serde_json::Value,
+    path: &str,
+    new_value: serde_json::Value,
+) -> Option<()> {
+    let target =
+        parse_json_path(path)?
+            .iter()
+            .try_fold(value, |current, segment| match segment {
+                PathSegment::Key(key) => current.get_mut(key),
+                PathSegment::Index(index) => current.get_mut(*index),
+            })?;
+    *target = new_value;
+    Some(())
+}
+
+/// Compare numerically when both sides parse as integers, otherwise as strings.
+///
+/// Integers are parsed as signed 256-bit so large u128 and u256/i256 values
+/// keep their numeric order instead of falling back to lexical comparison.
+fn compare_values(actual: &str, operator: Operator, expected: &str) -> bool {
+    let (actual, expected) = (actual.trim(), expected.trim());
+    let ordering = match (actual.parse::<I256>(), expected.parse::<I256>()) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        _ => actual.cmp(expected),
+    };
+
+    match operator {
+        Operator::Eq => ordering.is_eq(),
+        Operator::Ne => ordering.is_ne(),
+        Operator::Lt => ordering.is_lt(),
+        Operator::Le => ordering.is_le(),
+        Operator::Gt => ordering.is_gt(),
+        Operator::Ge => ordering.is_ge(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum ConditionToken {
+    Predicate(String),
+    And,
+    Or,
+    LParen,
+    RParen,
+}
+
+/// Split a condition into predicates, logical operators and parentheses.
+/// Text inside `[...]` (e.g. `storage[Price(XLM)]`) stays part of its predicate.
+fn tokenize_condition(s: &str) -> Vec<ConditionToken> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut chars = s.chars().peekable();
+    let mut bracket_depth = 0usize;
+
+    let flush = |current: &mut String, tokens: &mut Vec<ConditionToken>| {
+        let trimmed = current.trim();
+        if !trimmed.is_empty() {
+            tokens.push(ConditionToken::Predicate(trimmed.to_string()));
+        }
+        current.clear();
+    };
+
+    while let Some(c) = chars.next() {
+        match c {
+            '[' => {
+                bracket_depth += 1;
+                current.push(c);
+            }
+            ']' => {
+                bracket_depth = bracket_depth.saturating_sub(1);
+                current.push(c);
+            }
+            _ if bracket_depth > 0 => current.push(c),
+            '&' if chars.peek() == Some(&'&') => {
+                chars.next();
+                flush(&mut current, &mut tokens);
+                tokens.push(ConditionToken::And);
+            }
+            '|' if chars.peek() == Some(&'|') => {
+                chars.next();
+                flush(&mut current, &mut tokens);
+                tokens.push(ConditionToken::Or);
+            }
+            '(' => {
+                flush(&mut current, &mut tokens);
+                tokens.push(ConditionToken::LParen);
+            }
+            ')' => {
+                flush(&mut current, &mut tokens);
+                tokens.push(ConditionToken::RParen);
+            }
+            _ => current.push(c),
+        }
+    }
+    flush(&mut current, &mut tokens);
+
+    tokens
+}
+
+fn parse_or(tokens: &[ConditionToken], pos: &mut usize, source: &str) -> crate::Result<Condition> {
+    let mut lhs = parse_and(tokens, pos, source)?;
+    while tokens.get(*pos) == Some(&ConditionToken::Or) {
+        *pos += 1;
+        let rhs = parse_and(tokens, pos, source)?;
+        lhs = Condition::Or(Box::new(lhs), Box::new(rhs));
+    }
+    Ok(lhs)
+}
+
+fn parse_and(tokens: &[ConditionToken], pos: &mut usize, source: &str) -> crate::Result<Condition> {
+    let mut lhs = parse_primary(tokens, pos, source)?;
+    while tokens.get(*pos) == Some(&ConditionToken::And) {
+        *pos += 1;
+        let rhs = parse_primary(tokens, pos, source)?;
+        lhs = Condition::And(Box::new(lhs), Box::new(rhs));
+    }
+    Ok(lhs)
+}
+
+fn parse_primary(
+    tokens: &[ConditionToken],
+    pos: &mut usize,
+    source: &str,
+) -> crate::Result<Condition> {
+    match tokens.get(*pos) {
+        Some(ConditionToken::LParen) => {
+            *pos += 1;
+            let inner = parse_or(tokens, pos, source)?;
+            if tokens.get(*pos) != Some(&ConditionToken::RParen) {
+                return Err(crate::DebuggerError::BreakpointError(format!(
+                    "Invalid condition '{}': missing closing parenthesis",
+                    source
+                ))
+                .into());
+            }
+            *pos += 1;
+            Ok(inner)
+        }
+        Some(ConditionToken::Predicate(text)) => {
+            *pos += 1;
+            parse_predicate(text)
+        }
+        _ => Err(crate::DebuggerError::BreakpointError(format!(
+            "Invalid condition '{}': expected a comparison",
+            source
+        ))
+        .into()),
+    }
+}
+
+/// Parse a single `lhs <op> rhs` predicate
+fn parse_predicate(s: &str) -> crate::Result<Condition> {
+    let Some((pos, operator, len)) = find_comparison_operator(s) else {
+        return Err(crate::DebuggerError::BreakpointError(format!(
+            "Invalid condition '{}': must contain a comparison operator (==, !=, <, >, <=, >=)",
+            s
+        ))
+        .into());
+    };
+
+    let lhs = s[..pos].trim();
+    let value = s[pos + len..].trim().to_string();
+    if lhs.is_empty() || value.is_empty() {
+        return Err(crate::DebuggerError::BreakpointError(format!(
+            "Invalid condition '{}': both sides of '{}' are required",
+            s, operator
+        ))
+        .into());
+    }
+
+    if lhs == "result" {
+        return Ok(Condition::Result { operator, value });
+    }
+
+    if let Some(key) = lhs
+        .strip_prefix("storage[")
+        .and_then(|rest| rest.strip_suffix(']'))
+    {
+        return Ok(Condition::Storage {
+            key: key.trim().to_string(),
+            operator,
+            value,
+        });
+    }
+
+    if parse_json_path(lhs).is_none() {
+        return Err(crate::DebuggerError::BreakpointError(format!(
+            "Invalid condition '{}': '{}' is not a valid argument path (expected e.g. user.balance or items[0])",
+            s, lhs
+        ))
+        .into());
+    }
+
+    Ok(Condition::Argument {
+        name: lhs.to_string(),
+        operator,
+        value,
+    })
+}
+
+/// Locate the first comparison operator, preferring two-character operators
+fn find_comparison_operator(s: &str) -> Option<(usize, Operator, usize)> {
+    let bytes = s.as_bytes();
+    for i in 0..bytes.len() {
+        let next = bytes.get(i + 1).copied();
+        let found = match (bytes[i], next) {
+            (b'>', Some(b'=')) => Some((Operator::Ge, 2)),
+            (b'<', Some(b'=')) => Some((Operator::Le, 2)),
+            (b'=', Some(b'=')) => Some((Operator::Eq, 2)),
+            (b'!', Some(b'=')) => Some((Operator::Ne, 2)),
+            (b'>', _) => Some((Operator::Gt, 1)),
+            (b'<', _) => Some((Operator::Lt, 1)),
+            _ => None,
+        };
+        if let Some((operator, len)) = found {
+            return Some((i, operator, len));
+        }
+    }
+    None
 }
 
 /// Validate hit condition format
@@ -458,6 +1099,119 @@ impl Default for BreakpointManager {
 mod tests {
     use super::*;
 
+    fn storage(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    /// A `DataKey::Price(asset)` entry as the contract stores it.
+    fn price(asset: &str, value: i64) -> RecordedEntry {
+        use soroban_env_host::xdr::{ScString, ScSymbol, ScVal, ScVec};
+        RecordedEntry {
+            durability: crate::runtime::recording::RecordedDurability::Persistent,
+            key: ScVal::Vec(Some(ScVec(
+                vec![
+                    ScVal::Symbol(ScSymbol("Price".try_into().unwrap())),
+                    ScVal::String(ScString(asset.try_into().unwrap())),
+                ]
+                .try_into()
+                .unwrap(),
+            ))),
+            value: ScVal::I64(value),
+            live_until: None,
+        }
+    }
+
+    /// A `DataKey::Admin` entry.
+    fn admin(name: &str) -> RecordedEntry {
+        use soroban_env_host::xdr::{ScSymbol, ScVal, ScVec};
+        RecordedEntry {
+            durability: crate::runtime::recording::RecordedDurability::Instance,
+            key: ScVal::Vec(Some(ScVec(
+                vec![ScVal::Symbol(ScSymbol("Admin".try_into().unwrap()))]
+                    .try_into()
+                    .unwrap(),
+            ))),
+            value: ScVal::Symbol(ScSymbol(name.try_into().unwrap())),
+            live_until: None,
+        }
+    }
+
+    #[test]
+    fn test_watch_reports_changed_key() {
+        let mut manager = BreakpointManager::new();
+        manager.add_watch(r#"Price("XLM")"#);
+
+        let before = [price("XLM", 100), admin("GABC")];
+        let after = [price("XLM", 105), admin("GABC")];
+
+        let hits = manager.check_watches(&StorageDecoder::default(), &before, &after);
+        assert_eq!(
+            hits,
+            vec![WatchHit {
+                pattern: r#"Price("XLM")"#.to_string(),
+                key: r#"Price("XLM")"#.to_string(),
+                old_value: Some("100 (i64)".to_string()),
+                new_value: Some("105 (i64)".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_watch_ignores_unchanged_and_unwatched_keys() {
+        let mut manager = BreakpointManager::new();
+        manager.add_watch(r#"Price("XLM")"#);
+
+        let before = [price("XLM", 100), admin("GABC")];
+        let after = [price("XLM", 100), admin("GXYZ")];
+
+        assert!(manager
+            .check_watches(&StorageDecoder::default(), &before, &after)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_glob_watch_covers_created_and_removed_keys() {
+        let mut manager = BreakpointManager::new();
+        manager.add_watch("Price(*)");
+
+        let decoder = StorageDecoder::default();
+        let before = [price("BTC", 60000), admin("GABC")];
+        let after = [price("XLM", 105), admin("GXYZ")];
+
+        let hits = manager.check_watches(&decoder, &before, &after);
+        let changed: Vec<(&str, Option<&str>, Option<&str>)> = hits
+            .iter()
+            .map(|h| {
+                (
+                    h.key.as_str(),
+                    h.old_value.as_deref(),
+                    h.new_value.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            changed,
+            vec![
+                (r#"Price("BTC")"#, Some("60000 (i64)"), None),
+                (r#"Price("XLM")"#, None, Some("105 (i64)")),
+            ]
+        );
+        assert!(manager.remove_watch("Price(*)"));
+        assert!(manager.check_watches(&decoder, &before, &after).is_empty());
+    }
+
+    #[test]
+    fn test_glob_matches() {
+        assert!(glob_matches("Price(*)", r#"Price("XLM")"#));
+        assert!(glob_matches("*balance*", "user_balance_1"));
+        assert!(glob_matches("exact", "exact"));
+        assert!(!glob_matches("Price(*)", "Admin"));
+        assert!(!glob_matches("a*a", "a"));
+    }
+
     // Mock evaluator for testing
     struct MockEvaluator {
         variables: HashMap<String, i64>,
@@ -710,6 +1464,81 @@ mod tests {
         assert!(!should_break);
     }
 
+    #[test]
+    fn test_hit_count_breakpoint_fires_on_nth_hit() {
+        let mut manager = BreakpointManager::new();
+        manager.add_with_hit_count("retry", None, 3);
+
+        assert!(!manager.should_break("retry"));
+        assert!(!manager.should_break("retry"));
+        assert!(manager.should_break("retry"));
+        assert!(!manager.should_break("other"));
+    }
+
+    #[test]
+    fn test_reset_hit_counts_restarts_threshold() {
+        let mut manager = BreakpointManager::new();
+        manager.add_with_hit_count("retry", None, 2);
+
+        assert!(!manager.should_break("retry"));
+        assert!(manager.should_break("retry"));
+
+        manager.reset_hit_counts();
+        assert!(!manager.should_break("retry"));
+        assert!(manager.should_break("retry"));
+    }
+
+    #[test]
+    fn test_on_hit_respects_hit_threshold() {
+        let mut manager = BreakpointManager::new();
+        manager.add_with_hit_count("retry", None, 2);
+        let storage = HashMap::new();
+
+        assert!(manager.on_hit("retry", &storage, None).unwrap().is_none());
+        assert!(manager
+            .on_hit("retry", &storage, None)
+            .unwrap()
+            .is_some_and(|hit| hit.should_pause));
+    }
+
+    #[test]
+    fn test_hit_threshold_counts_only_hits_where_condition_holds() {
+        let mut manager = BreakpointManager::new();
+        manager.add_with_hit_count("transfer", Some("amount > 100".to_string()), 2);
+        let storage = HashMap::new();
+        let hit = |manager: &mut BreakpointManager, amount: u32| {
+            manager
+                .on_hit(
+                    "transfer",
+                    &storage,
+                    Some(&format!(r#"{{"amount": {amount}}}"#)),
+                )
+                .unwrap()
+                .is_some_and(|hit| hit.should_pause)
+        };
+
+        assert!(!hit(&mut manager, 500));
+        assert!(!hit(&mut manager, 5));
+        assert!(!hit(&mut manager, 5));
+        assert_eq!(manager.get("transfer").unwrap().hit_count, 1);
+        assert!(hit(&mut manager, 500));
+        assert_eq!(manager.get("transfer").unwrap().hit_count, 2);
+    }
+
+    #[test]
+    fn test_breakpoint_display_shows_hit_threshold() {
+        let mut manager = BreakpointManager::new();
+        manager.add_with_hit_count("retry", Some("attempt > 1".to_string()), 3);
+        assert_eq!(
+            manager.get("retry").unwrap().to_string(),
+            "retry (if attempt > 1) (hit 3)"
+        );
+        assert_eq!(
+            Breakpoint::simple("transfer".to_string()).to_string(),
+            "transfer"
+        );
+    }
+
     #[test]
     fn test_remove_breakpoint() {
         let mut manager = BreakpointManager::new();
@@ -742,6 +1571,266 @@ mod tests {
         assert!(BreakpointManager::parse_condition("just_a_variable").is_err());
     }
 
+    #[test]
+    fn test_parse_compound_condition_precedence() {
+        let cond =
+            BreakpointManager::parse_condition("storage[Admin] == GABC || amount > 5 && x < 3")
+                .unwrap();
+        match cond {
+            Condition::Or(lhs, rhs) => {
+                assert!(matches!(*lhs, Condition::Storage { .. }));
+                assert!(matches!(*rhs, Condition::And(_, _)));
+            }
+            other => panic!("Expected Or at top level, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_result_condition_is_checked_after_the_call() {
+        let cond = BreakpointManager::parse_condition("result > 1000000").unwrap();
+        assert_eq!(
+            cond,
+            Condition::Result {
+                operator: Operator::Gt,
+                value: "1000000".to_string(),
+            }
+        );
+        assert!(cond.is_post_call());
+        assert_eq!(cond.to_string(), "result > 1000000");
+
+        let storage = HashMap::new();
+        assert!(!evaluate_condition(&cond, &storage, None));
+        let high = serde_json::json!("1100000");
+        assert!(evaluate_post_call_condition(
+            &cond,
+            &storage,
+            None,
+            Some(&high)
+        ));
+        let low = serde_json::json!(5);
+        assert!(!evaluate_post_call_condition(
+            &cond,
+            &storage,
+            None,
+            Some(&low)
+        ));
+
+        let mut manager = BreakpointManager::new();
+        manager.set(Breakpoint::with_condition(
+            "get_price".to_string(),
+            "result > 1000000".to_string(),
+        ));
+        assert!(manager.get("get_price").unwrap().breaks_after_call());
+        assert!(!manager.should_break("get_price"));
+    }
+
+    #[test]
+    fn test_on_return_applies_hit_threshold_and_log_message() {
+        let mut manager = BreakpointManager::new();
+        manager.add_with_hit_count("get_price", Some("result > 1000000".to_string()), 2);
+        let storage = HashMap::new();
+        let high = serde_json::json!("1100000");
+        let low = serde_json::json!(5);
+
+        assert!(manager
+            .on_return("get_price", &storage, None, &high)
+            .unwrap()
+            .is_none());
+        assert!(manager
+            .on_return("get_price", &storage, None, &low)
+            .unwrap()
+            .is_none());
+        assert_eq!(manager.get("get_price").unwrap().hit_count, 1);
+        assert!(manager
+            .on_return("get_price", &storage, None, &high)
+            .unwrap()
+            .is_some_and(|hit| hit.should_pause));
+
+        let mut log_point =
+            Breakpoint::with_condition("get_price".to_string(), "result > 1000000".to_string());
+        log_point.log_message = Some("price is high".to_string());
+        manager.set(log_point);
+        let hit = manager
+            .on_return("get_price", &storage, None, &high)
+            .unwrap()
+            .unwrap();
+        assert!(!hit.should_pause);
+        assert_eq!(hit.log_messages, vec!["price is high".to_string()]);
+    }
+
+    #[test]
+    fn test_storage_condition_compares_values_beyond_i128() {
+        // 2^128 and 2^128 + 1: neither fits in i128, and "9" sorts after both lexically.
+        let supply = storage(&[("supply", "340282366920938463463374607431768211457")]);
+        let check = |condition: &str| {
+            let cond = BreakpointManager::parse_condition(condition).unwrap();
+            evaluate_condition(&cond, &supply, None)
+        };
+
+        assert!(check(
+            "storage[supply] > 340282366920938463463374607431768211456"
+        ));
+        assert!(check(
+            "storage[supply] >= 340282366920938463463374607431768211457"
+        ));
+        assert!(check(
+            "storage[supply] == 340282366920938463463374607431768211457"
+        ));
+        assert!(check(
+            "storage[supply] != 340282366920938463463374607431768211456"
+        ));
+        assert!(check(
+            "storage[supply] < 57896044618658097711785492504343953926634992332820282019728792003956564819967"
+        ));
+        assert!(check(
+            "storage[supply] <= 340282366920938463463374607431768211457"
+        ));
+        assert!(!check("storage[supply] < 9"));
+        assert!(check(
+            "storage[supply] > -340282366920938463463374607431768211456"
+        ));
+        // Non-numeric values still compare as strings.
+        assert!(compare_values("beta", Operator::Gt, "alpha"));
+    }
+
+    #[test]
+    fn test_compound_condition_display_round_trips() {
+        let cond =
+            BreakpointManager::parse_condition("(storage[Admin] == GABC || amount > 5) && x < 3")
+                .unwrap();
+        let rendered = cond.to_string();
+        assert_eq!(
+            rendered,
+            "((storage[Admin] == GABC || amount > 5) && x < 3)"
+        );
+        assert_eq!(BreakpointManager::parse_condition(&rendered).unwrap(), cond);
+    }
+
+    #[test]
+    fn test_storage_key_with_parentheses_is_one_token() {
+        let cond =
+            BreakpointManager::parse_condition("storage[Price(XLM)] > 5 && (amount > 1)").unwrap();
+        assert_eq!(cond.to_string(), "(storage[Price(XLM)] > 5 && amount > 1)");
+
+        let mut storage = HashMap::new();
+        storage.insert("Price(XLM)".to_string(), "7".to_string());
+        assert!(evaluate_condition(
+            &cond,
+            &storage,
+            Some(r#"{"amount": 2}"#)
+        ));
+        storage.insert("Price(XLM)".to_string(), "3".to_string());
+        assert!(!evaluate_condition(
+            &cond,
+            &storage,
+            Some(r#"{"amount": 2}"#)
+        ));
+    }
+
+    #[test]
+    fn test_evaluate_mixed_storage_and_argument_condition() {
+        let cond = BreakpointManager::parse_condition("storage[Admin] == GABC && price > 1000000")
+            .unwrap();
+        let mut storage = HashMap::new();
+        storage.insert("Admin".to_string(), "GABC".to_string());
+
+        assert!(evaluate_condition(
+            &cond,
+            &storage,
+            Some(r#"{"price": 2000000}"#)
+        ));
+        assert!(!evaluate_condition(
+            &cond,
+            &storage,
+            Some(r#"{"price": 10}"#)
+        ));
+
+        storage.insert("Admin".to_string(), "GXYZ".to_string());
+        assert!(!evaluate_condition(
+            &cond,
+            &storage,
+            Some(r#"{"price": 2000000}"#)
+        ));
+    }
+
+    #[test]
+    fn test_evaluate_or_condition_short_circuits() {
+        // The right-hand side references an argument that is never supplied;
+        // the left-hand side alone must decide the result.
+        let cond =
+            BreakpointManager::parse_condition("storage[paused] == true || amount > 5").unwrap();
+        let mut storage = HashMap::new();
+        storage.insert("paused".to_string(), "true".to_string());
+        assert!(evaluate_condition(&cond, &storage, None));
+
+        storage.insert("paused".to_string(), "false".to_string());
+        assert!(!evaluate_condition(&cond, &storage, None));
+        assert!(evaluate_condition(
+            &cond,
+            &storage,
+            Some(r#"{"amount": 6}"#)
+        ));
+    }
+
+    #[test]
+    fn test_on_hit_respects_compound_condition() {
+        let mut manager = BreakpointManager::new();
+        manager.set(Breakpoint::with_condition(
+            "transfer".to_string(),
+            "storage[balance] >= 100 && amount < 50".to_string(),
+        ));
+        let mut storage = HashMap::new();
+        storage.insert("balance".to_string(), "150".to_string());
+
+        let hit = manager
+            .on_hit("transfer", &storage, Some(r#"{"amount": 10}"#))
+            .unwrap();
+        assert!(hit.is_some_and(|h| h.should_pause));
+
+        let hit = manager
+            .on_hit("transfer", &storage, Some(r#"{"amount": 75}"#))
+            .unwrap();
+        assert!(hit.is_none());
+    }
+
+    #[test]
+    fn test_argument_condition_with_nested_path() {
+        let args = r#"{"user": {"balance": 150, "tags": ["vip", "new"]}, "items": [{"qty": 3}]}"#;
+        let storage = HashMap::new();
+
+        let matching = BreakpointManager::parse_condition("user.balance > 100").unwrap();
+        assert!(evaluate_condition(&matching, &storage, Some(args)));
+
+        let not_matching = BreakpointManager::parse_condition("user.balance > 500").unwrap();
+        assert!(!evaluate_condition(&not_matching, &storage, Some(args)));
+
+        let indexed = BreakpointManager::parse_condition("items[0].qty == 3").unwrap();
+        assert!(evaluate_condition(&indexed, &storage, Some(args)));
+
+        let string_index = BreakpointManager::parse_condition("user.tags[1] == new").unwrap();
+        assert!(evaluate_condition(&string_index, &storage, Some(args)));
+
+        let missing = BreakpointManager::parse_condition("user.missing.balance > 1").unwrap();
+        assert!(!evaluate_condition(&missing, &storage, Some(args)));
+
+        let out_of_range = BreakpointManager::parse_condition("items[5].qty == 3").unwrap();
+        assert!(!evaluate_condition(&out_of_range, &storage, Some(args)));
+    }
+
+    #[test]
+    fn test_invalid_argument_path_is_rejected() {
+        assert!(BreakpointManager::parse_condition("user..balance > 1").is_err());
+        assert!(BreakpointManager::parse_condition("items[x] > 1").is_err());
+        assert!(BreakpointManager::parse_condition("items[0 > 1").is_err());
+    }
+
+    #[test]
+    fn test_parse_compound_condition_errors() {
+        assert!(BreakpointManager::parse_condition("amount > 5 &&").is_err());
+        assert!(BreakpointManager::parse_condition("(amount > 5").is_err());
+        assert!(BreakpointManager::parse_condition("amount > 5 || balance").is_err());
+    }
+
     #[test]
     fn test_parse_hit_condition_validation() {
         // Valid hit conditions
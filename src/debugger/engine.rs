@@ -1,15 +1,17 @@
 use crate::debugger::breakpoint::BreakpointManager;
 use crate::debugger::instruction_pointer::StepMode;
 use crate::debugger::source_map::{SourceLocation, SourceMap};
-use crate::debugger::state::DebugState;
+use crate::debugger::state::{build_call_boundaries, CallBoundary, DebugState, PauseReason};
 use crate::debugger::stepper::Stepper;
+use crate::inspector::events::EventInspector;
 use crate::plugin::{EventContext, ExecutionEvent};
 use crate::runtime::executor::ContractExecutor;
 use crate::runtime::instruction::Instruction;
 use crate::runtime::instrumentation::Instrumenter;
+use crate::runtime::recording::RecordedEntry;
 use crate::Result;
 use std::sync::{Arc, Mutex};
-use tracing::info;
+use tracing::{info, warn};
 
 pub struct StepOverResult {
     pub paused: bool,
@@ -147,6 +149,25 @@ impl DebuggerEngine {
         self.execute_internal(function, args, false)
     }
 
+    /// Execute `function`, pausing at its entry through a one-shot breakpoint
+    /// that is removed again afterwards (`run-to`).
+    pub fn run_to(&mut self, function: &str, args: Option<&str>) -> Result<String> {
+        let temporary = self.breakpoints.get_breakpoint(function).is_none();
+        if temporary {
+            self.breakpoints.add_simple(function);
+        }
+        let result = self.execute_internal(function, args, true);
+        if temporary {
+            self.breakpoints.remove_function(function);
+            if self.paused {
+                self.set_paused_reason(Some(PauseReason::RunTo {
+                    function: function.to_string(),
+                }));
+            }
+        }
+        result
+    }
+
     fn execute_internal(
         &mut self,
         function: &str,
@@ -157,6 +178,7 @@ impl DebuggerEngine {
         self.paused = false;
 
         if let Ok(mut state) = self.state.lock() {
+            state.set_paused_reason(None);
             state.set_current_function(function.to_string(), args.map(str::to_string));
             state.call_stack_mut().clear();
             state.call_stack_mut().push(function.to_string(), None);
@@ -188,15 +210,35 @@ impl DebuggerEngine {
                 let condition = bp.condition.clone();
                 let _ = step_count;
                 let _ = current_args;
-                self.pause_at_function(function, condition);
+                if !bp.breaks_after_call() {
+                    self.pause_at_function(function, condition);
+                }
             }
         }
 
+        let mock_calls_before = self.executor.get_mock_call_log().len();
+        let watched_before = if self.breakpoints.watches().is_empty() {
+            None
+        } else {
+            self.executor.contract_data_entries().ok()
+        };
+
         let start_time = std::time::Instant::now();
         let result = self.executor.execute(function, args);
         let duration = start_time.elapsed();
 
         self.update_call_stack(duration)?;
+        self.record_call_boundaries(mock_calls_before);
+
+        if check_breakpoints && result.is_ok() {
+            self.check_result_breakpoint(function, args);
+        }
+        if let Some(index) = self.executor.hook_pause() {
+            self.pause_at_instruction(index);
+        }
+        if let (Some(before), true) = (watched_before, result.is_ok()) {
+            self.check_watchpoints(&before);
+        }
 
         let event_result = match &result {
             Ok(output) => Ok(output.clone()),
@@ -234,6 +276,7 @@ impl DebuggerEngine {
 
         crate::logging::log_breakpoint(function);
         self.paused = true;
+        self.record_breakpoint_reason(function);
 
         let mut plugin_ctx = EventContext::new();
         plugin_ctx.stack_depth = 1;
@@ -307,6 +350,55 @@ impl DebuggerEngine {
         Ok(())
     }
 
+    /// Rebuild the call boundaries from what the last execution recorded.
+    ///
+    /// The host starts a fresh diagnostic buffer for every top-level call, so
+    /// the whole buffer belongs to the last execution.
+    fn record_call_boundaries(&mut self, mock_calls_before: usize) {
+        let events = self.executor.get_diagnostic_events().unwrap_or_default();
+        let mock_calls = self.executor.get_mock_call_log();
+        let mock_calls = mock_calls.get(mock_calls_before..).unwrap_or(&mock_calls);
+        let boundaries = build_call_boundaries(
+            &EventInspector::decode_diagnostic_events(&events),
+            mock_calls,
+        );
+        if let Ok(mut state) = self.state.lock() {
+            state.set_call_boundaries(boundaries);
+        }
+    }
+
+    /// Step to the next call boundary of the last execution, breaking at a
+    /// cross-contract callee's entry.
+    pub fn step_into_call(&mut self) -> Result<Option<CallBoundary>> {
+        self.step_call(DebugState::step_into_call)
+    }
+
+    /// Step to the next call boundary of the last execution, running a
+    /// cross-contract call as a single step.
+    pub fn step_over_call(&mut self) -> Result<Option<CallBoundary>> {
+        self.step_call(DebugState::step_over_call)
+    }
+
+    fn step_call(
+        &mut self,
+        step: fn(&mut DebugState) -> Option<&CallBoundary>,
+    ) -> Result<Option<CallBoundary>> {
+        let boundary = {
+            let mut state = self
+                .state
+                .lock()
+                .map_err(|_| miette::miette!("Debug state lock poisoned"))?;
+            if state.call_boundaries().is_empty() {
+                return Err(miette::miette!(
+                    "No contract calls recorded; execute a function first"
+                ));
+            }
+            step(&mut state).cloned()
+        };
+        self.finish_step(boundary.is_some());
+        Ok(boundary)
+    }
+
     /// Step into next instruction.
     pub fn step_into(&mut self) -> Result<bool> {
         if !self.instruction_debug_enabled {
@@ -318,7 +410,7 @@ impl DebuggerEngine {
         } else {
             false
         };
-        self.paused = stepped;
+        self.finish_step(stepped);
         Ok(stepped)
     }
 
@@ -333,7 +425,7 @@ impl DebuggerEngine {
         } else {
             false
         };
-        self.paused = stepped;
+        self.finish_step(stepped);
         Ok(stepped)
     }
 
@@ -354,7 +446,7 @@ impl DebuggerEngine {
             (false, None)
         };
 
-        self.paused = paused;
+        self.finish_step(paused);
         Ok(StepOverResult { paused, location })
     }
 
@@ -369,7 +461,7 @@ impl DebuggerEngine {
         } else {
             false
         };
-        self.paused = stepped;
+        self.finish_step(stepped);
         Ok(stepped)
     }
 
@@ -384,7 +476,7 @@ impl DebuggerEngine {
         } else {
             false
         };
-        self.paused = stepped;
+        self.finish_step(stepped);
         Ok(stepped)
     }
 
@@ -399,7 +491,7 @@ impl DebuggerEngine {
         } else {
             false
         };
-        self.paused = stepped;
+        self.finish_step(stepped);
         Ok(stepped)
     }
 
@@ -422,6 +514,7 @@ impl DebuggerEngine {
         self.paused = false;
         if let Ok(mut state) = self.state.lock() {
             self.stepper.continue_execution(&mut state);
+            state.set_paused_reason(None);
         }
 
         let mut plugin_ctx = EventContext::new();
@@ -436,6 +529,7 @@ impl DebuggerEngine {
     fn pause_at_function(&mut self, function: &str, condition: Option<String>) {
         crate::logging::log_breakpoint(function);
         self.paused = true;
+        self.record_breakpoint_reason(function);
 
         if let Ok(mut state) = self.state.lock() {
             state.set_current_function(function.to_string(), None);
@@ -459,6 +553,136 @@ impl DebuggerEngine {
         );
     }
 
+    /// Pause at the instruction a registered instruction hook asked for.
+    fn pause_at_instruction(&mut self, index: usize) {
+        self.paused = true;
+        if let Ok(mut state) = self.state.lock() {
+            state.advance_to_instruction(index);
+            state.set_paused_reason(Some(PauseReason::InstructionHook { index }));
+        }
+
+        let mut plugin_ctx = EventContext::new();
+        plugin_ctx.is_paused = true;
+        crate::plugin::registry::dispatch_global_event(
+            &ExecutionEvent::ExecutionPaused {
+                reason: "instruction_hook".to_string(),
+            },
+            &mut plugin_ctx,
+        );
+    }
+
+    /// Pause after the call if `function` has a breakpoint on its return value
+    /// whose condition holds for the decoded result, honouring the hit count
+    /// and log message like entry breakpoints do.
+    fn check_result_breakpoint(&mut self, function: &str, args: Option<&str>) {
+        let Some(result) = self.executor.last_result_json() else {
+            return;
+        };
+        let storage = self
+            .executor
+            .last_execution()
+            .map(|record| record.storage_after.clone())
+            .unwrap_or_default();
+        let hit = match self
+            .breakpoints
+            .on_return(function, &storage, args, &result)
+        {
+            Ok(Some(hit)) => hit,
+            Ok(None) => return,
+            Err(e) => {
+                warn!("Result breakpoint on {} not checked: {}", function, e);
+                return;
+            }
+        };
+
+        for message in &hit.log_messages {
+            crate::logging::log_display(message, crate::logging::LogLevel::Info);
+        }
+        if !hit.should_pause {
+            return;
+        }
+
+        let condition = self
+            .breakpoints
+            .get_breakpoint(function)
+            .and_then(|bp| bp.condition.clone());
+        crate::logging::log_display(
+            format!(
+                "Breakpoint on {} hit after return: {} (result = {})",
+                function,
+                condition.as_deref().unwrap_or_default(),
+                result
+            ),
+            crate::logging::LogLevel::Info,
+        );
+        self.pause_at_function(function, condition);
+    }
+
+    /// Pause if a watched storage key changed during the last invocation.
+    fn check_watchpoints(&mut self, storage_before: &[RecordedEntry]) {
+        let Ok(storage_after) = self.executor.contract_data_entries() else {
+            return;
+        };
+        let decoder = self.executor.storage_decoder();
+        let hits = self
+            .breakpoints
+            .check_watches(&decoder, storage_before, &storage_after);
+        if hits.is_empty() {
+            return;
+        }
+
+        for hit in &hits {
+            crate::logging::log_display(
+                format!("Watchpoint {} hit: {}", hit.pattern, hit),
+                crate::logging::LogLevel::Info,
+            );
+        }
+        if !self.paused {
+            let hit = &hits[0];
+            self.set_paused_reason(Some(PauseReason::Watchpoint {
+                key: hit.key.clone(),
+                old_value: hit.old_value.clone(),
+                new_value: hit.new_value.clone(),
+            }));
+        }
+        self.paused = true;
+
+        let mut plugin_ctx = EventContext::new();
+        plugin_ctx.is_paused = true;
+        crate::plugin::registry::dispatch_global_event(
+            &ExecutionEvent::ExecutionPaused {
+                reason: "watchpoint".to_string(),
+            },
+            &mut plugin_ctx,
+        );
+    }
+
+    fn set_paused_reason(&mut self, reason: Option<PauseReason>) {
+        if let Ok(mut state) = self.state.lock() {
+            state.set_paused_reason(reason);
+        }
+    }
+
+    fn record_breakpoint_reason(&mut self, function: &str) {
+        let reason = self
+            .breakpoints
+            .get_breakpoint(function)
+            .map(|bp| PauseReason::Breakpoint {
+                id: bp.id.clone(),
+                function: bp.function.clone(),
+                condition: bp.condition.clone(),
+            });
+        self.set_paused_reason(reason);
+    }
+
+    /// Record the outcome of a step command.
+    fn finish_step(&mut self, stepped: bool) {
+        self.paused = stepped;
+        if stepped {
+            self.set_paused_reason(Some(PauseReason::Step));
+        }
+    }
+
     pub fn is_paused(&self) -> bool {
         self.paused
     }
@@ -509,6 +733,9 @@ impl DebuggerEngine {
         }
         if let Ok(mut state) = self.state.lock() {
             state.increment_step();
+            if !self.instruction_debug_enabled {
+                state.set_paused_reason(Some(PauseReason::Step));
+            }
         }
         Ok(())
     }
@@ -113,12 +113,24 @@ impl ErrorDatabase {
         }
     }
 
+    /// Find `code`, preferring errors declared in the contract spec over the
+    /// standard table. `name` and `description` come from the
+    /// `#[contracterror]` variant and its doc comment.
     pub fn lookup(&self, code: u32) -> Option<&ErrorExplanation> {
         self.custom_errors
             .get(&code)
             .or_else(|| self.standard_errors.get(&code))
     }
 
+    /// Short form for error messages, e.g. `InvalidPrice (code 2): Price must be positive`.
+    pub fn describe(&self, code: u32) -> String {
+        match self.lookup(code) {
+            Some(e) if e.description.is_empty() => format!("{} (code {})", e.name, code),
+            Some(e) => format!("{} (code {}): {}", e.name, code, e.description),
+            None => format!("code {}", code),
+        }
+    }
+
     pub fn add_custom_error(&mut self, error: ErrorExplanation) {
         self.custom_errors.insert(error.code, error);
     }
@@ -222,4 +234,48 @@ mod tests {
         let err = db.lookup(1001).expect("Should find custom error");
         assert_eq!(err.name, "MyCustomError");
     }
+
+    /// A module whose `contractspecv0` section declares the oracle's error enum.
+    fn oracle_spec_wasm() -> Vec<u8> {
+        use stellar_xdr::curr::{
+            Limits, ScSpecEntry, ScSpecUdtErrorEnumCaseV0, ScSpecUdtErrorEnumV0, StringM, WriteXdr,
+        };
+
+        let case = |name: &str, doc: &str, value: u32| ScSpecUdtErrorEnumCaseV0 {
+            doc: StringM::try_from(doc.as_bytes().to_vec()).unwrap(),
+            name: StringM::try_from(name.as_bytes().to_vec()).unwrap(),
+            value,
+        };
+        let payload = ScSpecEntry::UdtErrorEnumV0(ScSpecUdtErrorEnumV0 {
+            doc: StringM::default(),
+            lib: StringM::default(),
+            name: StringM::try_from(b"OracleError".to_vec()).unwrap(),
+            cases: vec![
+                case("AssetNotFound", "No price has been set for the asset", 1),
+                case("InvalidPrice", "Price must be positive", 2),
+            ]
+            .try_into()
+            .unwrap(),
+        })
+        .to_xdr(Limits::none())
+        .unwrap();
+
+        crate::utils::wasm::tests::make_custom_section_wasm("contractspecv0", &payload)
+    }
+
+    #[test]
+    fn test_spec_errors_shadow_standard_names() {
+        let mut db = ErrorDatabase::new();
+        db.load_custom_errors_from_wasm(&oracle_spec_wasm())
+            .expect("spec should parse");
+
+        let not_found = db.lookup(1).expect("code 1 should resolve");
+        assert_eq!(not_found.name, "AssetNotFound");
+        assert_eq!(not_found.description, "No price has been set for the asset");
+        assert_eq!(
+            db.describe(2),
+            "InvalidPrice (code 2): Price must be positive"
+        );
+        assert_eq!(db.describe(999), "code 999");
+    }
 }
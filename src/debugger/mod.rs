@@ -12,5 +12,5 @@ pub use engine::DebuggerEngine;
 pub use error_db::{ErrorDatabase, ErrorExplanation};
 pub use instruction_pointer::{InstructionPointer, StepMode};
 pub use source_map::{SourceBreakpointResolution, SourceLocation, SourceMap};
-pub use state::DebugState;
+pub use state::{CallBoundary, CallBoundaryKind, DebugState, PauseReason};
 pub use stepper::Stepper;
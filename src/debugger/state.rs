@@ -1,8 +1,114 @@
 use crate::debugger::instruction_pointer::{InstructionPointer, StepMode};
+use crate::inspector::events::{DiagnosticEvent, DiagnosticKind};
 use crate::inspector::stack::CallStackInspector;
 use crate::runtime::instruction::Instruction;
+use crate::runtime::mocking::MockCallLogEntry;
 use serde::{Deserialize, Serialize};
 
+/// Why execution is currently paused.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PauseReason {
+    /// A function breakpoint fired.
+    Breakpoint {
+        id: String,
+        function: String,
+        condition: Option<String>,
+    },
+    /// A watched storage key changed during the last call.
+    Watchpoint {
+        key: String,
+        old_value: Option<String>,
+        new_value: Option<String>,
+    },
+    /// A step command completed.
+    Step,
+    /// A `run-to` target was reached.
+    RunTo { function: String },
+    /// An instruction hook asked to pause at this instruction.
+    InstructionHook { index: usize },
+}
+
+/// Whether a call boundary enters or leaves a contract function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CallBoundaryKind {
+    Enter,
+    Exit,
+}
+
+/// A contract function entry or return seen during the last execution.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CallBoundary {
+    pub kind: CallBoundaryKind,
+    /// Nesting depth; the top-level invocation is 0.
+    pub depth: usize,
+    pub contract_id: Option<String>,
+    pub function: String,
+    /// Function executing once this boundary has been crossed.
+    pub frame: Option<String>,
+    /// Decoded arguments on entry, the return value on exit.
+    pub value: serde_json::Value,
+    /// Scripted `--mock` response when the callee is mocked.
+    pub mocked_response: Option<String>,
+}
+
+/// Pair `fn_call`/`fn_return` diagnostic events into call boundaries, attaching
+/// the scripted response of each mocked callee from the mock call log.
+pub fn build_call_boundaries(
+    events: &[DiagnosticEvent],
+    mock_calls: &[MockCallLogEntry],
+) -> Vec<CallBoundary> {
+    let text = |value: Option<&serde_json::Value>| match value {
+        Some(serde_json::Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+        None => String::new(),
+    };
+    let mut mocked: Vec<&MockCallLogEntry> = mock_calls.iter().filter(|c| c.mocked).collect();
+    let mut open: Vec<CallBoundary> = Vec::new();
+    let mut boundaries = Vec::new();
+
+    for event in events {
+        match event.kind {
+            DiagnosticKind::FnCall => {
+                let contract_id = event.topics.first().map(|t| text(Some(t)));
+                let function = text(event.topics.get(1));
+                let mocked_response = mocked
+                    .iter()
+                    .position(|c| {
+                        Some(&c.contract_id) == contract_id.as_ref() && c.function == function
+                    })
+                    .and_then(|i| mocked.remove(i).returned.clone());
+                let entry = CallBoundary {
+                    kind: CallBoundaryKind::Enter,
+                    depth: open.len(),
+                    contract_id,
+                    frame: Some(function.clone()),
+                    function,
+                    value: event.data.clone(),
+                    mocked_response,
+                };
+                boundaries.push(entry.clone());
+                open.push(entry);
+            }
+            DiagnosticKind::FnReturn => {
+                let Some(entry) = open.pop() else {
+                    continue;
+                };
+                boundaries.push(CallBoundary {
+                    kind: CallBoundaryKind::Exit,
+                    depth: open.len(),
+                    frame: open.last().map(|caller| caller.function.clone()),
+                    value: event.data.clone(),
+                    ..entry
+                });
+            }
+            _ => {}
+        }
+    }
+    boundaries
+}
+
 /// Represents the current state of the debugger.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DebugState {
@@ -16,6 +122,14 @@ pub struct DebugState {
     instructions: Vec<Instruction>,
     instruction_debug_enabled: bool,
     call_stack: CallStackInspector,
+    /// Why execution last paused; `None` while running or before the first stop.
+    #[serde(default)]
+    paused_reason: Option<PauseReason>,
+    #[serde(skip)]
+    call_boundaries: Vec<CallBoundary>,
+    /// Index of the call boundary execution is paused at.
+    #[serde(skip)]
+    call_cursor: Option<usize>,
 }
 
 impl DebugState {
@@ -30,6 +144,9 @@ impl DebugState {
             instructions: Vec::new(),
             instruction_debug_enabled: false,
             call_stack: CallStackInspector::new(),
+            paused_reason: None,
+            call_boundaries: Vec::new(),
+            call_cursor: None,
         }
     }
 
@@ -48,6 +165,14 @@ impl DebugState {
         self.current_args.as_deref()
     }
 
+    pub fn paused_reason(&self) -> Option<&PauseReason> {
+        self.paused_reason.as_ref()
+    }
+
+    pub fn set_paused_reason(&mut self, reason: Option<PauseReason>) {
+        self.paused_reason = reason;
+    }
+
     /// Increment step count
     pub fn increment_step(&mut self) {
         self.step_count += 1;
@@ -175,6 +300,61 @@ impl DebugState {
             .unwrap_or(false)
     }
 
+    /// Replace the call boundaries, pausing at the top-level entry when it was recorded.
+    pub fn set_call_boundaries(&mut self, boundaries: Vec<CallBoundary>) {
+        self.call_cursor = match boundaries.first() {
+            Some(first) if first.kind == CallBoundaryKind::Enter && first.depth == 0 => Some(0),
+            _ => None,
+        };
+        self.call_boundaries = boundaries;
+    }
+
+    pub fn call_boundaries(&self) -> &[CallBoundary] {
+        &self.call_boundaries
+    }
+
+    pub fn current_call_boundary(&self) -> Option<&CallBoundary> {
+        self.call_boundaries.get(self.call_cursor?)
+    }
+
+    /// Move to the next call boundary, breaking at a callee's entry.
+    pub fn step_into_call(&mut self) -> Option<&CallBoundary> {
+        let next = self.call_cursor.map_or(0, |i| i + 1);
+        self.move_to_call_boundary(next)
+    }
+
+    /// Move to the next call boundary, treating a cross-contract call as one
+    /// step that lands on its return. A callee that never returned is stepped
+    /// into instead.
+    pub fn step_over_call(&mut self) -> Option<&CallBoundary> {
+        let mut next = self.call_cursor.map_or(0, |i| i + 1);
+        if let Some(entry) = self.call_boundaries.get(next) {
+            if entry.kind == CallBoundaryKind::Enter {
+                let depth = entry.depth;
+                if let Some(offset) = self.call_boundaries[next..]
+                    .iter()
+                    .position(|b| b.kind == CallBoundaryKind::Exit && b.depth == depth)
+                {
+                    next += offset;
+                }
+            }
+        }
+        self.move_to_call_boundary(next)
+    }
+
+    fn move_to_call_boundary(&mut self, index: usize) -> Option<&CallBoundary> {
+        let Some(boundary) = self.call_boundaries.get(index) else {
+            self.call_cursor = Some(self.call_boundaries.len());
+            return None;
+        };
+        if let Some(frame) = &boundary.frame {
+            self.current_function = Some(frame.clone());
+        }
+        self.call_cursor = Some(index);
+        self.step_count += 1;
+        self.call_boundaries.get(index)
+    }
+
     pub fn call_stack(&self) -> &CallStackInspector {
         &self.call_stack
     }
@@ -190,6 +370,9 @@ impl DebugState {
         self.instruction_pointer.reset();
         self.current_instruction = self.instructions.first().cloned();
         self.call_stack.clear();
+        self.paused_reason = None;
+        self.call_boundaries.clear();
+        self.call_cursor = None;
     }
 
     pub fn get_instruction_context(&self, context_size: usize) -> Vec<(usize, &Instruction, bool)> {
@@ -212,3 +395,67 @@ impl Default for DebugState {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn diagnostic(
+        kind: DiagnosticKind,
+        topics: &[&str],
+        data: serde_json::Value,
+    ) -> DiagnosticEvent {
+        DiagnosticEvent {
+            index: 0,
+            kind,
+            contract_id: None,
+            topics: topics.iter().map(|t| json!(t)).collect(),
+            data,
+        }
+    }
+
+    #[test]
+    fn call_boundaries_pair_calls_and_step_over_nested_ones() {
+        let events = [
+            diagnostic(DiagnosticKind::FnCall, &["CCALLER", "call"], json!([])),
+            diagnostic(DiagnosticKind::FnCall, &["CORACLE", "price"], json!([])),
+            diagnostic(DiagnosticKind::Log, &[], json!("quote")),
+            diagnostic(DiagnosticKind::FnReturn, &["price"], json!(42)),
+            diagnostic(DiagnosticKind::FnReturn, &["call"], json!(42)),
+        ];
+        let mock_calls = [MockCallLogEntry {
+            contract_id: "CORACLE".to_string(),
+            function: "price".to_string(),
+            args_count: 0,
+            args: Vec::new(),
+            mocked: true,
+            returned: Some("42".to_string()),
+        }];
+
+        let boundaries = build_call_boundaries(&events, &mock_calls);
+        assert_eq!(boundaries.len(), 4);
+        assert_eq!(boundaries[1].depth, 1);
+        assert_eq!(boundaries[1].mocked_response.as_deref(), Some("42"));
+        assert_eq!(boundaries[2].kind, CallBoundaryKind::Exit);
+        assert_eq!(boundaries[2].frame.as_deref(), Some("call"));
+
+        let mut state = DebugState::new();
+        state.set_call_boundaries(boundaries.clone());
+        assert_eq!(
+            state.step_into_call().map(|b| b.function.as_str()),
+            Some("price")
+        );
+        assert_eq!(state.current_function(), Some("price"));
+
+        state.set_call_boundaries(boundaries);
+        let exit = state.step_over_call().unwrap();
+        assert_eq!(
+            (exit.kind, exit.function.as_str()),
+            (CallBoundaryKind::Exit, "price")
+        );
+        assert_eq!(state.current_function(), Some("call"));
+        assert_eq!(state.step_over_call().map(|b| b.depth), Some(0));
+        assert!(state.step_over_call().is_none());
+    }
+}
@@ -0,0 +1,177 @@
+//! Asset listing for price-feed contracts (`inspect --list-assets`).
+//!
+//! Reads the typed storage layout produced by
+//! [`typed_storage_json`](crate::inspector::storage::typed_storage_json) and
+//! pairs up `Price(asset)` and `Timestamp(asset)` entries. Contracts that do
+//! not keep prices under a `Price` variant are reported as not matching.
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// One tracked asset of an oracle-style contract.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AssetPrice {
+    pub asset: String,
+    /// Price as stored, rendered as text so i128 values stay exact.
+    pub price: String,
+    /// Last update time in UNIX seconds, if a `Timestamp` entry exists.
+    pub timestamp: Option<u64>,
+    /// `None` when the timestamp or staleness TTL is unknown.
+    pub stale: Option<bool>,
+}
+
+/// Collect every asset with a `Price` entry, sorted by asset name.
+///
+/// Returns `None` when the storage has no `Price` group. `stale_ttl` falls
+/// back to a `StaleTtl` entry in the storage itself; an asset is stale once
+/// `now - timestamp` exceeds it, as in the oracle's `is_stale`.
+pub fn list_assets(typed: &Value, stale_ttl: Option<u64>, now: u64) -> Option<Vec<AssetPrice>> {
+    let prices = typed.get("Price")?.as_object()?;
+    let timestamps = typed.get("Timestamp").and_then(Value::as_object);
+    let stale_ttl = stale_ttl.or_else(|| typed.get("StaleTtl").and_then(json_u64));
+
+    let mut assets: Vec<AssetPrice> = prices
+        .iter()
+        .map(|(asset, price)| {
+            let timestamp = timestamps
+                .and_then(|group| group.get(asset))
+                .and_then(json_u64);
+            let stale = timestamp
+                .zip(stale_ttl)
+                .map(|(ts, ttl)| now.saturating_sub(ts) > ttl);
+            AssetPrice {
+                asset: asset.clone(),
+                price: match price {
+                    Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                },
+                timestamp,
+                stale,
+            }
+        })
+        .collect();
+    assets.sort_by(|a, b| a.asset.cmp(&b.asset));
+    Some(assets)
+}
+
+/// Render assets as an aligned table, header first.
+pub fn format_asset_table(assets: &[AssetPrice]) -> Vec<String> {
+    let cell = |opt: Option<String>| opt.unwrap_or_else(|| "-".to_string());
+    let rows: Vec<[String; 4]> = assets
+        .iter()
+        .map(|a| {
+            [
+                a.asset.clone(),
+                a.price.clone(),
+                cell(a.timestamp.map(|ts| ts.to_string())),
+                cell(
+                    a.stale
+                        .map(|stale| if stale { "stale" } else { "fresh" }.to_string()),
+                ),
+            ]
+        })
+        .collect();
+
+    let headers = ["Asset", "Price", "Timestamp", "Status"];
+    let mut widths = headers.map(str::len);
+    for row in &rows {
+        for (width, value) in widths.iter_mut().zip(row) {
+            *width = (*width).max(value.len());
+        }
+    }
+
+    let render = |cols: [&str; 4]| {
+        format!(
+            "{:<w0$} | {:>w1$} | {:>w2$} | {:<w3$}",
+            cols[0],
+            cols[1],
+            cols[2],
+            cols[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3]
+        )
+        .trim_end()
+        .to_string()
+    };
+
+    let header = render(headers);
+    let mut lines = vec![header.clone(), "-".repeat(header.len())];
+    lines.extend(rows.iter().map(|row| {
+        render([
+            row[0].as_str(),
+            row[1].as_str(),
+            row[2].as_str(),
+            row[3].as_str(),
+        ])
+    }));
+    lines
+}
+
+fn json_u64(value: &Value) -> Option<u64> {
+    match value {
+        Value::Number(n) => n.as_u64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn oracle_storage() -> Value {
+        json!({
+            "Price": {"XLM": "1100000", "BTC": "65000000000"},
+            "Timestamp": {"XLM": 1000, "BTC": 400},
+        })
+    }
+
+    #[test]
+    fn lists_seeded_assets_with_prices() {
+        let assets = list_assets(&oracle_storage(), Some(300), 1200).unwrap();
+
+        assert_eq!(
+            assets,
+            vec![
+                AssetPrice {
+                    asset: "BTC".to_string(),
+                    price: "65000000000".to_string(),
+                    timestamp: Some(400),
+                    stale: Some(true),
+                },
+                AssetPrice {
+                    asset: "XLM".to_string(),
+                    price: "1100000".to_string(),
+                    timestamp: Some(1000),
+                    stale: Some(false),
+                },
+            ]
+        );
+
+        let table = format_asset_table(&assets);
+        assert_eq!(table.len(), 4);
+        assert!(table[2].starts_with("BTC") && table[2].contains("65000000000"));
+        assert!(table[3].starts_with("XLM") && table[3].contains("1100000"));
+        assert!(table[3].ends_with("fresh"));
+    }
+
+    #[test]
+    fn staleness_unknown_without_ttl() {
+        let assets = list_assets(&oracle_storage(), None, 1200).unwrap();
+        assert!(assets.iter().all(|a| a.stale.is_none()));
+
+        let mut storage = oracle_storage();
+        storage["StaleTtl"] = json!(100);
+        let assets = list_assets(&storage, None, 1050).unwrap();
+        assert_eq!(assets[1].stale, Some(false));
+    }
+
+    #[test]
+    fn non_oracle_layout_is_not_matched() {
+        assert_eq!(list_assets(&json!({"COUNTER": 3}), None, 0), None);
+        assert_eq!(list_assets(&json!({"Price": "1"}), None, 0), None);
+    }
+}
@@ -1,8 +1,10 @@
+use crate::utils::scval::scval_to_json;
 use crate::{DebuggerError, Result};
 use serde::{Deserialize, Serialize};
+use soroban_env_host::xdr::{ScAddress, ScVal};
 use soroban_sdk::{
     testutils::{AuthorizedFunction, AuthorizedInvocation},
-    Env,
+    Address, Env, TryFromVal, Val,
 };
 
 /// Status of an authorization node.
@@ -34,6 +36,9 @@ pub struct AuthNode {
     pub address: String,
     /// The contract function being authorized.
     pub function: String,
+    /// Decoded arguments of the authorized call.
+    #[serde(default)]
+    pub args: Vec<serde_json::Value>,
     /// The contract being called.
     pub contract_id: String,
     /// Whether this authorization was successful.
@@ -61,48 +66,62 @@ impl AuthInspector {
         let mut nodes = Vec::new();
 
         for (address, invocation) in recorded_auths {
-            let address_str = format!("{:?}", address);
-            nodes.push(Self::convert_invocation(&invocation, &address_str));
+            let address_str = Self::address_strkey(&address);
+            nodes.push(Self::convert_invocation(env, &invocation, &address_str));
         }
 
         Ok(nodes)
     }
 
-    fn convert_invocation(inv: &AuthorizedInvocation, address: &str) -> AuthNode {
-        let (function, contract_id) = match &inv.function {
+    fn convert_invocation(env: &Env, inv: &AuthorizedInvocation, address: &str) -> AuthNode {
+        let (function, args, contract_id) = match &inv.function {
             AuthorizedFunction::Contract(call) => {
-                let contract_id = format!("{:?}", call.0);
-                let function = format!("{:?}({:?})", call.1, call.2);
-                (function, contract_id)
-            }
-            AuthorizedFunction::CreateContractHostFn(create_fn) => {
-                let contract_id = "Host".to_string();
-                let function = format!("create_contract({:?})", create_fn);
-                (function, contract_id)
-            }
-            AuthorizedFunction::CreateContractV2HostFn(create_fn) => {
-                let contract_id = "Host".to_string();
-                let function = format!("create_contract_v2({:?})", create_fn);
-                (function, contract_id)
+                let function = match Self::val_to_json(env, &call.1.to_val()) {
+                    serde_json::Value::String(name) => name,
+                    other => other.to_string(),
+                };
+                let args = call.2.iter().map(|v| Self::val_to_json(env, &v)).collect();
+                (function, args, Self::address_strkey(&call.0))
             }
+            AuthorizedFunction::CreateContractHostFn(create_fn) => (
+                format!("create_contract({:?})", create_fn),
+                Vec::new(),
+                "Host".to_string(),
+            ),
+            AuthorizedFunction::CreateContractV2HostFn(create_fn) => (
+                format!("create_contract_v2({:?})", create_fn),
+                Vec::new(),
+                "Host".to_string(),
+            ),
         };
 
         // Sub-invocations share the same authorizing address.
         let sub_invocations = inv
             .sub_invocations
             .iter()
-            .map(|s| Self::convert_invocation(s, address))
+            .map(|s| Self::convert_invocation(env, s, address))
             .collect();
 
         AuthNode {
             address: address.to_string(),
             function,
+            args,
             contract_id,
             status: AuthStatus::Authorized,
             sub_invocations,
         }
     }
 
+    fn address_strkey(address: &Address) -> String {
+        ScAddress::from(address).to_string()
+    }
+
+    fn val_to_json(env: &Env, val: &Val) -> serde_json::Value {
+        ScVal::try_from_val(env, val)
+            .map(|sc| scval_to_json(&sc))
+            .unwrap_or_else(|_| serde_json::Value::String(format!("{:?}", val)))
+    }
+
     /// Build a set of failed/missing auth nodes from a list of required invocations
     /// that were NOT present in the recorded auth tree.
     pub fn build_failed_nodes(required: &[(&str, &str, &str)]) -> Vec<AuthNode> {
@@ -111,6 +130,7 @@ impl AuthInspector {
             .map(|(address, contract_id, function)| AuthNode {
                 address: address.to_string(),
                 function: function.to_string(),
+                args: vec![],
                 contract_id: contract_id.to_string(),
                 status: AuthStatus::Missing,
                 sub_invocations: vec![],
@@ -128,8 +148,8 @@ impl AuthInspector {
             return;
         }
 
-        for node in nodes {
-            Self::print_node(node, 0, true);
+        for line in Self::tree_lines(nodes, Self::colored_status) {
+            println!("{}", line);
         }
     }
 
@@ -143,8 +163,8 @@ impl AuthInspector {
         let total = Self::count_nodes(nodes);
         let failed = Self::count_failed(nodes);
 
-        for node in nodes {
-            Self::print_node(node, 0, true);
+        for line in Self::tree_lines(nodes, Self::colored_status) {
+            println!("{}", line);
         }
 
         println!();
@@ -164,37 +184,71 @@ impl AuthInspector {
         }
     }
 
-    fn print_node(node: &AuthNode, depth: usize, is_last: bool) {
-        let indent = "    ".repeat(depth);
-        let branch = if depth == 0 {
-            "".to_string()
-        } else if is_last {
-            format!("{}└── ", "    ".repeat(depth.saturating_sub(1)))
+    /// Render the tree as plain text lines in the style of `tree(1)`.
+    ///
+    /// Each root starts with its signer; sub-invocations hang below their
+    /// parent with `├──`/`└──` branches and `│` guides for open ancestors.
+    pub fn format_tree(nodes: &[AuthNode]) -> Vec<String> {
+        Self::tree_lines(nodes, |status| match status {
+            AuthStatus::Authorized => "[OK]".to_string(),
+            AuthStatus::Missing => "[MISSING]".to_string(),
+            AuthStatus::Failed => "[FAILED]".to_string(),
+        })
+    }
+
+    fn tree_lines(nodes: &[AuthNode], status_label: fn(&AuthStatus) -> String) -> Vec<String> {
+        let mut lines = Vec::new();
+        for node in nodes {
+            if !node.address.is_empty() {
+                lines.push(format!("Signer: {}", node.address));
+            }
+            lines.push(Self::node_label(node, status_label));
+            Self::push_children(node, "", status_label, &mut lines);
+        }
+        lines
+    }
+
+    fn push_children(
+        node: &AuthNode,
+        prefix: &str,
+        status_label: fn(&AuthStatus) -> String,
+        lines: &mut Vec<String>,
+    ) {
+        let child_count = node.sub_invocations.len();
+        for (i, sub) in node.sub_invocations.iter().enumerate() {
+            let is_last = i + 1 == child_count;
+            let branch = if is_last { "└── " } else { "├── " };
+            lines.push(format!(
+                "{}{}{}",
+                prefix,
+                branch,
+                Self::node_label(sub, status_label)
+            ));
+            let guide = if is_last { "    " } else { "│   " };
+            Self::push_children(sub, &format!("{}{}", prefix, guide), status_label, lines);
+        }
+    }
+
+    fn node_label(node: &AuthNode, status_label: fn(&AuthStatus) -> String) -> String {
+        let args: Vec<String> = node.args.iter().map(|a| a.to_string()).collect();
+        let call = if args.is_empty() && node.function.contains('(') {
+            node.function.clone()
         } else {
-            format!("{}├── ", "    ".repeat(depth.saturating_sub(1)))
+            format!("{}({})", node.function, args.join(", "))
         };
+        format!(
+            "{} {} [Contract: {}]",
+            status_label(&node.status),
+            call,
+            node.contract_id
+        )
+    }
 
-        let status_label = match node.status {
+    fn colored_status(status: &AuthStatus) -> String {
+        match status {
             AuthStatus::Authorized => Self::green("[OK]"),
             AuthStatus::Missing => Self::red("[MISSING]"),
             AuthStatus::Failed => Self::red("[FAILED]"),
-        };
-
-        // Address line (only at root level or when it differs from parent)
-        if depth == 0 && !node.address.is_empty() {
-            println!("{}Signer: {}", indent, Self::dim(&node.address));
-        }
-
-        let line = format!(
-            "{}{} {} [Contract: {}]",
-            branch, status_label, node.function, node.contract_id
-        );
-
-        println!("{}", line);
-
-        let child_count = node.sub_invocations.len();
-        for (i, sub) in node.sub_invocations.iter().enumerate() {
-            Self::print_node(sub, depth + 1, i == child_count - 1);
         }
     }
 
@@ -237,14 +291,6 @@ impl AuthInspector {
         }
     }
 
-    fn dim(s: &str) -> String {
-        if Self::colors_enabled() {
-            format!("\x1b[2m{}\x1b[0m", s)
-        } else {
-            s.to_string()
-        }
-    }
-
     fn colors_enabled() -> bool {
         // Respect the same NO_COLOR convention used by the rest of the tool.
         std::env::var_os("NO_COLOR").is_none()
@@ -1,5 +1,6 @@
 use crossterm::style::{Color, Stylize};
 use serde::{Deserialize, Serialize};
+use soroban_env_host::xdr::ContractCostType;
 use soroban_env_host::Host;
 use std::collections::VecDeque;
 
@@ -23,6 +24,68 @@ impl BudgetInspector {
         }
     }
 
+    /// CPU, memory and number of charges per host cost category, sorted by CPU
+    /// descending.
+    ///
+    /// Categories that were never charged are omitted.
+    pub fn breakdown(host: &Host) -> Vec<(ContractCostType, u64, u64, u64)> {
+        let budget = host.budget_cloned();
+        let mut rows: Vec<(ContractCostType, u64, u64, u64)> = ContractCostType::VARIANTS
+            .iter()
+            .filter_map(|ty| {
+                let tracker = budget.get_tracker(*ty).ok()?;
+                (tracker.cpu > 0 || tracker.mem > 0 || tracker.iterations > 0).then_some((
+                    *ty,
+                    tracker.cpu,
+                    tracker.mem,
+                    tracker.iterations,
+                ))
+            })
+            .collect();
+        rows.sort_by(|a, b| b.1.cmp(&a.1).then(b.2.cmp(&a.2)));
+        rows
+    }
+
+    /// Render a breakdown from [`Self::breakdown`] as table lines.
+    pub fn format_breakdown(rows: &[(ContractCostType, u64, u64, u64)]) -> Vec<String> {
+        let name_w = rows
+            .iter()
+            .map(|(ty, _, _, _)| ty.name().len())
+            .max()
+            .unwrap_or(0)
+            .max("Cost type".len());
+        let mut lines = vec![format!(
+            "{:<name_w$}  {:>12}  {:>12}",
+            "Cost type", "CPU", "Memory"
+        )];
+        for (ty, cpu, mem, _) in rows {
+            lines.push(format!(
+                "{:<name_w$}  {:>12}  {:>12}",
+                ty.name(),
+                Self::format_cpu_insns(*cpu),
+                Self::format_memory_bytes(*mem)
+            ));
+        }
+        lines
+    }
+
+    /// JSON form of a breakdown:
+    /// `[{"cost_type", "cpu_instructions", "memory_bytes", "charges"}]`.
+    pub fn breakdown_to_json(rows: &[(ContractCostType, u64, u64, u64)]) -> serde_json::Value {
+        serde_json::Value::Array(
+            rows.iter()
+                .map(|(ty, cpu, mem, charges)| {
+                    serde_json::json!({
+                        "cost_type": ty.name(),
+                        "cpu_instructions": cpu,
+                        "memory_bytes": mem,
+                        "charges": charges,
+                    })
+                })
+                .collect(),
+        )
+    }
+
     /// Display budget information with warnings
     pub fn display(host: &Host) {
         let info = Self::get_cpu_usage(host);
@@ -223,6 +286,21 @@ impl BudgetInfo {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_breakdown_counts_sha256_charges() {
+        let env = soroban_sdk::Env::default();
+        let data = soroban_sdk::Bytes::from_slice(&env, b"soroban");
+        let _ = env.crypto().sha256(&data);
+
+        let rows = BudgetInspector::breakdown(env.host());
+        let (_, cpu, _, charges) = rows
+            .iter()
+            .find(|(ty, _, _, _)| *ty == ContractCostType::ComputeSha256Hash)
+            .expect("sha256 should charge ComputeSha256Hash");
+        assert!(*charges >= 1);
+        assert!(*cpu > 0);
+    }
+
     #[test]
     fn test_budget_percentage_calculation() {
         let info = BudgetInfo {
@@ -519,6 +597,75 @@ impl MemorySummary {
     }
 }
 
+/// Memory consumed by each invocation, oldest first.
+///
+/// Used to spot state that keeps growing across calls, such as storage that is
+/// written on every call and never cleared.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MemorySeries {
+    samples: Vec<u64>,
+}
+
+/// A run of invocations whose memory use rose on every call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryGrowth {
+    pub window: usize,
+    pub first_bytes: u64,
+    pub last_bytes: u64,
+}
+
+impl MemoryGrowth {
+    /// Average growth between consecutive invocations in the window.
+    pub fn bytes_per_call(&self) -> u64 {
+        (self.last_bytes - self.first_bytes) / (self.window as u64 - 1)
+    }
+}
+
+impl std::fmt::Display for MemoryGrowth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "memory grew on each of the last {} calls: {} -> {} bytes (~{} bytes/call)",
+            self.window,
+            self.first_bytes,
+            self.last_bytes,
+            self.bytes_per_call()
+        )
+    }
+}
+
+impl MemorySeries {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the memory consumed by one invocation.
+    pub fn record(&mut self, mem_bytes: u64) {
+        self.samples.push(mem_bytes);
+    }
+
+    pub fn samples(&self) -> &[u64] {
+        &self.samples
+    }
+
+    /// Flag growth when memory use strictly increased across the last `window`
+    /// invocations. Needs at least two samples in the window.
+    pub fn detect_growth(&self, window: usize) -> Option<MemoryGrowth> {
+        if window < 2 || self.samples.len() < window {
+            return None;
+        }
+        let recent = &self.samples[self.samples.len() - window..];
+        if !recent.windows(2).all(|pair| pair[1] > pair[0]) {
+            return None;
+        }
+        Some(MemoryGrowth {
+            window,
+            first_bytes: recent[0],
+            last_bytes: recent[window - 1],
+        })
+    }
+}
+
 #[cfg(test)]
 mod memory_tests {
     use super::*;
@@ -553,4 +700,30 @@ mod memory_tests {
         let top_sizes: Vec<u64> = top.into_iter().map(|a| a.size).collect();
         assert_eq!(top_sizes, vec![80, 70, 50, 40, 30]);
     }
+
+    #[test]
+    fn memory_series_flags_increasing_window() {
+        let mut series = MemorySeries::new();
+        for bytes in [500, 480, 1000, 1200, 1400, 1600] {
+            series.record(bytes);
+        }
+
+        let growth = series.detect_growth(4).expect("growth detected");
+        assert_eq!(growth.first_bytes, 1000);
+        assert_eq!(growth.last_bytes, 1600);
+        assert_eq!(growth.bytes_per_call(), 200);
+        // The dip from 500 to 480 falls inside a six-call window.
+        assert!(series.detect_growth(6).is_none());
+    }
+
+    #[test]
+    fn memory_series_ignores_flat_or_short_series() {
+        let mut series = MemorySeries::new();
+        for bytes in [900, 900, 900, 900] {
+            series.record(bytes);
+        }
+        assert!(series.detect_growth(3).is_none());
+        assert!(series.detect_growth(10).is_none());
+        assert!(series.detect_growth(1).is_none());
+    }
 }
@@ -0,0 +1,370 @@
+use crate::runtime::recording::RecordedEntry;
+use crate::utils::scval::{scval_to_json, scval_to_json_with_type};
+use crate::utils::wasm::{
+    parse_contract_structs, parse_contract_unions, ContractStruct, ContractUnion,
+};
+use crossterm::style::{Color, Stylize};
+use serde::Serialize;
+use soroban_env_host::xdr::ScVal;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A storage value rendered for display, e.g. `1100000 (i128)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedValue {
+    pub rendered: String,
+    /// Integer payload, used to report the delta between two values.
+    pub number: Option<i128>,
+}
+
+/// Renders raw storage entries using the types declared in the contract spec.
+///
+/// Enum keys such as `DataKey::Price(String)` are shown as `Price("XLM")`;
+/// values carry their type (`1100000 (i128)`) and maps matching a
+/// `#[contracttype]` struct are shown as that struct in declaration order.
+#[derive(Debug, Clone, Default)]
+pub struct StorageDecoder {
+    structs: Vec<ContractStruct>,
+    unions: Vec<ContractUnion>,
+}
+
+impl StorageDecoder {
+    pub fn new(structs: Vec<ContractStruct>, unions: Vec<ContractUnion>) -> Self {
+        Self { structs, unions }
+    }
+
+    /// Build a decoder from the spec embedded in `wasm_bytes`; a missing spec
+    /// falls back to structural decoding.
+    pub fn from_wasm(wasm_bytes: &[u8]) -> Self {
+        Self::new(
+            parse_contract_structs(wasm_bytes).unwrap_or_default(),
+            parse_contract_unions(wasm_bytes).unwrap_or_default(),
+        )
+    }
+
+    /// Render a storage key, e.g. `Price("XLM")`, `Admin` or `counter`.
+    pub fn decode_key(&self, key: &ScVal) -> String {
+        match key {
+            ScVal::Symbol(sym) => String::from_utf8_lossy(sym.0.as_slice()).into_owned(),
+            ScVal::Vec(Some(items)) => match items.0.split_first() {
+                Some((ScVal::Symbol(sym), args)) => {
+                    let name = String::from_utf8_lossy(sym.0.as_slice()).into_owned();
+                    if args.is_empty() {
+                        return name;
+                    }
+                    let field_types = self
+                        .unions
+                        .iter()
+                        .flat_map(|u| u.cases.iter())
+                        .find(|case| case.name == name && case.fields.len() == args.len())
+                        .map(|case| case.fields.clone());
+                    let rendered: Vec<String> = args
+                        .iter()
+                        .enumerate()
+                        .map(|(i, arg)| match &field_types {
+                            Some(types) => {
+                                scval_to_json_with_type(arg, &types[i], &self.structs).to_string()
+                            }
+                            None => scval_to_json(arg).to_string(),
+                        })
+                        .collect();
+                    format!("{}({})", name, rendered.join(", "))
+                }
+                _ => scval_to_json(key).to_string(),
+            },
+            other => scval_to_json(other).to_string(),
+        }
+    }
+
+    /// Render a storage value with its type.
+    pub fn decode_value(&self, val: &ScVal) -> DecodedValue {
+        let number = match val {
+            ScVal::U32(n) => Some(i128::from(*n)),
+            ScVal::I32(n) => Some(i128::from(*n)),
+            ScVal::U64(n) => Some(i128::from(*n)),
+            ScVal::I64(n) => Some(i128::from(*n)),
+            ScVal::I128(parts) => Some(((parts.hi as i128) << 64) | parts.lo as i128),
+            ScVal::U128(parts) => {
+                i128::try_from(((parts.hi as u128) << 64) | parts.lo as u128).ok()
+            }
+            _ => None,
+        };
+
+        let rendered = match (type_label(val), scval_to_json(val)) {
+            (Some(label), serde_json::Value::String(s)) if label != "string" => {
+                format!("{} ({})", s, label)
+            }
+            (Some(label), json) => format!("{} ({})", json, label),
+            (None, json) => match self.matching_struct(val) {
+                Some(def) => format!(
+                    "{} {}",
+                    def.name,
+                    scval_to_json_with_type(val, &def.name, &self.structs)
+                ),
+                None => json.to_string(),
+            },
+        };
+
+        DecodedValue { rendered, number }
+    }
+
+    /// Decode a set of entries into a map keyed by rendered key.
+    pub fn decode_entries(&self, entries: &[RecordedEntry]) -> BTreeMap<String, DecodedValue> {
+        entries
+            .iter()
+            .map(|entry| (self.decode_key(&entry.key), self.decode_value(&entry.value)))
+            .collect()
+    }
+
+    /// The spec struct whose field names are exactly the keys of `val`, if any.
+    fn matching_struct(&self, val: &ScVal) -> Option<&ContractStruct> {
+        let ScVal::Map(Some(map)) = val else {
+            return None;
+        };
+        let keys: BTreeSet<String> = map
+            .0
+            .iter()
+            .filter_map(|entry| match &entry.key {
+                ScVal::Symbol(sym) => Some(String::from_utf8_lossy(sym.0.as_slice()).into_owned()),
+                _ => None,
+            })
+            .collect();
+        self.structs.iter().find(|def| {
+            def.fields.len() == keys.len() && def.fields.iter().all(|f| keys.contains(&f.name))
+        })
+    }
+}
+
+fn type_label(val: &ScVal) -> Option<&'static str> {
+    Some(match val {
+        ScVal::Bool(_) => "bool",
+        ScVal::U32(_) => "u32",
+        ScVal::I32(_) => "i32",
+        ScVal::U64(_) => "u64",
+        ScVal::I64(_) => "i64",
+        ScVal::U128(_) => "u128",
+        ScVal::I128(_) => "i128",
+        ScVal::U256(_) => "u256",
+        ScVal::I256(_) => "i256",
+        ScVal::Timepoint(_) => "timepoint",
+        ScVal::Duration(_) => "duration",
+        ScVal::Bytes(_) => "bytes",
+        ScVal::String(_) => "string",
+        ScVal::Symbol(_) => "symbol",
+        ScVal::Address(_) => "address",
+        _ => return None,
+    })
+}
+
+/// One changed storage key; `before`/`after` are `None` for added/removed keys.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DecodedChange {
+    pub key: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+    /// Signed difference for integer values, e.g. `+100000`.
+    pub delta: Option<String>,
+}
+
+/// The keys whose decoded value differs between two snapshots, sorted by key.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct DecodedStorageDiff {
+    pub changes: Vec<DecodedChange>,
+}
+
+impl DecodedStorageDiff {
+    pub fn compute(
+        before: &BTreeMap<String, DecodedValue>,
+        after: &BTreeMap<String, DecodedValue>,
+    ) -> Self {
+        let keys: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+        let changes = keys
+            .into_iter()
+            .filter_map(|key| {
+                let (old, new) = (before.get(key), after.get(key));
+                if old == new {
+                    return None;
+                }
+                let delta = match (old.and_then(|v| v.number), new.and_then(|v| v.number)) {
+                    (Some(a), Some(b)) => b.checked_sub(a).map(|d| format!("{:+}", d)),
+                    _ => None,
+                };
+                Some(DecodedChange {
+                    key: key.clone(),
+                    before: old.map(|v| v.rendered.clone()),
+                    after: new.map(|v| v.rendered.clone()),
+                    delta,
+                })
+            })
+            .collect();
+        Self { changes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Plain-text lines, one per changed key.
+    pub fn format_lines(&self) -> Vec<String> {
+        self.changes
+            .iter()
+            .map(|c| self.format_change(c, false))
+            .collect()
+    }
+
+    /// Print the colour-coded diff
+    pub fn display(&self) {
+        if self.is_empty() {
+            crate::logging::log_display("Storage: (no changes)", crate::logging::LogLevel::Info);
+            return;
+        }
+        for change in &self.changes {
+            crate::logging::log_display(
+                self.format_change(change, true),
+                crate::logging::LogLevel::Info,
+            );
+        }
+    }
+
+    fn format_change(&self, change: &DecodedChange, color: bool) -> String {
+        let paint = |text: String, c: Color| {
+            if color {
+                text.with(c).to_string()
+            } else {
+                text
+            }
+        };
+        match (&change.before, &change.after) {
+            (None, Some(after)) => format!(
+                "  {} {} = {}",
+                paint("+".to_string(), Color::Green),
+                change.key,
+                paint(after.clone(), Color::Green)
+            ),
+            (Some(before), None) => format!(
+                "  {} {} = {}",
+                paint("-".to_string(), Color::Red),
+                change.key,
+                paint(before.clone(), Color::Red)
+            ),
+            (Some(before), Some(after)) => {
+                let mut line = format!(
+                    "  {} {}: {} -> {}",
+                    paint("~".to_string(), Color::Yellow),
+                    change.key,
+                    paint(before.clone(), Color::Red),
+                    paint(after.clone(), Color::Green)
+                );
+                if let Some(delta) = &change.delta {
+                    line.push_str(&format!(" [{}]", delta));
+                }
+                line
+            }
+            (None, None) => String::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::recording::RecordedDurability;
+    use crate::utils::wasm::UnionCase;
+    use soroban_env_host::xdr::{Int128Parts, ScString, ScSymbol, StringM};
+
+    fn sym(s: &str) -> ScVal {
+        ScVal::Symbol(ScSymbol(StringM::try_from(s.as_bytes().to_vec()).unwrap()))
+    }
+
+    fn string(s: &str) -> ScVal {
+        ScVal::String(ScString(StringM::try_from(s.as_bytes().to_vec()).unwrap()))
+    }
+
+    fn data_key(variant: &str, args: Vec<ScVal>) -> ScVal {
+        let mut items = vec![sym(variant)];
+        items.extend(args);
+        ScVal::Vec(Some(items.try_into().unwrap()))
+    }
+
+    fn i128_val(n: i128) -> ScVal {
+        ScVal::I128(Int128Parts {
+            hi: (n >> 64) as i64,
+            lo: n as u64,
+        })
+    }
+
+    fn entry(key: ScVal, value: ScVal) -> RecordedEntry {
+        RecordedEntry {
+            durability: RecordedDurability::Persistent,
+            key,
+            value,
+            live_until: None,
+        }
+    }
+
+    fn oracle_decoder() -> StorageDecoder {
+        StorageDecoder::new(
+            Vec::new(),
+            vec![ContractUnion {
+                name: "DataKey".to_string(),
+                cases: vec![
+                    UnionCase {
+                        name: "Admin".to_string(),
+                        fields: Vec::new(),
+                    },
+                    UnionCase {
+                        name: "Price".to_string(),
+                        fields: vec!["String".to_string()],
+                    },
+                ],
+            }],
+        )
+    }
+
+    #[test]
+    fn test_decodes_oracle_key_and_i128_value() {
+        let decoder = oracle_decoder();
+        assert_eq!(
+            decoder.decode_key(&data_key("Price", vec![string("XLM")])),
+            r#"Price("XLM")"#
+        );
+        assert_eq!(decoder.decode_key(&data_key("Admin", vec![])), "Admin");
+        assert_eq!(
+            decoder.decode_value(&i128_val(1_100_000)).rendered,
+            "1100000 (i128)"
+        );
+    }
+
+    #[test]
+    fn test_price_update_reports_i128_delta() {
+        let decoder = oracle_decoder();
+        let before = decoder.decode_entries(&[
+            entry(data_key("Price", vec![string("XLM")]), i128_val(1_000_000)),
+            entry(data_key("Price", vec![string("BTC")]), i128_val(5)),
+            entry(data_key("Admin", vec![]), sym("old")),
+        ]);
+        let after = decoder.decode_entries(&[
+            entry(data_key("Price", vec![string("XLM")]), i128_val(1_100_000)),
+            entry(data_key("Price", vec![string("BTC")]), i128_val(5)),
+            entry(data_key("Price", vec![string("ETH")]), i128_val(-42)),
+        ]);
+
+        let diff = DecodedStorageDiff::compute(&before, &after);
+        assert_eq!(
+            diff.format_lines(),
+            vec![
+                "  - Admin = old (symbol)",
+                r#"  + Price("ETH") = -42 (i128)"#,
+                r#"  ~ Price("XLM"): 1000000 (i128) -> 1100000 (i128) [+100000]"#,
+            ]
+        );
+        assert_eq!(diff.changes[2].delta.as_deref(), Some("+100000"));
+    }
+
+    #[test]
+    fn test_unchanged_storage_has_empty_diff() {
+        let decoder = oracle_decoder();
+        let entries = [entry(data_key("Admin", vec![]), sym("admin"))];
+        let before = decoder.decode_entries(&entries);
+        assert!(DecodedStorageDiff::compute(&before, &before).is_empty());
+    }
+}
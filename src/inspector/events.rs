@@ -1,10 +1,20 @@
+use crate::inspector::storage::FilterPattern;
 use crate::{DebuggerError, Result};
 use serde::{Deserialize, Serialize};
-use soroban_env_host::{xdr::ContractEventBody, Host};
+use soroban_env_host::{
+    xdr::{ContractEventBody, ContractEventType, Hash, ScAddress, ScVal},
+    Host,
+};
 
 /// Represents a captured contract event
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContractEvent {
+    /// Emission order among the contract events of the session, starting at 0.
+    /// Kept as-is when events are filtered, so it identifies the same event
+    /// across runs. `None` for diagnostic and system events.
+    #[serde(default)]
+    pub index: Option<usize>,
+
     /// Contract id that emitted the event (if present)
     pub contract_id: Option<String>,
 
@@ -15,19 +25,143 @@ pub struct ContractEvent {
     pub data: String,
 }
 
+/// Event filter matching the first topic symbol, combined with OR logic.
+///
+/// Patterns follow the same syntax as `StorageFilter`: `set*` (prefix),
+/// `re:^set` (regex) or `setprice` (exact).
+#[derive(Debug, Clone)]
+pub struct EventFilter {
+    patterns: Vec<FilterPattern>,
+}
+
+impl EventFilter {
+    /// Create a new event filter from a list of pattern strings
+    pub fn new(patterns: &[String]) -> std::result::Result<Self, String> {
+        let parsed: std::result::Result<Vec<FilterPattern>, String> =
+            patterns.iter().map(|p| FilterPattern::parse(p)).collect();
+        Ok(Self { patterns: parsed? })
+    }
+
+    /// Check if the event's first topic matches any pattern.
+    /// Returns true if no filters are set (show everything).
+    pub fn matches(&self, event: &ContractEvent) -> bool {
+        if self.patterns.is_empty() {
+            return true;
+        }
+        let Some(first) = event.topics.first() else {
+            return false;
+        };
+        let name = topic_symbol(first);
+        self.patterns.iter().any(|p| p.matches(name))
+    }
+
+    /// Returns true if no filters are configured
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+}
+
+/// Extract the symbol name from a debug-formatted `ScVal::Symbol` topic,
+/// falling back to the raw topic string for other values.
+fn topic_symbol(topic: &str) -> &str {
+    topic
+        .strip_prefix("Symbol(ScSymbol(StringM(")
+        .and_then(|rest| rest.strip_suffix(")))"))
+        .unwrap_or(topic)
+}
+
+/// What a host diagnostic event records, taken from its first topic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticKind {
+    /// `fn_call`: a contract function was invoked.
+    FnCall,
+    /// `fn_return`: a contract function returned.
+    FnReturn,
+    /// `log`: a `log!` from the contract or a host log message.
+    Log,
+    /// `error`: the host recorded an error, e.g. the one that aborted the call.
+    Error,
+    /// A regular contract or system event.
+    Contract,
+    Other,
+}
+
+/// A diagnostic event with its topics and data decoded to JSON.
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticEvent {
+    pub index: usize,
+    pub kind: DiagnosticKind,
+    pub contract_id: Option<String>,
+    /// Topics after the first, which only names the event.
+    pub topics: Vec<serde_json::Value>,
+    pub data: serde_json::Value,
+}
+
+impl std::fmt::Display for DiagnosticEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = |value: &serde_json::Value| match value {
+            serde_json::Value::String(s) => s.clone(),
+            other => other.to_string(),
+        };
+        write!(f, "#{} ", self.index)?;
+        match self.kind {
+            // Topics: called contract, function name. Data: the arguments.
+            DiagnosticKind::FnCall => {
+                let contract = self.topics.first().map(text).unwrap_or_default();
+                let function = self.topics.get(1).map(text).unwrap_or_default();
+                let args = match &self.data {
+                    serde_json::Value::Array(args) => {
+                        args.iter().map(|a| a.to_string()).collect::<Vec<_>>()
+                    }
+                    serde_json::Value::Null => Vec::new(),
+                    other => vec![other.to_string()],
+                };
+                write!(
+                    f,
+                    "fn_call {}({}) on {}",
+                    function,
+                    args.join(", "),
+                    contract
+                )
+            }
+            DiagnosticKind::FnReturn => {
+                let function = self.topics.first().map(text).unwrap_or_default();
+                write!(f, "fn_return {} -> {}", function, self.data)
+            }
+            DiagnosticKind::Log => write!(f, "log: {}", text(&self.data)),
+            DiagnosticKind::Error => {
+                let error = self.topics.first().map(text).unwrap_or_default();
+                write!(f, "error {}: {}", error, text(&self.data))
+            }
+            DiagnosticKind::Contract | DiagnosticKind::Other => {
+                let topics: Vec<String> = self.topics.iter().map(text).collect();
+                write!(f, "{:?} [{}]: {}", self.kind, topics.join(", "), self.data)
+            }
+        }
+    }
+}
+
 pub struct EventInspector;
 
 impl EventInspector {
-    /// Extract events from the host and convert them to a friendly format
-    pub fn get_events(host: &Host) -> Result<Vec<ContractEvent>> {
+    /// Extract events from the host and convert them to a friendly format.
+    ///
+    /// Contract events are numbered from `first_index` in emission order.
+    pub fn get_events(host: &Host, first_index: usize) -> Result<Vec<ContractEvent>> {
         let events = host
             .get_events()
             .map_err(|e| DebuggerError::ExecutionError(format!("Failed to get events: {}", e)))?
             .0;
         let mut contract_events = Vec::new();
+        let mut next_index = first_index;
 
         for host_event in events.iter() {
             let event = &host_event.event;
+            let index = (event.type_ == ContractEventType::Contract).then(|| {
+                next_index += 1;
+                next_index - 1
+            });
 
             // Extract topics and data from event body
             let (topics, data) = match &event.body {
@@ -46,6 +180,7 @@ impl EventInspector {
             let contract_id = event.contract_id.as_ref().map(|h| format!("{:?}", h));
 
             contract_events.push(ContractEvent {
+                index,
                 contract_id,
                 topics,
                 data,
@@ -55,6 +190,23 @@ impl EventInspector {
         Ok(contract_events)
     }
 
+    /// Extract only the events contracts published, dropping host diagnostics.
+    pub fn get_contract_events(host: &Host, first_index: usize) -> Result<Vec<ContractEvent>> {
+        Ok(Self::get_events(host, first_index)?
+            .into_iter()
+            .filter(|e| e.index.is_some())
+            .collect())
+    }
+
+    /// Keep only the events accepted by `filter`
+    pub fn apply_filter(events: &[ContractEvent], filter: &EventFilter) -> Vec<ContractEvent> {
+        events
+            .iter()
+            .filter(|e| filter.matches(e))
+            .cloned()
+            .collect()
+    }
+
     /// Filter events by topic substring. If `topic_filter` is empty,
     /// returns a clone of input slice.
     pub fn filter_events(events: &[ContractEvent], topic_filter: &str) -> Vec<ContractEvent> {
@@ -80,8 +232,11 @@ impl EventInspector {
     /// Here we return a Vec<String> of formatted lines to let the caller decide how to print/log them.
     pub fn format_events(events: &[ContractEvent]) -> Vec<String> {
         let mut out = Vec::new();
-        for (i, ev) in events.iter().enumerate() {
-            out.push(format!("Event #{}:", i));
+        for ev in events {
+            match ev.index {
+                Some(index) => out.push(format!("Event #{}:", index)),
+                None => out.push("Event (host):".to_string()),
+            }
             out.push(format!(
                 "  Contract: {}",
                 ev.contract_id.as_deref().unwrap_or("<none>")
@@ -98,6 +253,7 @@ impl EventInspector {
             .iter()
             .map(|e| {
                 serde_json::json!({
+                    "index": e.index,
                     "contract_id": e.contract_id,
                     "topics": e.topics,
                     "data": e.data,
@@ -107,6 +263,67 @@ impl EventInspector {
         serde_json::Value::Array(arr)
     }
 
+    /// Decode raw host diagnostic events (see `ContractExecutor::get_diagnostic_events`).
+    pub fn decode_diagnostic_events(
+        events: &[soroban_env_host::xdr::ContractEvent],
+    ) -> Vec<DiagnosticEvent> {
+        events
+            .iter()
+            .enumerate()
+            .map(|(index, event)| {
+                let ContractEventBody::V0(body) = &event.body;
+                let name = match body.topics.first() {
+                    Some(ScVal::Symbol(sym)) => {
+                        String::from_utf8_lossy(sym.0.as_slice()).into_owned()
+                    }
+                    _ => String::new(),
+                };
+                let kind = match (event.type_, name.as_str()) {
+                    (ContractEventType::Diagnostic, "fn_call") => DiagnosticKind::FnCall,
+                    (ContractEventType::Diagnostic, "fn_return") => DiagnosticKind::FnReturn,
+                    (ContractEventType::Diagnostic, "log") => DiagnosticKind::Log,
+                    (ContractEventType::Diagnostic, "error") => DiagnosticKind::Error,
+                    (ContractEventType::Diagnostic, _) => DiagnosticKind::Other,
+                    _ => DiagnosticKind::Contract,
+                };
+                // Keep the name topic for events we could not classify.
+                let skip = usize::from(matches!(
+                    kind,
+                    DiagnosticKind::FnCall
+                        | DiagnosticKind::FnReturn
+                        | DiagnosticKind::Log
+                        | DiagnosticKind::Error
+                ));
+                DiagnosticEvent {
+                    index,
+                    kind,
+                    contract_id: event
+                        .contract_id
+                        .as_ref()
+                        .map(|id| ScAddress::Contract(id.clone()).to_string()),
+                    topics: body
+                        .topics
+                        .iter()
+                        .skip(skip)
+                        .map(|topic| match topic {
+                            // `fn_call` names the called contract by its raw id.
+                            ScVal::Bytes(bytes) if kind == DiagnosticKind::FnCall => {
+                                match <[u8; 32]>::try_from(bytes.0.as_slice()) {
+                                    Ok(id) => serde_json::Value::String(
+                                        ScAddress::Contract(Hash(id)).to_string(),
+                                    ),
+                                    Err(_) => crate::utils::scval::scval_to_json(topic),
+                                }
+                            }
+                            other => crate::utils::scval::scval_to_json(other),
+                        })
+                        .collect(),
+                    data: crate::utils::scval::scval_to_json(&body.data),
+                }
+            })
+            .collect()
+    }
+
     /// Return the events emitted since the previous snapshot length.
     pub fn events_since(events: &[ContractEvent], previous_len: usize) -> Vec<ContractEvent> {
         events.iter().skip(previous_len).cloned().collect()
@@ -116,21 +333,25 @@ impl EventInspector {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use soroban_env_host::xdr::{ScBytes, ScString, ScSymbol, ScVec};
 
     #[test]
     fn test_filter_events() {
         let events = vec![
             ContractEvent {
+                index: Some(0),
                 contract_id: None,
                 topics: vec!["topic1".to_string(), "common".to_string()],
                 data: "data1".to_string(),
             },
             ContractEvent {
+                index: Some(1),
                 contract_id: None,
                 topics: vec!["topic2".to_string(), "common".to_string()],
                 data: "data2".to_string(),
             },
             ContractEvent {
+                index: Some(2),
                 contract_id: None,
                 topics: vec!["topic3".to_string()],
                 data: "data3".to_string(),
@@ -148,15 +369,99 @@ mod tests {
         assert_eq!(filtered.len(), 0);
     }
 
+    fn oracle_events() -> Vec<ContractEvent> {
+        ["init", "setprice", "setadmin", "reset"]
+            .iter()
+            .enumerate()
+            .map(|(index, name)| ContractEvent {
+                index: Some(index),
+                contract_id: None,
+                topics: vec![
+                    format!("Symbol(ScSymbol(StringM({})))", name),
+                    "Symbol(ScSymbol(StringM(setprice)))".to_string(),
+                ],
+                data: format!("{}_data", name),
+            })
+            .collect()
+    }
+
+    fn filtered_data(pattern: &str) -> Vec<String> {
+        let filter = EventFilter::new(&[pattern.to_string()]).unwrap();
+        EventInspector::apply_filter(&oracle_events(), &filter)
+            .into_iter()
+            .map(|e| e.data)
+            .collect()
+    }
+
+    #[test]
+    fn test_event_filter_glob_matches_first_topic_prefix() {
+        assert_eq!(
+            filtered_data("set*"),
+            vec!["setprice_data", "setadmin_data"]
+        );
+    }
+
+    #[test]
+    fn test_event_filter_regex_matches_first_topic() {
+        assert_eq!(
+            filtered_data("re:^set"),
+            vec!["setprice_data", "setadmin_data"]
+        );
+        assert_eq!(
+            filtered_data("re:^(init|reset)$"),
+            vec!["init_data", "reset_data"]
+        );
+    }
+
+    #[test]
+    fn test_event_index_survives_filtering() {
+        let filter = EventFilter::new(&["set*".to_string()]).unwrap();
+        let filtered = EventInspector::apply_filter(&oracle_events(), &filter);
+        let indices: Vec<usize> = filtered.iter().filter_map(|e| e.index).collect();
+        assert_eq!(indices, vec![1, 2]);
+
+        let lines = EventInspector::format_events(&filtered);
+        assert_eq!(lines[0], "Event #1:");
+        let json = EventInspector::to_json_value(&filtered);
+        assert_eq!(json[1]["index"], 2);
+
+        let all = EventInspector::apply_filter(&oracle_events(), &EventFilter::new(&[]).unwrap());
+        let indices: Vec<usize> = all.iter().filter_map(|e| e.index).collect();
+        assert_eq!(indices, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_event_filter_exact_ignores_later_topics() {
+        assert_eq!(filtered_data("setprice"), vec!["setprice_data"]);
+        assert!(filtered_data("price").is_empty());
+    }
+
+    #[test]
+    fn test_empty_event_filter_keeps_everything() {
+        let filter = EventFilter::new(&[]).unwrap();
+        assert!(filter.is_empty());
+        assert_eq!(
+            EventInspector::apply_filter(&oracle_events(), &filter).len(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_event_filter_rejects_invalid_regex() {
+        assert!(EventFilter::new(&["re:(".to_string()]).is_err());
+    }
+
     #[test]
     fn test_events_since_returns_delta() {
         let events = vec![
             ContractEvent {
+                index: Some(0),
                 contract_id: None,
                 topics: vec!["topic1".to_string()],
                 data: "data1".to_string(),
             },
             ContractEvent {
+                index: Some(1),
                 contract_id: None,
                 topics: vec!["topic2".to_string()],
                 data: "data2".to_string(),
@@ -167,4 +472,64 @@ mod tests {
         assert_eq!(delta.len(), 1);
         assert_eq!(delta[0].data, "data2");
     }
+
+    fn diagnostic(topics: Vec<ScVal>, data: ScVal) -> soroban_env_host::xdr::ContractEvent {
+        use soroban_env_host::xdr::{ContractEventV0, ExtensionPoint};
+        soroban_env_host::xdr::ContractEvent {
+            ext: ExtensionPoint::V0,
+            contract_id: None,
+            type_: ContractEventType::Diagnostic,
+            body: ContractEventBody::V0(ContractEventV0 {
+                topics: topics.try_into().unwrap(),
+                data,
+            }),
+        }
+    }
+
+    fn string(text: &str) -> ScVal {
+        ScVal::String(ScString(text.try_into().unwrap()))
+    }
+
+    fn symbol(name: &str) -> ScVal {
+        ScVal::Symbol(ScSymbol(name.try_into().unwrap()))
+    }
+
+    #[test]
+    fn test_decode_diagnostic_events_classifies_host_diagnostics() {
+        let raw = vec![
+            diagnostic(
+                vec![
+                    symbol("fn_call"),
+                    ScVal::Bytes(ScBytes(vec![0u8; 32].try_into().unwrap())),
+                    symbol("set_price"),
+                ],
+                ScVal::Vec(Some(ScVec(vec![ScVal::U32(7)].try_into().unwrap()))),
+            ),
+            diagnostic(vec![symbol("log")], string("price must be positive")),
+            diagnostic(
+                vec![symbol("error"), ScVal::U32(2)],
+                string("escalating error to panic"),
+            ),
+        ];
+
+        let decoded = EventInspector::decode_diagnostic_events(&raw);
+        let kinds: Vec<DiagnosticKind> = decoded.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                DiagnosticKind::FnCall,
+                DiagnosticKind::Log,
+                DiagnosticKind::Error
+            ]
+        );
+        assert_eq!(
+            decoded[0].to_string(),
+            "#0 fn_call set_price(7) on CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4"
+        );
+        assert_eq!(decoded[1].to_string(), "#1 log: price must be positive");
+        assert_eq!(
+            decoded[2].to_string(),
+            "#2 error 2: escalating error to panic"
+        );
+    }
 }
@@ -0,0 +1,256 @@
+//! Ordered contract calls dispatched by the host (`run --trace-host-functions`,
+//! `run --trace`).
+//!
+//! soroban-env-host 22 keeps its per-env-call trace hook crate-private, so the
+//! calls are rebuilt from the `fn_call`/`fn_return` diagnostic events: the
+//! top-level invocation and every cross-contract call it makes, in the order
+//! the host entered them. Host work inside a call (hashing, storage access,
+//! conversions) is only available per cost type, see
+//! [`BudgetInspector::breakdown`](crate::inspector::budget::BudgetInspector::breakdown).
+//!
+//! Calls and cost types can also be exported in Chrome Trace Event Format.
+//! Diagnostic events carry no timestamps, so the trace clock advances by one
+//! microsecond per call entry or return rather than by wall-clock time.
+
+use crate::debugger::state::{CallBoundary, CallBoundaryKind};
+use crate::{DebuggerError, Result};
+use serde::{Deserialize, Serialize};
+use soroban_env_host::xdr::ContractCostType;
+use std::path::Path;
+
+/// One contract call the host dispatched during an invocation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HostCall {
+    /// 1-based position in call order.
+    pub index: usize,
+    /// Nesting depth; the top-level invocation is 0.
+    pub depth: usize,
+    pub contract_id: Option<String>,
+    pub function: String,
+    pub args: serde_json::Value,
+    /// Return value, or `None` when the call aborted before returning.
+    pub result: Option<serde_json::Value>,
+    /// Position of the entry boundary among the invocation's boundaries.
+    #[serde(skip)]
+    pub entered_at: usize,
+    /// Position of the exit boundary; a call that never returned closes with the invocation.
+    #[serde(skip)]
+    pub exited_at: usize,
+}
+
+pub struct HostCallInspector;
+
+impl HostCallInspector {
+    /// Turn paired call boundaries into calls, in the order they were entered.
+    pub fn from_boundaries(boundaries: &[CallBoundary]) -> Vec<HostCall> {
+        let mut calls: Vec<HostCall> = Vec::new();
+        let mut open: Vec<usize> = Vec::new();
+
+        for (position, boundary) in boundaries.iter().enumerate() {
+            match boundary.kind {
+                CallBoundaryKind::Enter => {
+                    open.push(calls.len());
+                    calls.push(HostCall {
+                        index: calls.len() + 1,
+                        depth: boundary.depth,
+                        contract_id: boundary.contract_id.clone(),
+                        function: boundary.function.clone(),
+                        args: boundary.value.clone(),
+                        result: None,
+                        entered_at: position,
+                        exited_at: boundaries.len(),
+                    });
+                }
+                CallBoundaryKind::Exit => {
+                    if let Some(call) = open.pop().and_then(|idx| calls.get_mut(idx)) {
+                        call.result = Some(boundary.value.clone());
+                        call.exited_at = position;
+                    }
+                }
+            }
+        }
+        calls
+    }
+
+    /// Render calls as a numbered list, indented by nesting depth.
+    pub fn format_lines(calls: &[HostCall]) -> Vec<String> {
+        calls
+            .iter()
+            .map(|call| {
+                let args = match &call.args {
+                    serde_json::Value::Array(args) => args
+                        .iter()
+                        .map(|a| a.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    serde_json::Value::Null => String::new(),
+                    other => other.to_string(),
+                };
+                let result = match &call.result {
+                    Some(value) => value.to_string(),
+                    None => "(did not return)".to_string(),
+                };
+                format!(
+                    "{:>4}. {}{}({}) -> {} on {}",
+                    call.index,
+                    "  ".repeat(call.depth),
+                    call.function,
+                    args,
+                    result,
+                    call.contract_id.as_deref().unwrap_or("?")
+                )
+            })
+            .collect()
+    }
+
+    /// Build a Chrome Trace Event Format document: one duration event per call
+    /// on the first track, then one event per charged cost type (`rows` from
+    /// [`BudgetInspector::breakdown`](crate::inspector::budget::BudgetInspector::breakdown))
+    /// spanning the invocation on its own track.
+    pub fn to_chrome_trace(
+        calls: &[HostCall],
+        rows: &[(ContractCostType, u64, u64, u64)],
+    ) -> serde_json::Value {
+        let span = calls
+            .iter()
+            .map(|call| call.exited_at)
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let call_events = calls.iter().map(|call| {
+            serde_json::json!({
+                "name": call.function,
+                "cat": "contract_call",
+                "ph": "X",
+                "ts": call.entered_at,
+                "dur": call.exited_at.saturating_sub(call.entered_at).max(1),
+                "pid": 1,
+                "tid": 0,
+                "args": {
+                    "index": call.index,
+                    "contract_id": call.contract_id,
+                    "args": call.args,
+                    "result": call.result,
+                },
+            })
+        });
+        let cost_events = rows
+            .iter()
+            .enumerate()
+            .map(|(idx, (ty, cpu, mem, charges))| {
+                serde_json::json!({
+                    "name": ty.name(),
+                    "cat": "host_cost",
+                    "ph": "X",
+                    "ts": 0,
+                    "dur": span,
+                    "pid": 1,
+                    "tid": idx + 1,
+                    "args": {
+                        "cpu_instructions": cpu,
+                        "memory_bytes": mem,
+                        "charges": charges,
+                    },
+                })
+            });
+
+        serde_json::json!({
+            "traceEvents": call_events.chain(cost_events).collect::<Vec<_>>(),
+        })
+    }
+
+    /// Write [`to_chrome_trace`](Self::to_chrome_trace) output to `path`.
+    pub fn write_chrome_trace(
+        path: &Path,
+        calls: &[HostCall],
+        rows: &[(ContractCostType, u64, u64, u64)],
+    ) -> Result<()> {
+        let json =
+            serde_json::to_string_pretty(&Self::to_chrome_trace(calls, rows)).map_err(|e| {
+                DebuggerError::FileError(format!("Failed to serialize Chrome trace: {}", e))
+            })?;
+        std::fs::write(path, json).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to write Chrome trace {:?}: {}", path, e))
+        })?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn boundary(
+        kind: CallBoundaryKind,
+        depth: usize,
+        function: &str,
+        value: serde_json::Value,
+    ) -> CallBoundary {
+        CallBoundary {
+            kind,
+            depth,
+            contract_id: Some(format!("C{}", function.to_uppercase())),
+            function: function.to_string(),
+            frame: None,
+            value,
+            mocked_response: None,
+        }
+    }
+
+    #[test]
+    fn calls_are_listed_in_entry_order_with_their_results() {
+        let boundaries = vec![
+            boundary(CallBoundaryKind::Enter, 0, "call", json!(["price"])),
+            boundary(CallBoundaryKind::Enter, 1, "price", json!([])),
+            boundary(CallBoundaryKind::Exit, 1, "price", json!(42)),
+            boundary(CallBoundaryKind::Exit, 0, "call", json!(42)),
+        ];
+
+        let calls = HostCallInspector::from_boundaries(&boundaries);
+        assert_eq!(calls.len(), 2);
+        assert_eq!((calls[0].function.as_str(), calls[0].depth), ("call", 0));
+        assert_eq!((calls[1].function.as_str(), calls[1].depth), ("price", 1));
+        assert_eq!(calls[1].result, Some(json!(42)));
+        assert_eq!((calls[0].entered_at, calls[0].exited_at), (0, 3));
+        assert_eq!((calls[1].entered_at, calls[1].exited_at), (1, 2));
+
+        let lines = HostCallInspector::format_lines(&calls);
+        assert_eq!(lines[0], "   1. call(\"price\") -> 42 on CCALL");
+        assert_eq!(lines[1], "   2.   price() -> 42 on CPRICE");
+    }
+
+    #[test]
+    fn calls_that_abort_have_no_result() {
+        let boundaries = vec![boundary(CallBoundaryKind::Enter, 0, "panic", json!([]))];
+
+        let calls = HostCallInspector::from_boundaries(&boundaries);
+        assert_eq!(calls[0].result, None);
+        assert_eq!(calls[0].exited_at, 1);
+        assert!(HostCallInspector::format_lines(&calls)[0].contains("(did not return)"));
+    }
+
+    #[test]
+    fn chrome_trace_nests_calls_and_adds_cost_type_tracks() {
+        let boundaries = vec![
+            boundary(CallBoundaryKind::Enter, 0, "call", json!([])),
+            boundary(CallBoundaryKind::Enter, 1, "price", json!([])),
+            boundary(CallBoundaryKind::Exit, 1, "price", json!(42)),
+            boundary(CallBoundaryKind::Exit, 0, "call", json!(42)),
+        ];
+        let calls = HostCallInspector::from_boundaries(&boundaries);
+        let rows = vec![(ContractCostType::ComputeSha256Hash, 300, 40, 2)];
+
+        let trace = HostCallInspector::to_chrome_trace(&calls, &rows);
+        let events = trace["traceEvents"].as_array().unwrap();
+        assert_eq!(events.len(), 3);
+        assert!(events.iter().all(|e| e["ph"] == "X"));
+        assert_eq!(events[0]["ts"], 0);
+        assert_eq!(events[0]["dur"], 3);
+        assert_eq!(events[1]["ts"], 1);
+        assert_eq!(events[1]["dur"], 1);
+        assert_eq!(events[2]["name"], "ComputeSha256Hash");
+        assert_eq!(events[2]["dur"], 3);
+        assert_eq!(events[2]["args"]["cpu_instructions"], 300);
+    }
+}
@@ -1,13 +1,19 @@
+pub mod assets;
 pub mod auth;
 pub mod budget;
+pub mod decoded_storage;
 pub mod events;
+pub mod host_calls;
 pub mod instructions;
 pub mod ledger;
 pub mod stack;
 pub mod storage;
 
 pub use auth::AuthInspector;
-pub use budget::{BudgetInfo, BudgetInspector, MemorySummary, MemoryTracker};
+pub use budget::{
+    BudgetInfo, BudgetInspector, MemoryGrowth, MemorySeries, MemorySummary, MemoryTracker,
+};
+pub use host_calls::{HostCall, HostCallInspector};
 pub use instructions::{FunctionInstructionCount, InstructionCounter};
 pub use ledger::LedgerEntryInspector;
 pub use stack::CallStackInspector;
@@ -1,14 +1,203 @@
+use crate::inspector::ledger::StorageType;
+use crate::runtime::recording::RecordedEntry;
 use crate::{DebuggerError, Result};
 use crossterm::style::{Color, Stylize};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use soroban_env_host::budget::AsBudget;
-use soroban_env_host::xdr::{LedgerEntryData, LedgerKey};
+use soroban_env_host::xdr::{LedgerEntryData, LedgerKey, ScVal};
 use soroban_env_host::Host;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
 use std::path::Path;
 
+/// Group contract data entries by their `#[contracttype]` enum variant.
+///
+/// Enum keys are encoded as `[Symbol(variant), args...]`, so `Price("XLM")`
+/// lands at `{"Price": {"XLM": ...}}` and unit variants like `Admin` map
+/// straight to their value. Variants with several arguments use the JSON
+/// array of those arguments as the inner key. Any other key is kept flat.
+/// Instance, persistent and temporary entries share one namespace; a key
+/// whose text is already taken gets a `#2`, `#3`, ... suffix.
+pub fn typed_storage_json(entries: &[RecordedEntry]) -> serde_json::Value {
+    use crate::utils::scval::scval_to_json;
+
+    let key_text = |val: &ScVal| match scval_to_json(val) {
+        serde_json::Value::String(s) => s,
+        other => other.to_string(),
+    };
+
+    let mut out = serde_json::Map::new();
+    // Variant name -> the top-level key its group was stored under.
+    let mut groups: HashMap<String, String> = HashMap::new();
+    for entry in entries {
+        let value = scval_to_json(&entry.value);
+        let variant = match &entry.key {
+            ScVal::Vec(Some(items)) => match items.0.first() {
+                Some(ScVal::Symbol(sym)) => Some((
+                    String::from_utf8_lossy(sym.0.as_slice()).into_owned(),
+                    &items.0[1..],
+                )),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        match variant {
+            Some((name, [])) => {
+                out.insert(unique_key(&out, name), value);
+            }
+            Some((name, args)) => {
+                let inner = match args {
+                    [single] => key_text(single),
+                    _ => serde_json::Value::Array(args.iter().map(scval_to_json).collect())
+                        .to_string(),
+                };
+                let slot = match groups.get(&name) {
+                    Some(slot) => slot.clone(),
+                    None => {
+                        let slot = unique_key(&out, name.clone());
+                        out.insert(
+                            slot.clone(),
+                            serde_json::Value::Object(serde_json::Map::new()),
+                        );
+                        groups.insert(name, slot.clone());
+                        slot
+                    }
+                };
+                if let Some(serde_json::Value::Object(group)) = out.get_mut(&slot) {
+                    group.insert(unique_key(group, inner), value);
+                }
+            }
+            None => {
+                out.insert(unique_key(&out, key_text(&entry.key)), value);
+            }
+        }
+    }
+
+    serde_json::Value::Object(out)
+}
+
+/// `base`, or `base#2`, `base#3`, ... if `map` already has that key.
+fn unique_key(map: &serde_json::Map<String, serde_json::Value>, base: String) -> String {
+    if !map.contains_key(&base) {
+        return base;
+    }
+    (2..)
+        .map(|n| format!("{}#{}", base, n))
+        .find(|key| !map.contains_key(key))
+        .expect("unbounded suffix range")
+}
+
+/// Express the change between two [`typed_storage_json`] snapshots as an
+/// RFC 6902 JSON Patch.
+///
+/// Objects are compared member by member, so updating `Price("XLM")` yields a
+/// single `replace` at `/Price/XLM`; arrays and scalars are replaced whole.
+pub fn storage_json_patch(
+    before: &serde_json::Value,
+    after: &serde_json::Value,
+) -> Vec<serde_json::Value> {
+    let mut ops = Vec::new();
+    push_patch_ops("", before, after, &mut ops);
+    ops
+}
+
+fn push_patch_ops(
+    path: &str,
+    before: &serde_json::Value,
+    after: &serde_json::Value,
+    ops: &mut Vec<serde_json::Value>,
+) {
+    use serde_json::{json, Value};
+
+    match (before, after) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old_val) in old {
+                let child = format!("{}/{}", path, escape_pointer_token(key));
+                match new.get(key) {
+                    Some(new_val) => push_patch_ops(&child, old_val, new_val, ops),
+                    None => ops.push(json!({"op": "remove", "path": child})),
+                }
+            }
+            for (key, new_val) in new {
+                if !old.contains_key(key) {
+                    let child = format!("{}/{}", path, escape_pointer_token(key));
+                    ops.push(json!({"op": "add", "path": child, "value": new_val}));
+                }
+            }
+        }
+        _ if before != after => {
+            ops.push(json!({"op": "replace", "path": path, "value": after}));
+        }
+        _ => {}
+    }
+}
+
+/// Escape a JSON Pointer reference token (RFC 6901).
+fn escape_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+/// Durability and simulated time-to-live of one contract storage entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EntryTtl {
+    pub key: String,
+    pub durability: StorageType,
+    /// Last ledger the entry is live in, if the host tracks one.
+    pub live_until_ledger: Option<u32>,
+    /// Ledgers left after the current one; negative once expired.
+    pub remaining: Option<i64>,
+    /// The entry's TTL has run out: a temporary entry is gone, a persistent
+    /// or instance entry is archived and must be restored before it is read.
+    pub expired: bool,
+    /// The call read the entry although it had already expired.
+    pub read_after_expiry: bool,
+}
+
+impl EntryTtl {
+    pub fn new(
+        key: String,
+        durability: StorageType,
+        live_until_ledger: Option<u32>,
+        ledger_sequence: u32,
+    ) -> Self {
+        let remaining =
+            live_until_ledger.map(|until| i64::from(until) - i64::from(ledger_sequence));
+        Self {
+            key,
+            durability,
+            live_until_ledger,
+            remaining,
+            expired: remaining.is_some_and(|left| left < 0),
+            read_after_expiry: false,
+        }
+    }
+}
+
+impl std::fmt::Display for EntryTtl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} [{}] ", self.key, self.durability)?;
+        match (self.live_until_ledger, self.remaining) {
+            (Some(until), Some(_)) if self.expired => {
+                let fate = match self.durability {
+                    StorageType::Temporary => "deleted",
+                    _ => "archived",
+                };
+                write!(f, "EXPIRED at ledger {} ({})", until + 1, fate)
+            }
+            (Some(until), Some(left)) => {
+                write!(f, "live until ledger {} ({} ledgers left)", until, left)
+            }
+            _ => write!(f, "no TTL"),
+        }?;
+        if self.read_after_expiry {
+            write!(f, "; READ after expiry")?;
+        }
+        Ok(())
+    }
+}
+
 /// Represents a storage key filter pattern
 #[derive(Debug, Clone)]
 pub enum FilterPattern {
@@ -48,6 +237,21 @@ impl StorageState {
         Ok(())
     }
 
+    /// Export storage grouped by `DataKey` variant (see [`typed_storage_json`])
+    pub fn export_typed_to_file<P: AsRef<Path>>(typed: &serde_json::Value, path: P) -> Result<()> {
+        let json = serde_json::to_string_pretty(typed).map_err(|e| {
+            DebuggerError::StorageError(format!("Failed to serialize storage state: {}", e))
+        })?;
+        fs::write(path.as_ref(), json).map_err(|e| {
+            DebuggerError::FileError(format!(
+                "Failed to write storage file {:?}: {}",
+                path.as_ref(),
+                e
+            ))
+        })?;
+        Ok(())
+    }
+
     /// Import storage state from JSON file
     pub fn import_from_file<P: AsRef<Path>>(path: P) -> Result<HashMap<String, String>> {
         let contents = fs::read_to_string(path.as_ref()).map_err(|e| {
@@ -120,6 +324,60 @@ impl StorageFilter {
         self.patterns.is_empty()
     }
 
+    /// Capture groups of the first `re:` pattern matching `key`.
+    ///
+    /// Named groups are keyed by name, unnamed ones by their index. Returns
+    /// `None` when no regex pattern with capture groups matches.
+    pub fn captures(&self, key: &str) -> Option<HashMap<String, String>> {
+        self.patterns.iter().find_map(|pattern| {
+            let FilterPattern::Regex(regex) = pattern else {
+                return None;
+            };
+            let caps = regex.captures(key)?;
+            let groups: HashMap<String, String> = regex
+                .capture_names()
+                .enumerate()
+                .skip(1)
+                .filter_map(|(index, name)| {
+                    let value = caps.get(index)?.as_str().to_string();
+                    Some((
+                        name.map_or_else(|| index.to_string(), str::to_string),
+                        value,
+                    ))
+                })
+                .collect();
+            (!groups.is_empty()).then_some(groups)
+        })
+    }
+
+    /// Bucket keys by their captured values, e.g. `asset=XLM`.
+    ///
+    /// Keys without captures are left out; keys within a group are sorted.
+    pub fn group_by_capture<'a>(
+        &self,
+        keys: impl IntoIterator<Item = &'a str>,
+    ) -> BTreeMap<String, Vec<String>> {
+        let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for key in keys {
+            let Some(captures) = self.captures(key) else {
+                continue;
+            };
+            let mut parts: Vec<String> = captures
+                .into_iter()
+                .map(|(name, value)| format!("{}={}", name, value))
+                .collect();
+            parts.sort();
+            groups
+                .entry(parts.join(", "))
+                .or_default()
+                .push(key.to_string());
+        }
+        for keys in groups.values_mut() {
+            keys.sort();
+        }
+        groups
+    }
+
     /// Get a human-readable summary of active filters
     pub fn summary(&self) -> String {
         self.patterns
@@ -414,6 +672,14 @@ impl StorageInspector {
         }
     }
 
+    /// Render a storage key as the `#[contracttype]` enum variant it encodes,
+    /// e.g. `Price("XLM")` or `Admin`, with argument types taken from `spec`.
+    /// This is `StorageDecoder::decode_key` for callers holding only the unions.
+    pub fn decode_key(spec: &[crate::utils::wasm::ContractUnion], raw_key: &ScVal) -> String {
+        crate::inspector::decoded_storage::StorageDecoder::new(Vec::new(), spec.to_vec())
+            .decode_key(raw_key)
+    }
+
     /// Compute the difference between two storage snapshots
     pub fn compute_diff(
         before: &HashMap<String, String>,
@@ -466,7 +732,8 @@ impl StorageInspector {
         }
     }
 
-    /// Display a color-coded storage diff
+    /// Display a color-coded storage diff: a table on a terminal, plain lines
+    /// when stdout is redirected or `NO_COLOR` is set.
     pub fn display_diff(diff: &StorageDiff) {
         if diff.is_empty() {
             crate::logging::log_display("Storage: (no changes)", crate::logging::LogLevel::Info);
@@ -475,6 +742,17 @@ impl StorageInspector {
 
         crate::logging::log_display("Storage Changes:", crate::logging::LogLevel::Info);
 
+        let stdout_is_tty = atty::is(atty::Stream::Stdout);
+        let colors_enabled = crate::output::OutputConfig::colors_enabled();
+        let table = use_diff_table(stdout_is_tty, colors_enabled);
+        if table || !stdout_is_tty || !colors_enabled {
+            for line in diff.render(table) {
+                crate::logging::log_display(line, crate::logging::LogLevel::Info);
+            }
+            Self::display_alerts(diff);
+            return;
+        }
+
         // Sort keys for deterministic output
         let mut added_keys: Vec<_> = diff.added.keys().collect();
         added_keys.sort();
@@ -515,6 +793,10 @@ impl StorageInspector {
             );
         }
 
+        Self::display_alerts(diff);
+    }
+
+    fn display_alerts(diff: &StorageDiff) {
         if !diff.triggered_alerts.is_empty() {
             crate::logging::log_display(
                 format!(
@@ -535,8 +817,43 @@ impl StorageInspector {
     }
 }
 
+/// Whether storage diffs render as a table: only when stdout is a terminal
+/// and colors are enabled.
+pub fn use_diff_table(stdout_is_tty: bool, colors_enabled: bool) -> bool {
+    cfg!(feature = "table-output") && stdout_is_tty && colors_enabled
+}
+
+/// Integer held by a debug-formatted value such as `U32(5)` or
+/// `I128(Int128Parts { hi: 0, lo: 5 })`, ignoring a ` (ttl=N)` suffix.
+#[cfg(feature = "table-output")]
+fn debug_integer(value: &str) -> Option<i128> {
+    let value = value.split(" (ttl=").next()?.trim();
+    let inner = match value.split_once('(') {
+        Some((_, rest)) => rest.strip_suffix(')')?,
+        None => value,
+    };
+    if let Some(parts) = inner
+        .strip_prefix("Int128Parts { hi: ")
+        .and_then(|rest| rest.strip_suffix(" }"))
+    {
+        let (hi, lo) = parts.split_once(", lo: ")?;
+        let (hi, lo): (i64, u64) = (hi.parse().ok()?, lo.parse().ok()?);
+        return Some((i128::from(hi) << 64) | i128::from(lo));
+    }
+    inner.parse().ok()
+}
+
+/// Δ column text: the signed change of integer values, otherwise `changed`.
+#[cfg(feature = "table-output")]
+fn value_delta(before: &str, after: &str) -> String {
+    debug_integer(before)
+        .zip(debug_integer(after))
+        .and_then(|(before, after)| after.checked_sub(before))
+        .map_or_else(|| "changed".to_string(), |delta| format!("{:+}", delta))
+}
+
 /// Represents the differences between two storage states
-#[derive(Debug, Clone, Default, Serialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StorageDiff {
     pub added: HashMap<String, String>,
     pub modified: HashMap<String, (String, String)>,
@@ -548,6 +865,79 @@ impl StorageDiff {
     pub fn is_empty(&self) -> bool {
         self.added.is_empty() && self.modified.is_empty() && self.deleted.is_empty()
     }
+
+    /// Plain `+ key = value`, `~ key: before -> after` and `- key` lines, sorted by key.
+    pub fn format_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut added: Vec<_> = self.added.iter().collect();
+        added.sort();
+        for (key, value) in added {
+            lines.push(format!("+ {} = {}", key, value));
+        }
+        let mut modified: Vec<_> = self.modified.iter().collect();
+        modified.sort();
+        for (key, (before, after)) in modified {
+            lines.push(format!("~ {}: {} -> {}", key, before, after));
+        }
+        let mut deleted = self.deleted.clone();
+        deleted.sort();
+        for key in deleted {
+            lines.push(format!("- {}", key));
+        }
+        lines
+    }
+
+    /// Aligned `Key | Before | After | Δ` table, sorted like [`Self::format_lines`]:
+    /// green rows for additions, red for removals and yellow for changes.
+    #[cfg(feature = "table-output")]
+    pub fn format_table(&self) -> String {
+        use comfy_table::presets::{ASCII_FULL, UTF8_FULL};
+        use comfy_table::{Cell, Color as CellColor, Table};
+
+        let no_unicode = crate::output::OutputConfig::no_unicode();
+        let mut table = Table::new();
+        table
+            .load_preset(if no_unicode { ASCII_FULL } else { UTF8_FULL })
+            .set_header(vec![
+                "Key",
+                "Before",
+                "After",
+                if no_unicode { "Delta" } else { "Δ" },
+            ]);
+        let mut row = |color, cells: [&str; 4]| {
+            table.add_row(cells.map(|text| Cell::new(text).fg(color)));
+        };
+
+        let mut added: Vec<_> = self.added.iter().collect();
+        added.sort();
+        for (key, value) in added {
+            row(CellColor::Green, [key, "", value, "added"]);
+        }
+        let mut modified: Vec<_> = self.modified.iter().collect();
+        modified.sort();
+        for (key, (before, after)) in modified {
+            let delta = value_delta(before, after);
+            row(CellColor::Yellow, [key, before, after, &delta]);
+        }
+        let mut deleted = self.deleted.clone();
+        deleted.sort();
+        for key in &deleted {
+            row(CellColor::Red, [key, "", "", "removed"]);
+        }
+        table.to_string()
+    }
+
+    /// Lines to print: the table when `table` is set and the `table-output`
+    /// feature is built, plain [`Self::format_lines`] otherwise.
+    pub fn render(&self, table: bool) -> Vec<String> {
+        #[cfg(feature = "table-output")]
+        if table {
+            return self.format_table().lines().map(str::to_string).collect();
+        }
+        #[cfg(not(feature = "table-output"))]
+        let _ = table;
+        self.format_lines()
+    }
 }
 
 /// Statistics for a single storage access key
@@ -575,6 +965,222 @@ impl Default for StorageInspector {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::runtime::recording::RecordedDurability;
+    use soroban_env_host::xdr::{Int128Parts, ScString, ScSymbol, ScVec, StringM};
+
+    fn sym(s: &str) -> ScVal {
+        ScVal::Symbol(ScSymbol(StringM::try_from(s.as_bytes().to_vec()).unwrap()))
+    }
+
+    fn data_key(variant: &str, args: Vec<ScVal>) -> ScVal {
+        let mut items = vec![sym(variant)];
+        items.extend(args);
+        ScVal::Vec(Some(ScVec(items.try_into().unwrap())))
+    }
+
+    fn i128_val(n: i128) -> ScVal {
+        ScVal::I128(Int128Parts {
+            hi: (n >> 64) as i64,
+            lo: n as u64,
+        })
+    }
+
+    fn recorded(durability: RecordedDurability, key: ScVal, value: ScVal) -> RecordedEntry {
+        RecordedEntry {
+            durability,
+            key,
+            value,
+            live_until: None,
+        }
+    }
+
+    fn persistent(entries: &[(ScVal, ScVal)]) -> Vec<RecordedEntry> {
+        entries
+            .iter()
+            .map(|(key, value)| {
+                recorded(RecordedDurability::Persistent, key.clone(), value.clone())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_typed_storage_groups_oracle_entries_by_variant() {
+        let entries = vec![
+            (data_key("Admin", vec![]), sym("GADMIN")),
+            (data_key("Price", vec![sym("XLM")]), i128_val(1_100_000)),
+            (data_key("Price", vec![sym("BTC")]), i128_val(65_000_000)),
+            (data_key("Timestamp", vec![sym("XLM")]), ScVal::U64(5000)),
+            (sym("COUNTER"), ScVal::U32(3)),
+        ];
+
+        let typed = typed_storage_json(&persistent(&entries));
+
+        assert_eq!(
+            typed,
+            serde_json::json!({
+                "Admin": "GADMIN",
+                "Price": {"XLM": "1100000", "BTC": "65000000"},
+                "Timestamp": {"XLM": 5000},
+                "COUNTER": 3,
+            })
+        );
+    }
+
+    #[test]
+    fn test_typed_storage_multi_arg_variant_uses_array_key() {
+        let entries = vec![(
+            data_key("Allowance", vec![sym("alice"), sym("bob")]),
+            ScVal::U64(10),
+        )];
+
+        let typed = typed_storage_json(&persistent(&entries));
+        assert_eq!(typed["Allowance"][r#"["alice","bob"]"#], 10);
+    }
+
+    #[test]
+    fn test_typed_storage_keeps_instance_entries_and_colliding_keys() {
+        let string = |s: &str| ScVal::String(ScString(s.try_into().unwrap()));
+        let entries = vec![
+            recorded(
+                RecordedDurability::Instance,
+                data_key("Admin", vec![]),
+                sym("GADMIN"),
+            ),
+            recorded(RecordedDurability::Instance, sym("counter"), ScVal::U32(1)),
+            recorded(RecordedDurability::Temporary, sym("counter"), ScVal::U32(2)),
+            recorded(
+                RecordedDurability::Persistent,
+                data_key("Price", vec![sym("XLM")]),
+                ScVal::U64(10),
+            ),
+            recorded(
+                RecordedDurability::Persistent,
+                data_key("Price", vec![string("XLM")]),
+                ScVal::U64(20),
+            ),
+            recorded(RecordedDurability::Persistent, sym("Price"), ScVal::U64(30)),
+        ];
+
+        let typed = typed_storage_json(&entries);
+
+        assert_eq!(
+            typed,
+            serde_json::json!({
+                "Admin": "GADMIN",
+                "counter": 1,
+                "counter#2": 2,
+                "Price": {"XLM": 10, "XLM#2": 20},
+                "Price#2": 30,
+            })
+        );
+    }
+
+    #[test]
+    fn test_json_patch_set_price_replaces_nested_price() {
+        let before = typed_storage_json(&persistent(&[
+            (data_key("Price", vec![sym("XLM")]), i128_val(1_000_000)),
+            (data_key("Timestamp", vec![sym("XLM")]), ScVal::U64(5000)),
+        ]));
+        let after = typed_storage_json(&persistent(&[
+            (data_key("Price", vec![sym("XLM")]), i128_val(1_100_000)),
+            (data_key("Timestamp", vec![sym("XLM")]), ScVal::U64(6000)),
+            (data_key("Price", vec![sym("BTC")]), i128_val(65_000_000)),
+        ]));
+
+        let patch = storage_json_patch(&before, &after);
+
+        assert_eq!(
+            patch,
+            vec![
+                serde_json::json!({"op": "replace", "path": "/Price/XLM", "value": "1100000"}),
+                serde_json::json!({"op": "add", "path": "/Price/BTC", "value": "65000000"}),
+                serde_json::json!({"op": "replace", "path": "/Timestamp/XLM", "value": 6000}),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json_patch_removes_and_escapes_keys() {
+        let before = serde_json::json!({"Allowance": {"a/b": 1, "x~y": 2}, "Admin": "G"});
+        let after = serde_json::json!({"Allowance": {"x~y": 2}});
+
+        let patch = storage_json_patch(&before, &after);
+
+        assert_eq!(
+            patch,
+            vec![
+                serde_json::json!({"op": "remove", "path": "/Admin"}),
+                serde_json::json!({"op": "remove", "path": "/Allowance/a~1b"}),
+            ]
+        );
+        assert!(storage_json_patch(&after, &after).is_empty());
+    }
+
+    /// Minimal RFC 6902 `add`/`remove`/`replace` over object members.
+    fn apply_patch(doc: &mut serde_json::Value, patch: &[serde_json::Value]) {
+        for op in patch {
+            let path = op["path"].as_str().unwrap();
+            let (parent, token) = path.rsplit_once('/').unwrap();
+            let token = token.replace("~1", "/").replace("~0", "~");
+            let target = doc.pointer_mut(parent).unwrap().as_object_mut().unwrap();
+            match op["op"].as_str().unwrap() {
+                "add" | "replace" => {
+                    target.insert(token, op["value"].clone());
+                }
+                "remove" => {
+                    target.remove(&token).unwrap();
+                }
+                other => panic!("unexpected op {other}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_json_patch_applied_to_before_yields_after() {
+        let before = typed_storage_json(&persistent(&[
+            (data_key("Admin", vec![]), sym("alice")),
+            (data_key("Price", vec![sym("XLM")]), i128_val(1_000_000)),
+            (data_key("Price", vec![sym("ETH")]), i128_val(3_000_000)),
+            (sym("counter"), ScVal::U32(1)),
+        ]));
+        let after = typed_storage_json(&persistent(&[
+            (data_key("Price", vec![sym("XLM")]), i128_val(1_100_000)),
+            (data_key("Price", vec![sym("BTC")]), i128_val(65_000_000)),
+            (data_key("Timestamp", vec![sym("BTC")]), ScVal::U64(6000)),
+            (sym("counter"), ScVal::U32(2)),
+        ]));
+
+        let patch = storage_json_patch(&before, &after);
+        let mut patched = before.clone();
+        apply_patch(&mut patched, &patch);
+
+        assert_eq!(patched, after);
+    }
+
+    #[test]
+    fn entry_ttl_reports_remaining_ledgers_and_expiry() {
+        let live = EntryTtl::new("nonce".to_string(), StorageType::Temporary, Some(15), 10);
+        assert_eq!(live.remaining, Some(5));
+        assert!(!live.expired);
+        assert_eq!(
+            live.to_string(),
+            "nonce [Temporary] live until ledger 15 (5 ledgers left)"
+        );
+
+        let expired = EntryTtl::new("nonce".to_string(), StorageType::Temporary, Some(15), 16);
+        assert!(expired.expired);
+        assert_eq!(
+            expired.to_string(),
+            "nonce [Temporary] EXPIRED at ledger 16 (deleted)"
+        );
+
+        let mut archived = EntryTtl::new("c".to_string(), StorageType::Instance, Some(15), 100);
+        assert!(archived.to_string().ends_with("(archived)"));
+        archived.read_after_expiry = true;
+        assert!(archived
+            .to_string()
+            .ends_with("(archived); READ after expiry"));
+    }
 
     // ── FilterPattern tests ──────────────────────────────────────────
 
@@ -675,6 +1281,54 @@ mod tests {
         assert!(summary.contains("admin"));
     }
 
+    #[test]
+    fn test_captures_named_groups() {
+        let filter = StorageFilter::new(&[r"re:^Price\((?P<asset>\w+)\)$".to_string()]).unwrap();
+        let caps = filter.captures("Price(XLM)").unwrap();
+        assert_eq!(caps.get("asset").map(String::as_str), Some("XLM"));
+        assert!(filter.captures("Admin").is_none());
+    }
+
+    #[test]
+    fn test_captures_need_a_regex_with_groups() {
+        let filter = StorageFilter::new(&["Price*".to_string(), r"re:^Price".to_string()]).unwrap();
+        assert!(filter.captures("Price(XLM)").is_none());
+
+        let unnamed = StorageFilter::new(&[r"re:^(\w+)\(".to_string()]).unwrap();
+        let caps = unnamed.captures("Timestamp(BTC)").unwrap();
+        assert_eq!(caps.get("1").map(String::as_str), Some("Timestamp"));
+    }
+
+    #[test]
+    fn test_group_by_capture_buckets_price_keys() {
+        let filter =
+            StorageFilter::new(&[r#"re:^\w+\("?(?P<asset>\w+)"?\)$"#.to_string()]).unwrap();
+        let groups = filter.group_by_capture([
+            "Price(\"XLM\")",
+            "Timestamp(\"XLM\")",
+            "Price(\"BTC\")",
+            "Price(ETH)",
+            "Admin",
+        ]);
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(
+            groups["asset=XLM"],
+            vec![
+                "Price(\"XLM\")".to_string(),
+                "Timestamp(\"XLM\")".to_string()
+            ]
+        );
+        assert_eq!(groups["asset=BTC"], vec!["Price(\"BTC\")".to_string()]);
+        assert_eq!(groups["asset=ETH"], vec!["Price(ETH)".to_string()]);
+    }
+
+    #[test]
+    fn test_invalid_capture_regex_errors_at_construction() {
+        let err = StorageFilter::new(&[r"re:^Price\((?P<asset>\w+$".to_string()]).unwrap_err();
+        assert!(err.contains("Invalid regex pattern"));
+    }
+
     // ── StorageInspector filtering tests ─────────────────────────────
 
     #[test]
@@ -979,4 +1633,40 @@ mod tests {
         // Ensure display_diff doesn't panic with these values
         StorageInspector::display_diff(&diff);
     }
+
+    fn price_update_diff() -> StorageDiff {
+        let price = |lo: u64| format!("I128(Int128Parts {{ hi: 0, lo: {} }}) (ttl=100)", lo);
+        let before = HashMap::from([
+            ("price:XLM".to_string(), price(100)),
+            ("stale".to_string(), "Bool(true)".to_string()),
+        ]);
+        let after = HashMap::from([
+            ("price:XLM".to_string(), price(110)),
+            ("price:BTC".to_string(), price(7)),
+        ]);
+        StorageInspector::compute_diff(&before, &after, &[])
+    }
+
+    #[test]
+    fn storage_diff_uses_plain_lines_when_stdout_is_not_a_tty() {
+        let diff = price_update_diff();
+        let table = use_diff_table(false, true);
+
+        assert!(!table);
+        assert!(!use_diff_table(true, false));
+        assert_eq!(diff.render(table), diff.format_lines());
+    }
+
+    #[cfg(feature = "table-output")]
+    #[test]
+    fn storage_diff_table_shows_numeric_delta() {
+        let rendered = price_update_diff().render(true).join("\n");
+
+        assert!(rendered.contains("Key"), "{rendered}");
+        assert!(rendered.contains("Δ"), "{rendered}");
+        assert!(rendered.contains("+10"), "{rendered}");
+        assert!(rendered.contains("added"), "{rendered}");
+        assert!(rendered.contains("removed"), "{rendered}");
+        assert_eq!(value_delta("Symbol(a)", "Symbol(b)"), "changed");
+    }
 }
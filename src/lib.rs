@@ -20,6 +20,7 @@ pub mod repeat;
 pub mod repl;
 pub mod runtime;
 pub mod scenario;
+pub mod script;
 pub mod server;
 pub mod simulator;
 pub mod ui;
@@ -112,4 +113,52 @@ pub enum DebuggerError {
         help("Action: Ensure the shared security token matches the server, and the transport protocol is correct.\nContext: The server rejected communication because authentication wasn't verified.")
     )]
     AuthenticationFailed(String),
+
+    #[error("Budget exceeded: {0}")]
+    #[diagnostic(
+        code(debugger::budget_exceeded),
+        help("Action: Raise --max-insns/--max-mem, or reduce the work done in this call.\nContext: The invocation hit the configured CPU instruction or memory ceiling before it finished.")
+    )]
+    BudgetExceeded(String),
+
+    #[error("Execution timed out: {0}")]
+    #[diagnostic(
+        code(debugger::execution_timeout),
+        help("Action: Raise --timeout, or pass --timeout 0 to disable the watchdog.\nContext: The call ran longer than the configured wall-clock limit; the session is still usable.")
+    )]
+    Timeout(String),
+
+    #[error("Contract error: {message}")]
+    #[diagnostic(
+        code(debugger::contract_error),
+        help("Action: Look the code up in the contract's `#[contracterror]` enum and check which inputs trigger it.\nContext: The contract returned an error value instead of a result.")
+    )]
+    ContractError {
+        code: u32,
+        /// Error name resolved from the contract spec or the standard error table.
+        name: Option<String>,
+        message: String,
+    },
+}
+
+impl DebuggerError {
+    /// The variant name, used as `error_kind` in `--json-errors` output.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DebuggerError::WasmLoadError(_) => "WasmLoadError",
+            DebuggerError::ExecutionError(_) => "ExecutionError",
+            DebuggerError::InvalidFunction(_) => "InvalidFunction",
+            DebuggerError::InvalidArguments(_) => "InvalidArguments",
+            DebuggerError::BreakpointError(_) => "BreakpointError",
+            DebuggerError::StorageError(_) => "StorageError",
+            DebuggerError::ChecksumMismatch(_, _) => "ChecksumMismatch",
+            DebuggerError::FileError(_) => "FileError",
+            DebuggerError::NetworkError(_) => "NetworkError",
+            DebuggerError::RequestTimeout(_, _) => "RequestTimeout",
+            DebuggerError::AuthenticationFailed(_) => "AuthenticationFailed",
+            DebuggerError::BudgetExceeded(_) => "BudgetExceeded",
+            DebuggerError::Timeout(_) => "Timeout",
+            DebuggerError::ContractError { .. } => "ContractError",
+        }
+    }
 }
@@ -68,7 +68,7 @@ fn handle_deprecations(cli: &mut Cli) {
         Some(Commands::Inspect(args)) => {
             if let Some(wasm) = args.wasm.take() {
                 print_deprecation_warning("--wasm", "--contract");
-                args.contract = wasm;
+                args.contract = Some(wasm);
             }
         }
         Some(Commands::Optimize(args)) => {
@@ -146,14 +146,9 @@ fn main() -> miette::Result<()> {
 
     let run_json_output_requested = matches!(
         cli.command.as_ref(),
-        Some(Commands::Run(args))
-            if args.output_format == soroban_debugger::cli::args::OutputFormat::Json
-                || args.json
-                || args
-                    .format
-                    .as_deref()
-                    .is_some_and(|f| f.eq_ignore_ascii_case("json"))
+        Some(Commands::Run(args)) if args.is_json_output()
     );
+    let json_errors = cli.json_errors;
     let verbosity = cli.verbosity();
 
     Formatter::set_verbosity(verbosity_to_level(verbosity));
@@ -179,6 +174,11 @@ fn main() -> miette::Result<()> {
             soroban_debugger::cli::commands::optimize(args, verbosity)
         }
         Some(Commands::UpgradeCheck(args)) => soroban_debugger::cli::commands::upgrade_check(args),
+        Some(Commands::Diff(args)) => soroban_debugger::cli::commands::diff(args),
+        Some(Commands::ValidateArgs(args)) => soroban_debugger::cli::commands::validate_args(args),
+        Some(Commands::CheckInterface(args)) => {
+            soroban_debugger::cli::commands::check_interface(args)
+        }
         Some(Commands::Compare(args)) => soroban_debugger::cli::commands::compare(args),
         Some(Commands::Replay(args)) => soroban_debugger::cli::commands::replay(args, verbosity),
         Some(Commands::Completions(args)) => {
@@ -190,12 +190,15 @@ fn main() -> miette::Result<()> {
         Some(Commands::Symbolic(args)) => {
             soroban_debugger::cli::commands::symbolic(args, verbosity)
         }
+        Some(Commands::Fuzz(args)) => soroban_debugger::cli::commands::fuzz(args),
         Some(Commands::Server(args)) => soroban_debugger::cli::commands::server(args),
         Some(Commands::Remote(args)) => soroban_debugger::cli::commands::remote(args, verbosity),
+        Some(Commands::Dap(args)) => soroban_debugger::cli::commands::dap(args),
         Some(Commands::Analyze(args)) => soroban_debugger::cli::commands::analyze(args, verbosity),
         Some(Commands::Scenario(args)) => {
             soroban_debugger::cli::commands::scenario(args, verbosity)
         }
+        Some(Commands::Script(args)) => soroban_debugger::cli::commands::script(args, verbosity),
         Some(Commands::HistoryPrune(args)) => soroban_debugger::cli::commands::history_prune(args),
         Some(Commands::Repl(mut args)) => {
             args.merge_config(&config);
@@ -254,7 +257,9 @@ fn main() -> miette::Result<()> {
             if let Some(path) = cli.list_functions {
                 return soroban_debugger::cli::commands::inspect(
                     soroban_debugger::cli::args::InspectArgs {
-                        contract: path,
+                        contract: Some(path),
+                        rpc: None,
+                        contract_id: None,
                         wasm: None,
                         functions: true,
                         metadata: false,
@@ -263,6 +268,12 @@ fn main() -> miette::Result<()> {
                         source_map_limit: 20,
                         expected_hash: None,
                         dependency_graph: None,
+                        call_graph: false,
+                        list_assets: None,
+                        stale_ttl: None,
+                        spec: false,
+                        opcodes: false,
+                        opcode_limit: 20,
                     },
                     verbosity,
                 );
@@ -287,7 +298,12 @@ fn main() -> miette::Result<()> {
     };
 
     if let Err(err) = result {
-        if run_json_output_requested {
+        if json_errors {
+            let output = soroban_debugger::output::JsonError::from_report(&err);
+            if let Ok(json) = serde_json::to_string(&output) {
+                println!("{}", json);
+            }
+        } else if run_json_output_requested {
             let mut message = err.to_string();
             if let Some(help) = err.help() {
                 message.push_str(&format!(" | hint: {}", help));
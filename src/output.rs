@@ -2,7 +2,8 @@
 //!
 //! Supports `NO_COLOR` (disable ANSI colors) and `--no-unicode` (ASCII-only output).
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 static NO_UNICODE: AtomicBool = AtomicBool::new(false);
@@ -21,6 +22,61 @@ pub struct OutputError {
     pub message: String,
 }
 
+/// A non-fatal issue (silent coercion, failed spec load, ...) surfaced to the user.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutputWarning {
+    pub code: String,
+    pub message: String,
+}
+
+thread_local! {
+    static WARNINGS: RefCell<Vec<OutputWarning>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Per-thread collector for warnings raised anywhere in the execution pipeline.
+///
+/// Warnings are still logged through `tracing`, but are also buffered here so
+/// commands can report them in JSON output and in a dedicated human section.
+pub struct WarningCollector;
+
+impl WarningCollector {
+    /// Record a warning with a stable machine-readable `code`.
+    ///
+    /// A warning identical to one already buffered is dropped, since the same
+    /// input (e.g. the contract spec) may be read several times per command.
+    pub fn record(code: &str, message: impl Into<String>) {
+        let warning = OutputWarning {
+            code: code.to_string(),
+            message: message.into(),
+        };
+        WARNINGS.with(|w| {
+            let mut warnings = w.borrow_mut();
+            if !warnings.contains(&warning) {
+                tracing::warn!(code, "{}", warning.message);
+                warnings.push(warning);
+            }
+        });
+    }
+
+    /// Drain all warnings recorded on the current thread.
+    pub fn take() -> Vec<OutputWarning> {
+        WARNINGS.with(|w| std::mem::take(&mut *w.borrow_mut()))
+    }
+
+    /// Discard any buffered warnings.
+    pub fn clear() {
+        WARNINGS.with(|w| w.borrow_mut().clear());
+    }
+
+    /// Render warnings as human-readable lines for the "Warnings" section.
+    pub fn format_lines(warnings: &[OutputWarning]) -> Vec<String> {
+        warnings
+            .iter()
+            .map(|w| format!("[{}] {}", w.code, w.message))
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct VersionedOutput<T>
 where
@@ -31,6 +87,8 @@ where
     pub status: OutputStatus,
     pub result: Option<T>,
     pub error: Option<OutputError>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<OutputWarning>,
 }
 
 impl<T> VersionedOutput<T>
@@ -44,6 +102,7 @@ where
             status: OutputStatus::Success,
             result: Some(result),
             error: None,
+            warnings: WarningCollector::take(),
         }
     }
 
@@ -56,6 +115,49 @@ where
             error: Some(OutputError {
                 message: message.into(),
             }),
+            warnings: WarningCollector::take(),
+        }
+    }
+}
+
+/// A command failure in the shape printed by `--json-errors`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct JsonError {
+    /// `DebuggerError` variant name, or `Other` for errors raised outside it.
+    pub error_kind: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract_code: Option<u32>,
+    /// Name of the contract error code, when the error database knows it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract_error: Option<String>,
+}
+
+impl JsonError {
+    pub fn from_report(err: &miette::Report) -> Self {
+        match err.downcast_ref::<crate::DebuggerError>() {
+            Some(crate::DebuggerError::ContractError {
+                code,
+                name,
+                message,
+            }) => Self {
+                error_kind: "ContractError".to_string(),
+                message: message.clone(),
+                contract_code: Some(*code),
+                contract_error: name.clone(),
+            },
+            Some(debugger_err) => Self {
+                error_kind: debugger_err.kind().to_string(),
+                message: debugger_err.to_string(),
+                contract_code: None,
+                contract_error: None,
+            },
+            None => Self {
+                error_kind: "Other".to_string(),
+                message: err.to_string(),
+                contract_code: None,
+                contract_error: None,
+            },
         }
     }
 }
@@ -213,3 +315,70 @@ impl OutputWriter {
         Ok(())
     }
 }
+
+/// Render rows as RFC 4180 CSV, quoting fields that contain commas, quotes or newlines.
+pub fn to_csv(rows: &[Vec<String>]) -> String {
+    let escape = |field: &String| {
+        if field.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.clone()
+        }
+    };
+    rows.iter()
+        .map(|row| row.iter().map(escape).collect::<Vec<_>>().join(",") + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warnings_are_drained_into_versioned_output() {
+        WarningCollector::clear();
+        WarningCollector::record("custom_errors_unavailable", "spec could not be parsed");
+        WarningCollector::record("custom_errors_unavailable", "spec could not be parsed");
+
+        let output = VersionedOutput::success("run", serde_json::json!({}));
+        assert_eq!(output.warnings.len(), 1);
+        assert_eq!(output.warnings[0].code, "custom_errors_unavailable");
+        assert!(WarningCollector::take().is_empty());
+
+        let json = serde_json::to_value(&output).unwrap();
+        assert_eq!(json["warnings"][0]["message"], "spec could not be parsed");
+    }
+
+    #[test]
+    fn csv_quotes_only_fields_that_need_it() {
+        let rows = vec![
+            vec!["key".to_string(), "value".to_string()],
+            vec!["Price(\"XLM\")".to_string(), "1, 2".to_string()],
+        ];
+        assert_eq!(
+            to_csv(&rows),
+            "key,value\n\"Price(\"\"XLM\"\")\",\"1, 2\"\n"
+        );
+    }
+
+    #[test]
+    fn json_error_uses_variant_name_as_kind() {
+        let report: miette::Report =
+            crate::DebuggerError::InvalidFunction("transfer".to_string()).into();
+        let json = serde_json::to_value(JsonError::from_report(&report)).unwrap();
+        assert_eq!(json["error_kind"], "InvalidFunction");
+        assert_eq!(json["message"], "Invalid function name: transfer");
+        assert!(json.get("contract_code").is_none());
+
+        let other = JsonError::from_report(&miette::miette!("boom"));
+        assert_eq!(other.error_kind, "Other");
+    }
+
+    #[test]
+    fn warnings_field_omitted_when_empty() {
+        WarningCollector::clear();
+        let output = VersionedOutput::success("inspect", serde_json::json!({}));
+        let json = serde_json::to_value(&output).unwrap();
+        assert!(json.get("warnings").is_none());
+    }
+}
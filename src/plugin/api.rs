@@ -28,23 +28,6 @@ pub enum PluginError {
     VersionMismatch { required: String, found: String },
 
     /// Dependency error
-#[derive(Debug, Clone, thiserror::Error)]
-pub enum PluginError {
-    #[error("Plugin initialization failed: {0}")]
-    InitializationFailed(String),
-
-    #[error("Plugin execution failed: {0}")]
-    ExecutionFailed(String),
-
-    #[error("Plugin not found: {0}")]
-    NotFound(String),
-
-    #[error("Invalid plugin: {0}")]
-    Invalid(String),
-
-    #[error("Version mismatch: required {required}, found {found}")]
-    VersionMismatch { required: String, found: String },
-
     #[error("Dependency error: {0}")]
     DependencyError(String),
 
@@ -179,10 +162,6 @@ pub trait InspectorPlugin: Send + Sync {
     }
 }
 
-/// Symbol name for the plugin constructor function
-///
-/// Every plugin shared library must export a function with this name
-/// that returns a boxed instance of the plugin.
 /// Current version of the plugin API
 pub const PLUGIN_API_VERSION: u32 = 1;
 
@@ -192,6 +171,10 @@ pub const PLUGIN_VERSION_SYMBOL: &str = "plugin_api_version";
 /// Type of the plugin API version function
 pub type PluginVersionFn = unsafe fn() -> u32;
 
+/// Symbol name for the plugin constructor function
+///
+/// Every plugin shared library must export a function with this name
+/// that returns a boxed instance of the plugin.
 pub const PLUGIN_CONSTRUCTOR_SYMBOL: &str = "create_plugin";
 
 /// Type of the plugin constructor function
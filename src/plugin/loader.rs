@@ -435,30 +435,13 @@ impl Drop for LoadedPlugin {
     }
 }
 
-
-
-#[cfg(test)]
-impl LoadedPlugin {
-    pub(crate) fn from_parts_for_tests(
-        plugin: Box<dyn InspectorPlugin>,
-        path: PathBuf,
-        manifest: PluginManifest,
-        trust: PluginTrustAssessment,
-    ) -> Self {
-        Self {
-            plugin,
-            library: None,
-            path,
-            manifest,
-            trust,
-        }
 /// Checks if the plugin API version matches the host's expected version.
-pub fn check_api_version(plugin_version: u32) -> Result<(), crate::plugin::api::PluginError> {
-    use crate::plugin::api::{PluginError, PLUGIN_API_VERSION};
+pub fn check_api_version(plugin_version: u32) -> PluginResult<()> {
+    use super::api::PLUGIN_API_VERSION;
     if plugin_version != PLUGIN_API_VERSION {
         return Err(PluginError::VersionMismatch {
-            expected: PLUGIN_API_VERSION,
-            found: plugin_version,
+            required: PLUGIN_API_VERSION.to_string(),
+            found: plugin_version.to_string(),
         });
     }
     Ok(())
@@ -466,8 +449,8 @@ pub fn check_api_version(plugin_version: u32) -> Result<(), crate::plugin::api::
 
 #[cfg(test)]
 mod tests {
+    use super::super::api::PLUGIN_API_VERSION;
     use super::super::manifest::PluginSignature;
-mod version_tests {
     use super::*;
     use base64::engine::general_purpose::STANDARD as BASE64_STANDARD;
     use base64::Engine;
@@ -509,7 +492,6 @@ mod version_tests {
         });
         manifest
     }
-    use crate::plugin::api::{PluginError, PLUGIN_API_VERSION};
 
     #[test]
     fn test_default_plugin_dir() {
@@ -519,9 +501,13 @@ mod version_tests {
         let path = dir.unwrap();
         assert!(path.ends_with(".soroban-debug/plugins"));
     }
+
+    #[test]
     fn test_api_version_check() {
         let result = check_api_version(999);
         assert!(matches!(result, Err(PluginError::VersionMismatch { .. })));
+        assert!(check_api_version(PLUGIN_API_VERSION).is_ok());
+    }
 
     #[test]
     fn test_loader_creation() {
@@ -661,5 +647,5 @@ mod version_tests {
             assessment.signer.as_ref().map(|s| s.signer.as_str()),
             Some("trusted-signer")
         );
-        let result_ok = check_api_version(PLUGIN_API_VERSION);
-        assert!(result_ok.is_ok());
+    }
+}
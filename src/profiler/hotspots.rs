@@ -0,0 +1,188 @@
+//! Function hotspots for `run --profile`.
+//!
+//! The host meters an invocation as a whole, so the measured cpu-insn are
+//! split over the functions the entry point reaches through direct `call`s, in
+//! proportion to each function's instruction count from [`InstructionParser`].
+//! The split is a code-size share: it ignores loops, untaken branches and the
+//! cost of host functions. Functions are named from the WASM `name` section,
+//! then from exports, and otherwise by index as `func[N]`.
+
+use crate::runtime::instruction::InstructionParser;
+use crate::utils::wasm::CallGraph;
+use crate::{DebuggerError, Result};
+use std::collections::{BTreeMap, HashMap};
+use wasmparser::{Name, NameSectionReader, Parser, Payload};
+
+/// Code-size share of one function among those the entry point reaches.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct Hotspot {
+    pub function: String,
+    pub function_index: u32,
+    /// Instructions in the function body.
+    pub instructions: u64,
+    /// The invocation's cpu-insn weighted by this function's code share.
+    pub cpu_insns: u64,
+    /// Percentage of the reachable instructions that are in this function.
+    pub percent: f64,
+}
+
+/// Attribute `cpu_insns` spent in `entry` to the functions it reaches, largest
+/// code share first.
+pub fn attribute_hotspots(wasm_bytes: &[u8], entry: &str, cpu_insns: u64) -> Result<Vec<Hotspot>> {
+    let graph = CallGraph::parse(wasm_bytes)?;
+    let entry_index = graph.export_index(entry).ok_or_else(|| {
+        DebuggerError::InvalidFunction(format!("'{}' is not an exported function", entry))
+    })?;
+    let names = function_names(wasm_bytes);
+
+    let mut parser = InstructionParser::new();
+    let instructions = parser
+        .parse(wasm_bytes)
+        .map_err(DebuggerError::WasmLoadError)?;
+    let mut sizes: BTreeMap<u32, u64> = BTreeMap::new();
+    for instruction in instructions {
+        *sizes.entry(instruction.function_index).or_default() += 1;
+    }
+
+    let reachable = graph.reachable_from(entry_index);
+    let total: u64 = reachable.iter().filter_map(|i| sizes.get(i)).sum();
+    if total == 0 {
+        return Ok(Vec::new());
+    }
+    let mut hotspots: Vec<Hotspot> = reachable
+        .into_iter()
+        .filter_map(|index| {
+            let instructions = *sizes.get(&index)?;
+            let function = names
+                .get(&index)
+                .cloned()
+                .or_else(|| {
+                    graph
+                        .exports
+                        .iter()
+                        .find(|(_, export)| *export == index)
+                        .map(|(name, _)| name.clone())
+                })
+                .unwrap_or_else(|| format!("func[{}]", index));
+            Some(Hotspot {
+                function,
+                function_index: index,
+                instructions,
+                cpu_insns: (cpu_insns as u128 * instructions as u128 / total as u128) as u64,
+                percent: instructions as f64 * 100.0 / total as f64,
+            })
+        })
+        .collect();
+    hotspots.sort_by(|a, b| {
+        b.instructions
+            .cmp(&a.instructions)
+            .then(a.function_index.cmp(&b.function_index))
+    });
+    Ok(hotspots)
+}
+
+/// Function names from the WASM `name` section. Names are a debugging aid, so a
+/// missing or malformed section only loses them.
+fn function_names(wasm_bytes: &[u8]) -> HashMap<u32, String> {
+    let mut names = HashMap::new();
+    for payload in Parser::new(0).parse_all(wasm_bytes).flatten() {
+        if let Payload::CustomSection(reader) = payload {
+            if reader.name() != "name" {
+                continue;
+            }
+            let subsections = NameSectionReader::new(reader.data(), reader.data_offset());
+            for subsection in subsections.into_iter().flatten() {
+                if let Name::Function(map) = subsection {
+                    for naming in map.into_iter().flatten() {
+                        names.insert(naming.index, naming.name.to_string());
+                    }
+                }
+            }
+        }
+    }
+    names
+}
+
+/// Table lines for the `top` most expensive hotspots.
+pub fn format_hotspots(hotspots: &[Hotspot], top: usize) -> Vec<String> {
+    let shown = &hotspots[..hotspots.len().min(top)];
+    let width = shown
+        .iter()
+        .map(|h| h.function.len())
+        .max()
+        .unwrap_or(0)
+        .max("Function".len());
+    let mut lines = vec![format!(
+        "{:<width$} | {:>12} | {:>10}",
+        "Function",
+        "Instructions",
+        "Code share",
+        width = width
+    )];
+    for hotspot in shown {
+        lines.push(format!(
+            "{:<width$} | {:>12} | {:>9.2}%",
+            hotspot.function,
+            hotspot.instructions,
+            hotspot.percent,
+            width = width
+        ));
+    }
+    if hotspots.len() > shown.len() {
+        lines.push(format!("... {} more", hotspots.len() - shown.len()));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Imports `env.f`, exports `entry` (func 1) which calls func 2; func 3 is
+    /// never called.
+    const MODULE: &[u8] = &[
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+        0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type section
+        0x02, 0x09, 0x01, 0x03, 0x65, 0x6e, 0x76, 0x01, 0x66, 0x00, 0x00, // import env.f
+        0x03, 0x04, 0x03, 0x00, 0x00, 0x00, // function section
+        0x07, 0x09, 0x01, 0x05, 0x65, 0x6e, 0x74, 0x72, 0x79, 0x00, 0x01, // export entry
+        0x0a, 0x11, 0x03, // code section
+        0x04, 0x00, 0x10, 0x02, 0x0b, // func 1: call 2
+        0x06, 0x00, 0x01, 0x01, 0x01, 0x01, 0x0b, // func 2: 4 x nop
+        0x03, 0x00, 0x01, 0x0b, // func 3: nop
+    ];
+
+    /// `name` section naming func 2 `helper`.
+    const NAME_SECTION: &[u8] = &[
+        0x00, 0x10, 0x04, 0x6e, 0x61, 0x6d, 0x65, // custom section "name"
+        0x01, 0x09, 0x01, 0x02, 0x06, 0x68, 0x65, 0x6c, 0x70, 0x65, 0x72, // func 2 = helper
+    ];
+
+    #[test]
+    fn attributes_budget_to_reachable_functions_by_size() {
+        let mut wasm = MODULE.to_vec();
+        wasm.extend_from_slice(NAME_SECTION);
+        let hotspots = attribute_hotspots(&wasm, "entry", 700).unwrap();
+
+        let ranked: Vec<(&str, u64)> = hotspots
+            .iter()
+            .map(|h| (h.function.as_str(), h.cpu_insns))
+            .collect();
+        assert_eq!(ranked, vec![("helper", 500), ("entry", 200)]);
+    }
+
+    #[test]
+    fn falls_back_to_function_indices_without_name_section() {
+        let hotspots = attribute_hotspots(MODULE, "entry", 700).unwrap();
+        assert_eq!(hotspots[0].function, "func[2]");
+
+        let lines = format_hotspots(&hotspots, 1);
+        assert!(lines[1].starts_with("func[2]"), "{lines:?}");
+        assert_eq!(lines.last().unwrap(), "... 1 more");
+    }
+
+    #[test]
+    fn rejects_unknown_entry_point() {
+        assert!(attribute_hotspots(MODULE, "missing", 700).is_err());
+    }
+}
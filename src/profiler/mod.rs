@@ -1,6 +1,8 @@
 pub mod analyzer;
 pub mod flamegraph;
+pub mod hotspots;
 pub mod session;
+pub mod size;
 
 pub use analyzer::{GasOptimizer, OptimizationReport, OptimizationSuggestion};
 pub use flamegraph::FlameGraphGenerator;
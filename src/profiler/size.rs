@@ -0,0 +1,295 @@
+//! Static WASM size analysis for the `optimize` command.
+//!
+//! Reports section sizes, unused or undeclared exports and large constant data, and can
+//! strip custom sections the Soroban host does not need at runtime.
+
+use crate::utils::wasm::{
+    exports_missing_from_spec, get_module_info, parse_functions, WasmSection,
+};
+use crate::{DebuggerError, Result};
+use std::fmt::Write;
+use wasmparser::{Parser, Payload};
+
+/// Custom sections the host reads when instantiating a contract.
+const ESSENTIAL_CUSTOM_SECTIONS: &[&str] = &["contractspecv0", "contractenvmetav0"];
+
+/// Data segments at least this large are flagged as worth a second look.
+const LARGE_DATA_SEGMENT_BYTES: usize = 1024;
+
+/// Code sections above this size get a build-profile suggestion.
+const LARGE_CODE_SECTION_BYTES: usize = 64 * 1024;
+
+/// A constant data segment above [`LARGE_DATA_SEGMENT_BYTES`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataSegment {
+    pub index: usize,
+    pub size: usize,
+}
+
+/// Size breakdown and suggestions for one WASM binary.
+#[derive(Debug, Clone)]
+pub struct SizeAnalysis {
+    pub total_size: usize,
+    pub sections: Vec<WasmSection>,
+    pub unused_exports: Vec<String>,
+    /// Exports with no `contractspecv0` entry, i.e. not part of the contract interface.
+    pub extra_exports: Vec<String>,
+    pub large_data_segments: Vec<DataSegment>,
+    pub suggestions: Vec<String>,
+}
+
+impl SizeAnalysis {
+    /// Analyze `wasm_bytes`. Exports outside `entry_points` are reported as
+    /// unused; an empty entry set disables that check.
+    pub fn analyze(wasm_bytes: &[u8], entry_points: &[String]) -> Result<Self> {
+        let info = get_module_info(wasm_bytes)?;
+        let sections: Vec<WasmSection> = info
+            .sections
+            .into_iter()
+            .filter(|s| s.name != "Code (Entry)")
+            .collect();
+
+        let unused_exports = if entry_points.is_empty() {
+            Vec::new()
+        } else {
+            parse_functions(wasm_bytes)?
+                .into_iter()
+                // `_`-prefixed exports are SDK plumbing, not contract entry points.
+                .filter(|name| !name.starts_with('_') && !entry_points.contains(name))
+                .collect()
+        };
+
+        let mut large_data_segments = Vec::new();
+        for payload in Parser::new(0).parse_all(wasm_bytes) {
+            let payload = payload.map_err(|e| {
+                DebuggerError::WasmLoadError(format!("Failed to parse WASM: {}", e))
+            })?;
+            if let Payload::DataSection(reader) = payload {
+                for (index, data) in reader.into_iter().enumerate() {
+                    let data = data.map_err(|e| {
+                        DebuggerError::WasmLoadError(format!("Failed to parse WASM: {}", e))
+                    })?;
+                    if data.data.len() >= LARGE_DATA_SEGMENT_BYTES {
+                        large_data_segments.push(DataSegment {
+                            index,
+                            size: data.data.len(),
+                        });
+                    }
+                }
+            }
+        }
+
+        let mut analysis = SizeAnalysis {
+            total_size: wasm_bytes.len(),
+            sections,
+            unused_exports,
+            extra_exports: exports_missing_from_spec(wasm_bytes)?,
+            large_data_segments,
+            suggestions: Vec::new(),
+        };
+        analysis.suggestions = analysis.build_suggestions();
+        Ok(analysis)
+    }
+
+    fn build_suggestions(&self) -> Vec<String> {
+        let mut suggestions = Vec::new();
+
+        for section in &self.sections {
+            if let Some(name) = custom_section_name(&section.name) {
+                if !ESSENTIAL_CUSTOM_SECTIONS.contains(&name) {
+                    suggestions.push(format!(
+                        "{} is {}; strip with `soroban contract optimize` or `--strip-custom-sections`",
+                        name,
+                        format_size(section.size)
+                    ));
+                }
+            } else if section.name == "Code" && section.size > LARGE_CODE_SECTION_BYTES {
+                suggestions.push(format!(
+                    "Code section is {}; build with `opt-level = \"z\"`, `lto = true` and `codegen-units = 1`",
+                    format_size(section.size)
+                ));
+            }
+        }
+
+        for name in &self.extra_exports {
+            suggestions.push(format!(
+                "Export `{}` is not part of the contract interface; make it private unless it is meant to be callable",
+                name
+            ));
+        }
+
+        for name in &self.unused_exports {
+            suggestions.push(format!(
+                "Export `{}` is not in the entry set; remove it so the code behind it can be dropped",
+                name
+            ));
+        }
+
+        for segment in &self.large_data_segments {
+            suggestions.push(format!(
+                "Data segment #{} holds {} of constant data; move large constants into storage or shorten string literals",
+                segment.index,
+                format_size(segment.size)
+            ));
+        }
+
+        suggestions
+    }
+
+    /// Render the analysis as a Markdown section for the optimization report.
+    pub fn to_markdown(&self) -> String {
+        let mut output = String::new();
+
+        writeln!(output, "## WASM Size").unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "- **Total Size:** {}", format_size(self.total_size)).unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "| Section | Size |").unwrap();
+        writeln!(output, "|---------|------|").unwrap();
+        for section in &self.sections {
+            writeln!(
+                output,
+                "| {} | {} |",
+                section.name,
+                format_size(section.size)
+            )
+            .unwrap();
+        }
+        writeln!(output).unwrap();
+
+        writeln!(output, "### Size Suggestions").unwrap();
+        writeln!(output).unwrap();
+        if self.suggestions.is_empty() {
+            writeln!(output, "No size suggestions found.").unwrap();
+        } else {
+            for suggestion in &self.suggestions {
+                writeln!(output, "- {}", suggestion).unwrap();
+            }
+        }
+        writeln!(output).unwrap();
+
+        output
+    }
+}
+
+/// Remove custom sections the host does not need, returning the new module and
+/// the names of the sections that were dropped.
+pub fn strip_custom_sections(wasm_bytes: &[u8]) -> Result<(Vec<u8>, Vec<String>)> {
+    let truncated = || DebuggerError::WasmLoadError("Truncated WASM section".to_string());
+
+    if wasm_bytes.len() < 8 || &wasm_bytes[..4] != b"\0asm" {
+        return Err(DebuggerError::WasmLoadError("Not a WASM module".to_string()).into());
+    }
+
+    let mut stripped = wasm_bytes[..8].to_vec();
+    let mut removed = Vec::new();
+    let mut pos = 8;
+
+    while pos < wasm_bytes.len() {
+        let start = pos;
+        let id = wasm_bytes[pos];
+        let (size, header_len) = read_leb128(&wasm_bytes[pos + 1..]).ok_or_else(truncated)?;
+        let body_start = pos + 1 + header_len;
+        let end = body_start.checked_add(size).ok_or_else(truncated)?;
+        if end > wasm_bytes.len() {
+            return Err(truncated().into());
+        }
+        pos = end;
+
+        if id == 0 {
+            let body = &wasm_bytes[body_start..end];
+            let (name_len, name_header) = read_leb128(body).ok_or_else(truncated)?;
+            let name = body
+                .get(name_header..name_header + name_len)
+                .map(|n| String::from_utf8_lossy(n).into_owned())
+                .ok_or_else(truncated)?;
+            if !ESSENTIAL_CUSTOM_SECTIONS.contains(&name.as_str()) {
+                removed.push(name);
+                continue;
+            }
+        }
+
+        stripped.extend_from_slice(&wasm_bytes[start..end]);
+    }
+
+    Ok((stripped, removed))
+}
+
+/// Decode an unsigned LEB128 value, returning it with the number of bytes read.
+fn read_leb128(bytes: &[u8]) -> Option<(usize, usize)> {
+    let mut value = 0usize;
+    for (i, byte) in bytes.iter().enumerate().take(5) {
+        value |= ((byte & 0x7f) as usize) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+    }
+    None
+}
+
+fn custom_section_name(section_name: &str) -> Option<&str> {
+    section_name
+        .strip_prefix("Custom (")
+        .and_then(|rest| rest.strip_suffix(')'))
+}
+
+fn format_size(bytes: usize) -> String {
+    crate::inspector::budget::BudgetInspector::format_memory_bytes(bytes as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom_section(name: &str, payload: &[u8]) -> Vec<u8> {
+        let mut body = vec![name.len() as u8];
+        body.extend_from_slice(name.as_bytes());
+        body.extend_from_slice(payload);
+        let mut section = vec![0x00, body.len() as u8];
+        section.extend(body);
+        section
+    }
+
+    fn module_with_custom_sections() -> Vec<u8> {
+        let mut wasm = b"\0asm\x01\0\0\0".to_vec();
+        wasm.extend(custom_section("contractspecv0", &[1, 2, 3]));
+        wasm.extend(custom_section("contractmetav0", &[0; 100]));
+        wasm.extend(custom_section("producers", &[0; 20]));
+        wasm
+    }
+
+    #[test]
+    fn strip_removes_non_essential_custom_sections() {
+        let wasm = module_with_custom_sections();
+        let (stripped, removed) = strip_custom_sections(&wasm).unwrap();
+
+        assert!(stripped.len() < wasm.len());
+        assert_eq!(removed, vec!["contractmetav0", "producers"]);
+
+        let info = get_module_info(&stripped).expect("stripped module still parses");
+        let names: Vec<_> = info.sections.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["Custom (contractspecv0)"]);
+    }
+
+    #[test]
+    fn strip_rejects_truncated_module() {
+        let mut wasm = module_with_custom_sections();
+        wasm.truncate(wasm.len() - 5);
+        assert!(strip_custom_sections(&wasm).is_err());
+    }
+
+    #[test]
+    fn analysis_suggests_stripping_metadata() {
+        let analysis = SizeAnalysis::analyze(&module_with_custom_sections(), &[]).unwrap();
+
+        assert!(analysis.unused_exports.is_empty());
+        assert!(analysis
+            .suggestions
+            .iter()
+            .any(|s| s.starts_with("contractmetav0 is ") && s.contains("strip with")));
+        assert!(!analysis
+            .suggestions
+            .iter()
+            .any(|s| s.starts_with("contractspecv0")));
+    }
+}
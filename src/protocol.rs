@@ -1,3 +1,5 @@
+pub mod server;
+
 use crate::debugger::state::DebugState;
 use serde::{Deserialize, Serialize};
 
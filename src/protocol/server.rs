@@ -0,0 +1,619 @@
+//! Debug Adapter Protocol (DAP) transport.
+//!
+//! Speaks `Content-Length` framed JSON over stdio or a TCP socket so editors
+//! such as VS Code can attach. Incoming DAP requests are translated into
+//! [`DebugRequest`]s and answered from the engine's [`DebugState`].
+
+use super::{DebugRequest, DebugResponse};
+use crate::debugger::breakpoint::{evaluate_condition, BreakpointManager};
+use crate::debugger::engine::DebuggerEngine;
+use crate::debugger::state::DebugState;
+use crate::runtime::executor::ContractExecutor;
+use crate::{DebuggerError, Result};
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+/// DAP only models threads; a contract invocation always runs on one.
+const THREAD_ID: i64 = 1;
+
+/// Function and arguments supplied by the DAP `launch` request.
+struct LaunchTarget {
+    function: String,
+    args: Option<String>,
+}
+
+/// A DAP server driving a single [`DebuggerEngine`].
+pub struct DapServer {
+    default_contract: Option<PathBuf>,
+    engine: Option<DebuggerEngine>,
+    wasm_bytes: Option<Vec<u8>>,
+    target: Option<LaunchTarget>,
+    /// Execution that stopped at a function breakpoint and waits for `continue`.
+    paused_at: Option<LaunchTarget>,
+    /// Functions bound by `setBreakpoints`, keyed by source path.
+    source_breakpoints: HashMap<String, Vec<String>>,
+    function_breakpoints: Vec<String>,
+    seq: i64,
+}
+
+impl DapServer {
+    /// Create a server. `default_contract` is used when `launch` does not name one.
+    pub fn new(default_contract: Option<PathBuf>) -> Self {
+        Self {
+            default_contract,
+            engine: None,
+            wasm_bytes: None,
+            target: None,
+            paused_at: None,
+            source_breakpoints: HashMap::new(),
+            function_breakpoints: Vec::new(),
+            seq: 0,
+        }
+    }
+
+    /// Serve a single client over stdin/stdout.
+    pub fn run_stdio(mut self) -> Result<()> {
+        let stdin = std::io::stdin();
+        let stdout = std::io::stdout();
+        self.serve(stdin.lock(), stdout.lock())
+    }
+
+    /// Listen on `127.0.0.1:<port>` and serve the first client that connects.
+    pub fn run_tcp(mut self, port: u16) -> Result<()> {
+        let addr = format!("127.0.0.1:{}", port);
+        let listener = TcpListener::bind(&addr)
+            .map_err(|e| DebuggerError::NetworkError(format!("Failed to bind {}: {}", addr, e)))?;
+        info!("DAP server listening on {}", addr);
+
+        let (stream, peer) = listener
+            .accept()
+            .map_err(|e| DebuggerError::NetworkError(format!("Failed to accept client: {}", e)))?;
+        info!("DAP client connected from {}", peer);
+
+        let reader = BufReader::new(
+            stream
+                .try_clone()
+                .map_err(|e| DebuggerError::NetworkError(e.to_string()))?,
+        );
+        self.serve(reader, stream)
+    }
+
+    /// Process framed requests from `reader` until `disconnect` or end of input.
+    pub fn serve<R: BufRead, W: Write>(&mut self, mut reader: R, mut writer: W) -> Result<()> {
+        while let Some(message) = read_message(&mut reader)? {
+            if message.get("type").and_then(Value::as_str) != Some("request") {
+                continue;
+            }
+
+            let outgoing = self.handle_request(&message);
+            for out in &outgoing {
+                write_message(&mut writer, out)?;
+            }
+
+            if message.get("command").and_then(Value::as_str) == Some("disconnect") {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Handle one DAP request, returning the response followed by any events.
+    pub fn handle_request(&mut self, request: &Value) -> Vec<Value> {
+        let request_seq = request.get("seq").and_then(Value::as_i64).unwrap_or(0);
+        let command = request
+            .get("command")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        let arguments = request.get("arguments").cloned().unwrap_or(Value::Null);
+
+        let outcome = match command.as_str() {
+            "initialize" => Ok((capabilities(), Vec::new())),
+            "launch" => self.launch(&arguments),
+            "setBreakpoints" => self.set_breakpoints(&arguments),
+            "setFunctionBreakpoints" => self.set_function_breakpoints(&arguments),
+            "configurationDone" => self.configuration_done(),
+            "threads" => Ok((
+                json!({"threads": [{"id": THREAD_ID, "name": "main"}]}),
+                Vec::new(),
+            )),
+            "continue" => self.continue_execution(),
+            "next" => self.next(),
+            "stackTrace" => self.stack_trace(),
+            "evaluate" => self.evaluate(&arguments),
+            "disconnect" => Ok((Value::Null, Vec::new())),
+            other => Err(format!("Unsupported request '{}'", other)),
+        };
+
+        match outcome {
+            Ok((body, events)) => {
+                let mut out = vec![self.response(request_seq, &command, Ok(body))];
+                out.extend(
+                    events
+                        .into_iter()
+                        .map(|(event, body)| self.event(event, body)),
+                );
+                out
+            }
+            Err(message) => {
+                warn!("DAP request '{}' failed: {}", command, message);
+                vec![self.response(request_seq, &command, Err(message))]
+            }
+        }
+    }
+
+    /// Apply a [`DebugRequest`] to the loaded engine.
+    fn dispatch(&mut self, request: DebugRequest) -> DebugResponse {
+        let Some(engine) = self.engine.as_mut() else {
+            return DebugResponse::Error("No contract loaded; send 'launch' first".to_string());
+        };
+
+        match request {
+            DebugRequest::Handshake { .. } => DebugResponse::AuthSuccess,
+            DebugRequest::AddBreakpoint { function } => {
+                engine.breakpoints_mut().add(&function);
+                DebugResponse::Ok
+            }
+            DebugRequest::RemoveBreakpoint { function } => {
+                engine.breakpoints_mut().remove(&function);
+                DebugResponse::Ok
+            }
+            DebugRequest::GetState => snapshot_state(engine),
+            DebugRequest::Step => {
+                if self.paused_at.is_none() {
+                    return DebugResponse::Error("Execution is not paused".to_string());
+                }
+                match engine.step() {
+                    Ok(()) => snapshot_state(engine),
+                    Err(e) => DebugResponse::Error(e.to_string()),
+                }
+            }
+            DebugRequest::Continue => {
+                let Some(target) = self.paused_at.take() else {
+                    return DebugResponse::Error("Execution is not paused".to_string());
+                };
+                if let Err(e) = engine.continue_execution() {
+                    return DebugResponse::Error(e.to_string());
+                }
+                match engine.execute_without_breakpoints(&target.function, target.args.as_deref()) {
+                    Ok(result) => DebugResponse::ExecutionResult { result },
+                    Err(e) => DebugResponse::Error(e.to_string()),
+                }
+            }
+            DebugRequest::Execute { function, args } => {
+                let hit = engine
+                    .executor()
+                    .get_storage_snapshot()
+                    .and_then(|storage| {
+                        engine
+                            .breakpoints_mut()
+                            .on_hit(&function, &storage, args.as_deref())
+                    });
+                let hit = match hit {
+                    Ok(hit) => hit,
+                    Err(e) => return DebugResponse::Error(e.to_string()),
+                };
+                if hit.is_some_and(|hit| hit.should_pause) {
+                    engine.prepare_breakpoint_stop(&function, args.as_deref());
+                    self.paused_at = Some(LaunchTarget { function, args });
+                    return snapshot_state(engine);
+                }
+                match engine.execute_without_breakpoints(&function, args.as_deref()) {
+                    Ok(result) => DebugResponse::ExecutionResult { result },
+                    Err(e) => DebugResponse::Error(e.to_string()),
+                }
+            }
+        }
+    }
+
+    fn launch(&mut self, arguments: &Value) -> RequestOutcome {
+        let contract = arguments
+            .get("contract")
+            .or_else(|| arguments.get("program"))
+            .and_then(Value::as_str)
+            .map(PathBuf::from)
+            .or_else(|| self.default_contract.clone())
+            .ok_or_else(|| "launch requires a 'contract' path".to_string())?;
+        let function = arguments
+            .get("function")
+            .and_then(Value::as_str)
+            .ok_or_else(|| "launch requires a 'function' name".to_string())?
+            .to_string();
+        let args = match arguments.get("args") {
+            None | Some(Value::Null) => None,
+            Some(Value::String(s)) => Some(s.clone()),
+            Some(other) => Some(other.to_string()),
+        };
+
+        let wasm_bytes = std::fs::read(&contract)
+            .map_err(|e| format!("Failed to read contract {:?}: {}", contract, e))?;
+        let executor = ContractExecutor::new(wasm_bytes.clone()).map_err(|e| e.to_string())?;
+        let mut engine = DebuggerEngine::new(executor, Vec::new());
+        engine.try_load_source_map(&wasm_bytes);
+
+        self.engine = Some(engine);
+        self.wasm_bytes = Some(wasm_bytes);
+        self.target = Some(LaunchTarget { function, args });
+        self.paused_at = None;
+
+        // Configuration requests (breakpoints) may only follow once a contract is loaded.
+        Ok((Value::Null, vec![("initialized", Value::Null)]))
+    }
+
+    fn set_breakpoints(&mut self, arguments: &Value) -> RequestOutcome {
+        let source_path = arguments
+            .pointer("/source/path")
+            .and_then(Value::as_str)
+            .ok_or_else(|| "setBreakpoints requires 'source.path'".to_string())?
+            .to_string();
+        let lines: Vec<u32> = arguments
+            .get("breakpoints")
+            .and_then(Value::as_array)
+            .map(|bps| {
+                bps.iter()
+                    .filter_map(|bp| bp.get("line").and_then(Value::as_u64))
+                    .map(|line| line as u32)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let resolutions = {
+            let (Some(engine), Some(wasm_bytes)) = (self.engine.as_ref(), self.wasm_bytes.as_ref())
+            else {
+                return Err("No contract loaded; send 'launch' first".to_string());
+            };
+            match engine.source_map() {
+                Some(source_map) => {
+                    let exported: HashSet<String> = crate::utils::wasm::parse_functions(wasm_bytes)
+                        .unwrap_or_default()
+                        .into_iter()
+                        .collect();
+                    source_map.resolve_source_breakpoints(
+                        wasm_bytes,
+                        Path::new(&source_path),
+                        &lines,
+                        &exported,
+                    )
+                }
+                None => {
+                    return Ok((
+                        json!({
+                            "breakpoints": lines.iter().map(|line| json!({
+                                "verified": false,
+                                "line": line,
+                                "message": "Contract is missing DWARF source mappings; rebuild with debug info to bind source breakpoints.",
+                            })).collect::<Vec<_>>()
+                        }),
+                        Vec::new(),
+                    ));
+                }
+            }
+        };
+
+        for function in self
+            .source_breakpoints
+            .remove(&source_path)
+            .unwrap_or_default()
+        {
+            if !self.function_breakpoints.contains(&function) {
+                self.dispatch(DebugRequest::RemoveBreakpoint { function });
+            }
+        }
+
+        let mut bound = Vec::new();
+        let mut breakpoints = Vec::new();
+        for resolution in resolutions {
+            if let Some(function) = resolution.function.clone().filter(|_| resolution.verified) {
+                self.dispatch(DebugRequest::AddBreakpoint {
+                    function: function.clone(),
+                });
+                bound.push(function);
+            }
+            breakpoints.push(json!({
+                "verified": resolution.verified,
+                "line": resolution.line,
+                "message": resolution.message,
+            }));
+        }
+        self.source_breakpoints.insert(source_path, bound);
+
+        Ok((json!({ "breakpoints": breakpoints }), Vec::new()))
+    }
+
+    fn set_function_breakpoints(&mut self, arguments: &Value) -> RequestOutcome {
+        if self.engine.is_none() {
+            return Err("No contract loaded; send 'launch' first".to_string());
+        }
+
+        let names: Vec<String> = arguments
+            .get("breakpoints")
+            .and_then(Value::as_array)
+            .map(|bps| {
+                bps.iter()
+                    .filter_map(|bp| bp.get("name").and_then(Value::as_str))
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        for function in std::mem::take(&mut self.function_breakpoints) {
+            self.dispatch(DebugRequest::RemoveBreakpoint { function });
+        }
+        for function in &names {
+            self.dispatch(DebugRequest::AddBreakpoint {
+                function: function.clone(),
+            });
+        }
+        let breakpoints: Vec<Value> = names.iter().map(|_| json!({"verified": true})).collect();
+        self.function_breakpoints = names;
+
+        Ok((json!({ "breakpoints": breakpoints }), Vec::new()))
+    }
+
+    fn configuration_done(&mut self) -> RequestOutcome {
+        let Some(target) = self.target.as_ref() else {
+            return Err("No launch target; send 'launch' first".to_string());
+        };
+        let request = DebugRequest::Execute {
+            function: target.function.clone(),
+            args: target.args.clone(),
+        };
+        let events = execution_events(self.dispatch(request), "breakpoint")?;
+        Ok((Value::Null, events))
+    }
+
+    fn continue_execution(&mut self) -> RequestOutcome {
+        let events = execution_events(self.dispatch(DebugRequest::Continue), "breakpoint")?;
+        Ok((json!({"allThreadsContinued": true}), events))
+    }
+
+    fn next(&mut self) -> RequestOutcome {
+        let events = execution_events(self.dispatch(DebugRequest::Step), "step")?;
+        Ok((Value::Null, events))
+    }
+
+    fn stack_trace(&mut self) -> RequestOutcome {
+        let state = match self.dispatch(DebugRequest::GetState) {
+            DebugResponse::State(state) => state,
+            DebugResponse::Error(message) => return Err(message),
+            other => return Err(format!("Unexpected response: {:?}", other)),
+        };
+
+        let frames: Vec<Value> = state
+            .call_stack()
+            .get_stack()
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(id, frame)| {
+                json!({
+                    "id": id,
+                    "name": frame.function,
+                    "line": 0,
+                    "column": 0,
+                })
+            })
+            .collect();
+        let total = frames.len();
+        Ok((
+            json!({"stackFrames": frames, "totalFrames": total}),
+            Vec::new(),
+        ))
+    }
+
+    /// Evaluate an expression using the breakpoint condition syntax.
+    fn evaluate(&mut self, arguments: &Value) -> RequestOutcome {
+        let expression = arguments
+            .get("expression")
+            .and_then(Value::as_str)
+            .ok_or_else(|| "evaluate requires an 'expression'".to_string())?;
+        let condition =
+            BreakpointManager::parse_condition(expression).map_err(|e| e.to_string())?;
+
+        let state = match self.dispatch(DebugRequest::GetState) {
+            DebugResponse::State(state) => state,
+            DebugResponse::Error(message) => return Err(message),
+            other => return Err(format!("Unexpected response: {:?}", other)),
+        };
+        let storage = self
+            .engine
+            .as_ref()
+            .map(|engine| engine.executor().get_storage_snapshot())
+            .transpose()
+            .map_err(|e| e.to_string())?
+            .unwrap_or_default();
+
+        let result = evaluate_condition(&condition, &storage, state.current_args());
+        Ok((
+            json!({"result": result.to_string(), "variablesReference": 0}),
+            Vec::new(),
+        ))
+    }
+
+    fn next_seq(&mut self) -> i64 {
+        self.seq += 1;
+        self.seq
+    }
+
+    fn response(
+        &mut self,
+        request_seq: i64,
+        command: &str,
+        outcome: std::result::Result<Value, String>,
+    ) -> Value {
+        let seq = self.next_seq();
+        match outcome {
+            Ok(body) => {
+                let mut response = json!({
+                    "seq": seq,
+                    "type": "response",
+                    "request_seq": request_seq,
+                    "success": true,
+                    "command": command,
+                });
+                if !body.is_null() {
+                    response["body"] = body;
+                }
+                response
+            }
+            Err(message) => json!({
+                "seq": seq,
+                "type": "response",
+                "request_seq": request_seq,
+                "success": false,
+                "command": command,
+                "message": message,
+                "body": {"error": {"id": 1, "format": message}},
+            }),
+        }
+    }
+
+    fn event(&mut self, event: &str, body: Value) -> Value {
+        let mut message = json!({
+            "seq": self.next_seq(),
+            "type": "event",
+            "event": event,
+        });
+        if !body.is_null() {
+            message["body"] = body;
+        }
+        message
+    }
+}
+
+/// Response body plus the events to emit after it, or an error message.
+type RequestOutcome = std::result::Result<(Value, Vec<(&'static str, Value)>), String>;
+
+/// DAP capabilities advertised in the `initialize` response.
+pub fn capabilities() -> Value {
+    json!({
+        "supportsConfigurationDoneRequest": true,
+        "supportsFunctionBreakpoints": true,
+        "supportsConditionalBreakpoints": false,
+        "supportsEvaluateForHovers": false,
+        "supportsStepBack": false,
+        "supportsTerminateRequest": false,
+    })
+}
+
+fn snapshot_state(engine: &DebuggerEngine) -> DebugResponse {
+    match engine.state().lock() {
+        Ok(state) => DebugResponse::State(DebugState::clone(&state)),
+        Err(_) => DebugResponse::Error("Debug state is unavailable".to_string()),
+    }
+}
+
+/// Translate the outcome of an execution-related request into DAP events.
+fn execution_events(
+    response: DebugResponse,
+    stop_reason: &str,
+) -> std::result::Result<Vec<(&'static str, Value)>, String> {
+    match response {
+        DebugResponse::State(_) => Ok(vec![(
+            "stopped",
+            json!({"reason": stop_reason, "threadId": THREAD_ID}),
+        )]),
+        DebugResponse::ExecutionResult { result } => Ok(vec![
+            (
+                "output",
+                json!({"category": "stdout", "output": format!("Result: {}\n", result)}),
+            ),
+            ("terminated", Value::Null),
+        ]),
+        DebugResponse::Error(message) => Err(message),
+        other => Err(format!("Unexpected response: {:?}", other)),
+    }
+}
+
+/// Read one `Content-Length` framed message. Returns `None` at end of input.
+pub fn read_message<R: BufRead>(reader: &mut R) -> Result<Option<Value>> {
+    let mut content_length = None;
+    loop {
+        let mut line = String::new();
+        let read = reader
+            .read_line(&mut line)
+            .map_err(|e| DebuggerError::NetworkError(format!("Failed to read header: {}", e)))?;
+        if read == 0 {
+            return Ok(None);
+        }
+
+        let line = line.trim_end();
+        if line.is_empty() {
+            if content_length.is_some() {
+                break;
+            }
+            continue;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("Content-Length") {
+                content_length = Some(value.trim().parse::<usize>().map_err(|e| {
+                    DebuggerError::NetworkError(format!("Invalid Content-Length: {}", e))
+                })?);
+            }
+        }
+    }
+
+    let mut body = vec![0u8; content_length.unwrap_or_default()];
+    reader
+        .read_exact(&mut body)
+        .map_err(|e| DebuggerError::NetworkError(format!("Failed to read message body: {}", e)))?;
+    let message = serde_json::from_slice(&body)
+        .map_err(|e| DebuggerError::NetworkError(format!("Invalid DAP message: {}", e)))?;
+    Ok(Some(message))
+}
+
+/// Write one message with a `Content-Length` header.
+pub fn write_message<W: Write>(writer: &mut W, message: &Value) -> Result<()> {
+    let body = message.to_string();
+    write!(writer, "Content-Length: {}\r\n\r\n{}", body.len(), body)
+        .and_then(|_| writer.flush())
+        .map_err(|e| DebuggerError::NetworkError(format!("Failed to write message: {}", e)).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn frame(message: Value) -> Vec<u8> {
+        let mut out = Vec::new();
+        write_message(&mut out, &message).unwrap();
+        out
+    }
+
+    #[test]
+    fn framing_round_trips() {
+        let message = json!({"seq": 1, "type": "request", "command": "threads"});
+        let mut reader = Cursor::new(frame(message.clone()));
+        assert_eq!(read_message(&mut reader).unwrap(), Some(message));
+        assert_eq!(read_message(&mut reader).unwrap(), None);
+    }
+
+    #[test]
+    fn unknown_request_returns_error_response() {
+        let mut server = DapServer::new(None);
+        let out =
+            server.handle_request(&json!({"seq": 4, "type": "request", "command": "restartFrame"}));
+        assert_eq!(out.len(), 1);
+        assert_eq!(out[0]["success"], false);
+        assert_eq!(out[0]["request_seq"], 4);
+        assert!(out[0]["message"]
+            .as_str()
+            .unwrap()
+            .contains("Unsupported request"));
+    }
+
+    #[test]
+    fn execution_requests_require_launch() {
+        let mut server = DapServer::new(None);
+        for command in ["continue", "next", "stackTrace", "configurationDone"] {
+            let out =
+                server.handle_request(&json!({"seq": 1, "type": "request", "command": command}));
+            assert_eq!(out[0]["success"], false, "{} should fail", command);
+        }
+    }
+}
@@ -33,6 +33,28 @@ pub enum ReplCommand {
         function: String,
     },
     Functions,
+    /// Evaluate a breakpoint condition against current state: eval-condition <condition>
+    EvalCondition {
+        condition: String,
+    },
+    /// Undo the last call's storage changes: back
+    Back,
+    /// Re-apply storage changes undone by `back`: forward
+    Forward,
+    /// Show per-call memory use and flag steady growth: memtrend [window]
+    MemTrend {
+        window: usize,
+    },
+    /// Decode one storage value at the current state: print storage[<key>]
+    Print {
+        key: String,
+    },
+    /// Call a function and bind its result: let <name> = <function> [args...]
+    Let {
+        name: String,
+        function: String,
+        args: Vec<String>,
+    },
 }
 
 impl ReplCommand {
@@ -50,6 +72,12 @@ impl ReplCommand {
             "list-breaks",
             "clear-break",
             "functions",
+            "eval-condition",
+            "back",
+            "forward",
+            "memtrend",
+            "print",
+            "let",
         ]
     }
 
@@ -94,9 +122,70 @@ impl ReplCommand {
                 let function = parts[1].to_string();
                 Ok(ReplCommand::ClearBreak { function })
             }
+            "eval-condition" => {
+                if parts.len() < 2 {
+                    return Err(miette::miette!("eval-condition requires a condition"));
+                }
+                let condition = parts[1..].join(" ");
+                let condition = strip_matching_quotes(&condition).to_string();
+                Ok(ReplCommand::EvalCondition { condition })
+            }
             "storage" => Ok(ReplCommand::Storage),
             "history" => Ok(ReplCommand::History),
             "functions" => Ok(ReplCommand::Functions),
+            "back" => Ok(ReplCommand::Back),
+            "forward" => Ok(ReplCommand::Forward),
+            "memtrend" => {
+                let window = match parts.get(1) {
+                    Some(raw) => {
+                        raw.parse::<usize>()
+                            .ok()
+                            .filter(|w| *w >= 2)
+                            .ok_or_else(|| {
+                                miette::miette!("memtrend window must be a number of at least 2")
+                            })?
+                    }
+                    None => DEFAULT_MEMTREND_WINDOW,
+                };
+                Ok(ReplCommand::MemTrend { window })
+            }
+            "print" => {
+                let expr = trimmed["print".len()..].trim();
+                let key = expr
+                    .strip_prefix("storage[")
+                    .and_then(|rest| rest.strip_suffix(']'))
+                    .map(str::trim)
+                    .filter(|key| !key.is_empty())
+                    .ok_or_else(|| miette::miette!("print expects storage[<key>]"))?;
+                Ok(ReplCommand::Print {
+                    key: key.to_string(),
+                })
+            }
+            "let" => {
+                let usage = || miette::miette!("let expects: let <name> = <function> [args...]");
+                if parts.len() < 4 || parts[2] != "=" {
+                    return Err(usage());
+                }
+                let name = parts[1];
+                if !is_identifier(name) {
+                    return Err(miette::miette!(
+                        "Invalid variable name '{}': use letters, digits and '_'",
+                        name
+                    ));
+                }
+                // `let x = call f ...` reads the same as `let x = f ...`.
+                let rest = if parts[3] == "call" {
+                    &parts[4..]
+                } else {
+                    &parts[3..]
+                };
+                let (function, args) = rest.split_first().ok_or_else(usage)?;
+                Ok(ReplCommand::Let {
+                    name: name.to_string(),
+                    function: function.to_string(),
+                    args: args.iter().map(|s| s.to_string()).collect(),
+                })
+            }
             "clear" => Ok(ReplCommand::Clear),
             "help" => Ok(ReplCommand::Help),
             "exit" | "quit" => Ok(ReplCommand::Exit),
@@ -108,6 +197,27 @@ impl ReplCommand {
     }
 }
 
+/// Calls `memtrend` looks back over when no window is given.
+pub const DEFAULT_MEMTREND_WINDOW: usize = 5;
+
+/// Whether `name` can be bound with `let` and referenced as `$name`
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Remove one pair of surrounding single or double quotes, if present
+fn strip_matching_quotes(s: &str) -> &str {
+    let s = s.trim();
+    for quote in ['\'', '"'] {
+        if s.len() >= 2 && s.starts_with(quote) && s.ends_with(quote) {
+            return &s[1..s.len() - 1];
+        }
+    }
+    s
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,6 +267,85 @@ mod tests {
         assert!(matches!(cmd, ReplCommand::Functions));
     }
 
+    #[test]
+    fn test_parse_eval_condition_strips_quotes() {
+        let cmd = ReplCommand::parse("eval-condition 'storage[counter] > 5'").unwrap();
+        match cmd {
+            ReplCommand::EvalCondition { condition } => {
+                assert_eq!(condition, "storage[counter] > 5");
+            }
+            _ => panic!("Expected EvalCondition command"),
+        }
+        assert!(ReplCommand::parse("eval-condition").is_err());
+    }
+
+    #[test]
+    fn test_parse_back_and_forward_commands() {
+        assert!(matches!(
+            ReplCommand::parse("back").unwrap(),
+            ReplCommand::Back
+        ));
+        assert!(matches!(
+            ReplCommand::parse("forward").unwrap(),
+            ReplCommand::Forward
+        ));
+    }
+
+    #[test]
+    fn test_parse_memtrend_command() {
+        assert!(matches!(
+            ReplCommand::parse("memtrend").unwrap(),
+            ReplCommand::MemTrend {
+                window: DEFAULT_MEMTREND_WINDOW
+            }
+        ));
+        assert!(matches!(
+            ReplCommand::parse("memtrend 8").unwrap(),
+            ReplCommand::MemTrend { window: 8 }
+        ));
+        assert!(ReplCommand::parse("memtrend 1").is_err());
+        assert!(ReplCommand::parse("memtrend many").is_err());
+    }
+
+    #[test]
+    fn test_parse_print_storage_command() {
+        match ReplCommand::parse(r#"print storage[Price("XLM")]"#).unwrap() {
+            ReplCommand::Print { key } => assert_eq!(key, r#"Price("XLM")"#),
+            _ => panic!("Expected Print command"),
+        }
+        match ReplCommand::parse(r#"print storage[{"type": "symbol", "value": "c"}]"#).unwrap() {
+            ReplCommand::Print { key } => assert_eq!(key, r#"{"type": "symbol", "value": "c"}"#),
+            _ => panic!("Expected Print command"),
+        }
+        assert!(ReplCommand::parse("print").is_err());
+        assert!(ReplCommand::parse("print counter").is_err());
+        assert!(ReplCommand::parse("print storage[]").is_err());
+    }
+
+    #[test]
+    fn test_parse_let_command() {
+        match ReplCommand::parse(r#"let p = get_price "XLM""#).unwrap() {
+            ReplCommand::Let {
+                name,
+                function,
+                args,
+            } => {
+                assert_eq!(name, "p");
+                assert_eq!(function, "get_price");
+                assert_eq!(args, vec![r#""XLM""#]);
+            }
+            _ => panic!("Expected Let command"),
+        }
+        assert!(matches!(
+            ReplCommand::parse("let n = call get").unwrap(),
+            ReplCommand::Let { function, .. } if function == "get"
+        ));
+        assert!(ReplCommand::parse("let n get").is_err());
+        assert!(ReplCommand::parse("let n =").is_err());
+        assert!(ReplCommand::parse("let n = call").is_err());
+        assert!(ReplCommand::parse("let 1n = get").is_err());
+    }
+
     #[test]
     fn test_unknown_command_fails() {
         let result = ReplCommand::parse("unknown");
@@ -3,12 +3,18 @@
 /// Handles execution of function calls and storage inspection
 /// against the loaded contract.
 use super::ReplConfig;
-use crate::inspector::StorageInspector;
+use crate::debugger::breakpoint::{evaluate_condition, BreakpointManager};
+use crate::inspector::decoded_storage::StorageDecoder;
 use crate::runtime::executor::ContractExecutor;
-use crate::utils::wasm::{parse_function_signatures, ContractFunctionSignature};
-use crate::Result;
+use crate::runtime::recording::RecordedEntry;
+use crate::utils::scval::{display_decoded, scval_to_json};
+use crate::utils::wasm::{parse_function_signatures, ContractFunctionSignature, FunctionParam};
+use crate::utils::ArgumentParser;
+use crate::{DebuggerError, Result};
 use serde_json::json;
 use serde_json::Value;
+use soroban_env_host::xdr::ScVal;
+use soroban_env_host::TryFromVal;
 use std::collections::HashMap;
 use std::fs;
 
@@ -17,6 +23,9 @@ pub struct ReplExecutor {
     engine: crate::debugger::engine::DebuggerEngine,
     signatures: HashMap<String, ContractFunctionSignature>,
     address_aliases: HashMap<String, String>,
+    decoder: StorageDecoder,
+    /// Results bound with `let`, substituted for `$name` arguments.
+    bindings: HashMap<String, ScVal>,
 }
 
 impl ReplExecutor {
@@ -32,9 +41,16 @@ impl ReplExecutor {
             .into_iter()
             .map(|sig| (sig.name.clone(), sig))
             .collect();
+        let decoder = StorageDecoder::from_wasm(&wasm_bytes);
         let executor = ContractExecutor::new(wasm_bytes)?;
         let mut engine = crate::debugger::engine::DebuggerEngine::new(executor, Vec::new());
         engine.executor_mut().enable_mock_all_auths();
+        if let Some(seed) = config.account_seed {
+            engine.executor_mut().set_account_seed(seed);
+        }
+        if let Some(log_path) = &config.log_json {
+            engine.executor_mut().set_invocation_log(log_path)?;
+        }
 
         if let Some(snapshot_path) = &config.network_snapshot {
             let loader =
@@ -56,11 +72,18 @@ impl ReplExecutor {
             engine,
             signatures,
             address_aliases: HashMap::new(),
+            decoder,
+            bindings: HashMap::new(),
         })
     }
 
-    /// Call a contract function
-    pub async fn call_function(&mut self, function: &str, args: Vec<String>) -> Result<()> {
+    /// Call a contract function, returning its result unless execution paused
+    /// at a breakpoint first.
+    pub async fn call_function(
+        &mut self,
+        function: &str,
+        args: Vec<String>,
+    ) -> Result<Option<ScVal>> {
         let args_json = self.args_to_json_array_for(function, &args)?;
         let args_ref = if args_json == "[]" {
             None
@@ -69,34 +92,63 @@ impl ReplExecutor {
         };
 
         // Check if we should break before starting
-        if self.engine.breakpoints().should_break(function) {
+        if self.engine.breakpoints_mut().should_break(function) {
             self.engine.prepare_breakpoint_stop(function, args_ref);
             crate::logging::log_display(
                 format!("Execution paused at function: {}", function),
                 crate::logging::LogLevel::Warn,
             );
-            return Ok(());
+            return Ok(None);
         }
 
-        let storage_before = self.engine.executor().get_storage_snapshot()?;
         let result = self.engine.execute(function, args_ref)?;
-        let storage_after = self.engine.executor().get_storage_snapshot()?;
 
         crate::logging::log_display(
             format!("Result: {}", result),
             crate::logging::LogLevel::Info,
         );
 
-        let diff = StorageInspector::compute_diff(&storage_before, &storage_after, &[]);
-        if diff.is_empty() {
-            crate::logging::log_display("Storage: (no changes)", crate::logging::LogLevel::Info);
-        } else {
-            StorageInspector::display_diff(&diff);
+        // Live feed: events and storage changes from this step, in order.
+        if let Some(step) = self.engine.executor().step_feed().last() {
+            for line in step.format_lines() {
+                crate::logging::log_display(line, crate::logging::LogLevel::Info);
+            }
         }
 
+        Ok(self
+            .engine
+            .executor()
+            .last_execution()
+            .and_then(|record| record.result.as_ref().ok().cloned()))
+    }
+
+    /// Call `function` and bind its result to `name` for later `$name` arguments.
+    pub async fn bind_call(&mut self, name: &str, function: &str, args: Vec<String>) -> Result<()> {
+        let Some(value) = self.call_function(function, args).await? else {
+            crate::logging::log_display(
+                format!("Nothing bound to ${}: the call did not complete", name),
+                crate::logging::LogLevel::Warn,
+            );
+            return Ok(());
+        };
+
+        crate::logging::log_display(
+            format!(
+                "{} = {}",
+                name,
+                display_decoded(&value, &scval_to_json(&value).into())
+            ),
+            crate::logging::LogLevel::Info,
+        );
+        self.bindings.insert(name.to_string(), value);
         Ok(())
     }
 
+    /// Events and storage changes of every call made in this session.
+    pub fn step_feed(&self) -> &[crate::runtime::executor::StepRecord] {
+        self.engine.executor().step_feed()
+    }
+
     /// Return known exported function names for REPL completion.
     pub fn function_names(&self) -> Vec<String> {
         let mut names: Vec<String> = self.signatures.keys().cloned().collect();
@@ -109,7 +161,10 @@ impl ReplExecutor {
             self.typed_repl_args(&sig, args)?
         } else {
             args.iter()
-                .map(|arg| parse_repl_arg(arg))
+                .map(|arg| match arg.strip_prefix('$') {
+                    Some(name) => self.binding_arg(name, None),
+                    None => parse_repl_arg(arg),
+                })
                 .collect::<std::result::Result<Vec<_>, _>>()?
         };
 
@@ -125,6 +180,10 @@ impl ReplExecutor {
         let mut values = Vec::with_capacity(args.len());
 
         for (idx, raw) in args.iter().enumerate() {
+            if let Some(name) = raw.strip_prefix('$') {
+                values.push(self.binding_arg(name, signature.params.get(idx))?);
+                continue;
+            }
             let typed = signature.params.get(idx).map(|p| p.type_name.as_str());
             let value = match typed {
                 Some("Address") => self.parse_address_arg(raw)?,
@@ -138,6 +197,39 @@ impl ReplExecutor {
         Ok(values)
     }
 
+    /// The argument annotation for `$name`, checked against `param` when the
+    /// function signature is known.
+    fn binding_arg(&self, name: &str, param: Option<&FunctionParam>) -> Result<Value> {
+        let value = self.bindings.get(name).ok_or_else(|| {
+            DebuggerError::InvalidArguments(format!(
+                "Unknown variable ${}; bind one with `let {} = <function> [args...]`",
+                name, name
+            ))
+        })?;
+
+        if let Some(param) = param {
+            if !binding_fits(value, &param.type_name) {
+                return Err(DebuggerError::InvalidArguments(format!(
+                    "${} holds {} but parameter '{}' expects {}",
+                    name,
+                    scval_type_name(value),
+                    param.name,
+                    param.type_name
+                ))
+                .into());
+            }
+        }
+
+        scval_to_arg(value).ok_or_else(|| {
+            DebuggerError::InvalidArguments(format!(
+                "${} holds {}, which cannot be passed back as an argument",
+                name,
+                scval_type_name(value)
+            ))
+            .into()
+        })
+    }
+
     fn parse_address_arg(&mut self, raw: &str) -> Result<Value> {
         // Allow explicit JSON/typed annotations to pass through unchanged.
         if let Ok(v) = serde_json::from_str::<Value>(raw) {
@@ -148,7 +240,7 @@ impl ReplExecutor {
             raw.to_string()
         } else {
             if !self.address_aliases.contains_key(raw) {
-                let generated = self.engine.executor_mut().generate_repl_account_strkey()?;
+                let generated = self.engine.executor().generate_repl_account_strkey(raw)?;
                 crate::logging::log_display(
                     format!("Address alias '{}' -> {}", raw, generated),
                     crate::logging::LogLevel::Info,
@@ -193,6 +285,93 @@ impl ReplExecutor {
 
         Ok(())
     }
+
+    /// Print the value stored under `key` at the current state, decoded with
+    /// the contract spec.
+    ///
+    /// `key` is either the decoded form, such as `Price("XLM")`,
+    /// `DataKey::Admin` or `c`, or a typed annotation like
+    /// `{"type":"symbol","value":"c"}`. Keys with no value print `<not set>`.
+    pub fn print_storage(&self, key: &str) -> Result<()> {
+        let typed_key = match serde_json::from_str::<Value>(key) {
+            Ok(Value::Object(_)) => Some(self.typed_storage_key(key)?),
+            _ => None,
+        };
+        let entries = self.engine.executor().contract_data_entries()?;
+        let value = find_storage_entry(&self.decoder, &entries, key, typed_key.as_ref())
+            .map(|entry| self.decoder.decode_value(&entry.value).rendered)
+            .unwrap_or_else(|| "<not set>".to_string());
+
+        crate::logging::log_display(
+            format!("storage[{}] = {}", key, value),
+            crate::logging::LogLevel::Info,
+        );
+        Ok(())
+    }
+
+    /// Convert a typed annotation into the storage key it denotes.
+    fn typed_storage_key(&self, key: &str) -> Result<ScVal> {
+        let executor = self.engine.executor();
+        let invalid = |reason: String| -> miette::Report {
+            DebuggerError::InvalidArguments(format!("Invalid storage key {}: {}", key, reason))
+                .into()
+        };
+        let parser = ArgumentParser::new(executor.env().clone());
+        let vals = parser
+            .parse_args_string(&format!("[{}]", key))
+            .map_err(|e| invalid(e.to_string()))?;
+        let val = vals
+            .first()
+            .ok_or_else(|| invalid("no value".to_string()))?;
+        ScVal::try_from_val(executor.host(), val).map_err(|e| invalid(format!("{:?}", e)))
+    }
+
+    /// Print memory used by each call and warn if it grew over the last `window` calls
+    pub fn display_memory_trend(&self, window: usize) {
+        let series = self.engine.executor().memory_series();
+        if series.samples().is_empty() {
+            crate::logging::log_display("No calls recorded yet", crate::logging::LogLevel::Warn);
+            return;
+        }
+
+        crate::logging::log_display("=== Memory per Call ===", crate::logging::LogLevel::Info);
+        for (idx, bytes) in series.samples().iter().enumerate() {
+            crate::logging::log_display(
+                format!("  #{}: {} bytes", idx + 1, bytes),
+                crate::logging::LogLevel::Info,
+            );
+        }
+
+        match series.detect_growth(window) {
+            Some(growth) => crate::logging::log_display(
+                format!("Possible leak: {}", growth),
+                crate::logging::LogLevel::Warn,
+            ),
+            None if series.samples().len() < window => crate::logging::log_display(
+                format!(
+                    "Need {} calls to check for growth, have {}",
+                    window,
+                    series.samples().len()
+                ),
+                crate::logging::LogLevel::Info,
+            ),
+            None => crate::logging::log_display(
+                format!("No steady growth over the last {} calls", window),
+                crate::logging::LogLevel::Info,
+            ),
+        }
+    }
+
+    /// Restore storage to before the most recent call
+    pub fn step_back(&mut self) -> Result<()> {
+        self.engine.executor_mut().step_back()
+    }
+
+    /// Re-apply the storage changes undone by `step_back`
+    pub fn step_forward(&mut self) -> Result<()> {
+        self.engine.executor_mut().step_forward()
+    }
+
     pub fn add_breakpoint(&mut self, function: &str, condition: Option<&str>) -> Result<()> {
         if let Some(condition) = condition {
             self.engine.breakpoints_mut().set(
@@ -207,6 +386,41 @@ impl ReplExecutor {
         Ok(())
     }
 
+    /// Parse a breakpoint condition and evaluate it against the current storage
+    /// and the arguments of the most recent call.
+    pub fn eval_condition(&self, condition: &str) -> Result<bool> {
+        let storage = self.engine.executor().get_storage_snapshot()?;
+        let args = self.last_call_args_json();
+        evaluate_condition_str(condition, &storage, args.as_deref())
+    }
+
+    /// Arguments of the last call as a JSON object keyed by parameter name.
+    ///
+    /// REPL calls store positional arguments, so they are matched against the
+    /// function signature to make `amount > 5` style conditions usable.
+    fn last_call_args_json(&self) -> Option<String> {
+        let state = self.engine.state();
+        let state = state.lock().ok()?;
+        let function = state.current_function()?;
+        let raw = state.current_args()?;
+
+        let Ok(Value::Array(values)) = serde_json::from_str::<Value>(raw) else {
+            return Some(raw.to_string());
+        };
+        let Some(sig) = self.signatures.get(function) else {
+            return Some(raw.to_string());
+        };
+
+        // Ordered map, so the arguments are listed in parameter order.
+        let named: indexmap::IndexMap<String, Value> = sig
+            .params
+            .iter()
+            .zip(values)
+            .map(|(param, value)| (param.name.clone(), unwrap_typed_value(value)))
+            .collect();
+        serde_json::to_string(&named).ok()
+    }
+
     pub fn list_breakpoints(&self) -> Vec<crate::debugger::breakpoint::Breakpoint> {
         self.engine
             .breakpoints()
@@ -248,6 +462,153 @@ impl ReplExecutor {
     }
 }
 
+/// The entry `key` refers to: matched by value for a typed annotation, and
+/// otherwise by decoded key, ignoring an enum prefix such as `DataKey::`.
+fn find_storage_entry<'a>(
+    decoder: &StorageDecoder,
+    entries: &'a [RecordedEntry],
+    key: &str,
+    typed_key: Option<&ScVal>,
+) -> Option<&'a RecordedEntry> {
+    if let Some(typed_key) = typed_key {
+        return entries.iter().find(|entry| &entry.key == typed_key);
+    }
+    let head = key.split('(').next().unwrap_or(key);
+    let wanted = match head.rfind("::") {
+        Some(idx) => &key[idx + 2..],
+        None => key,
+    };
+    entries
+        .iter()
+        .find(|entry| decoder.decode_key(&entry.key) == wanted)
+}
+
+fn evaluate_condition_str(
+    condition: &str,
+    storage: &HashMap<String, String>,
+    args: Option<&str>,
+) -> Result<bool> {
+    let parsed = BreakpointManager::parse_condition(condition)?;
+    Ok(evaluate_condition(&parsed, storage, args))
+}
+
+/// Strip a `{"type": ..., "value": ...}` annotation down to its value
+fn unwrap_typed_value(value: Value) -> Value {
+    match value {
+        Value::Object(mut obj) if obj.contains_key("type") && obj.contains_key("value") => {
+            obj.remove("value").unwrap_or(Value::Null)
+        }
+        other => other,
+    }
+}
+
+/// Spec type name of a bound value, as used in mismatch errors.
+fn scval_type_name(val: &ScVal) -> &'static str {
+    match val {
+        ScVal::Bool(_) => "Bool",
+        ScVal::Void => "Void",
+        ScVal::U32(_) => "U32",
+        ScVal::I32(_) => "I32",
+        ScVal::U64(_) => "U64",
+        ScVal::I64(_) => "I64",
+        ScVal::Timepoint(_) => "Timepoint",
+        ScVal::Duration(_) => "Duration",
+        ScVal::U128(_) => "U128",
+        ScVal::I128(_) => "I128",
+        ScVal::U256(_) => "U256",
+        ScVal::I256(_) => "I256",
+        ScVal::Bytes(_) => "Bytes",
+        ScVal::String(_) => "String",
+        ScVal::Symbol(_) => "Symbol",
+        ScVal::Vec(_) => "Vec",
+        ScVal::Map(_) => "Map",
+        ScVal::Address(_) => "Address",
+        _ => "Val",
+    }
+}
+
+/// Whether a bound value can be passed where the spec expects `type_name`.
+///
+/// User-defined types are not checked here; the host rejects a bad shape.
+fn binding_fits(val: &ScVal, type_name: &str) -> bool {
+    const BUILTIN: &[&str] = &[
+        "Bool",
+        "Void",
+        "U32",
+        "I32",
+        "U64",
+        "I64",
+        "Timepoint",
+        "Duration",
+        "U128",
+        "I128",
+        "U256",
+        "I256",
+        "Bytes",
+        "String",
+        "Symbol",
+        "Address",
+    ];
+    let actual = scval_type_name(val);
+    if let Some(inner) = type_name
+        .strip_prefix("Option<")
+        .and_then(|rest| rest.strip_suffix('>'))
+    {
+        return matches!(val, ScVal::Void) || binding_fits(val, inner);
+    }
+    match type_name.split('<').next().unwrap_or(type_name) {
+        "Val" => true,
+        "Vec" => actual == "Vec",
+        "Map" => actual == "Map",
+        "BytesN" => actual == "Bytes",
+        name if BUILTIN.contains(&name) => name == actual,
+        _ => true,
+    }
+}
+
+/// Convert a bound value into the typed annotation the argument parser reads.
+fn scval_to_arg(val: &ScVal) -> Option<Value> {
+    let typed = |type_name: &str, value: Value| json!({ "type": type_name, "value": value });
+    Some(match val {
+        ScVal::Bool(b) => json!(b),
+        ScVal::Void => Value::Null,
+        ScVal::U32(n) => typed("u32", json!(n)),
+        ScVal::I32(n) => typed("i32", json!(n)),
+        ScVal::U64(n) => typed("u64", json!(n)),
+        ScVal::I64(n) => typed("i64", json!(n)),
+        ScVal::Timepoint(t) => typed("timepoint", json!(t.0)),
+        ScVal::Duration(d) => typed("duration", json!(d.0)),
+        ScVal::U128(_) => typed("u128", scval_to_json(val)),
+        ScVal::I128(_) => typed("i128", scval_to_json(val)),
+        ScVal::U256(_) => typed("u256", scval_to_json(val)),
+        ScVal::I256(_) => typed("i256", scval_to_json(val)),
+        ScVal::Bytes(_) => typed("bytes", scval_to_json(val)),
+        ScVal::String(_) => typed("string", scval_to_json(val)),
+        ScVal::Symbol(_) => typed("symbol", scval_to_json(val)),
+        ScVal::Address(_) => typed("address", scval_to_json(val)),
+        ScVal::Vec(Some(items)) => {
+            Value::Array(items.0.iter().map(scval_to_arg).collect::<Option<_>>()?)
+        }
+        ScVal::Vec(None) => Value::Array(Vec::new()),
+        ScVal::Map(entries) => typed(
+            "map",
+            Value::Array(
+                entries
+                    .iter()
+                    .flat_map(|map| map.0.iter())
+                    .map(|entry| {
+                        Some(json!([
+                            scval_to_arg(&entry.key)?,
+                            scval_to_arg(&entry.val)?
+                        ]))
+                    })
+                    .collect::<Option<_>>()?,
+            ),
+        ),
+        _ => return None,
+    })
+}
+
 fn parse_repl_arg(arg: &str) -> Result<Value> {
     match serde_json::from_str::<Value>(arg) {
         Ok(value) => Ok(value),
@@ -285,6 +646,10 @@ fn parse_typed_symbol_arg(raw: &str) -> Value {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use soroban_env_host::xdr::{
+        Int128Parts, ScMap, ScMapEntry, ScString, ScSymbol, ScVec, StringM, VecM,
+    };
+    use soroban_sdk::TryFromVal;
 
     #[test]
     fn repl_args_default_to_strings() {
@@ -308,6 +673,120 @@ mod tests {
         assert_eq!(json, "[100,true,{\"type\":\"u32\",\"value\":7}]");
     }
 
+    #[test]
+    fn eval_condition_against_known_storage() {
+        let mut storage = HashMap::new();
+        storage.insert("counter".to_string(), "7".to_string());
+
+        assert!(evaluate_condition_str("storage[counter] > 5", &storage, None).unwrap());
+        assert!(!evaluate_condition_str("storage[counter] > 10", &storage, None).unwrap());
+    }
+
+    #[test]
+    fn eval_condition_reports_parse_error() {
+        let storage = HashMap::new();
+        let err = evaluate_condition_str("storage[counter]", &storage, None).unwrap_err();
+        assert!(err.to_string().contains("comparison operator"));
+    }
+
+    #[test]
+    fn typed_values_are_unwrapped_for_conditions() {
+        let value = unwrap_typed_value(json!({"type":"u32","value":7}));
+        assert_eq!(value, json!(7));
+        assert_eq!(unwrap_typed_value(json!("Alice")), json!("Alice"));
+    }
+
+    fn entry(key: ScVal, value: ScVal) -> RecordedEntry {
+        RecordedEntry {
+            durability: crate::runtime::recording::RecordedDurability::Persistent,
+            key,
+            value,
+            live_until: None,
+        }
+    }
+
+    fn symbol(name: &str) -> ScVal {
+        ScVal::Symbol(ScSymbol(name.try_into().unwrap()))
+    }
+
+    fn price(n: u64) -> ScVal {
+        ScVal::I128(Int128Parts { hi: 0, lo: n })
+    }
+
+    #[test]
+    fn storage_entries_are_found_by_decoded_or_typed_key() {
+        let xlm = ScVal::String(ScString(StringM::try_from(b"XLM".to_vec()).unwrap()));
+        let price_key = ScVal::Vec(Some(ScVec(
+            VecM::try_from(vec![symbol("Price"), xlm]).unwrap(),
+        )));
+        let entries = vec![
+            entry(price_key, price(1_100_000)),
+            entry(symbol("c"), ScVal::I64(3)),
+        ];
+        let decoder = StorageDecoder::default();
+        let find = |key: &str, typed: Option<&ScVal>| {
+            find_storage_entry(&decoder, &entries, key, typed).map(|e| e.value.clone())
+        };
+
+        assert_eq!(find(r#"Price("XLM")"#, None), Some(price(1_100_000)));
+        assert_eq!(
+            find(r#"DataKey::Price("XLM")"#, None),
+            Some(price(1_100_000))
+        );
+        assert_eq!(find("c", None), Some(ScVal::I64(3)));
+        assert_eq!(find("ignored", Some(&symbol("c"))), Some(ScVal::I64(3)));
+        assert_eq!(find(r#"Price("BTC")"#, None), None);
+    }
+
+    #[test]
+    fn bindings_are_checked_against_the_parameter_type() {
+        let n = price(42);
+        assert!(binding_fits(&n, "I128"));
+        assert!(binding_fits(&n, "Option<I128>"));
+        assert!(binding_fits(&ScVal::Void, "Option<I128>"));
+        assert!(binding_fits(&n, "Val"));
+        assert!(binding_fits(&n, "PriceData"));
+        assert!(!binding_fits(&n, "Symbol"));
+        assert!(!binding_fits(&n, "Vec<I128>"));
+        assert_eq!(
+            scval_to_arg(&n),
+            Some(json!({"type": "i128", "value": "42"}))
+        );
+        let list = ScVal::Vec(Some(ScVec(VecM::try_from(vec![ScVal::U32(7)]).unwrap())));
+        assert_eq!(
+            scval_to_arg(&list),
+            Some(json!([{"type": "u32", "value": 7}]))
+        );
+    }
+
+    #[test]
+    fn map_bindings_pass_back_as_map_arguments() {
+        let prices = ScVal::Map(Some(ScMap(
+            VecM::try_from(vec![ScMapEntry {
+                key: symbol("XLM"),
+                val: price(1_100_000),
+            }])
+            .unwrap(),
+        )));
+        assert!(binding_fits(&prices, "Map<Symbol, I128>"));
+
+        let arg = scval_to_arg(&prices).unwrap();
+        assert_eq!(
+            arg,
+            json!({"type": "map", "value": [[
+                {"type": "symbol", "value": "XLM"},
+                {"type": "i128", "value": "1100000"},
+            ]]})
+        );
+
+        let env = soroban_sdk::Env::default();
+        let vals = crate::utils::ArgumentParser::new(env.clone())
+            .parse_args_string(&json!([arg]).to_string())
+            .unwrap();
+        let round_trip = ScVal::try_from_val(&env, &vals[0]).unwrap();
+        assert_eq!(round_trip, prices);
+    }
+
     #[test]
     fn typed_string_arg_uses_string_annotation() {
         let value = parse_typed_string_arg("MTK");
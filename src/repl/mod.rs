@@ -18,6 +18,10 @@ pub struct ReplConfig {
     pub contract_path: PathBuf,
     pub network_snapshot: Option<PathBuf>,
     pub storage: Option<String>,
+    /// `--log-json` file receiving one line per invocation in the session.
+    pub log_json: Option<PathBuf>,
+    /// `--account-seed` for reproducible alias addresses.
+    pub account_seed: Option<u64>,
 }
 
 /// Start the REPL interactive session
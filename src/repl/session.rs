@@ -246,11 +246,7 @@ impl ReplSession {
                 } else {
                     tracing::info!("{}", Formatter::success("Breakpoints:"));
                     for bp in breaks {
-                        let cond = bp
-                            .condition
-                            .map(|c| format!(" (if {:?})", c))
-                            .unwrap_or_default();
-                        tracing::info!("  - {}{}", bp.function, cond);
+                        tracing::info!("  - {}", bp);
                     }
                 }
                 Ok(false)
@@ -273,6 +269,46 @@ impl ReplSession {
                 self.executor.display_functions()?;
                 Ok(false)
             }
+            ReplCommand::EvalCondition { condition } => {
+                let result = self.executor.eval_condition(&condition)?;
+                tracing::info!(
+                    "{}",
+                    Formatter::info(format!("{} => {}", condition, result))
+                );
+                Ok(false)
+            }
+            ReplCommand::Back => {
+                self.executor.step_back()?;
+                tracing::info!(
+                    "{}",
+                    Formatter::success("Stepped back to previous storage state")
+                );
+                Ok(false)
+            }
+            ReplCommand::Forward => {
+                self.executor.step_forward()?;
+                tracing::info!(
+                    "{}",
+                    Formatter::success("Stepped forward to next storage state")
+                );
+                Ok(false)
+            }
+            ReplCommand::MemTrend { window } => {
+                self.executor.display_memory_trend(window);
+                Ok(false)
+            }
+            ReplCommand::Print { key } => {
+                self.executor.print_storage(&key)?;
+                Ok(false)
+            }
+            ReplCommand::Let {
+                name,
+                function,
+                args,
+            } => {
+                self.executor.bind_call(&name, &function, args).await?;
+                Ok(false)
+            }
         }
     }
 
@@ -325,6 +361,30 @@ impl ReplSession {
             "  {}                 Show available contract functions",
             Formatter::info("functions")
         );
+        tracing::info!(
+            "  {} <cond>  Evaluate a breakpoint condition now",
+            Formatter::info("eval-condition")
+        );
+        tracing::info!(
+            "  {}                     Undo the last call's storage changes",
+            Formatter::info("back")
+        );
+        tracing::info!(
+            "  {}                  Re-apply storage undone by 'back'",
+            Formatter::info("forward")
+        );
+        tracing::info!(
+            "  {} [window]        Show memory per call and flag steady growth",
+            Formatter::info("memtrend")
+        );
+        tracing::info!(
+            "  {} storage[<key>]  Decode one storage value, e.g. storage[Price(\"XLM\")]",
+            Formatter::info("print")
+        );
+        tracing::info!(
+            "  {} <name> = <func> [args...]  Bind a call's result; pass it on as $name",
+            Formatter::info("let")
+        );
         tracing::info!(
             "  {}                     Exit the REPL",
             Formatter::info("exit")
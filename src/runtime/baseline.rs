@@ -0,0 +1,175 @@
+//! `run --baseline` budget regression gate.
+//!
+//! The baseline file maps each function to the cpu-insn and mem-byte budget it
+//! consumed when first recorded. Later runs compare against that entry and fail
+//! when either metric grows by more than the allowed tolerance, so CI catches a
+//! change that makes a function more expensive. Functions missing from the file
+//! are added on their first run.
+
+use crate::inspector::budget::BudgetInfo;
+use crate::{DebuggerError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+/// Budget one function consumed when its baseline was recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionBudget {
+    pub cpu_insns: u64,
+    pub mem_bytes: u64,
+}
+
+/// Per-function budget baselines, as stored in the `--baseline` file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BudgetBaseline {
+    pub functions: BTreeMap<String, FunctionBudget>,
+}
+
+/// One metric of a run compared with its baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BaselineDelta {
+    pub metric: &'static str,
+    pub baseline: u64,
+    pub current: u64,
+    /// Growth over the baseline in percent; negative when the run was cheaper.
+    pub percent: f64,
+    /// Whether the growth exceeds the tolerance.
+    pub regressed: bool,
+}
+
+impl BaselineDelta {
+    fn new(metric: &'static str, baseline: u64, current: u64, tolerance: f64) -> Self {
+        let percent = if baseline == 0 {
+            if current == 0 {
+                0.0
+            } else {
+                100.0
+            }
+        } else {
+            (current as f64 - baseline as f64) / baseline as f64 * 100.0
+        };
+        Self {
+            metric,
+            baseline,
+            current,
+            percent,
+            regressed: percent > tolerance,
+        }
+    }
+}
+
+impl fmt::Display for BaselineDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} -> {} ({:+.2}%){}",
+            self.metric,
+            self.baseline,
+            self.current,
+            self.percent,
+            if self.regressed { " REGRESSION" } else { "" }
+        )
+    }
+}
+
+impl BudgetBaseline {
+    /// Load the baseline at `path`, or `None` when the file does not exist yet.
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to read baseline {:?}: {}", path, e))
+        })?;
+        let baseline = serde_json::from_str(&content).map_err(|e| {
+            DebuggerError::FileError(format!("Invalid baseline file {:?}: {}", path, e))
+        })?;
+        Ok(Some(baseline))
+    }
+
+    /// Write the baseline to `path` as pretty JSON.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to serialize baseline: {}", e))
+        })?;
+        std::fs::write(path, json).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to write baseline {:?}: {}", path, e)).into()
+        })
+    }
+
+    /// Record `budget` as the baseline for `function`, replacing any earlier entry.
+    pub fn insert(&mut self, function: &str, budget: &BudgetInfo) {
+        self.functions.insert(
+            function.to_string(),
+            FunctionBudget {
+                cpu_insns: budget.cpu_instructions,
+                mem_bytes: budget.memory_bytes,
+            },
+        );
+    }
+
+    /// Compare `budget` with the baseline for `function`, or `None` if it has none.
+    ///
+    /// `tolerance` is the growth in percent each metric may show before it counts
+    /// as a regression.
+    pub fn compare(
+        &self,
+        function: &str,
+        budget: &BudgetInfo,
+        tolerance: f64,
+    ) -> Option<[BaselineDelta; 2]> {
+        let baseline = self.functions.get(function)?;
+        Some([
+            BaselineDelta::new(
+                "cpu-insns",
+                baseline.cpu_insns,
+                budget.cpu_instructions,
+                tolerance,
+            ),
+            BaselineDelta::new(
+                "mem-bytes",
+                baseline.mem_bytes,
+                budget.memory_bytes,
+                tolerance,
+            ),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn budget(cpu: u64, mem: u64) -> BudgetInfo {
+        BudgetInfo {
+            cpu_instructions: cpu,
+            cpu_limit: 0,
+            memory_bytes: mem,
+            memory_limit: 0,
+        }
+    }
+
+    #[test]
+    fn growth_beyond_tolerance_is_a_regression() {
+        let mut baseline = BudgetBaseline::default();
+        baseline.insert("increment", &budget(1000, 200));
+
+        let [cpu, mem] = baseline
+            .compare("increment", &budget(1040, 260), 5.0)
+            .unwrap();
+        assert!(!cpu.regressed);
+        assert_eq!(cpu.to_string(), "cpu-insns: 1000 -> 1040 (+4.00%)");
+        assert!(mem.regressed);
+        assert_eq!(
+            mem.to_string(),
+            "mem-bytes: 200 -> 260 (+30.00%) REGRESSION"
+        );
+
+        let [cpu, _] = baseline
+            .compare("increment", &budget(900, 200), 0.0)
+            .unwrap();
+        assert!(!cpu.regressed);
+        assert!(baseline.compare("get", &budget(1, 1), 0.0).is_none());
+    }
+}
@@ -0,0 +1,172 @@
+//! `run --on-event` routes.
+//!
+//! A route `TOPIC => CONTRACT_ID.function` forwards every contract event whose
+//! first topic is the symbol `TOPIC` to `function` on another contract, with
+//! the event data as arguments: a vector is spread into one argument per
+//! element, `void` passes none and any other value is passed as is. Events are
+//! forwarded in emission order, and for one event the routes fire in the order
+//! they were given. Events emitted by a forwarded call are not forwarded again.
+
+use crate::utils::scval::scval_to_json;
+use crate::{DebuggerError, Result};
+use soroban_env_host::xdr::{ContractEvent, ContractEventBody, ContractEventType, ScVal};
+
+/// One `TOPIC => CONTRACT_ID.function` route.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventRoute {
+    pub topic: String,
+    pub contract_id: String,
+    pub function: String,
+}
+
+impl EventRoute {
+    pub fn parse(spec: &str) -> Result<Self> {
+        let invalid = |reason: &str| -> miette::Report {
+            DebuggerError::InvalidArguments(format!(
+                "Invalid --on-event '{}': {}; expected TOPIC => CONTRACT_ID.function",
+                spec, reason
+            ))
+            .into()
+        };
+        let (topic, target) = spec
+            .split_once("=>")
+            .ok_or_else(|| invalid("missing '=>'"))?;
+        let (contract_id, function) = target
+            .trim()
+            .rsplit_once('.')
+            .ok_or_else(|| invalid("missing '.function'"))?;
+        let (topic, contract_id, function) = (topic.trim(), contract_id.trim(), function.trim());
+        if topic.is_empty() || contract_id.is_empty() || function.is_empty() {
+            return Err(invalid("TOPIC, CONTRACT_ID and function are required"));
+        }
+        Ok(Self {
+            topic: topic.to_string(),
+            contract_id: contract_id.to_string(),
+            function: function.to_string(),
+        })
+    }
+}
+
+/// A downstream call made for one forwarded event.
+#[derive(Debug, Clone)]
+pub struct ForwardedCall {
+    /// Emission index of the event that triggered the call.
+    pub event_index: usize,
+    pub route: EventRoute,
+    pub args: Vec<ScVal>,
+    /// The decoded return value, or why the call failed.
+    pub result: std::result::Result<serde_json::Value, String>,
+}
+
+impl ForwardedCall {
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut value = serde_json::json!({
+            "event_index": self.event_index,
+            "topic": self.route.topic,
+            "contract_id": self.route.contract_id,
+            "function": self.route.function,
+            "args": self.args.iter().map(scval_to_json).collect::<Vec<_>>(),
+        });
+        match &self.result {
+            Ok(result) => value["result"] = result.clone(),
+            Err(error) => value["error"] = serde_json::Value::String(error.clone()),
+        }
+        value
+    }
+}
+
+impl std::fmt::Display for ForwardedCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let args: Vec<String> = self
+            .args
+            .iter()
+            .map(|arg| scval_to_json(arg).to_string())
+            .collect();
+        write!(
+            f,
+            "#{} {} -> {}.{}({})",
+            self.event_index,
+            self.route.topic,
+            self.route.contract_id,
+            self.route.function,
+            args.join(", ")
+        )?;
+        match &self.result {
+            Ok(value) => write!(f, " = {}", value),
+            Err(error) => write!(f, " failed: {}", error),
+        }
+    }
+}
+
+/// The first-topic symbol and downstream arguments of a contract event, or
+/// `None` for diagnostic events and events without a symbol topic.
+pub fn event_call(event: &ContractEvent) -> Option<(String, Vec<ScVal>)> {
+    if event.type_ != ContractEventType::Contract {
+        return None;
+    }
+    let ContractEventBody::V0(body) = &event.body;
+    let Some(ScVal::Symbol(topic)) = body.topics.first() else {
+        return None;
+    };
+    let args = match &body.data {
+        ScVal::Vec(Some(items)) => items.iter().cloned().collect(),
+        ScVal::Void => Vec::new(),
+        other => vec![other.clone()],
+    };
+    Some((
+        String::from_utf8_lossy(topic.0.as_slice()).into_owned(),
+        args,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_env_host::xdr::{ContractEventV0, ExtensionPoint, ScSymbol, ScVec, VecM};
+
+    fn symbol(name: &str) -> ScVal {
+        ScVal::Symbol(ScSymbol(name.try_into().unwrap()))
+    }
+
+    fn event(type_: ContractEventType, topic: &str, data: ScVal) -> ContractEvent {
+        ContractEvent {
+            ext: ExtensionPoint::V0,
+            contract_id: None,
+            type_,
+            body: ContractEventBody::V0(ContractEventV0 {
+                topics: VecM::try_from(vec![symbol(topic)]).unwrap(),
+                data,
+            }),
+        }
+    }
+
+    #[test]
+    fn parse_reads_topic_contract_and_function() {
+        let route = EventRoute::parse("setprice => CABC.notify").unwrap();
+        assert_eq!(route.topic, "setprice");
+        assert_eq!(route.contract_id, "CABC");
+        assert_eq!(route.function, "notify");
+
+        let err = EventRoute::parse("setprice -> CABC.notify")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("missing '=>'"), "{err}");
+        assert!(EventRoute::parse("setprice => CABC").is_err());
+    }
+
+    #[test]
+    fn event_call_spreads_vector_data_into_arguments() {
+        let data = ScVal::Vec(Some(ScVec(
+            VecM::try_from(vec![symbol("XLM"), ScVal::U32(7)]).unwrap(),
+        )));
+        let (topic, args) = event_call(&event(ContractEventType::Contract, "setprice", data))
+            .expect("contract event");
+        assert_eq!(topic, "setprice");
+        assert_eq!(args, vec![symbol("XLM"), ScVal::U32(7)]);
+
+        let (_, args) =
+            event_call(&event(ContractEventType::Contract, "ping", ScVal::U32(1))).unwrap();
+        assert_eq!(args, vec![ScVal::U32(1)]);
+        assert!(event_call(&event(ContractEventType::Diagnostic, "log", ScVal::Void)).is_none());
+    }
+}
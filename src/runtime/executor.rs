@@ -8,18 +8,34 @@
 //! - [`super::invoker`] â€” Function invocation with timeout protection.
 //! - [`super::result`]  â€” Result types and formatting helpers.
 
-use crate::inspector::budget::MemorySummary;
+use crate::inspector::budget::{MemorySeries, MemorySummary};
+use crate::inspector::decoded_storage::StorageDecoder;
 use crate::runtime::env::DebugEnv;
+use crate::runtime::event_routes::{EventRoute, ForwardedCall};
+use crate::runtime::function_guard::FunctionGuard;
+use crate::runtime::instrumentation::{InstructionObserver, Instrumenter};
+use crate::runtime::invocation_log::{invocation_entry, InvocationLog, InvocationTiming};
+use crate::runtime::invoker::BudgetLimits;
+use crate::runtime::metrics::SessionMetrics;
 use crate::runtime::mocking::{MockCallLogEntry, MockContractDispatcher, MockRegistry};
+use crate::runtime::recording::{RecordedDurability, RecordedEntry};
 use crate::server::protocol::{DynamicTraceEvent, DynamicTraceEventKind};
 use crate::utils::arguments::ArgumentParser;
+use crate::utils::wasm::ContractFunctionSignature;
 use crate::{DebuggerError, Result};
 
+use soroban_env_host::budget::AsBudget;
+use soroban_env_host::storage::Footprint;
+use soroban_env_host::xdr::{
+    ContractDataDurability, ContractEventType, LedgerEntryData, LedgerKey, LedgerKeyContractData,
+    ScAddress, ScVal,
+};
 use soroban_env_host::Host;
 use soroban_sdk::testutils::Address as _;
 use soroban_sdk::testutils::Ledger as _;
-use soroban_sdk::{Address, Env};
-use std::collections::HashMap;
+use soroban_sdk::{Address, Env, IntoVal, TryFromVal, Val};
+use std::cell::OnceCell;
+use std::collections::{HashMap, VecDeque};
 use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::sync::{
     atomic::{AtomicBool, Ordering},
@@ -29,16 +45,23 @@ use tracing::info;
 
 // â”€â”€ re-exports so callers never need to import sub-modules directly â”€â”€â”€â”€â”€â”€â”€â”€â”€â”€â”€
 pub use crate::runtime::mocking::MockCallLogEntry as MockCallEntry;
-pub use crate::runtime::result::{ExecutionRecord, InstructionCounts, StorageSnapshot};
+pub use crate::runtime::result::{ExecutionRecord, InstructionCounts, StepRecord, StorageSnapshot};
 
 /// Executes Soroban contracts in a test environment.
 pub const DEFAULT_EXECUTION_TIMEOUT_SECS: u64 = 30;
 
+/// Storage snapshots kept for `step_back`; the oldest is dropped beyond this.
+const MAX_STORAGE_HISTORY: usize = 64;
+
 pub struct ContractExecutor {
     env: Env,
     contract_address: Address,
     last_execution: Option<ExecutionRecord>,
     last_memory_summary: Option<MemorySummary>,
+    /// Instruction a registered hook asked to pause at during the last call.
+    hook_pause: Option<usize>,
+    /// Memory consumed by each successful invocation, for growth detection.
+    memory_series: MemorySeries,
     mock_registry: Arc<Mutex<MockRegistry>>,
     wasm_bytes: Vec<u8>,
     timeout_secs: u64,
@@ -46,6 +69,34 @@ pub struct ContractExecutor {
     debug_env: DebugEnv,
     /// Accumulated CPU instruction deltas keyed by function name.
     per_function_cpu: HashMap<String, u64>,
+    budget_limits: BudgetLimits,
+    quiet_budget: bool,
+    /// Storage before each invocation, most recent last, for `step_back`.
+    storage_history: VecDeque<StorageSnapshot>,
+    /// States undone by `step_back`, re-applied by `step_forward`.
+    storage_redo: Vec<StorageSnapshot>,
+    /// Events and storage changes of every successful invocation, in order.
+    step_feed: Vec<StepRecord>,
+    /// Index of the first contract event of the last invocation, and the number
+    /// of contract events the session has seen, so numbering spans calls.
+    event_base: usize,
+    events_seen: usize,
+    /// Export names and spec signatures, parsed on first use. `wasm_bytes` never
+    /// changes for an executor, so these live until a new module is loaded.
+    exported_names: OnceCell<Vec<String>>,
+    function_signatures: OnceCell<Vec<ContractFunctionSignature>>,
+    /// `--log-json` audit log; every invocation appends a line.
+    invocation_log: Option<InvocationLog>,
+    /// `--metrics-out` file and the metrics written to it after every invocation.
+    metrics_out: Option<(std::path::PathBuf, SessionMetrics)>,
+    /// Holds user-registered instruction hooks run around each invocation.
+    instrumenter: Instrumenter,
+    /// Addresses allowed to satisfy `require_auth`, when simulating auth.
+    auth_signers: Option<Vec<Address>>,
+    /// `--account-seed`; makes REPL alias addresses reproducible.
+    account_seed: Option<u64>,
+    /// `--allow-functions` / `--deny-functions`, checked before any host work.
+    function_guard: FunctionGuard,
 }
 
 impl ContractExecutor {
@@ -53,18 +104,70 @@ impl ContractExecutor {
     #[tracing::instrument(skip_all)]
     pub fn new(wasm: Vec<u8>) -> Result<Self> {
         let loaded = crate::runtime::loader::load_contract(&wasm)?;
-        Ok(Self {
+        Ok(Self::from_loaded(wasm, loaded))
+    }
+
+    /// Create an executor with the contract registered at `contract_id`, e.g.
+    /// from `run --contract-id`.
+    #[tracing::instrument(skip(wasm))]
+    pub fn new_at(wasm: Vec<u8>, contract_id: &str) -> Result<Self> {
+        let loaded = crate::runtime::loader::load_contract_at(&wasm, contract_id)?;
+        Ok(Self::from_loaded(wasm, loaded))
+    }
+
+    /// Create an executor whose contract runs its `__constructor` with `args_json`
+    /// at registration, e.g. from `run --constructor-args`.
+    #[tracing::instrument(skip(wasm))]
+    pub fn new_with_constructor(
+        wasm: Vec<u8>,
+        contract_id: Option<&str>,
+        args_json: &str,
+    ) -> Result<Self> {
+        let loaded =
+            crate::runtime::loader::load_contract_with_constructor(&wasm, contract_id, args_json)?;
+        Ok(Self::from_loaded(wasm, loaded))
+    }
+
+    /// Create an executor on top of a saved ledger, e.g. from `run --snapshot-in`.
+    #[tracing::instrument(skip_all)]
+    pub fn from_ledger_snapshot(
+        wasm: Vec<u8>,
+        snapshot: soroban_ledger_snapshot::LedgerSnapshot,
+    ) -> Result<Self> {
+        let loaded = crate::runtime::loader::load_contract_from_snapshot(&wasm, snapshot)?;
+        Ok(Self::from_loaded(wasm, loaded))
+    }
+
+    fn from_loaded(wasm: Vec<u8>, loaded: crate::runtime::loader::LoadedContract) -> Self {
+        Self {
             env: loaded.env,
             contract_address: loaded.contract_address,
             last_execution: None,
             last_memory_summary: None,
+            hook_pause: None,
+            memory_series: MemorySeries::new(),
             mock_registry: Arc::new(Mutex::new(MockRegistry::default())),
             wasm_bytes: wasm,
             timeout_secs: DEFAULT_EXECUTION_TIMEOUT_SECS,
             error_db: loaded.error_db,
             debug_env: DebugEnv::new(),
             per_function_cpu: HashMap::new(),
-        })
+            budget_limits: BudgetLimits::default(),
+            quiet_budget: false,
+            storage_history: VecDeque::new(),
+            storage_redo: Vec::new(),
+            step_feed: Vec::new(),
+            event_base: 0,
+            events_seen: 0,
+            exported_names: OnceCell::new(),
+            function_signatures: OnceCell::new(),
+            invocation_log: None,
+            metrics_out: None,
+            instrumenter: Instrumenter::new(),
+            auth_signers: None,
+            account_seed: None,
+            function_guard: FunctionGuard::default(),
+        }
     }
 
     pub fn env(&self) -> &Env {
@@ -79,17 +182,238 @@ impl ContractExecutor {
         self.timeout_secs = secs;
     }
 
+    /// Register a hook that observes the contract's instructions on every invocation.
+    pub fn add_instruction_hook(&mut self, hook: Box<dyn InstructionObserver>) -> Result<()> {
+        if self.instrumenter.instructions().is_empty() {
+            self.instrumenter
+                .parse_instructions(&self.wasm_bytes)
+                .map_err(|e| {
+                    DebuggerError::WasmLoadError(format!("Failed to parse instructions: {}", e))
+                })?;
+        }
+        self.instrumenter.add_hook(hook);
+        Ok(())
+    }
+
+    /// Append a JSON line describing each subsequent invocation to `path`.
+    pub fn set_invocation_log(&mut self, path: &std::path::Path) -> Result<()> {
+        self.invocation_log = Some(InvocationLog::open(path)?);
+        Ok(())
+    }
+
+    /// Write Prometheus metrics for all subsequent invocations to `path`,
+    /// rewriting it after each one.
+    pub fn set_metrics_out(&mut self, path: &std::path::Path) -> Result<()> {
+        let metrics = SessionMetrics::new();
+        metrics.write_to(path)?;
+        self.metrics_out = Some((path.to_path_buf(), metrics));
+        Ok(())
+    }
+
+    /// Derive REPL alias addresses from `seed` instead of generating random ones.
+    pub fn set_account_seed(&mut self, seed: u64) {
+        self.account_seed = Some(seed);
+    }
+
+    /// Restrict which functions `execute` and `execute_scvals` may invoke.
+    pub fn set_function_guard(&mut self, guard: FunctionGuard) {
+        self.function_guard = guard;
+    }
+
     pub fn timeout_secs(&self) -> u64 {
         self.timeout_secs
     }
 
+    /// Cap CPU instructions and/or memory bytes for each subsequent invocation.
+    ///
+    /// Exceeding a limit fails the call with [`DebuggerError::BudgetExceeded`].
+    pub fn set_budget_limits(&mut self, cpu_insns: Option<u64>, mem_bytes: Option<u64>) {
+        self.budget_limits = BudgetLimits {
+            cpu_insns,
+            mem_bytes,
+        };
+    }
+
+    /// Skip the budget and memory summary normally displayed after each invocation.
+    pub fn set_quiet_budget(&mut self, quiet: bool) {
+        self.quiet_budget = quiet;
+    }
+
+    /// Current ledger timestamp in UNIX seconds.
+    pub fn ledger_timestamp(&self) -> u64 {
+        self.env.ledger().timestamp()
+    }
+
+    /// Current ledger sequence number.
+    pub fn ledger_sequence(&self) -> u32 {
+        self.env.ledger().sequence()
+    }
+
+    /// Set the ledger timestamp, sequence and network ID before invocation.
+    pub fn apply_ledger_seed(&mut self, seed: &crate::runtime::loader::LedgerSeed) {
+        seed.apply(&self.env);
+    }
+
+    /// Move the ledger clock forward by `secs` seconds.
+    pub fn advance_ledger_time(&mut self, secs: u64) {
+        self.env
+            .ledger()
+            .with_mut(|l| l.timestamp = l.timestamp.saturating_add(secs));
+    }
+
+    /// Move the ledger sequence forward by `count` ledgers, ageing every
+    /// storage entry's TTL.
+    pub fn advance_ledgers(&mut self, count: u32) {
+        self.env
+            .ledger()
+            .with_mut(|l| l.sequence_number = l.sequence_number.saturating_add(count));
+    }
+
+    /// Durability and remaining TTL of this contract's storage entries at the
+    /// current ledger, sorted by key. Entries whose ledger key is in
+    /// `read_after_expiry` are flagged as read after they expired.
+    pub fn storage_ttls(
+        &self,
+        decoder: &crate::inspector::decoded_storage::StorageDecoder,
+        read_after_expiry: &[LedgerKey],
+    ) -> Result<Vec<crate::inspector::storage::EntryTtl>> {
+        use crate::inspector::ledger::StorageType;
+
+        let mut ttls: Vec<_> = self
+            .contract_data_entries()?
+            .iter()
+            .map(|entry| {
+                let durability = match entry.durability {
+                    RecordedDurability::Instance => StorageType::Instance,
+                    RecordedDurability::Persistent => StorageType::Persistent,
+                    RecordedDurability::Temporary => StorageType::Temporary,
+                };
+                let mut ttl = crate::inspector::storage::EntryTtl::new(
+                    decoder.decode_key(&entry.key),
+                    durability,
+                    entry.live_until,
+                    self.ledger_sequence(),
+                );
+                ttl.read_after_expiry = read_after_expiry.contains(&self.entry_ledger_key(entry));
+                ttl
+            })
+            .collect();
+        ttls.sort_by(|a, b| a.key.cmp(&b.key));
+        Ok(ttls)
+    }
+
+    /// Ledger keys of this contract's entries whose TTL has run out.
+    pub fn expired_entry_keys(&self) -> Result<Vec<LedgerKey>> {
+        let ledger = self.ledger_sequence();
+        Ok(self
+            .contract_data_entries()?
+            .iter()
+            .filter(|entry| entry.live_until.is_some_and(|until| until < ledger))
+            .map(|entry| self.entry_ledger_key(entry))
+            .collect())
+    }
+
+    /// The ledger entry a captured entry lives in: the contract instance for
+    /// instance storage, its own contract data entry otherwise.
+    fn entry_ledger_key(&self, entry: &RecordedEntry) -> LedgerKey {
+        let (key, durability) = match entry.durability {
+            RecordedDurability::Instance => (
+                ScVal::LedgerKeyContractInstance,
+                ContractDataDurability::Persistent,
+            ),
+            RecordedDurability::Persistent => {
+                (entry.key.clone(), ContractDataDurability::Persistent)
+            }
+            RecordedDurability::Temporary => (entry.key.clone(), ContractDataDurability::Temporary),
+        };
+        LedgerKey::ContractData(LedgerKeyContractData {
+            contract: ScAddress::from(&self.contract_address),
+            key,
+            durability,
+        })
+    }
+
+    /// Set the footprint recorded so far aside, so the host's footprint holds
+    /// only the ledger keys touched from now on. Pass the result to
+    /// [`restore_footprint`](Self::restore_footprint).
+    pub fn take_footprint(&self) -> Result<Footprint> {
+        self.env
+            .host()
+            .with_mut_storage(|s| Ok(std::mem::take(&mut s.footprint)))
+            .map_err(|e| {
+                DebuggerError::ExecutionError(format!("Failed to read footprint: {:?}", e)).into()
+            })
+    }
+
+    /// Ledger keys touched since [`take_footprint`](Self::take_footprint); the
+    /// footprint it set aside is merged back in.
+    pub fn restore_footprint(&self, earlier: Footprint) -> Result<Vec<LedgerKey>> {
+        // Merge on a throwaway budget so it does not count towards the call's cost.
+        let budget = soroban_env_host::budget::Budget::default();
+        self.env
+            .host()
+            .with_mut_storage(|s| {
+                let touched = s
+                    .footprint
+                    .0
+                    .iter(&budget)?
+                    .map(|(key, _)| (**key).clone())
+                    .collect();
+                for (key, access) in earlier.0.iter(&budget)? {
+                    s.footprint.record_access(key, *access, &budget)?;
+                }
+                Ok(touched)
+            })
+            .map_err(|e| {
+                DebuggerError::ExecutionError(format!("Failed to merge footprint: {:?}", e)).into()
+            })
+    }
+
     /// Enable auth mocking for interactive/test-like execution flows (e.g. REPL).
     pub fn enable_mock_all_auths(&self) {
         self.env.mock_all_auths();
     }
 
-    /// Generate a test account address (StrKey) for REPL shorthand aliases.
-    pub fn generate_repl_account_strkey(&self) -> Result<String> {
+    /// Let `require_auth` succeed only for `signers` (StrKeys). Calls that need
+    /// any other address fail with "missing auth for ..." and keep no storage changes.
+    pub fn require_auth_from(&mut self, signers: &[String]) -> Result<()> {
+        let mut addresses = Vec::with_capacity(signers.len());
+        for signer in signers {
+            let address = catch_unwind(AssertUnwindSafe(|| Address::from_str(&self.env, signer)))
+                .map_err(|_| {
+                DebuggerError::InvalidArguments(format!("Invalid --auth address: {}", signer))
+            })?;
+            addresses.push(address);
+        }
+        // Record every require_auth, then check the recorded addresses after the call.
+        self.env.mock_all_auths();
+        self.auth_signers = Some(addresses);
+        Ok(())
+    }
+
+    /// Addresses that required auth in the last call but are not allowed signers.
+    fn missing_auth_signers(&self) -> Vec<String> {
+        let Some(signers) = &self.auth_signers else {
+            return Vec::new();
+        };
+        let mut missing = Vec::new();
+        for (address, _) in self.env.auths() {
+            let strkey = ScAddress::from(&address).to_string();
+            if !signers.contains(&address) && !missing.contains(&strkey) {
+                missing.push(strkey);
+            }
+        }
+        missing
+    }
+
+    /// Generate a test account address (StrKey) for the REPL shorthand `alias`.
+    ///
+    /// With an account seed the address depends only on the seed and the alias,
+    /// so `acc1` resolves to the same StrKey in every run.
+    pub fn generate_repl_account_strkey(&self, alias: &str) -> Result<String> {
+        if let Some(seed) = self.account_seed {
+            return Ok(seeded_account_strkey(seed, alias));
+        }
         let addr = Address::generate(&self.env);
         let debug = format!("{:?}", addr);
         for token in debug
@@ -109,53 +433,199 @@ impl ContractExecutor {
     /// Execute a contract function.
     #[tracing::instrument(skip(self), fields(function = function))]
     pub fn execute(&mut self, function: &str, args: Option<&str>) -> Result<String> {
-        // 1. Validate function exists in the WASM export section.
-        let exported = crate::utils::wasm::parse_functions(&self.wasm_bytes)?;
-        if !exported.contains(&function.to_string()) {
-            return Err(DebuggerError::InvalidFunction(function.to_string()).into());
-        }
+        let result = self.execute_json_args(function, args);
+        self.record_metrics(function, &result)?;
+        result
+    }
+
+    fn execute_json_args(&mut self, function: &str, args: Option<&str>) -> Result<String> {
+        // 1. Validate function is permitted and exists in the WASM export section.
+        self.function_guard.check(function)?;
+        self.ensure_exported(function)?;
 
         // 2. Parse arguments.
         let parsed_args = match args {
-            Some(json) => {
-                crate::runtime::parser::parse_args(&self.env, &self.wasm_bytes, function, json)?
-            }
+            Some(json) => crate::runtime::parser::parse_args_with_signatures(
+                &self.env,
+                self.function_signatures()?,
+                function,
+                json,
+            )?,
             None => vec![],
         };
 
+        self.invoke_parsed(function, parsed_args)
+    }
+
+    /// Execute `function` with already-encoded arguments, e.g. from an [`ExecutionRecord`].
+    pub fn execute_scvals(&mut self, function: &str, args: &[ScVal]) -> Result<String> {
+        let result = self.execute_scval_args(function, args);
+        self.record_metrics(function, &result)?;
+        result
+    }
+
+    fn execute_scval_args(&mut self, function: &str, args: &[ScVal]) -> Result<String> {
+        self.function_guard.check(function)?;
+        self.ensure_exported(function)?;
+        let parsed_args = args
+            .iter()
+            .map(|arg| Val::try_from_val(&self.env, arg))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|e| {
+                DebuggerError::InvalidArguments(format!(
+                    "Failed to convert recorded argument: {:?}",
+                    e
+                ))
+            })?;
+        self.invoke_parsed(function, parsed_args)
+    }
+
+    /// Count a finished call in the `--metrics-out` file, if one is set.
+    fn record_metrics(&mut self, function: &str, result: &Result<String>) -> Result<()> {
+        let Some((path, metrics)) = &mut self.metrics_out else {
+            return Ok(());
+        };
+        let budget = match result {
+            Ok(_) => self.last_execution.as_ref().map(|record| &record.budget),
+            Err(_) => None,
+        };
+        metrics.record(function, budget, result.as_ref().err());
+        metrics.write_to(path)
+    }
+
+    /// Names of the functions exported by the loaded contract.
+    pub fn exported_functions(&self) -> Result<Vec<String>> {
+        Ok(self.exported_names()?.to_vec())
+    }
+
+    fn exported_names(&self) -> Result<&[String]> {
+        if let Some(names) = self.exported_names.get() {
+            return Ok(names);
+        }
+        let names = crate::utils::wasm::parse_functions(&self.wasm_bytes)?;
+        Ok(self.exported_names.get_or_init(|| names))
+    }
+
+    /// Contract spec signatures of the loaded contract, parsed once and cached.
+    pub fn function_signatures(&self) -> Result<&[ContractFunctionSignature]> {
+        if let Some(signatures) = self.function_signatures.get() {
+            return Ok(signatures);
+        }
+        let signatures = crate::utils::wasm::parse_function_signatures(&self.wasm_bytes)?;
+        Ok(self.function_signatures.get_or_init(|| signatures))
+    }
+
+    fn ensure_exported(&self, function: &str) -> Result<()> {
+        if !self.exported_names()?.iter().any(|name| name == function) {
+            return Err(DebuggerError::InvalidFunction(function.to_string()).into());
+        }
+        Ok(())
+    }
+
+    fn invoke_parsed(&mut self, function: &str, parsed_args: Vec<Val>) -> Result<String> {
         // Track function call entry
         let contract_addr_str = format!("{:?}", self.contract_address);
         let arg_strings: Vec<String> = parsed_args.iter().map(|val| format!("{:?}", val)).collect();
         self.debug_env.enter_function(&contract_addr_str, function);
 
+        // Pushed to the undo history only once the call has committed.
+        let snapshot = self.snapshot_storage()?;
+
         // 3. Invoke and capture the result.
-        let storage_fn = || self.get_storage_snapshot();
+        // Borrow only the env so the instrumenter can be lent out mutably below.
+        let env = &self.env;
+        let storage_fn =
+            || Ok(crate::inspector::storage::StorageInspector::capture_snapshot(env.host()));
         let storage_before = storage_fn()?;
 
+        let started_at = chrono::Utc::now();
         let timeout_guard = ExecutionTimeoutWatchdog::start(self.timeout_secs);
-        let (display, record) = crate::runtime::invoker::invoke_function(
+        let invocation = crate::runtime::invoker::invoke_function(
             &self.env,
             &self.contract_address,
             &self.error_db,
             function,
             parsed_args,
             self.timeout_secs,
+            self.budget_limits,
+            self.quiet_budget,
+            &mut self.instrumenter,
+            &timeout_guard.token(),
             storage_fn,
-        )?;
+        );
         drop(timeout_guard);
 
+        self.hook_pause = invocation.as_ref().ok().and_then(|inv| inv.hook_pause);
+        let mut invocation = invocation?;
+        // The call ran under mock_all_auths: undo it if a required signer was
+        // not allowed, but still record it as the failed call it would have been.
+        let mut auth_error = None;
+        if invocation.record.result.is_ok() {
+            let missing = self.missing_auth_signers();
+            if !missing.is_empty() {
+                self.restore_storage(&snapshot)?;
+                let message = format!("missing auth for {}", missing.join(", "));
+                invocation.record.result = Err(message.clone());
+                invocation.record.storage_after =
+                    crate::inspector::storage::StorageInspector::capture_snapshot(self.env.host());
+                auth_error = Some(message);
+            }
+        }
+        if invocation.record.result.is_ok() && auth_error.is_none() {
+            if self.storage_history.len() == MAX_STORAGE_HISTORY {
+                self.storage_history.pop_front();
+            }
+            self.storage_history.push_back(snapshot);
+            self.storage_redo.clear();
+        }
+        if let Some(log) = &mut self.invocation_log {
+            let timing = InvocationTiming {
+                started_at,
+                finished_at: chrono::Utc::now(),
+            };
+            log.append(&invocation_entry(
+                &self.wasm_bytes,
+                &invocation.record,
+                &invocation.memory,
+                timing,
+            ))?;
+        }
+        self.last_memory_summary = Some(invocation.memory);
+        let record = invocation.record;
+        let display = invocation.display?;
+        self.memory_series.record(record.budget.memory_bytes);
+
         // Track storage changes as accesses
         let storage_after = &record.storage_after;
         self.track_storage_changes(&storage_before, storage_after);
 
+        // The host starts a fresh event buffer for every top-level call.
+        self.event_base = self.events_seen;
+        let events = crate::inspector::events::EventInspector::get_contract_events(
+            self.env.host(),
+            self.event_base,
+        )
+        .unwrap_or_default();
+        self.events_seen += events.len();
+        self.step_feed.push(StepRecord {
+            step: self.step_feed.len() + 1,
+            function: function.to_string(),
+            events,
+            storage_diff: crate::inspector::storage::StorageInspector::compute_diff(
+                &storage_before,
+                storage_after,
+                &[],
+            ),
+        });
+
         // Record completed function call
-        let result_str = display.clone();
+        let result_str = auth_error.is_none().then(|| display.clone());
         self.debug_env.record_function_call(
             &contract_addr_str,
             function,
             arg_strings,
-            Some(result_str),
-            None::<&str>,
+            result_str,
+            auth_error.as_deref(),
         );
 
         *self
@@ -163,7 +633,10 @@ impl ContractExecutor {
             .entry(function.to_string())
             .or_insert(0) += record.budget.cpu_instructions;
         self.last_execution = Some(record);
-        Ok(display)
+        match auth_error {
+            Some(message) => Err(DebuggerError::ExecutionError(message).into()),
+            None => Ok(display),
+        }
     }
 
     /// Track storage changes by comparing before and after snapshots
@@ -198,10 +671,41 @@ impl ContractExecutor {
         self.last_execution.as_ref()
     }
 
+    /// Decoder for this contract's storage, built from its spec.
+    pub fn storage_decoder(&self) -> StorageDecoder {
+        StorageDecoder::from_wasm(&self.wasm_bytes)
+    }
+
+    /// Events and storage changes of each invocation so far, oldest first.
+    pub fn step_feed(&self) -> &[StepRecord] {
+        &self.step_feed
+    }
+
+    /// Return value of the last successful call, decoded with the contract spec.
+    pub fn last_result_json(&self) -> Option<serde_json::Value> {
+        let record = self.last_execution.as_ref()?;
+        let val = record.result.as_ref().ok()?;
+        Some(
+            crate::utils::scval::decode_return_value(&self.wasm_bytes, &record.function, val)
+                .into(),
+        )
+    }
+
+    /// Index of the instruction a registered hook asked to pause at during the
+    /// last call, if any.
+    pub fn hook_pause(&self) -> Option<usize> {
+        self.hook_pause
+    }
+
     pub fn last_memory_summary(&self) -> Option<&MemorySummary> {
         self.last_memory_summary.as_ref()
     }
 
+    /// Memory consumed by each invocation so far, oldest first.
+    pub fn memory_series(&self) -> &MemorySeries {
+        &self.memory_series
+    }
+
     pub fn debug_env(&self) -> &DebugEnv {
         &self.debug_env
     }
@@ -388,6 +892,87 @@ impl ContractExecutor {
 
         Ok(())
     }
+    /// Write one instance-storage entry keyed by a unit variant of a spec enum,
+    /// e.g. `StaleTtl`, without going through the contract's own setters.
+    ///
+    /// The value type is taken from a zero-argument `get_<key>` getter or a
+    /// parameter named `<key>` (snake case); typed JSON annotations are always
+    /// accepted. Keys that are not a unit variant in the spec are rejected.
+    pub fn set_instance_entry(&mut self, key: &str, value: &str) -> Result<()> {
+        let unions = crate::utils::wasm::parse_contract_unions(&self.wasm_bytes)?;
+        let unit_variants: Vec<&str> = unions
+            .iter()
+            .flat_map(|u| &u.cases)
+            .filter(|case| case.fields.is_empty())
+            .map(|case| case.name.as_str())
+            .collect();
+        if !unit_variants.contains(&key) {
+            return Err(DebuggerError::StorageError(format!(
+                "Instance key '{}' is not a unit variant of any contract type in the spec (known: {})",
+                key,
+                if unit_variants.is_empty() {
+                    "none".to_string()
+                } else {
+                    unit_variants.join(", ")
+                }
+            ))
+            .into());
+        }
+
+        let snake = to_snake_case(key);
+        let getter = format!("get_{}", snake);
+        let signatures = self.function_signatures()?;
+        let type_name = signatures
+            .iter()
+            .find(|sig| sig.name == getter && sig.params.is_empty())
+            .and_then(|sig| sig.return_type.clone())
+            .or_else(|| {
+                signatures
+                    .iter()
+                    .flat_map(|sig| &sig.params)
+                    .find(|param| param.name == snake)
+                    .map(|param| param.type_name.clone())
+            });
+
+        let mut value_json = serde_json::from_str::<serde_json::Value>(value)
+            .unwrap_or_else(|_| serde_json::Value::String(value.to_string()));
+        let is_typed = value_json
+            .as_object()
+            .is_some_and(|obj| obj.contains_key("type") && obj.contains_key("value"));
+        if let Some(annotation) = type_name.as_deref().and_then(instance_value_annotation) {
+            if !is_typed {
+                value_json = serde_json::json!({ "type": annotation, "value": value_json });
+            }
+        }
+
+        let parser = ArgumentParser::new(self.env.clone());
+        let mut vals = parser
+            .parse_args_string(&serde_json::json!([value_json]).to_string())
+            .map_err(|e| {
+                DebuggerError::StorageError(format!(
+                    "Invalid value for instance key '{}': {}",
+                    key, e
+                ))
+            })?;
+        let value_val = vals.remove(0);
+        // Unit enum variants are stored as a one-element vec holding the variant symbol.
+        let variant: Val = soroban_sdk::Symbol::new(&self.env, key).into_val(&self.env);
+        let key_items: soroban_sdk::Vec<Val> = soroban_sdk::vec![&self.env, variant];
+        let key_val: Val = key_items.into_val(&self.env);
+
+        let contract_address = self.contract_address.clone();
+        self.env.as_contract(&contract_address, || {
+            self.env.storage().instance().set(&key_val, &value_val);
+        });
+        info!(
+            "Set instance storage {} = {} ({})",
+            key,
+            value,
+            type_name.as_deref().unwrap_or("untyped")
+        );
+        Ok(())
+    }
+
     /// Apply ledger metadata (sequence, timestamp, network ID) from a network snapshot.
     pub fn apply_snapshot_ledger(
         &mut self,
@@ -431,6 +1016,93 @@ impl ContractExecutor {
             .map(|r| r.calls().to_vec())
             .unwrap_or_default()
     }
+
+    /// Call the `--on-event` routes for each event the last invocation emitted,
+    /// in emission order. A failing downstream call is reported in its
+    /// [`ForwardedCall`] rather than failing the run.
+    pub fn forward_events(&mut self, routes: &[EventRoute]) -> Result<Vec<ForwardedCall>> {
+        let Some(step) = self.step_feed.last() else {
+            return Ok(Vec::new());
+        };
+        let host_events = self
+            .env
+            .host()
+            .get_events()
+            .map_err(|e| DebuggerError::ExecutionError(format!("Failed to get events: {}", e)))?
+            .0;
+        let contract_events = host_events
+            .iter()
+            .filter(|host_event| host_event.event.type_ == ContractEventType::Contract);
+        // Collect every call first: downstream calls may reset the event buffer.
+        let mut pending = Vec::new();
+        for (event, host_event) in step.events.iter().zip(contract_events) {
+            let Some(event_index) = event.index else {
+                continue;
+            };
+            if host_event.failed_call {
+                continue;
+            }
+            let Some((topic, args)) = crate::runtime::event_routes::event_call(&host_event.event)
+            else {
+                continue;
+            };
+            for route in routes.iter().filter(|route| route.topic == topic) {
+                pending.push((event_index, route.clone(), args.clone()));
+            }
+        }
+
+        let mut forwarded = Vec::with_capacity(pending.len());
+        for (event_index, route, args) in pending {
+            let contract = self.parse_contract_address(&route.contract_id)?;
+            let result = self.invoke_downstream(&contract, &route.function, &args);
+            forwarded.push(ForwardedCall {
+                event_index,
+                route,
+                args,
+                result,
+            });
+        }
+        Ok(forwarded)
+    }
+
+    fn invoke_downstream(
+        &self,
+        contract: &Address,
+        function: &str,
+        args: &[ScVal],
+    ) -> std::result::Result<serde_json::Value, String> {
+        let mut vals = soroban_sdk::Vec::<Val>::new(&self.env);
+        for arg in args {
+            let val = Val::try_from_val(&self.env, arg)
+                .map_err(|e| format!("Failed to convert event data: {:?}", e))?;
+            vals.push_back(val);
+        }
+        let symbol = soroban_sdk::Symbol::new(&self.env, function);
+        match self
+            .env
+            .try_invoke_contract::<Val, soroban_sdk::InvokeError>(contract, &symbol, vals)
+        {
+            Ok(Ok(val)) => ScVal::try_from_val(&self.env, &val)
+                .map(|sc| crate::utils::scval::scval_to_json(&sc))
+                .map_err(|e| format!("Failed to decode result: {:?}", e)),
+            Ok(Err(e)) => Err(format!("Failed to decode result: {:?}", e)),
+            Err(Ok(e)) => Err(format!("{:?}", e)),
+            Err(Err(e)) => Err(format!("{:?}", e)),
+        }
+    }
+
+    /// Check how often a mocked function was called; see [`MockRegistry::assert_called`].
+    pub fn assert_mock_called(
+        &self,
+        contract_id: &str,
+        function: &str,
+        times: usize,
+    ) -> Result<()> {
+        self.mock_registry
+            .lock()
+            .map_err(|_| DebuggerError::ExecutionError("Mock registry lock poisoned".into()))?
+            .assert_called(contract_id, function, times)
+    }
     pub fn get_instruction_counts(&self) -> Result<InstructionCounts> {
         let mut function_counts: Vec<(String, u64)> = self
             .per_function_cpu
@@ -451,14 +1123,107 @@ impl ContractExecutor {
         crate::inspector::auth::AuthInspector::get_auth_tree(&self.env)
     }
     pub fn get_events(&self) -> Result<Vec<crate::inspector::events::ContractEvent>> {
-        crate::inspector::events::EventInspector::get_events(self.env.host())
+        crate::inspector::events::EventInspector::get_events(self.env.host(), self.event_base)
     }
     pub fn get_storage_snapshot(&self) -> Result<HashMap<String, String>> {
         Ok(crate::inspector::storage::StorageInspector::capture_snapshot(self.env.host()))
     }
+    /// Capture this contract's data entries, including instance storage, as XDR.
+    pub fn contract_data_entries(&self) -> Result<Vec<RecordedEntry>> {
+        let host = self.env.host();
+        let contract = ScAddress::from(&self.contract_address);
+        host.with_mut_storage(|storage| {
+            let mut entries = Vec::new();
+            for (key, entry_opt) in storage.map.iter(host.as_budget())? {
+                let (LedgerKey::ContractData(cd_key), Some((entry, live_until))) =
+                    (key.as_ref(), entry_opt.as_ref())
+                else {
+                    continue;
+                };
+                let LedgerEntryData::ContractData(cd) = &entry.as_ref().data else {
+                    continue;
+                };
+                if cd_key.contract != contract {
+                    continue;
+                }
+
+                match (&cd_key.key, &cd.val) {
+                    (ScVal::LedgerKeyContractInstance, ScVal::ContractInstance(instance)) => {
+                        for item in instance.storage.iter().flat_map(|map| map.0.iter()) {
+                            entries.push(RecordedEntry {
+                                durability: RecordedDurability::Instance,
+                                key: item.key.clone(),
+                                value: item.val.clone(),
+                                live_until: *live_until,
+                            });
+                        }
+                    }
+                    (ScVal::LedgerKeyContractInstance, _) => {}
+                    (key, val) => entries.push(RecordedEntry {
+                        durability: match cd_key.durability {
+                            soroban_env_host::xdr::ContractDataDurability::Temporary => {
+                                RecordedDurability::Temporary
+                            }
+                            soroban_env_host::xdr::ContractDataDurability::Persistent => {
+                                RecordedDurability::Persistent
+                            }
+                        },
+                        key: key.clone(),
+                        value: val.clone(),
+                        live_until: *live_until,
+                    }),
+                }
+            }
+            Ok(entries)
+        })
+        .map_err(|e| {
+            DebuggerError::StorageError(format!("Failed to capture contract data: {:?}", e)).into()
+        })
+    }
+
+    /// Write entries captured by [`contract_data_entries`](Self::contract_data_entries) back.
+    pub fn restore_contract_data(&mut self, entries: &[RecordedEntry]) -> Result<()> {
+        let mut vals = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let to_val = |val: &ScVal| {
+                Val::try_from_val(&self.env, val).map_err(|e| {
+                    DebuggerError::StorageError(format!(
+                        "Failed to restore recorded storage entry: {:?}",
+                        e
+                    ))
+                })
+            };
+            vals.push((entry.durability, to_val(&entry.key)?, to_val(&entry.value)?));
+        }
+
+        let contract_address = self.contract_address.clone();
+        self.env.as_contract(&contract_address, || {
+            for (durability, key, value) in vals {
+                match durability {
+                    RecordedDurability::Instance => self.env.storage().instance().set(&key, &value),
+                    RecordedDurability::Persistent => {
+                        self.env.storage().persistent().set(&key, &value)
+                    }
+                    RecordedDurability::Temporary => {
+                        self.env.storage().temporary().set(&key, &value)
+                    }
+                }
+            }
+        });
+        Ok(())
+    }
+
     pub fn get_ledger_snapshot(&self) -> Result<soroban_ledger_snapshot::LedgerSnapshot> {
         Ok(self.env.to_ledger_snapshot())
     }
+
+    /// Write the current ledger as JSON for a later `run --snapshot-in`.
+    pub fn save_ledger_snapshot(&self, path: &std::path::Path) -> Result<()> {
+        self.get_ledger_snapshot()?.write_file(path).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to write ledger snapshot {:?}: {}", path, e))
+                .into()
+        })
+    }
     pub fn finish(
         &mut self,
     ) -> Result<(
@@ -479,6 +1244,17 @@ impl ContractExecutor {
         })?;
         Ok((storage.footprint.clone(), storage))
     }
+    /// Ledger keys read or written so far, as a transaction `LedgerFootprint`.
+    pub fn ledger_footprint(&self) -> Result<soroban_env_host::xdr::LedgerFootprint> {
+        let footprint = self
+            .env
+            .host()
+            .with_mut_storage(|s| Ok(s.footprint.clone()))
+            .map_err(|e| {
+                DebuggerError::ExecutionError(format!("Failed to read footprint: {:?}", e))
+            })?;
+        crate::runtime::footprint::to_ledger_footprint(&footprint)
+    }
     pub fn snapshot_storage(&self) -> Result<StorageSnapshot> {
         let storage = self
             .env
@@ -502,6 +1278,29 @@ impl ContractExecutor {
         info!("Storage state restored (dry-run rollback)");
         Ok(())
     }
+
+    /// Undo the most recent invocation's storage changes.
+    pub fn step_back(&mut self) -> Result<()> {
+        let previous = self.storage_history.pop_back().ok_or_else(|| {
+            DebuggerError::ExecutionError("No earlier storage state to step back to".to_string())
+        })?;
+        self.storage_redo.push(self.snapshot_storage()?);
+        self.restore_storage(&previous)
+    }
+
+    /// Re-apply storage undone by [`step_back`](Self::step_back).
+    pub fn step_forward(&mut self) -> Result<()> {
+        let next = self.storage_redo.pop().ok_or_else(|| {
+            DebuggerError::ExecutionError("No later storage state to step forward to".to_string())
+        })?;
+        self.storage_history.push_back(self.snapshot_storage()?);
+        self.restore_storage(&next)
+    }
+
+    /// Number of invocations that can currently be stepped back over.
+    pub fn storage_history_len(&self) -> usize {
+        self.storage_history.len()
+    }
     pub fn get_diagnostic_events(&self) -> Result<Vec<soroban_env_host::xdr::ContractEvent>> {
         Ok(self
             .env
@@ -525,7 +1324,10 @@ impl ContractExecutor {
         for event in self.get_diagnostic_events().unwrap_or_default() {
             let message = format!("{:?}", event);
             // Skip events already captured (this is a bit heuristic)
-            if out.iter().any(|e| e.message.contains(&message) || message.contains(&e.message)) {
+            if out
+                .iter()
+                .any(|e| e.message.contains(&message) || message.contains(&e.message))
+            {
                 continue;
             }
 
@@ -569,14 +1371,10 @@ impl ContractExecutor {
         Ok(())
     }
 
+    /// Accepts the same forms as `--contract-id`, so a mock can target the
+    /// address the primary contract was registered at.
     fn parse_contract_address(&self, contract_id: &str) -> Result<Address> {
-        catch_unwind(AssertUnwindSafe(|| {
-            Address::from_str(&self.env, contract_id)
-        }))
-        .map_err(|_| {
-            DebuggerError::InvalidArguments(format!("Invalid contract id in --mock: {contract_id}"))
-                .into()
-        })
+        crate::runtime::loader::parse_contract_id(&self.env, contract_id)
     }
 }
 
@@ -645,7 +1443,6 @@ impl ExecutionTimeoutWatchdog {
         }
     }
 
-    #[allow(dead_code)]
     pub fn token(&self) -> CancellationToken {
         self.cancellation_token.clone()
     }
@@ -686,10 +1483,88 @@ fn classify_diagnostic_event_kind(message: &str) -> DynamicTraceEventKind {
     }
 }
 
+/// `StaleTtl` -> `stale_ttl`, matching how the SDK names function parameters.
+fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_ascii_uppercase() {
+            // A word starts after a lowercase letter or digit, or at the last
+            // capital of an acronym (`USDPrice` -> `usd_price`).
+            let prev = i.checked_sub(1).map(|p| chars[p]);
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_ascii_lowercase());
+            let boundary = match prev {
+                Some(p) if p.is_ascii_lowercase() || p.is_ascii_digit() => true,
+                Some(p) if p.is_ascii_uppercase() => next_lower,
+                _ => false,
+            };
+            if boundary {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// `ArgumentParser` type annotation for a scalar spec type, e.g. `U64` -> `u64`.
+fn instance_value_annotation(type_name: &str) -> Option<&'static str> {
+    Some(match type_name {
+        "U32" => "u32",
+        "I32" => "i32",
+        "U64" => "u64",
+        "I64" => "i64",
+        "U128" => "u128",
+        "I128" => "i128",
+        "U256" => "u256",
+        "I256" => "i256",
+        "Timepoint" => "timepoint",
+        "Duration" => "duration",
+        "Bool" => "bool",
+        "String" => "string",
+        "Symbol" => "symbol",
+        "Address" => "address",
+        "Bytes" => "bytes",
+        _ => return None,
+    })
+}
+
+/// The account StrKey whose ed25519 key is `sha256(seed || alias)`.
+fn seeded_account_strkey(seed: u64, alias: &str) -> String {
+    use sha2::{Digest, Sha256};
+    use soroban_env_host::xdr::{AccountId, PublicKey, Uint256};
+
+    let mut hasher = Sha256::new();
+    hasher.update(seed.to_be_bytes());
+    hasher.update(alias.as_bytes());
+    let key: [u8; 32] = hasher.finalize().into();
+    ScAddress::Account(AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(key)))).to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn seeded_account_strkeys_depend_on_seed_and_alias() {
+        let acc1 = seeded_account_strkey(7, "acc1");
+        assert_eq!(acc1, seeded_account_strkey(7, "acc1"));
+        assert!(acc1.starts_with('G') && acc1.len() == 56, "{acc1}");
+        assert_ne!(acc1, seeded_account_strkey(7, "acc2"));
+        assert_ne!(acc1, seeded_account_strkey(8, "acc1"));
+    }
+
+    #[test]
+    fn instance_keys_map_to_snake_case_names() {
+        assert_eq!(to_snake_case("StaleTtl"), "stale_ttl");
+        assert_eq!(to_snake_case("Admin"), "admin");
+        assert_eq!(to_snake_case("USDPrice"), "usd_price");
+        assert_eq!(instance_value_annotation("U64"), Some("u64"));
+        assert_eq!(instance_value_annotation("Vec<U64>"), None);
+    }
+
     #[test]
     fn test_debug_env_storage_tracking() {
         let mut debug_env = DebugEnv::new();
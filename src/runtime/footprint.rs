@@ -0,0 +1,73 @@
+//! Storage footprint export for `run --dump-footprint-xdr`.
+//!
+//! Converts the host's recorded [`Footprint`] into the XDR `LedgerFootprint`
+//! that stellar-cli and other transaction builders place in
+//! `SorobanResources`, split into read-only and read-write ledger keys.
+
+use crate::{DebuggerError, Result};
+use soroban_env_host::storage::{AccessType, Footprint};
+use soroban_env_host::xdr::{LedgerFootprint, LedgerKey, Limits, ReadXdr, WriteXdr};
+
+/// Split the recorded footprint into read-only and read-write key sets.
+pub fn to_ledger_footprint(footprint: &Footprint) -> Result<LedgerFootprint> {
+    let mut read_only: Vec<LedgerKey> = Vec::new();
+    let mut read_write: Vec<LedgerKey> = Vec::new();
+    for (key, access) in &footprint.0 {
+        match access {
+            AccessType::ReadOnly => read_only.push((**key).clone()),
+            AccessType::ReadWrite => read_write.push((**key).clone()),
+        }
+    }
+
+    let too_many =
+        |e| DebuggerError::ExecutionError(format!("Footprint has too many ledger keys: {:?}", e));
+    Ok(LedgerFootprint {
+        read_only: read_only.try_into().map_err(too_many)?,
+        read_write: read_write.try_into().map_err(too_many)?,
+    })
+}
+
+/// Encode a footprint as base64 XDR.
+pub fn encode_footprint(footprint: &LedgerFootprint) -> Result<String> {
+    footprint.to_xdr_base64(Limits::none()).map_err(|e| {
+        DebuggerError::ExecutionError(format!("Failed to encode footprint XDR: {:?}", e)).into()
+    })
+}
+
+/// Decode a base64 XDR `LedgerFootprint`.
+pub fn decode_footprint(xdr: &str) -> Result<LedgerFootprint> {
+    LedgerFootprint::from_xdr_base64(xdr.trim(), Limits::none()).map_err(|e| {
+        DebuggerError::InvalidArguments(format!("Invalid footprint XDR: {:?}", e)).into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_env_host::xdr::{
+        ContractDataDurability, Hash, LedgerKeyContractCode, LedgerKeyContractData, ScAddress,
+        ScVal,
+    };
+
+    #[test]
+    fn footprint_round_trips_through_base64_xdr() {
+        let instance = LedgerKey::ContractData(LedgerKeyContractData {
+            contract: ScAddress::Contract(Hash([7; 32])),
+            key: ScVal::LedgerKeyContractInstance,
+            durability: ContractDataDurability::Persistent,
+        });
+        let code = LedgerKey::ContractCode(LedgerKeyContractCode {
+            hash: Hash([9; 32]),
+        });
+        let footprint = LedgerFootprint {
+            read_only: vec![code.clone()].try_into().unwrap(),
+            read_write: vec![instance.clone()].try_into().unwrap(),
+        };
+
+        let encoded = encode_footprint(&footprint).unwrap();
+        let decoded = decode_footprint(&format!("{encoded}\n")).unwrap();
+        assert_eq!(decoded.read_only.to_vec(), vec![code]);
+        assert_eq!(decoded.read_write.to_vec(), vec![instance]);
+        assert!(decode_footprint("not-xdr").is_err());
+    }
+}
@@ -0,0 +1,78 @@
+//! `--allow-functions` / `--deny-functions` invocation guard.
+//!
+//! A sandbox can limit which contract functions may be called. An empty allow
+//! list permits every function; a non-empty one permits only those listed. A
+//! denied function is always rejected, even when it is also allowed.
+
+use crate::{DebuggerError, Result};
+
+/// Which functions an executor may invoke.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FunctionGuard {
+    allow: Vec<String>,
+    deny: Vec<String>,
+}
+
+impl FunctionGuard {
+    pub fn new(allow: Vec<String>, deny: Vec<String>) -> Self {
+        Self { allow, deny }
+    }
+
+    /// Whether the guard restricts anything at all.
+    pub fn is_empty(&self) -> bool {
+        self.allow.is_empty() && self.deny.is_empty()
+    }
+
+    /// Reject `function` unless the lists permit it.
+    pub fn check(&self, function: &str) -> Result<()> {
+        if self.deny.iter().any(|name| name == function) {
+            return Err(DebuggerError::InvalidFunction(format!(
+                "'{}' is blocked by --deny-functions",
+                function
+            ))
+            .into());
+        }
+        if !self.allow.is_empty() && !self.allow.iter().any(|name| name == function) {
+            return Err(DebuggerError::InvalidFunction(format!(
+                "'{}' is not in --allow-functions ({})",
+                function,
+                self.allow.join(", ")
+            ))
+            .into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn allow_list_permits_only_listed_functions() {
+        let guard = FunctionGuard::new(names(&["get", "increment"]), Vec::new());
+        assert!(guard.check("get").is_ok());
+        let err = guard.check("reset").unwrap_err().to_string();
+        assert!(err.contains("not in --allow-functions"), "{err}");
+    }
+
+    #[test]
+    fn deny_list_blocks_listed_functions() {
+        let guard = FunctionGuard::new(Vec::new(), names(&["set_admin"]));
+        assert!(guard.check("get").is_ok());
+        let err = guard.check("set_admin").unwrap_err().to_string();
+        assert!(err.contains("blocked by --deny-functions"), "{err}");
+    }
+
+    #[test]
+    fn deny_takes_precedence_over_allow() {
+        let guard = FunctionGuard::new(names(&["get", "set_admin"]), names(&["set_admin"]));
+        assert!(guard.check("get").is_ok());
+        assert!(guard.check("set_admin").is_err());
+        assert!(FunctionGuard::default().is_empty());
+    }
+}
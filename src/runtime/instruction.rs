@@ -1,5 +1,6 @@
 //! WASM instruction representation and parsing for debugger stepping
 
+use std::collections::BTreeMap;
 use std::fmt;
 use wasmparser::Operator;
 
@@ -34,110 +35,7 @@ impl Instruction {
 
     /// Get the instruction name
     pub fn name(&self) -> &'static str {
-        match &self.operator {
-            Operator::Unreachable => "unreachable",
-            Operator::Nop => "nop",
-            Operator::Block { .. } => "block",
-            Operator::Loop { .. } => "loop",
-            Operator::If { .. } => "if",
-            Operator::Else => "else",
-            Operator::End => "end",
-            Operator::Br { .. } => "br",
-            Operator::BrIf { .. } => "br_if",
-            Operator::BrTable { .. } => "br_table",
-            Operator::Return => "return",
-            Operator::Call { .. } => "call",
-            Operator::CallIndirect { .. } => "call_indirect",
-            Operator::Drop => "drop",
-            Operator::Select => "select",
-            Operator::LocalGet { .. } => "local.get",
-            Operator::LocalSet { .. } => "local.set",
-            Operator::LocalTee { .. } => "local.tee",
-            Operator::GlobalGet { .. } => "global.get",
-            Operator::GlobalSet { .. } => "global.set",
-            Operator::I32Load { .. } => "i32.load",
-            Operator::I64Load { .. } => "i64.load",
-            Operator::F32Load { .. } => "f32.load",
-            Operator::F64Load { .. } => "f64.load",
-            Operator::I32Load8S { .. } => "i32.load8_s",
-            Operator::I32Load8U { .. } => "i32.load8_u",
-            Operator::I32Load16S { .. } => "i32.load16_s",
-            Operator::I32Load16U { .. } => "i32.load16_u",
-            Operator::I64Load8S { .. } => "i64.load8_s",
-            Operator::I64Load8U { .. } => "i64.load8_u",
-            Operator::I64Load16S { .. } => "i64.load16_s",
-            Operator::I64Load16U { .. } => "i64.load16_u",
-            Operator::I64Load32S { .. } => "i64.load32_s",
-            Operator::I64Load32U { .. } => "i64.load32_u",
-            Operator::I32Store { .. } => "i32.store",
-            Operator::I64Store { .. } => "i64.store",
-            Operator::F32Store { .. } => "f32.store",
-            Operator::F64Store { .. } => "f64.store",
-            Operator::I32Store8 { .. } => "i32.store8",
-            Operator::I32Store16 { .. } => "i32.store16",
-            Operator::I64Store8 { .. } => "i64.store8",
-            Operator::I64Store16 { .. } => "i64.store16",
-            Operator::I64Store32 { .. } => "i64.store32",
-            Operator::MemorySize { .. } => "memory.size",
-            Operator::MemoryGrow { .. } => "memory.grow",
-            Operator::I32Const { .. } => "i32.const",
-            Operator::I64Const { .. } => "i64.const",
-            Operator::F32Const { .. } => "f32.const",
-            Operator::F64Const { .. } => "f64.const",
-            Operator::I32Eqz => "i32.eqz",
-            Operator::I32Eq => "i32.eq",
-            Operator::I32Ne => "i32.ne",
-            Operator::I32LtS => "i32.lt_s",
-            Operator::I32LtU => "i32.lt_u",
-            Operator::I32GtS => "i32.gt_s",
-            Operator::I32GtU => "i32.gt_u",
-            Operator::I32LeS => "i32.le_s",
-            Operator::I32LeU => "i32.le_u",
-            Operator::I32GeS => "i32.ge_s",
-            Operator::I32GeU => "i32.ge_u",
-            Operator::I64Eqz => "i64.eqz",
-            Operator::I64Eq => "i64.eq",
-            Operator::I64Ne => "i64.ne",
-            Operator::I64LtS => "i64.lt_s",
-            Operator::I64LtU => "i64.lt_u",
-            Operator::I64GtS => "i64.gt_s",
-            Operator::I64GtU => "i64.gt_u",
-            Operator::I64LeS => "i64.le_s",
-            Operator::I64LeU => "i64.le_u",
-            Operator::I64GeS => "i64.ge_s",
-            Operator::I64GeU => "i64.ge_u",
-            Operator::F32Eq => "f32.eq",
-            Operator::F32Ne => "f32.ne",
-            Operator::F32Lt => "f32.lt",
-            Operator::F32Gt => "f32.gt",
-            Operator::F32Le => "f32.le",
-            Operator::F32Ge => "f32.ge",
-            Operator::F64Eq => "f64.eq",
-            Operator::F64Ne => "f64.ne",
-            Operator::F64Lt => "f64.lt",
-            Operator::F64Gt => "f64.gt",
-            Operator::F64Le => "f64.le",
-            Operator::F64Ge => "f64.ge",
-            Operator::I32Clz => "i32.clz",
-            Operator::I32Ctz => "i32.ctz",
-            Operator::I32Popcnt => "i32.popcnt",
-            Operator::I32Add => "i32.add",
-            Operator::I32Sub => "i32.sub",
-            Operator::I32Mul => "i32.mul",
-            Operator::I32DivS => "i32.div_s",
-            Operator::I32DivU => "i32.div_u",
-            Operator::I32RemS => "i32.rem_s",
-            Operator::I32RemU => "i32.rem_u",
-            Operator::I32And => "i32.and",
-            Operator::I32Or => "i32.or",
-            Operator::I32Xor => "i32.xor",
-            Operator::I32Shl => "i32.shl",
-            Operator::I32ShrS => "i32.shr_s",
-            Operator::I32ShrU => "i32.shr_u",
-            Operator::I32Rotl => "i32.rotl",
-            Operator::I32Rotr => "i32.rotr",
-            _ => "unknown",
-        }
+        operator_name(&self.operator)
     }
 
     /// Get operand description for display
@@ -191,6 +89,287 @@ impl Instruction {
     }
 }
 
+/// WASM text name of `op`, such as `i64.extend_i32_s` or `i32.atomic.rmw8.add_u`.
+///
+/// Covers the MVP and the sign-extension, saturating conversion, bulk memory,
+/// reference types, tail call and threads proposals; anything else (SIMD)
+/// is `unknown`.
+pub fn operator_name(op: &Operator) -> &'static str {
+    match op {
+        Operator::Unreachable => "unreachable",
+        Operator::Nop => "nop",
+        Operator::Block { .. } => "block",
+        Operator::Loop { .. } => "loop",
+        Operator::If { .. } => "if",
+        Operator::Else => "else",
+        Operator::End => "end",
+        Operator::Br { .. } => "br",
+        Operator::BrIf { .. } => "br_if",
+        Operator::BrTable { .. } => "br_table",
+        Operator::Return => "return",
+        Operator::Call { .. } => "call",
+        Operator::CallIndirect { .. } => "call_indirect",
+        Operator::ReturnCall { .. } => "return_call",
+        Operator::ReturnCallIndirect { .. } => "return_call_indirect",
+        Operator::Drop => "drop",
+        Operator::Select => "select",
+        Operator::TypedSelect { .. } => "select",
+        Operator::LocalGet { .. } => "local.get",
+        Operator::LocalSet { .. } => "local.set",
+        Operator::LocalTee { .. } => "local.tee",
+        Operator::GlobalGet { .. } => "global.get",
+        Operator::GlobalSet { .. } => "global.set",
+        Operator::I32Load { .. } => "i32.load",
+        Operator::I64Load { .. } => "i64.load",
+        Operator::F32Load { .. } => "f32.load",
+        Operator::F64Load { .. } => "f64.load",
+        Operator::I32Load8S { .. } => "i32.load8_s",
+        Operator::I32Load8U { .. } => "i32.load8_u",
+        Operator::I32Load16S { .. } => "i32.load16_s",
+        Operator::I32Load16U { .. } => "i32.load16_u",
+        Operator::I64Load8S { .. } => "i64.load8_s",
+        Operator::I64Load8U { .. } => "i64.load8_u",
+        Operator::I64Load16S { .. } => "i64.load16_s",
+        Operator::I64Load16U { .. } => "i64.load16_u",
+        Operator::I64Load32S { .. } => "i64.load32_s",
+        Operator::I64Load32U { .. } => "i64.load32_u",
+        Operator::I32Store { .. } => "i32.store",
+        Operator::I64Store { .. } => "i64.store",
+        Operator::F32Store { .. } => "f32.store",
+        Operator::F64Store { .. } => "f64.store",
+        Operator::I32Store8 { .. } => "i32.store8",
+        Operator::I32Store16 { .. } => "i32.store16",
+        Operator::I64Store8 { .. } => "i64.store8",
+        Operator::I64Store16 { .. } => "i64.store16",
+        Operator::I64Store32 { .. } => "i64.store32",
+        Operator::MemorySize { .. } => "memory.size",
+        Operator::MemoryGrow { .. } => "memory.grow",
+        Operator::I32Const { .. } => "i32.const",
+        Operator::I64Const { .. } => "i64.const",
+        Operator::F32Const { .. } => "f32.const",
+        Operator::F64Const { .. } => "f64.const",
+        Operator::RefNull { .. } => "ref.null",
+        Operator::RefIsNull => "ref.is_null",
+        Operator::RefFunc { .. } => "ref.func",
+        Operator::I32Eqz => "i32.eqz",
+        Operator::I32Eq => "i32.eq",
+        Operator::I32Ne => "i32.ne",
+        Operator::I32LtS => "i32.lt_s",
+        Operator::I32LtU => "i32.lt_u",
+        Operator::I32GtS => "i32.gt_s",
+        Operator::I32GtU => "i32.gt_u",
+        Operator::I32LeS => "i32.le_s",
+        Operator::I32LeU => "i32.le_u",
+        Operator::I32GeS => "i32.ge_s",
+        Operator::I32GeU => "i32.ge_u",
+        Operator::I64Eqz => "i64.eqz",
+        Operator::I64Eq => "i64.eq",
+        Operator::I64Ne => "i64.ne",
+        Operator::I64LtS => "i64.lt_s",
+        Operator::I64LtU => "i64.lt_u",
+        Operator::I64GtS => "i64.gt_s",
+        Operator::I64GtU => "i64.gt_u",
+        Operator::I64LeS => "i64.le_s",
+        Operator::I64LeU => "i64.le_u",
+        Operator::I64GeS => "i64.ge_s",
+        Operator::I64GeU => "i64.ge_u",
+        Operator::F32Eq => "f32.eq",
+        Operator::F32Ne => "f32.ne",
+        Operator::F32Lt => "f32.lt",
+        Operator::F32Gt => "f32.gt",
+        Operator::F32Le => "f32.le",
+        Operator::F32Ge => "f32.ge",
+        Operator::F64Eq => "f64.eq",
+        Operator::F64Ne => "f64.ne",
+        Operator::F64Lt => "f64.lt",
+        Operator::F64Gt => "f64.gt",
+        Operator::F64Le => "f64.le",
+        Operator::F64Ge => "f64.ge",
+        Operator::I32Clz => "i32.clz",
+        Operator::I32Ctz => "i32.ctz",
+        Operator::I32Popcnt => "i32.popcnt",
+        Operator::I32Add => "i32.add",
+        Operator::I32Sub => "i32.sub",
+        Operator::I32Mul => "i32.mul",
+        Operator::I32DivS => "i32.div_s",
+        Operator::I32DivU => "i32.div_u",
+        Operator::I32RemS => "i32.rem_s",
+        Operator::I32RemU => "i32.rem_u",
+        Operator::I32And => "i32.and",
+        Operator::I32Or => "i32.or",
+        Operator::I32Xor => "i32.xor",
+        Operator::I32Shl => "i32.shl",
+        Operator::I32ShrS => "i32.shr_s",
+        Operator::I32ShrU => "i32.shr_u",
+        Operator::I32Rotl => "i32.rotl",
+        Operator::I32Rotr => "i32.rotr",
+        Operator::I64Clz => "i64.clz",
+        Operator::I64Ctz => "i64.ctz",
+        Operator::I64Popcnt => "i64.popcnt",
+        Operator::I64Add => "i64.add",
+        Operator::I64Sub => "i64.sub",
+        Operator::I64Mul => "i64.mul",
+        Operator::I64DivS => "i64.div_s",
+        Operator::I64DivU => "i64.div_u",
+        Operator::I64RemS => "i64.rem_s",
+        Operator::I64RemU => "i64.rem_u",
+        Operator::I64And => "i64.and",
+        Operator::I64Or => "i64.or",
+        Operator::I64Xor => "i64.xor",
+        Operator::I64Shl => "i64.shl",
+        Operator::I64ShrS => "i64.shr_s",
+        Operator::I64ShrU => "i64.shr_u",
+        Operator::I64Rotl => "i64.rotl",
+        Operator::I64Rotr => "i64.rotr",
+        Operator::F32Abs => "f32.abs",
+        Operator::F32Neg => "f32.neg",
+        Operator::F32Ceil => "f32.ceil",
+        Operator::F32Floor => "f32.floor",
+        Operator::F32Trunc => "f32.trunc",
+        Operator::F32Nearest => "f32.nearest",
+        Operator::F32Sqrt => "f32.sqrt",
+        Operator::F32Add => "f32.add",
+        Operator::F32Sub => "f32.sub",
+        Operator::F32Mul => "f32.mul",
+        Operator::F32Div => "f32.div",
+        Operator::F32Min => "f32.min",
+        Operator::F32Max => "f32.max",
+        Operator::F32Copysign => "f32.copysign",
+        Operator::F64Abs => "f64.abs",
+        Operator::F64Neg => "f64.neg",
+        Operator::F64Ceil => "f64.ceil",
+        Operator::F64Floor => "f64.floor",
+        Operator::F64Trunc => "f64.trunc",
+        Operator::F64Nearest => "f64.nearest",
+        Operator::F64Sqrt => "f64.sqrt",
+        Operator::F64Add => "f64.add",
+        Operator::F64Sub => "f64.sub",
+        Operator::F64Mul => "f64.mul",
+        Operator::F64Div => "f64.div",
+        Operator::F64Min => "f64.min",
+        Operator::F64Max => "f64.max",
+        Operator::F64Copysign => "f64.copysign",
+        Operator::I32WrapI64 => "i32.wrap_i64",
+        Operator::I32TruncF32S => "i32.trunc_f32_s",
+        Operator::I32TruncF32U => "i32.trunc_f32_u",
+        Operator::I32TruncF64S => "i32.trunc_f64_s",
+        Operator::I32TruncF64U => "i32.trunc_f64_u",
+        Operator::I64ExtendI32S => "i64.extend_i32_s",
+        Operator::I64ExtendI32U => "i64.extend_i32_u",
+        Operator::I64TruncF32S => "i64.trunc_f32_s",
+        Operator::I64TruncF32U => "i64.trunc_f32_u",
+        Operator::I64TruncF64S => "i64.trunc_f64_s",
+        Operator::I64TruncF64U => "i64.trunc_f64_u",
+        Operator::F32ConvertI32S => "f32.convert_i32_s",
+        Operator::F32ConvertI32U => "f32.convert_i32_u",
+        Operator::F32ConvertI64S => "f32.convert_i64_s",
+        Operator::F32ConvertI64U => "f32.convert_i64_u",
+        Operator::F32DemoteF64 => "f32.demote_f64",
+        Operator::F64ConvertI32S => "f64.convert_i32_s",
+        Operator::F64ConvertI32U => "f64.convert_i32_u",
+        Operator::F64ConvertI64S => "f64.convert_i64_s",
+        Operator::F64ConvertI64U => "f64.convert_i64_u",
+        Operator::F64PromoteF32 => "f64.promote_f32",
+        Operator::I32ReinterpretF32 => "i32.reinterpret_f32",
+        Operator::I64ReinterpretF64 => "i64.reinterpret_f64",
+        Operator::F32ReinterpretI32 => "f32.reinterpret_i32",
+        Operator::F64ReinterpretI64 => "f64.reinterpret_i64",
+        Operator::I32Extend8S => "i32.extend8_s",
+        Operator::I32Extend16S => "i32.extend16_s",
+        Operator::I64Extend8S => "i64.extend8_s",
+        Operator::I64Extend16S => "i64.extend16_s",
+        Operator::I64Extend32S => "i64.extend32_s",
+        Operator::I32TruncSatF32S => "i32.trunc_sat_f32_s",
+        Operator::I32TruncSatF32U => "i32.trunc_sat_f32_u",
+        Operator::I32TruncSatF64S => "i32.trunc_sat_f64_s",
+        Operator::I32TruncSatF64U => "i32.trunc_sat_f64_u",
+        Operator::I64TruncSatF32S => "i64.trunc_sat_f32_s",
+        Operator::I64TruncSatF32U => "i64.trunc_sat_f32_u",
+        Operator::I64TruncSatF64S => "i64.trunc_sat_f64_s",
+        Operator::I64TruncSatF64U => "i64.trunc_sat_f64_u",
+        Operator::MemoryInit { .. } => "memory.init",
+        Operator::DataDrop { .. } => "data.drop",
+        Operator::MemoryCopy { .. } => "memory.copy",
+        Operator::MemoryFill { .. } => "memory.fill",
+        Operator::TableInit { .. } => "table.init",
+        Operator::ElemDrop { .. } => "elem.drop",
+        Operator::TableCopy { .. } => "table.copy",
+        Operator::TableFill { .. } => "table.fill",
+        Operator::TableGet { .. } => "table.get",
+        Operator::TableSet { .. } => "table.set",
+        Operator::TableGrow { .. } => "table.grow",
+        Operator::TableSize { .. } => "table.size",
+        Operator::MemoryAtomicNotify { .. } => "memory.atomic.notify",
+        Operator::MemoryAtomicWait32 { .. } => "memory.atomic.wait32",
+        Operator::MemoryAtomicWait64 { .. } => "memory.atomic.wait64",
+        Operator::AtomicFence => "atomic.fence",
+        Operator::I32AtomicLoad { .. } => "i32.atomic.load",
+        Operator::I64AtomicLoad { .. } => "i64.atomic.load",
+        Operator::I32AtomicLoad8U { .. } => "i32.atomic.load8_u",
+        Operator::I32AtomicLoad16U { .. } => "i32.atomic.load16_u",
+        Operator::I64AtomicLoad8U { .. } => "i64.atomic.load8_u",
+        Operator::I64AtomicLoad16U { .. } => "i64.atomic.load16_u",
+        Operator::I64AtomicLoad32U { .. } => "i64.atomic.load32_u",
+        Operator::I32AtomicStore { .. } => "i32.atomic.store",
+        Operator::I64AtomicStore { .. } => "i64.atomic.store",
+        Operator::I32AtomicStore8 { .. } => "i32.atomic.store8",
+        Operator::I32AtomicStore16 { .. } => "i32.atomic.store16",
+        Operator::I64AtomicStore8 { .. } => "i64.atomic.store8",
+        Operator::I64AtomicStore16 { .. } => "i64.atomic.store16",
+        Operator::I64AtomicStore32 { .. } => "i64.atomic.store32",
+        Operator::I32AtomicRmwAdd { .. } => "i32.atomic.rmw.add",
+        Operator::I64AtomicRmwAdd { .. } => "i64.atomic.rmw.add",
+        Operator::I32AtomicRmw8AddU { .. } => "i32.atomic.rmw8.add_u",
+        Operator::I32AtomicRmw16AddU { .. } => "i32.atomic.rmw16.add_u",
+        Operator::I64AtomicRmw8AddU { .. } => "i64.atomic.rmw8.add_u",
+        Operator::I64AtomicRmw16AddU { .. } => "i64.atomic.rmw16.add_u",
+        Operator::I64AtomicRmw32AddU { .. } => "i64.atomic.rmw32.add_u",
+        Operator::I32AtomicRmwSub { .. } => "i32.atomic.rmw.sub",
+        Operator::I64AtomicRmwSub { .. } => "i64.atomic.rmw.sub",
+        Operator::I32AtomicRmw8SubU { .. } => "i32.atomic.rmw8.sub_u",
+        Operator::I32AtomicRmw16SubU { .. } => "i32.atomic.rmw16.sub_u",
+        Operator::I64AtomicRmw8SubU { .. } => "i64.atomic.rmw8.sub_u",
+        Operator::I64AtomicRmw16SubU { .. } => "i64.atomic.rmw16.sub_u",
+        Operator::I64AtomicRmw32SubU { .. } => "i64.atomic.rmw32.sub_u",
+        Operator::I32AtomicRmwAnd { .. } => "i32.atomic.rmw.and",
+        Operator::I64AtomicRmwAnd { .. } => "i64.atomic.rmw.and",
+        Operator::I32AtomicRmw8AndU { .. } => "i32.atomic.rmw8.and_u",
+        Operator::I32AtomicRmw16AndU { .. } => "i32.atomic.rmw16.and_u",
+        Operator::I64AtomicRmw8AndU { .. } => "i64.atomic.rmw8.and_u",
+        Operator::I64AtomicRmw16AndU { .. } => "i64.atomic.rmw16.and_u",
+        Operator::I64AtomicRmw32AndU { .. } => "i64.atomic.rmw32.and_u",
+        Operator::I32AtomicRmwOr { .. } => "i32.atomic.rmw.or",
+        Operator::I64AtomicRmwOr { .. } => "i64.atomic.rmw.or",
+        Operator::I32AtomicRmw8OrU { .. } => "i32.atomic.rmw8.or_u",
+        Operator::I32AtomicRmw16OrU { .. } => "i32.atomic.rmw16.or_u",
+        Operator::I64AtomicRmw8OrU { .. } => "i64.atomic.rmw8.or_u",
+        Operator::I64AtomicRmw16OrU { .. } => "i64.atomic.rmw16.or_u",
+        Operator::I64AtomicRmw32OrU { .. } => "i64.atomic.rmw32.or_u",
+        Operator::I32AtomicRmwXor { .. } => "i32.atomic.rmw.xor",
+        Operator::I64AtomicRmwXor { .. } => "i64.atomic.rmw.xor",
+        Operator::I32AtomicRmw8XorU { .. } => "i32.atomic.rmw8.xor_u",
+        Operator::I32AtomicRmw16XorU { .. } => "i32.atomic.rmw16.xor_u",
+        Operator::I64AtomicRmw8XorU { .. } => "i64.atomic.rmw8.xor_u",
+        Operator::I64AtomicRmw16XorU { .. } => "i64.atomic.rmw16.xor_u",
+        Operator::I64AtomicRmw32XorU { .. } => "i64.atomic.rmw32.xor_u",
+        Operator::I32AtomicRmwXchg { .. } => "i32.atomic.rmw.xchg",
+        Operator::I64AtomicRmwXchg { .. } => "i64.atomic.rmw.xchg",
+        Operator::I32AtomicRmw8XchgU { .. } => "i32.atomic.rmw8.xchg_u",
+        Operator::I32AtomicRmw16XchgU { .. } => "i32.atomic.rmw16.xchg_u",
+        Operator::I64AtomicRmw8XchgU { .. } => "i64.atomic.rmw8.xchg_u",
+        Operator::I64AtomicRmw16XchgU { .. } => "i64.atomic.rmw16.xchg_u",
+        Operator::I64AtomicRmw32XchgU { .. } => "i64.atomic.rmw32.xchg_u",
+        Operator::I32AtomicRmwCmpxchg { .. } => "i32.atomic.rmw.cmpxchg",
+        Operator::I64AtomicRmwCmpxchg { .. } => "i64.atomic.rmw.cmpxchg",
+        Operator::I32AtomicRmw8CmpxchgU { .. } => "i32.atomic.rmw8.cmpxchg_u",
+        Operator::I32AtomicRmw16CmpxchgU { .. } => "i32.atomic.rmw16.cmpxchg_u",
+        Operator::I64AtomicRmw8CmpxchgU { .. } => "i64.atomic.rmw8.cmpxchg_u",
+        Operator::I64AtomicRmw16CmpxchgU { .. } => "i64.atomic.rmw16.cmpxchg_u",
+        Operator::I64AtomicRmw32CmpxchgU { .. } => "i64.atomic.rmw32.cmpxchg_u",
+        _ => "unknown",
+    }
+}
+
 impl fmt::Display for Instruction {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let operands = self.operands();
@@ -222,13 +401,26 @@ impl InstructionParser {
         self.instructions.clear();
 
         let parser = Parser::new(0);
+        // Imported functions come first in the function index space.
+        let mut function_index = 0u32;
 
         for payload in parser.parse_all(wasm_bytes) {
             let payload = payload.map_err(|e| format!("WASM parsing error: {}", e))?;
 
-            if let Payload::CodeSectionEntry(body) = payload {
-                let function_index = self.instructions.len() as u32; // Simplified function indexing
-                self.parse_function_body(body, function_index)?;
+            match payload {
+                Payload::ImportSection(reader) => {
+                    for import in reader {
+                        let import = import.map_err(|e| format!("WASM parsing error: {}", e))?;
+                        if let wasmparser::TypeRef::Func(_) = import.ty {
+                            function_index += 1;
+                        }
+                    }
+                }
+                Payload::CodeSectionEntry(body) => {
+                    self.parse_function_body(body, function_index)?;
+                    function_index += 1;
+                }
+                _ => {}
             }
         }
 
@@ -276,6 +468,34 @@ impl InstructionParser {
     pub fn instructions(&self) -> &[Instruction] {
         &self.instructions
     }
+
+    /// Count each opcode across all function bodies, keyed by its WASM text
+    /// name such as `i64.const`.
+    ///
+    /// Counting stops at the first unreadable section or operator, so a
+    /// malformed module yields the counts of the code read before it.
+    pub fn opcode_histogram(wasm_bytes: &[u8]) -> BTreeMap<String, u64> {
+        use wasmparser::{Parser, Payload};
+
+        let mut histogram = BTreeMap::new();
+        for payload in Parser::new(0).parse_all(wasm_bytes) {
+            let body = match payload {
+                Ok(Payload::CodeSectionEntry(body)) => body,
+                Ok(_) => continue,
+                Err(_) => break,
+            };
+            let Ok(mut reader) = body.get_operators_reader() else {
+                break;
+            };
+            while !reader.eof() {
+                let Ok(op) = reader.read() else {
+                    return histogram;
+                };
+                *histogram.entry(operator_name(&op).to_string()).or_insert(0) += 1;
+            }
+        }
+        histogram
+    }
 }
 
 impl Default for InstructionParser {
@@ -300,6 +520,83 @@ mod tests {
         assert_eq!(inst.operands(), "$5");
     }
 
+    /// One imported function, then two local functions: `nop` and `call 0`.
+    const IMPORT_THEN_TWO_FUNCTIONS: [u8; 40] = [
+        0x00, 0x61, 0x73, 0x6d, 0x01, 0x00, 0x00, 0x00, // header
+        0x01, 0x04, 0x01, 0x60, 0x00, 0x00, // type section
+        0x02, 0x07, 0x01, 0x01, 0x65, 0x01, 0x66, 0x00, 0x00, // import e.f
+        0x03, 0x03, 0x02, 0x00, 0x00, // function section
+        0x0a, 0x0a, 0x02, 0x03, 0x00, 0x01, 0x0b, 0x04, 0x00, 0x10, 0x00, 0x0b, // code
+    ];
+
+    #[test]
+    fn parse_numbers_functions_after_imports() {
+        let mut parser = InstructionParser::new();
+        let instructions = parser.parse(&IMPORT_THEN_TWO_FUNCTIONS).unwrap();
+
+        let indices: Vec<u32> = instructions.iter().map(|i| i.function_index).collect();
+        assert_eq!(indices, vec![1, 1, 2, 2]);
+        assert_eq!(instructions[2].local_index, 0);
+    }
+
+    #[test]
+    fn opcode_histogram_counts_every_body() {
+        let histogram = InstructionParser::opcode_histogram(&IMPORT_THEN_TWO_FUNCTIONS);
+        let expected: BTreeMap<String, u64> = [("call", 1), ("end", 2), ("nop", 1)]
+            .into_iter()
+            .map(|(name, count)| (name.to_string(), count))
+            .collect();
+        assert_eq!(histogram, expected);
+
+        let truncated = &IMPORT_THEN_TWO_FUNCTIONS[..IMPORT_THEN_TWO_FUNCTIONS.len() - 3];
+        let truncated_total: u64 = InstructionParser::opcode_histogram(truncated)
+            .values()
+            .sum();
+        assert!(truncated_total < 4);
+        assert!(InstructionParser::opcode_histogram(b"not wasm").is_empty());
+    }
+
+    #[test]
+    fn operator_names_follow_wasm_text_format() {
+        let memarg = wasmparser::MemArg {
+            align: 0,
+            max_align: 0,
+            offset: 0,
+            memory: 0,
+        };
+        assert_eq!(operator_name(&Operator::I64ExtendI32S), "i64.extend_i32_s");
+        assert_eq!(operator_name(&Operator::I32Const { value: 1 }), "i32.const");
+        assert_eq!(
+            operator_name(&Operator::BrIf { relative_depth: 0 }),
+            "br_if"
+        );
+        assert_eq!(
+            operator_name(&Operator::LocalGet { local_index: 0 }),
+            "local.get"
+        );
+        assert_eq!(operator_name(&Operator::Unreachable), "unreachable");
+        assert_eq!(operator_name(&Operator::I64Extend32S), "i64.extend32_s");
+        assert_eq!(
+            operator_name(&Operator::I32TruncSatF64U),
+            "i32.trunc_sat_f64_u"
+        );
+        assert_eq!(
+            operator_name(&Operator::MemoryCopy {
+                dst_mem: 0,
+                src_mem: 0
+            }),
+            "memory.copy"
+        );
+        assert_eq!(
+            operator_name(&Operator::I32AtomicRmw8AddU { memarg }),
+            "i32.atomic.rmw8.add_u"
+        );
+        assert_eq!(
+            operator_name(&Operator::I64AtomicLoad32U { memarg }),
+            "i64.atomic.load32_u"
+        );
+    }
+
     #[test]
     fn test_control_flow_detection() {
         let call_inst = Instruction::new(0x100, Operator::Call { function_index: 1 }, 0, 0);
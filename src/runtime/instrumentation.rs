@@ -1,4 +1,5 @@
 use crate::runtime::instruction::{Instruction, InstructionParser};
+use crate::utils::wasm::CallGraph;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use walrus::{FunctionId, Module, ModuleConfig};
@@ -6,6 +7,42 @@ use walrus::{FunctionId, Module, ModuleConfig};
 /// Callback function type for instruction hooks
 pub type InstructionHook = Arc<dyn Fn(usize, &Instruction) -> bool + Send + Sync>;
 
+/// Observer of contract instructions, registered with [`Instrumenter::add_hook`].
+///
+/// Downstream crates implement this for custom profiling, e.g. counting only
+/// `i64.mul`. For every invocation the invoker calls, in order:
+///
+/// 1. [`on_invocation_start`](Self::on_invocation_start) before the contract call,
+/// 2. [`on_instruction`](Self::on_instruction) once per instruction of the
+///    invoked function and of the functions it reaches through direct calls,
+///    in code order, after the call returns,
+/// 3. [`on_invocation_end`](Self::on_invocation_end).
+///
+/// This is a static walk, not an execution trace: the host does not report
+/// the executed instruction stream, so loops, untaken branches and
+/// `call_indirect` targets are not reflected. Hooks run in registration order.
+/// Closures `FnMut(usize, &Instruction) -> bool` implement this trait directly.
+pub trait InstructionObserver: Send {
+    /// Observe the instruction at `index` in the parsed module. Returning
+    /// `true` asks the debugger to pause there.
+    fn on_instruction(&mut self, index: usize, instruction: &Instruction) -> bool;
+
+    /// Called before `function` is invoked.
+    fn on_invocation_start(&mut self, _function: &str) {}
+
+    /// Called after the instructions of an invocation of `function` were visited.
+    fn on_invocation_end(&mut self, _function: &str) {}
+}
+
+impl<F> InstructionObserver for F
+where
+    F: FnMut(usize, &Instruction) -> bool + Send,
+{
+    fn on_instruction(&mut self, index: usize, instruction: &Instruction) -> bool {
+        self(index, instruction)
+    }
+}
+
 /// Instruction counter for tracking per-function execution
 #[derive(Debug, Clone)]
 pub struct InstructionCounter {
@@ -81,10 +118,12 @@ impl Default for InstructionCounter {
 pub struct Instrumenter {
     /// Whether instrumentation is enabled
     enabled: bool,
-    /// Instruction hook callback
-    hook: Option<InstructionHook>,
+    /// Registered instruction hooks, in registration order
+    hooks: Vec<Box<dyn InstructionObserver>>,
     /// Parsed instructions for reference
     instructions: Vec<Instruction>,
+    /// Direct-call graph, to find the code an invocation can reach
+    call_graph: CallGraph,
     /// Instruction counter
     pub counter: InstructionCounter,
 }
@@ -94,8 +133,9 @@ impl Instrumenter {
     pub fn new() -> Self {
         Self {
             enabled: false,
-            hook: None,
+            hooks: Vec::new(),
             instructions: Vec::new(),
+            call_graph: CallGraph::default(),
             counter: InstructionCounter::new(),
         }
     }
@@ -115,17 +155,63 @@ impl Instrumenter {
         self.enabled
     }
 
-    /// Set instruction hook callback
+    /// Replace all hooks with a single callback
     pub fn set_hook<F>(&mut self, hook: F)
     where
-        F: Fn(usize, &Instruction) -> bool + Send + Sync + 'static,
+        F: FnMut(usize, &Instruction) -> bool + Send + 'static,
     {
-        self.hook = Some(Arc::new(hook));
+        self.hooks = vec![Box::new(hook)];
+    }
+
+    /// Register an additional instruction hook
+    pub fn add_hook(&mut self, hook: Box<dyn InstructionObserver>) {
+        self.hooks.push(hook);
     }
 
-    /// Remove instruction hook
+    /// Remove all instruction hooks
     pub fn remove_hook(&mut self) {
-        self.hook = None;
+        self.hooks.clear();
+    }
+
+    /// Check if any hook is registered
+    pub fn has_hooks(&self) -> bool {
+        !self.hooks.is_empty()
+    }
+
+    /// Notify hooks that `function` is about to be invoked
+    pub fn begin_invocation(&mut self, function: &str) {
+        for hook in &mut self.hooks {
+            hook.on_invocation_start(function);
+        }
+    }
+
+    /// Feed the instructions `function` can reach to the hooks, then end the
+    /// invocation.
+    ///
+    /// Returns the index of the first instruction a hook asked to pause at.
+    pub fn finish_invocation(&mut self, function: &str) -> Option<usize> {
+        let reachable = self
+            .call_graph
+            .export_index(function)
+            .map(|entry| self.call_graph.reachable_from(entry))
+            .unwrap_or_default();
+        let mut pause_at = None;
+        for (index, instruction) in self
+            .instructions
+            .iter()
+            .enumerate()
+            .filter(|(_, instruction)| reachable.contains(&instruction.function_index))
+        {
+            for hook in &mut self.hooks {
+                if hook.on_instruction(index, instruction) && pause_at.is_none() {
+                    pause_at = Some(index);
+                }
+            }
+        }
+        for hook in &mut self.hooks {
+            hook.on_invocation_end(function);
+        }
+        pause_at
     }
 
     /// Parse instructions from WASM bytecode
@@ -133,6 +219,7 @@ impl Instrumenter {
         let mut parser = InstructionParser::new();
         let instructions = parser.parse(wasm_bytes)?;
         self.instructions = instructions.to_vec();
+        self.call_graph = CallGraph::parse(wasm_bytes).map_err(|e| e.to_string())?;
         Ok(&self.instructions)
     }
 
@@ -146,7 +233,7 @@ impl Instrumenter {
     /// This adds calls to a debug callback function before each instruction
     /// when debug mode is enabled.
     pub fn instrument(&self, wasm_bytes: &[u8]) -> Result<Vec<u8>, String> {
-        if !self.enabled || self.hook.is_none() {
+        if !self.enabled || self.hooks.is_empty() {
             // If not enabled or no hook, return original WASM
             return Ok(wasm_bytes.to_vec());
         }
@@ -194,15 +281,16 @@ impl Instrumenter {
         Ok(())
     }
 
-    /// Call the instruction hook if present
-    pub fn call_hook(&self, instruction_index: usize) -> bool {
-        if let (Some(hook), Some(instruction)) =
-            (&self.hook, self.instructions.get(instruction_index))
-        {
-            hook(instruction_index, instruction)
-        } else {
-            false // Continue execution
+    /// Call the instruction hooks; `true` if any of them asks to pause
+    pub fn call_hook(&mut self, instruction_index: usize) -> bool {
+        let Some(instruction) = self.instructions.get(instruction_index) else {
+            return false; // Continue execution
+        };
+        let mut pause = false;
+        for hook in &mut self.hooks {
+            pause |= hook.on_instruction(instruction_index, instruction);
         }
+        pause
     }
 
     /// Create a simple instrumenter that just parses instructions
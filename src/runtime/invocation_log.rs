@@ -0,0 +1,154 @@
+//! `--log-json` audit log.
+//!
+//! Every invocation appends one JSON object to the log file: the function,
+//! its arguments and result both as base64 XDR and as spec-typed JSON, the
+//! budget consumed, the memory summary and timestamps. Each line is flushed
+//! as soon as it is written, so the file can be followed with `tail -f`.
+
+use crate::inspector::budget::MemorySummary;
+use crate::runtime::result::ExecutionRecord;
+use crate::{DebuggerError, Result};
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+use soroban_env_host::xdr::{Limits, ScVal, WriteXdr};
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// When an invocation started and finished.
+#[derive(Debug, Clone, Copy)]
+pub struct InvocationTiming {
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+}
+
+/// An append-only JSON Lines file with one object per invocation.
+pub struct InvocationLog {
+    path: PathBuf,
+    writer: BufWriter<File>,
+}
+
+impl InvocationLog {
+    /// Open `path` for appending, creating it if it does not exist.
+    pub fn open(path: &Path) -> Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| {
+                DebuggerError::FileError(format!("Failed to open JSON log {:?}: {}", path, e))
+            })?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            writer: BufWriter::new(file),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Write `entry` as one line and flush it.
+    pub fn append(&mut self, entry: &Value) -> Result<()> {
+        writeln!(self.writer, "{}", entry)
+            .and_then(|()| self.writer.flush())
+            .map_err(|e| {
+                DebuggerError::FileError(format!("Failed to write JSON log {:?}: {}", self.path, e))
+                    .into()
+            })
+    }
+}
+
+fn xdr(val: &ScVal) -> Value {
+    val.to_xdr_base64(Limits::none())
+        .map(Value::String)
+        .unwrap_or(Value::Null)
+}
+
+/// The log line for one invocation of the contract in `wasm`.
+pub fn invocation_entry(
+    wasm: &[u8],
+    record: &ExecutionRecord,
+    memory: &MemorySummary,
+    timing: InvocationTiming,
+) -> Value {
+    let result = match &record.result {
+        Ok(val) => json!({
+            "ok": true,
+            "xdr": xdr(val),
+            "json": crate::utils::scval::decode_return_value(wasm, &record.function, val),
+        }),
+        Err(message) => json!({"ok": false, "error": message}),
+    };
+    json!({
+        "function": record.function,
+        "started_at": timing.started_at.to_rfc3339(),
+        "finished_at": timing.finished_at.to_rfc3339(),
+        "duration_ms": (timing.finished_at - timing.started_at).num_milliseconds(),
+        "args": {
+            "xdr": record.args.iter().map(xdr).collect::<Vec<_>>(),
+            "json": crate::utils::scval::decode_args(wasm, &record.function, &record.args),
+        },
+        "result": result,
+        "budget": record.budget,
+        "memory": memory,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspector::budget::BudgetInfo;
+    use std::collections::HashMap;
+
+    #[test]
+    fn entries_are_appended_one_per_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("calls.jsonl");
+        let record = ExecutionRecord {
+            function: "get".to_string(),
+            args: vec![ScVal::U32(7)],
+            result: Err("Contract execution was aborted.".to_string()),
+            budget: BudgetInfo {
+                cpu_instructions: 10,
+                cpu_limit: 100,
+                memory_bytes: 1,
+                memory_limit: 10,
+            },
+            storage_before: HashMap::new(),
+            storage_after: HashMap::new(),
+            duration: std::time::Duration::default(),
+            cpu_insns: 0,
+        };
+        let memory = MemorySummary {
+            peak_memory: 1,
+            allocation_count: 0,
+            total_allocated_bytes: 0,
+            final_memory: 1,
+            initial_memory: 0,
+            top_allocations: Vec::new(),
+        };
+        let now = Utc::now();
+        let timing = InvocationTiming {
+            started_at: now,
+            finished_at: now,
+        };
+
+        for _ in 0..2 {
+            let mut log = InvocationLog::open(&path).unwrap();
+            log.append(&invocation_entry(&[], &record, &memory, timing))
+                .unwrap();
+        }
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<Value> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["args"]["json"], json!([7]));
+        assert_eq!(lines[0]["args"]["xdr"][0], "AAAAAwAAAAc=");
+        assert_eq!(lines[1]["result"]["ok"], false);
+        assert_eq!(lines[1]["budget"]["cpu_instructions"], 10);
+    }
+}
@@ -7,17 +7,93 @@
 //! - Post-invocation result formatting via [`super::result`].
 
 use crate::debugger::error_db::ErrorDatabase;
-use crate::inspector::budget::{BudgetInspector, MemoryTracker};
+use crate::inspector::budget::{BudgetInspector, MemorySummary, MemoryTracker};
+use crate::runtime::executor::CancellationToken;
+use crate::runtime::instrumentation::Instrumenter;
 use crate::runtime::result::{format_invocation_result, ExecutionRecord};
 use crate::{DebuggerError, Result};
 use indicatif::{ProgressBar, ProgressStyle};
 use soroban_env_host::xdr::ScVal;
+use soroban_env_host::Host;
 use soroban_env_host::TryFromVal; // needed for ScVal::try_from_val
 use soroban_sdk::{Address, Env, InvokeError, Symbol, Val, Vec as SorobanVec};
 use std::collections::HashMap;
 use tracing::info;
 
+/// Optional CPU/memory ceiling applied to a single invocation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BudgetLimits {
+    pub cpu_insns: Option<u64>,
+    pub mem_bytes: Option<u64>,
+}
+
+impl BudgetLimits {
+    fn is_set(&self) -> bool {
+        self.cpu_insns.is_some() || self.mem_bytes.is_some()
+    }
+
+    /// Reset the host budget so the invocation starts from zero with these limits.
+    /// A dimension left as `None` keeps its current limit.
+    fn apply(&self, host: &Host) -> Result<()> {
+        let budget = host.budget_cloned();
+        let cpu = self.cpu_insns.unwrap_or_else(|| {
+            let consumed = budget.get_cpu_insns_consumed().unwrap_or(0);
+            consumed.saturating_add(budget.get_cpu_insns_remaining().unwrap_or(u64::MAX))
+        });
+        let mem = self.mem_bytes.unwrap_or_else(|| {
+            let consumed = budget.get_mem_bytes_consumed().unwrap_or(0);
+            consumed.saturating_add(budget.get_mem_bytes_remaining().unwrap_or(u64::MAX))
+        });
+        budget.reset_limits(cpu, mem).map_err(|e| {
+            DebuggerError::ExecutionError(format!("Failed to set budget limits: {:?}", e)).into()
+        })
+    }
+
+    /// Describe which configured limit has been used up, if any.
+    fn exceeded(&self, host: &Host) -> Option<String> {
+        let budget = host.budget_cloned();
+        if let Some(limit) = self.cpu_insns {
+            if budget.get_cpu_insns_remaining().unwrap_or(0) == 0 {
+                return Some(format!("CPU instruction limit of {} exceeded", limit));
+            }
+        }
+        if let Some(limit) = self.mem_bytes {
+            if budget.get_mem_bytes_remaining().unwrap_or(0) == 0 {
+                return Some(format!("memory limit of {} bytes exceeded", limit));
+            }
+        }
+        None
+    }
+}
+
+/// Describe a call that used up the whole host CPU budget without a user limit,
+/// which almost always means the contract is looping forever.
+fn runaway_reason(host: &Host) -> Option<String> {
+    let budget = host.budget_cloned();
+    if budget.get_cpu_insns_remaining().unwrap_or(1) != 0 {
+        return None;
+    }
+    Some(format!(
+        "runaway execution: the host CPU budget was exhausted after {} instructions; \
+         the contract may be stuck in an infinite loop",
+        budget.get_cpu_insns_consumed().unwrap_or(0)
+    ))
+}
+
+/// Outcome of [`invoke_function`]. `record` and `memory` are filled in even
+/// when the contract call itself failed.
+pub struct Invocation {
+    pub display: Result<String>,
+    pub record: ExecutionRecord,
+    pub memory: MemorySummary,
+    /// Instruction an instruction hook asked to pause at.
+    pub hook_pause: Option<usize>,
+}
+
 /// Invoke `function` on the already-registered contract at `contract_address`.
+///
+/// With `quiet_budget`, the budget and memory summary is not displayed.
+#[allow(clippy::too_many_arguments)]
 #[tracing::instrument(skip_all, fields(function = function))]
 pub fn invoke_function(
     env: &Env,
@@ -25,9 +101,13 @@ pub fn invoke_function(
     error_db: &ErrorDatabase,
     function: &str,
     parsed_args: Vec<Val>,
-    _timeout_secs: u64,
+    timeout_secs: u64,
+    budget_limits: BudgetLimits,
+    quiet_budget: bool,
+    instrumenter: &mut Instrumenter,
+    cancellation: &CancellationToken,
     storage_fn: impl Fn() -> Result<HashMap<String, String>>,
-) -> Result<(String, ExecutionRecord)> {
+) -> Result<Invocation> {
     info!("Executing function: {}", function);
 
     let mut memory_tracker = MemoryTracker::new(
@@ -73,14 +153,57 @@ pub fn invoke_function(
         })?;
     memory_tracker.record_snapshot(env.host(), "invoke:convert_args");
 
+    if budget_limits.is_set() {
+        budget_limits
+            .apply(env.host())
+            .inspect_err(|_| spinner.finish_and_clear())?;
+    }
+
     // ── The actual call ───────────────────────────────────────────────────────
-    let budget_before = BudgetInspector::get_cpu_usage(env.host());
-    let invocation_result =
-        env.try_invoke_contract::<Val, InvokeError>(contract_address, &func_symbol, args_vec);
+    // The host resets the budget counters when a top-level invocation starts,
+    // so whatever is consumed afterwards belongs to this call alone.
+    instrumenter.begin_invocation(function);
+    let started = std::time::Instant::now();
+    // The SDK escalates budget exhaustion to a panic instead of an error.
+    let invocation_result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        env.try_invoke_contract::<Val, InvokeError>(contract_address, &func_symbol, args_vec)
+    }))
+    .unwrap_or(Err(Ok(InvokeError::Abort)));
+    let duration = started.elapsed();
+    let hook_pause = instrumenter.finish_invocation(function);
+    let cpu_insns = env
+        .host()
+        .budget_cloned()
+        .get_cpu_insns_consumed()
+        .unwrap_or(0);
+    let execution_budget = BudgetInspector::get_cpu_usage(env.host());
     memory_tracker.record_snapshot(env.host(), "invoke:invoke");
 
     spinner.finish_and_clear();
 
+    if invocation_result.is_err() {
+        let reason = budget_limits
+            .exceeded(env.host())
+            .or_else(|| runaway_reason(env.host()));
+        if let Some(reason) = reason {
+            // Lift the ceiling again so the session can keep inspecting the host.
+            let _ = env.host().budget_cloned().reset_unlimited();
+            return Err(DebuggerError::BudgetExceeded(reason).into());
+        }
+    }
+
+    // The watchdog only flags the overrun; the caller gets an error it can
+    // recover from instead of the process being torn down.
+    if cancellation.is_cancelled() {
+        return Err(DebuggerError::Timeout(format!(
+            "{} did not finish within {}s ({} ms elapsed)",
+            function,
+            timeout_secs,
+            duration.as_millis()
+        ))
+        .into());
+    }
+
     // Capture storage state after the call.
     let storage_after = storage_fn()?;
     memory_tracker.record_snapshot(env.host(), "invoke:storage_after");
@@ -91,11 +214,11 @@ pub fn invoke_function(
     memory_tracker.record_snapshot(env.host(), "invoke:result_convert");
 
     // Display budget / memory usage.
-    let budget_after = BudgetInspector::get_cpu_usage(env.host());
-    let execution_budget = budget_after.delta_from(&budget_before);
-    crate::inspector::BudgetInspector::display(env.host());
     let memory_summary = memory_tracker.finalize(env.host());
-    memory_summary.display();
+    if !quiet_budget {
+        crate::inspector::BudgetInspector::display(env.host());
+        memory_summary.display();
+    }
 
     let record = ExecutionRecord {
         function: function.to_string(),
@@ -104,7 +227,14 @@ pub fn invoke_function(
         budget: execution_budget,
         storage_before,
         storage_after,
+        duration,
+        cpu_insns,
     };
 
-    display_result.map(|s| (s, record))
+    Ok(Invocation {
+        display: display_result,
+        record,
+        memory: memory_summary,
+        hook_pause,
+    })
 }
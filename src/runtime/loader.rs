@@ -3,18 +3,25 @@
 //! This module is responsible for:
 //! - Reading and validating WASM bytes.
 //! - Bootstrapping a [`soroban_sdk::Env`] in debug mode.
-//! - Registering the contract with the host.
+//! - Registering the contract with the host (running its `__constructor`, if any),
+//!   or finding it in a saved ledger snapshot.
 //! - Loading the custom error catalogue from the contract spec.
+//! - Seeding ledger state (timestamp, sequence, network ID) before invocation.
 //!
 //! It intentionally has **no** knowledge of argument parsing or invocation
 //! so it can be unit-tested with a minimal WASM fixture.
 
 use crate::debugger::error_db::ErrorDatabase;
+use crate::output::WarningCollector;
 use crate::{DebuggerError, Result};
 use indicatif::{ProgressBar, ProgressStyle};
+use soroban_env_host::xdr::{ContractExecutable, Hash, LedgerEntryData, ScAddress, ScVal};
 use soroban_env_host::DiagnosticLevel;
-use soroban_sdk::{Address, Env};
-use tracing::{info, warn};
+use soroban_ledger_snapshot::LedgerSnapshot;
+use soroban_sdk::testutils::Ledger as _;
+use soroban_sdk::{Address, Env, TryFromVal, Val};
+use std::path::Path;
+use tracing::info;
 
 /// Output of a successful [`load_contract`] call.
 pub struct LoadedContract {
@@ -29,7 +36,94 @@ pub struct LoadedContract {
 /// ensures it is always cleared — even if this function returns an error.
 #[tracing::instrument(skip_all)]
 pub fn load_contract(wasm: &[u8]) -> Result<LoadedContract> {
+    load_contract_into(Env::default(), wasm, None, None, None)
+}
+
+/// Like [`load_contract`], but register `wasm` at `contract_id` (see
+/// [`parse_contract_id`]) instead of a generated address, so callers with a
+/// hardcoded callee address reach it.
+#[tracing::instrument(skip(wasm))]
+pub fn load_contract_at(wasm: &[u8], contract_id: &str) -> Result<LoadedContract> {
+    load_contract_into(Env::default(), wasm, None, Some(contract_id), None)
+}
+
+/// Like [`load_contract`], but pass `args_json` (a JSON array checked against the
+/// contract's `__constructor` spec) to the constructor at registration, optionally
+/// registering at `contract_id`.
+#[tracing::instrument(skip(wasm))]
+pub fn load_contract_with_constructor(
+    wasm: &[u8],
+    contract_id: Option<&str>,
+    args_json: &str,
+) -> Result<LoadedContract> {
+    load_contract_into(Env::default(), wasm, None, contract_id, Some(args_json))
+}
+
+/// Parse a contract id given as a `C...` strkey or as the contract hash in hex.
+pub fn parse_contract_id(env: &Env, contract_id: &str) -> Result<Address> {
+    let contract_id = contract_id.trim();
+    let address = contract_sc_address(contract_id)?;
+    Address::try_from_val(env, &ScVal::Address(address)).map_err(|e| {
+        DebuggerError::InvalidArguments(format!("Invalid contract id '{}': {:?}", contract_id, e))
+            .into()
+    })
+}
+
+/// The XDR address for a contract id accepted by [`parse_contract_id`].
+pub fn contract_sc_address(contract_id: &str) -> Result<ScAddress> {
+    let contract_id = contract_id.trim();
+    match hex::decode(contract_id) {
+        Ok(bytes) => <[u8; 32]>::try_from(bytes)
+            .ok()
+            .map(|hash| ScAddress::Contract(Hash(hash))),
+        Err(_) => contract_id
+            .parse::<ScAddress>()
+            .ok()
+            .filter(|address| matches!(address, ScAddress::Contract(_))),
+    }
+    .ok_or_else(|| {
+        DebuggerError::InvalidArguments(format!(
+            "Invalid contract id '{}': expected a C... strkey or a 64-character hex contract hash",
+            contract_id
+        ))
+        .into()
+    })
+}
+
+/// Like [`load_contract`], but start from a saved ledger instead of an empty one.
+///
+/// If the snapshot already holds an instance of `wasm`, that contract (and its
+/// storage) is reused; otherwise `wasm` is registered as a new contract.
+#[tracing::instrument(skip_all)]
+pub fn load_contract_from_snapshot(
+    wasm: &[u8],
+    snapshot: LedgerSnapshot,
+) -> Result<LoadedContract> {
+    let existing = find_contract_instance(&snapshot, wasm);
+    if existing.is_none() {
+        WarningCollector::record(
+            "snapshot_contract_missing",
+            "Ledger snapshot has no instance of this contract; registering it as a new contract",
+        );
+    }
+    load_contract_into(
+        Env::from_ledger_snapshot(snapshot),
+        wasm,
+        existing,
+        None,
+        None,
+    )
+}
+
+fn load_contract_into(
+    env: Env,
+    wasm: &[u8],
+    existing: Option<ScAddress>,
+    contract_id: Option<&str>,
+    constructor_args_json: Option<&str>,
+) -> Result<LoadedContract> {
     info!("Initializing contract executor");
+    crate::utils::wasm::validate_soroban_contract(wasm)?;
 
     let pb = ProgressBar::new(100);
     pb.set_style(
@@ -50,7 +144,6 @@ pub fn load_contract(wasm: &[u8]) -> Result<LoadedContract> {
     }
     let guard = ProgressGuard(pb);
 
-    let env = Env::default();
     env.host()
         .set_diagnostic_level(DiagnosticLevel::Debug)
         .map_err(|e| {
@@ -60,13 +153,28 @@ pub fn load_contract(wasm: &[u8]) -> Result<LoadedContract> {
     guard.0.set_position(50);
     guard.0.set_message("Registering contract...");
 
-    // `env.register` is the current, non-deprecated API in soroban-sdk ≥ 0.0.18.
-    let contract_address = env.register(wasm, ());
+    let contract_address = match (existing, contract_id) {
+        (Some(address), _) => {
+            Address::try_from_val(&env, &ScVal::Address(address)).map_err(|e| {
+                DebuggerError::ExecutionError(format!(
+                    "Invalid contract address in snapshot: {:?}",
+                    e
+                ))
+            })?
+        }
+        (None, Some(contract_id)) => {
+            let address = parse_contract_id(&env, contract_id)?;
+            let args = constructor_args(&env, wasm, constructor_args_json)?;
+            env.register_at(&address, wasm, args)
+        }
+        // `env.register` is the current, non-deprecated API in soroban-sdk ≥ 0.0.18.
+        (None, None) => {
+            let args = constructor_args(&env, wasm, constructor_args_json)?;
+            env.register(wasm, args)
+        }
+    };
 
-    let mut error_db = ErrorDatabase::new();
-    if let Err(e) = error_db.load_custom_errors_from_wasm(wasm) {
-        warn!("Failed to load custom errors from spec: {}", e);
-    }
+    let error_db = load_error_db(wasm);
 
     guard.0.set_position(100);
     guard.0.set_message("Contract loaded successfully");
@@ -77,3 +185,247 @@ pub fn load_contract(wasm: &[u8]) -> Result<LoadedContract> {
         error_db,
     })
 }
+
+/// Export name of a contract constructor.
+const CONSTRUCTOR: &str = "__constructor";
+
+/// Arguments for the contract's `__constructor`, parsed from `args_json` and
+/// checked against its spec entry.
+///
+/// Without `args_json` this is empty, which is only valid for contracts whose
+/// constructor (if any) takes no arguments.
+fn constructor_args(
+    env: &Env,
+    wasm: &[u8],
+    args_json: Option<&str>,
+) -> Result<soroban_sdk::Vec<Val>> {
+    let signatures = crate::utils::wasm::parse_function_signatures(wasm)?;
+    let constructor = signatures.iter().find(|sig| sig.name == CONSTRUCTOR);
+
+    let Some(args_json) = args_json else {
+        if let Some(sig) = constructor.filter(|sig| !sig.params.is_empty()) {
+            return Err(DebuggerError::InvalidArguments(format!(
+                "Contract constructor takes {} argument(s) ({}); pass them with --constructor-args",
+                sig.params.len(),
+                sig.params_display()
+            ))
+            .into());
+        }
+        return Ok(soroban_sdk::Vec::new(env));
+    };
+
+    if constructor.is_none() {
+        return Err(DebuggerError::InvalidArguments(
+            "--constructor-args was given, but the contract has no __constructor".to_string(),
+        )
+        .into());
+    }
+    let args =
+        crate::runtime::parser::parse_args_checked(env, &signatures, CONSTRUCTOR, args_json)?;
+    Ok(soroban_sdk::Vec::from_slice(env, &args))
+}
+
+/// Ledger fields to set before invocation (`run --ledger-timestamp` and friends).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LedgerSeed {
+    /// UNIX seconds.
+    pub timestamp: Option<u64>,
+    pub sequence: Option<u32>,
+    pub network_id: Option<[u8; 32]>,
+}
+
+impl LedgerSeed {
+    /// Parse a network ID given as 64 hex characters, or hash a network
+    /// passphrase the way the network does (`sha256(passphrase)`).
+    pub fn parse_network_id(value: &str) -> [u8; 32] {
+        use sha2::{Digest, Sha256};
+
+        let mut id = [0u8; 32];
+        if value.len() == 64 && hex::decode_to_slice(value, &mut id).is_ok() {
+            return id;
+        }
+        Sha256::digest(value.as_bytes()).into()
+    }
+
+    /// Reject a snapshot taken on a different network than the requested `network_id`.
+    pub fn check_snapshot_network(&self, snapshot: &LedgerSnapshot) -> Result<()> {
+        match self.network_id {
+            Some(network_id) if network_id != snapshot.network_id => {
+                Err(DebuggerError::InvalidArguments(format!(
+                    "Ledger snapshot was taken on network {} but --ledger-network-id is {}",
+                    hex::encode(snapshot.network_id),
+                    hex::encode(network_id)
+                ))
+                .into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Current wall-clock time in UNIX seconds, the default ledger timestamp.
+    pub fn wall_clock_timestamp() -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0)
+    }
+
+    /// Write the seed into `env`'s ledger; unset fields are left alone.
+    pub fn apply(&self, env: &Env) {
+        env.ledger().with_mut(|l| {
+            if let Some(timestamp) = self.timestamp {
+                l.timestamp = timestamp;
+            }
+            if let Some(sequence) = self.sequence {
+                l.sequence_number = sequence;
+            }
+            if let Some(network_id) = self.network_id {
+                l.network_id = network_id;
+            }
+        });
+        info!(
+            "Seeded ledger: timestamp={}, sequence={}",
+            env.ledger().timestamp(),
+            env.ledger().sequence()
+        );
+    }
+}
+
+/// Address of the contract instance in `snapshot` whose code hash matches `wasm`.
+fn find_contract_instance(snapshot: &LedgerSnapshot, wasm: &[u8]) -> Option<ScAddress> {
+    use sha2::{Digest, Sha256};
+
+    let hash: [u8; 32] = Sha256::digest(wasm).into();
+    snapshot.ledger_entries.iter().find_map(|(_, (entry, _))| {
+        let LedgerEntryData::ContractData(data) = &entry.data else {
+            return None;
+        };
+        let ScVal::ContractInstance(instance) = &data.val else {
+            return None;
+        };
+        matches!(&instance.executable, ContractExecutable::Wasm(code) if code.0 == hash)
+            .then(|| data.contract.clone())
+    })
+}
+
+/// Read a ledger snapshot written by `run --snapshot-out`.
+pub fn read_ledger_snapshot(path: &Path) -> Result<LedgerSnapshot> {
+    LedgerSnapshot::read_file(path).map_err(|e| {
+        DebuggerError::FileError(format!("Failed to read ledger snapshot {:?}: {}", path, e)).into()
+    })
+}
+
+/// Build the error catalogue for `wasm`, recording a warning if the contract
+/// spec cannot be read. Standard errors remain available either way.
+pub fn load_error_db(wasm: &[u8]) -> ErrorDatabase {
+    let mut error_db = ErrorDatabase::new();
+    if let Err(e) = error_db.load_custom_errors_from_wasm(wasm) {
+        WarningCollector::record(
+            "custom_errors_unavailable",
+            format!("Failed to load custom errors from spec: {}", e),
+        );
+    }
+    error_db
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unreadable_spec_records_warning() {
+        WarningCollector::clear();
+        let db = load_error_db(b"not a wasm module");
+
+        let warnings = WarningCollector::take();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "custom_errors_unavailable");
+        assert!(warnings[0].message.contains("custom errors"));
+        // Standard errors are still usable.
+        assert!(db.lookup(6).is_some());
+    }
+
+    #[test]
+    fn contract_id_accepts_strkey_and_hex_hash() {
+        let env = Env::default();
+        let strkey = "CAAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQC526";
+        let from_strkey = parse_contract_id(&env, strkey).unwrap();
+        let from_hex = parse_contract_id(&env, &"01".repeat(32)).unwrap();
+        assert_eq!(from_strkey, from_hex);
+        assert_eq!(ScAddress::from(&from_strkey).to_string(), strkey);
+
+        let account = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+        for invalid in [account, "abcd", "not-a-contract"] {
+            let err = parse_contract_id(&env, invalid).unwrap_err().to_string();
+            assert!(err.contains("Invalid contract id"), "{err}");
+        }
+    }
+
+    #[test]
+    fn ledger_seed_sets_requested_fields() {
+        let env = Env::default();
+        LedgerSeed {
+            timestamp: Some(1_700_000_000),
+            sequence: Some(42),
+            network_id: Some(LedgerSeed::parse_network_id(&"ab".repeat(32))),
+        }
+        .apply(&env);
+
+        assert_eq!(env.ledger().timestamp(), 1_700_000_000);
+        assert_eq!(env.ledger().sequence(), 42);
+        assert_eq!(env.ledger().get().network_id, [0xab; 32]);
+    }
+
+    #[test]
+    fn ledger_seed_leaves_unset_fields_alone() {
+        let env = Env::default();
+        env.ledger().with_mut(|l| {
+            l.timestamp = 1_600_000_000;
+            l.sequence_number = 7;
+        });
+        LedgerSeed::default().apply(&env);
+
+        assert_eq!(env.ledger().timestamp(), 1_600_000_000);
+        assert_eq!(env.ledger().sequence(), 7);
+        // Anything after 2023 rules out the host's zero default.
+        assert!(LedgerSeed::wall_clock_timestamp() > 1_672_531_200);
+    }
+
+    #[test]
+    fn snapshot_network_must_match_requested_network() {
+        let env = Env::default();
+        LedgerSeed {
+            network_id: Some([0xab; 32]),
+            ..LedgerSeed::default()
+        }
+        .apply(&env);
+        let snapshot = env.to_ledger_snapshot();
+
+        let same = LedgerSeed {
+            network_id: Some([0xab; 32]),
+            ..LedgerSeed::default()
+        };
+        assert!(same.check_snapshot_network(&snapshot).is_ok());
+        assert!(LedgerSeed::default()
+            .check_snapshot_network(&snapshot)
+            .is_ok());
+
+        let other = LedgerSeed {
+            network_id: Some(LedgerSeed::parse_network_id(
+                "Public Global Stellar Network ; September 2015",
+            )),
+            ..LedgerSeed::default()
+        };
+        let err = other.check_snapshot_network(&snapshot).unwrap_err();
+        assert!(err.to_string().contains(&"ab".repeat(32)), "{err}");
+    }
+
+    #[test]
+    fn network_id_hashes_passphrases() {
+        let id = LedgerSeed::parse_network_id("Test SDF Network ; September 2015");
+        assert_eq!(
+            hex::encode(id),
+            "cee0302d59844d32bdca915c8203dd44b33fbb7edc19051ea37abedf28ecd472"
+        );
+    }
+}
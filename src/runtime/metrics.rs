@@ -0,0 +1,175 @@
+//! `--metrics-out` Prometheus metrics.
+//!
+//! Totals accumulate over every invocation of a session (one `run`, a batch or
+//! a script) and are written in the Prometheus text exposition format, so CI
+//! can scrape or archive them to track regressions. Budget totals only count
+//! invocations that completed, since a failed call leaves no execution record.
+
+use crate::inspector::budget::BudgetInfo;
+use crate::{DebuggerError, Result};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+/// Invocation counters and budget totals for one session.
+#[derive(Debug, Clone, Default)]
+pub struct SessionMetrics {
+    invocations: u64,
+    failures: BTreeMap<String, u64>,
+    cpu_insns: u64,
+    mem_bytes: u64,
+    /// Completed invocations and their summed cpu-insn, per function.
+    functions: BTreeMap<String, (u64, u64)>,
+}
+
+/// The `error_kind` of a failed invocation, as in `--json-errors` output.
+pub fn error_kind(error: &miette::Report) -> &'static str {
+    error
+        .downcast_ref::<DebuggerError>()
+        .map(DebuggerError::kind)
+        .unwrap_or("Other")
+}
+
+impl SessionMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count one invocation of `function`. `budget` is what a completed call
+    /// consumed; `error` marks a failed one.
+    pub fn record(
+        &mut self,
+        function: &str,
+        budget: Option<&BudgetInfo>,
+        error: Option<&miette::Report>,
+    ) {
+        self.invocations += 1;
+        if let Some(error) = error {
+            *self
+                .failures
+                .entry(error_kind(error).to_string())
+                .or_default() += 1;
+        }
+        if let Some(budget) = budget {
+            self.cpu_insns += budget.cpu_instructions;
+            self.mem_bytes += budget.memory_bytes;
+            let (calls, cpu) = self.functions.entry(function.to_string()).or_default();
+            *calls += 1;
+            *cpu += budget.cpu_instructions;
+        }
+    }
+
+    /// The metrics in Prometheus text format.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let mut metric = |name: &str, kind: &str, help: &str, samples: Vec<(String, String)>| {
+            let _ = writeln!(out, "# HELP {} {}", name, help);
+            let _ = writeln!(out, "# TYPE {} {}", name, kind);
+            for (labels, value) in samples {
+                let _ = writeln!(out, "{}{} {}", name, labels, value);
+            }
+        };
+
+        metric(
+            "soroban_debug_invocations_total",
+            "counter",
+            "Contract invocations run.",
+            vec![(String::new(), self.invocations.to_string())],
+        );
+        metric(
+            "soroban_debug_failures_total",
+            "counter",
+            "Failed invocations by error kind.",
+            self.failures
+                .iter()
+                .map(|(kind, count)| (label("kind", kind), count.to_string()))
+                .collect(),
+        );
+        metric(
+            "soroban_debug_cpu_insns_total",
+            "counter",
+            "CPU instructions consumed by completed invocations.",
+            vec![(String::new(), self.cpu_insns.to_string())],
+        );
+        metric(
+            "soroban_debug_mem_bytes_total",
+            "counter",
+            "Memory bytes allocated by completed invocations.",
+            vec![(String::new(), self.mem_bytes.to_string())],
+        );
+        metric(
+            "soroban_debug_function_cpu_insns_avg",
+            "gauge",
+            "Average CPU instructions per completed invocation of a function.",
+            self.functions
+                .iter()
+                .map(|(function, (calls, cpu))| {
+                    let average = *cpu as f64 / (*calls).max(1) as f64;
+                    (label("function", function), format!("{:.1}", average))
+                })
+                .collect(),
+        );
+        out
+    }
+
+    /// Write [`render`](Self::render) to `path`, replacing its contents.
+    pub fn write_to(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.render()).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to write metrics to {:?}: {}", path, e)).into()
+        })
+    }
+}
+
+/// `{name="value"}` with the value escaped as the exposition format requires.
+fn label(name: &str, value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("{{{}=\"{}\"}}", name, escaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn budget(cpu: u64, mem: u64) -> BudgetInfo {
+        BudgetInfo {
+            cpu_instructions: cpu,
+            cpu_limit: 0,
+            memory_bytes: mem,
+            memory_limit: 0,
+        }
+    }
+
+    #[test]
+    fn render_totals_failures_and_per_function_averages() {
+        let mut metrics = SessionMetrics::new();
+        metrics.record("increment", Some(&budget(100, 10)), None);
+        metrics.record("increment", Some(&budget(300, 30)), None);
+        let error = miette::Report::new(DebuggerError::BudgetExceeded("cpu".to_string()));
+        metrics.record("heavy", None, Some(&error));
+
+        let text = metrics.render();
+        assert!(
+            text.contains("soroban_debug_invocations_total 3\n"),
+            "{text}"
+        );
+        assert!(text.contains("soroban_debug_failures_total{kind=\"BudgetExceeded\"} 1\n"));
+        assert!(text.contains("soroban_debug_cpu_insns_total 400\n"));
+        assert!(text.contains("soroban_debug_mem_bytes_total 40\n"));
+        assert!(
+            text.contains("soroban_debug_function_cpu_insns_avg{function=\"increment\"} 200.0\n")
+        );
+        assert!(text.contains("# TYPE soroban_debug_function_cpu_insns_avg gauge\n"));
+    }
+
+    #[test]
+    fn label_values_are_escaped() {
+        let mut metrics = SessionMetrics::new();
+        metrics.record("a\"b", Some(&budget(10, 1)), None);
+
+        let text = metrics.render();
+        assert!(text.contains("{function=\"a\\\"b\"} 10.0\n"), "{text}");
+    }
+}
@@ -1,7 +1,11 @@
+use crate::utils::scval::scval_to_json;
 use crate::utils::ArgumentParser;
 use crate::{DebuggerError, Result};
+use serde_json::Value;
+use soroban_env_host::xdr::{ScErrorCode, ScErrorType, ScVal};
+use soroban_env_host::TryFromVal;
 use soroban_env_host::{ContractFunctionSet, Host, Symbol as HostSymbol, Val as HostVal};
-use soroban_sdk::{Env, Val};
+use soroban_sdk::{Env, Error, Val};
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
@@ -13,11 +17,24 @@ pub struct MockKey {
     pub function: String,
 }
 
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    pub raw: String,
+    pub val: Val,
+}
+
 #[derive(Clone, Debug)]
 pub struct MockSpec {
     pub key: MockKey,
-    pub return_raw: String,
-    pub return_val: Val,
+    /// Responses in call order; the last one repeats once the others are used up.
+    pub responses: Vec<MockResponse>,
+}
+
+impl MockSpec {
+    /// Response for the call that follows `served` earlier mocked calls.
+    pub fn response(&self, served: usize) -> &MockResponse {
+        &self.responses[served.min(self.responses.len() - 1)]
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -25,6 +42,8 @@ pub struct MockCallLogEntry {
     pub contract_id: String,
     pub function: String,
     pub args_count: usize,
+    /// Arguments the caller passed, decoded from host values.
+    pub args: Vec<ScVal>,
     pub mocked: bool,
     pub returned: Option<String>,
 }
@@ -57,26 +76,35 @@ impl MockRegistry {
         &mut self,
         contract_id: &str,
         function: &str,
-        args_count: usize,
+        args: Vec<ScVal>,
     ) -> Option<Val> {
+        let args_count = args.len();
         let key = MockKey {
             contract_id: contract_id.to_string(),
             function: function.to_string(),
         };
         if let Some(spec) = self.entries.get(&key) {
+            let served = self
+                .calls
+                .iter()
+                .filter(|c| c.mocked && c.contract_id == contract_id && c.function == function)
+                .count();
+            let response = spec.response(served);
             self.calls.push(MockCallLogEntry {
                 contract_id: contract_id.to_string(),
                 function: function.to_string(),
                 args_count,
+                args,
                 mocked: true,
-                returned: Some(spec.return_raw.clone()),
+                returned: Some(response.raw.clone()),
             });
-            return Some(spec.return_val);
+            return Some(response.val);
         }
         self.calls.push(MockCallLogEntry {
             contract_id: contract_id.to_string(),
             function: function.to_string(),
             args_count,
+            args,
             mocked: false,
             returned: None,
         });
@@ -87,12 +115,34 @@ impl MockRegistry {
         &self.calls
     }
 
+    /// Fail unless `contract_id.function` was called exactly `times` times.
+    pub fn assert_called(&self, contract_id: &str, function: &str, times: usize) -> Result<()> {
+        let actual = self
+            .calls
+            .iter()
+            .filter(|c| c.contract_id == contract_id && c.function == function)
+            .count();
+        if actual != times {
+            return Err(DebuggerError::ExecutionError(format!(
+                "Expected {contract_id}.{function} to be called {times} time(s), but it was called {actual} time(s)"
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
     fn parse_spec(parser: &ArgumentParser, spec: &str) -> Result<MockSpec> {
         let (signature, return_raw) = spec.split_once('=').ok_or_else(|| {
             DebuggerError::InvalidArguments(format!(
                 "Invalid mock '{spec}'. Expected CONTRACT_ID.function=return_value"
             ))
         })?;
+        // `=>[v1, v2, ...]` scripts one response per call; `=>err:N` and
+        // `=>abort` make the call fail instead.
+        let (return_raw, is_sequence) = match return_raw.strip_prefix('>') {
+            Some(sequence) => (sequence, true),
+            None => (return_raw, false),
+        };
         let (contract_id, function) = signature.rsplit_once('.').ok_or_else(|| {
             DebuggerError::InvalidArguments(format!(
                 "Invalid mock signature '{signature}'. Expected CONTRACT_ID.function"
@@ -108,14 +158,48 @@ impl MockRegistry {
             .into());
         }
 
-        let parsed = parser
-            .parse_args_string(return_raw)
-            .map_err(|e| DebuggerError::InvalidArguments(e.to_string()))?;
-        if parsed.len() != 1 {
-            return Err(DebuggerError::InvalidArguments(format!(
-                "Mock '{spec}' must parse to exactly one return value"
-            ))
-            .into());
+        if is_sequence {
+            if let Some(response) = Self::parse_failure(spec, return_raw)? {
+                return Ok(MockSpec {
+                    key: MockKey {
+                        contract_id: contract_id.to_string(),
+                        function: function.to_string(),
+                    },
+                    responses: vec![response],
+                });
+            }
+        }
+
+        let raw_values = if is_sequence {
+            let items = match serde_json::from_str::<Value>(return_raw) {
+                Ok(Value::Array(items)) if !items.is_empty() => items,
+                _ => {
+                    return Err(DebuggerError::InvalidArguments(format!(
+                        "Invalid mock '{spec}'. Expected [v1, ...], err:CODE or abort after '=>'"
+                    ))
+                    .into())
+                }
+            };
+            items.iter().map(Value::to_string).collect()
+        } else {
+            vec![return_raw.to_string()]
+        };
+
+        let mut responses = Vec::with_capacity(raw_values.len());
+        for raw in raw_values {
+            let parsed = parser
+                .parse_args_string(&raw)
+                .map_err(|e| DebuggerError::InvalidArguments(e.to_string()))?;
+            if parsed.len() != 1 {
+                return Err(DebuggerError::InvalidArguments(format!(
+                    "Mock '{spec}' must parse to exactly one return value per response"
+                ))
+                .into());
+            }
+            responses.push(MockResponse {
+                raw,
+                val: parsed[0],
+            });
         }
 
         Ok(MockSpec {
@@ -123,10 +207,30 @@ impl MockRegistry {
                 contract_id: contract_id.to_string(),
                 function: function.to_string(),
             },
-            return_raw: return_raw.to_string(),
-            return_val: parsed[0],
+            responses,
         })
     }
+
+    /// Parse `err:N` or `abort` into a response carrying an error value, which
+    /// the host turns into a failed cross-contract call.
+    fn parse_failure(spec: &str, raw: &str) -> Result<Option<MockResponse>> {
+        let error = if raw == "abort" {
+            Error::from_type_and_code(ScErrorType::Context, ScErrorCode::InvalidAction)
+        } else if let Some(code) = raw.strip_prefix("err:") {
+            let code = code.trim().parse::<u32>().map_err(|_| {
+                DebuggerError::InvalidArguments(format!(
+                    "Invalid mock '{spec}'. Expected err:CODE with a u32 contract error code"
+                ))
+            })?;
+            Error::from_contract_error(code)
+        } else {
+            return Ok(None);
+        };
+        Ok(Some(MockResponse {
+            raw: raw.to_string(),
+            val: Val::from(error),
+        }))
+    }
 }
 
 pub struct MockContractDispatcher {
@@ -148,7 +252,7 @@ impl MockContractDispatcher {
 }
 
 impl ContractFunctionSet for MockContractDispatcher {
-    fn call(&self, func: &HostSymbol, _host: &Host, args: &[HostVal]) -> Option<HostVal> {
+    fn call(&self, func: &HostSymbol, host: &Host, args: &[HostVal]) -> Option<HostVal> {
         let debug_str = format!("{:?}", func);
         let function = if let Some(s) = debug_str.strip_prefix("Symbol(") {
             s.trim_end_matches(')').to_string()
@@ -163,7 +267,12 @@ impl ContractFunctionSet for MockContractDispatcher {
             Ok(g) => g,
             Err(_) => return None,
         };
-        let resolved = guard.resolve_call(&self.contract_id, &function, args.len());
+        // Keep argument positions intact even if a value fails to decode.
+        let decoded = args
+            .iter()
+            .map(|v| ScVal::try_from_val(host, v).unwrap_or(ScVal::Void))
+            .collect();
+        let resolved = guard.resolve_call(&self.contract_id, &function, decoded);
         if resolved.is_none() {
             warn!(
                 contract_id = self.contract_id,
@@ -174,6 +283,304 @@ impl ContractFunctionSet for MockContractDispatcher {
     }
 }
 
+/// Expected value of one argument in an `--expect-mock-call` assertion.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExpectedValue {
+    /// `_` matches anything.
+    Any,
+    /// `@name` matches any address, but the same alias must always bind to the
+    /// same address across all expectations of a run.
+    Alias(String),
+    /// JSON (or a bare word, taken as a string). Objects match on the listed
+    /// fields only.
+    Json(Value),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExpectedArg {
+    /// Label from `name=value`, if the argument was given by name.
+    pub name: Option<String>,
+    /// Index of the call argument this is checked against: its place in the
+    /// list when unnamed, or the named parameter's position.
+    pub position: usize,
+    pub value: ExpectedValue,
+}
+
+/// Parameter names of the standard token interface (SEP-41, plus the Stellar
+/// Asset Contract's admin functions). Mocked contracts carry no spec, so these
+/// are the only names `--expect-mock-call` can resolve.
+fn token_params(function: &str) -> Option<&'static [&'static str]> {
+    Some(match function {
+        "allowance" => &["from", "spender"],
+        "approve" => &["from", "spender", "amount", "expiration_ledger"],
+        "balance" | "authorized" => &["id"],
+        "transfer" => &["from", "to", "amount"],
+        "transfer_from" => &["spender", "from", "to", "amount"],
+        "burn" | "clawback" => &["from", "amount"],
+        "burn_from" => &["spender", "from", "amount"],
+        "mint" => &["to", "amount"],
+        "set_admin" => &["new_admin"],
+        "set_authorized" => &["id", "authorize"],
+        "admin" | "decimals" | "name" | "symbol" => &[],
+        _ => return None,
+    })
+}
+
+/// A call a mocked contract must have received, parsed from
+/// `CONTRACT_ID:function(arg, name=value, ...)`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MockCallExpectation {
+    pub contract_id: String,
+    pub function: String,
+    pub args: Vec<ExpectedArg>,
+    raw: String,
+}
+
+impl MockCallExpectation {
+    pub fn parse(spec: &str) -> Result<Self> {
+        let invalid = |reason: &str| {
+            DebuggerError::InvalidArguments(format!(
+                "Invalid mock call expectation '{spec}': {reason}"
+            ))
+        };
+        let trimmed = spec.trim();
+        let (target, rest) = trimmed
+            .split_once('(')
+            .ok_or_else(|| invalid("expected CONTRACT_ID:function(args)"))?;
+        let inner = rest
+            .strip_suffix(')')
+            .ok_or_else(|| invalid("missing closing ')'"))?;
+        let (contract_id, function) = target
+            .rsplit_once(':')
+            .ok_or_else(|| invalid("expected CONTRACT_ID:function"))?;
+        let contract_id = contract_id.trim();
+        let function = function.trim();
+        if contract_id.is_empty() || function.is_empty() {
+            return Err(invalid("CONTRACT_ID and function are required").into());
+        }
+
+        let mut args = split_top_level(inner)
+            .into_iter()
+            .map(parse_expected_arg)
+            .collect::<std::result::Result<Vec<_>, String>>()
+            .map_err(|reason| invalid(&reason))?;
+        for (index, arg) in args.iter_mut().enumerate() {
+            arg.position = match &arg.name {
+                None => index,
+                Some(name) => {
+                    let params = token_params(function).ok_or_else(|| {
+                        invalid(&format!(
+                            "'{name}=' needs parameter names, which are only known for the \
+                             token interface; give '{function}' arguments by position"
+                        ))
+                    })?;
+                    params.iter().position(|p| p == name).ok_or_else(|| {
+                        invalid(&format!(
+                            "{function} has no parameter '{name}' (expected one of: {})",
+                            params.join(", ")
+                        ))
+                    })?
+                }
+            };
+        }
+
+        Ok(Self {
+            contract_id: contract_id.to_string(),
+            function: function.to_string(),
+            args,
+            raw: trimmed.to_string(),
+        })
+    }
+
+    /// Check every expectation against the call log.
+    ///
+    /// Each expectation must be met by at least one call to its contract and
+    /// function; arguments the expectation leaves out are not checked. On
+    /// failure the message lists the arguments the function was actually
+    /// called with.
+    pub fn check_all(
+        expectations: &[Self],
+        calls: &[MockCallLogEntry],
+    ) -> std::result::Result<(), String> {
+        let mut aliases = HashMap::new();
+        for expectation in expectations {
+            let candidates: Vec<Vec<Value>> = calls
+                .iter()
+                .filter(|c| {
+                    c.contract_id == expectation.contract_id && c.function == expectation.function
+                })
+                .map(|c| c.args.iter().map(scval_to_json).collect())
+                .collect();
+            if candidates.is_empty() {
+                return Err(format!(
+                    "Expected mock call {} but {} was never called on {}",
+                    expectation.raw, expectation.function, expectation.contract_id
+                ));
+            }
+
+            let bound = candidates
+                .iter()
+                .find_map(|actual| expectation.match_args(actual, &aliases));
+            match bound {
+                Some(bound) => aliases = bound,
+                None => {
+                    let actual: Vec<String> = candidates
+                        .iter()
+                        .map(|args| format_call(&expectation.function, args))
+                        .collect();
+                    return Err(format!(
+                        "Expected mock call {} did not match; actual: {}",
+                        expectation.raw,
+                        actual.join("; ")
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Match against one call's arguments, returning the alias bindings on success.
+    fn match_args(
+        &self,
+        actual: &[Value],
+        aliases: &HashMap<String, String>,
+    ) -> Option<HashMap<String, String>> {
+        let mut aliases = aliases.clone();
+        for arg in &self.args {
+            let value = actual.get(arg.position)?;
+            if !value_matches(&arg.value, value, &mut aliases) {
+                return None;
+            }
+        }
+        Some(aliases)
+    }
+}
+
+fn value_matches(
+    expected: &ExpectedValue,
+    actual: &Value,
+    aliases: &mut HashMap<String, String>,
+) -> bool {
+    match expected {
+        ExpectedValue::Any => true,
+        ExpectedValue::Alias(name) => {
+            let Value::String(address) = actual else {
+                return false;
+            };
+            if !(address.len() == 56 && (address.starts_with('G') || address.starts_with('C'))) {
+                return false;
+            }
+            *aliases
+                .entry(name.clone())
+                .or_insert_with(|| address.clone())
+                == *address
+        }
+        ExpectedValue::Json(expected) => json_matches(expected, actual),
+    }
+}
+
+/// Whether `actual` matches `expected`; objects only need the expected keys and
+/// integers match their decimal-string form.
+pub(crate) fn json_matches(expected: &Value, actual: &Value) -> bool {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => expected
+            .iter()
+            .all(|(k, v)| actual.get(k).is_some_and(|a| json_matches(v, a))),
+        (Value::Array(expected), Value::Array(actual)) => {
+            expected.len() == actual.len()
+                && expected.iter().zip(actual).all(|(e, a)| json_matches(e, a))
+        }
+        // 128-bit integers decode as decimal strings.
+        (Value::Number(n), Value::String(s)) | (Value::String(s), Value::Number(n)) => {
+            n.to_string() == *s
+        }
+        _ => expected == actual,
+    }
+}
+
+fn parse_expected_arg(raw: &str) -> std::result::Result<ExpectedArg, String> {
+    let raw = raw.trim();
+    let (name, value) = match raw.split_once('=') {
+        Some((name, value))
+            if !name.trim().is_empty()
+                && name
+                    .trim()
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_') =>
+        {
+            (Some(name.trim().to_string()), value.trim())
+        }
+        _ => (None, raw),
+    };
+    if value.is_empty() {
+        return Err(format!("empty argument '{raw}'"));
+    }
+
+    let value = if value == "_" {
+        ExpectedValue::Any
+    } else if let Some(alias) = value.strip_prefix('@') {
+        if alias.is_empty() {
+            return Err("'@' must be followed by an alias name".to_string());
+        }
+        ExpectedValue::Alias(alias.to_string())
+    } else {
+        ExpectedValue::Json(
+            serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_string())),
+        )
+    };
+    Ok(ExpectedArg {
+        name,
+        position: 0,
+        value,
+    })
+}
+
+/// Split on commas that are not nested in brackets or quoted strings.
+fn split_top_level(input: &str) -> Vec<&str> {
+    if input.trim().is_empty() {
+        return Vec::new();
+    }
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut start = 0;
+    for (i, c) in input.char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_string = true,
+            '[' | '{' | '(' => depth += 1,
+            ']' | '}' | ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&input[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&input[start..]);
+    parts
+}
+
+fn format_call(function: &str, args: &[Value]) -> String {
+    let rendered: Vec<String> = args
+        .iter()
+        .map(|v| match v {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        })
+        .collect();
+    format!("{}({})", function, rendered.join(", "))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -189,7 +596,7 @@ mod tests {
         let resolved = registry.resolve_call(
             "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHK3M",
             "echo",
-            1,
+            vec![ScVal::U32(1)],
         );
 
         assert!(resolved.is_some());
@@ -207,11 +614,154 @@ mod tests {
         let resolved = registry.resolve_call(
             "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHK3M",
             "transfer",
-            2,
+            vec![ScVal::U32(1), ScVal::U32(2)],
         );
 
         assert!(resolved.is_none());
         assert_eq!(registry.calls().len(), 1);
         assert!(!registry.calls()[0].mocked);
     }
+
+    fn address(byte: u8) -> ScVal {
+        use soroban_env_host::xdr::{Hash, ScAddress};
+        ScVal::Address(ScAddress::Contract(Hash([byte; 32])))
+    }
+
+    fn transfer_log(amount: u64) -> Vec<MockCallLogEntry> {
+        use soroban_env_host::xdr::Int128Parts;
+        let mut registry = MockRegistry::default();
+        registry.resolve_call(
+            "CTOKEN",
+            "transfer",
+            vec![
+                address(1),
+                address(2),
+                ScVal::I128(Int128Parts { hi: 0, lo: amount }),
+            ],
+        );
+        registry.calls().to_vec()
+    }
+
+    #[test]
+    fn mock_call_expectation_matches_transfer_amount() {
+        let calls = transfer_log(100);
+        let expect = MockCallExpectation::parse("CTOKEN:transfer(to=@acc0, amount=100)").unwrap();
+        let positions: Vec<usize> = expect.args.iter().map(|a| a.position).collect();
+        assert_eq!(positions, vec![1, 2]);
+        assert!(MockCallExpectation::check_all(&[expect], &calls).is_ok());
+
+        // Names pick the parameter, so `from` is checked against the first address.
+        let from =
+            MockCallExpectation::parse("CTOKEN:transfer(amount=100, from=@a, to=@a)").unwrap();
+        assert!(MockCallExpectation::check_all(&[from], &calls).is_err());
+
+        let positional = MockCallExpectation::parse("CTOKEN:transfer(_, @acc1, \"100\")").unwrap();
+        assert!(MockCallExpectation::check_all(&[positional], &calls).is_ok());
+    }
+
+    #[test]
+    fn mock_call_expectation_reports_actual_args() {
+        let calls = transfer_log(99);
+        let expect = MockCallExpectation::parse("CTOKEN:transfer(to=@acc0, amount=100)").unwrap();
+        let err = MockCallExpectation::check_all(&[expect], &calls).unwrap_err();
+        assert!(err.contains("did not match"), "{err}");
+        assert!(err.contains(", 99)"), "{err}");
+
+        let missing = MockCallExpectation::parse("CTOKEN:burn(1)").unwrap();
+        let err = MockCallExpectation::check_all(&[missing], &calls).unwrap_err();
+        assert!(err.contains("burn was never called"), "{err}");
+    }
+
+    #[test]
+    fn mock_call_aliases_bind_consistently() {
+        let calls = transfer_log(100);
+        let expectations = [
+            MockCallExpectation::parse("CTOKEN:transfer(@from, @to)").unwrap(),
+            MockCallExpectation::parse("CTOKEN:transfer(@to, _)").unwrap(),
+        ];
+        assert!(MockCallExpectation::check_all(&expectations, &calls).is_err());
+        assert!(MockCallExpectation::parse("CTOKEN.transfer(1)").is_err());
+    }
+
+    #[test]
+    fn mock_call_names_must_be_known_parameters() {
+        let err = MockCallExpectation::parse("CTOKEN:transfer(value=1)").unwrap_err();
+        assert!(err.to_string().contains("from, to, amount"), "{err}");
+
+        let err = MockCallExpectation::parse("CORACLE:notify(asset=XLM)").unwrap_err();
+        assert!(err.to_string().contains("by position"), "{err}");
+        assert!(MockCallExpectation::parse("CORACLE:notify(XLM, _)").is_ok());
+    }
+
+    #[test]
+    fn mock_sequence_repeats_last_response_after_exhaustion() {
+        let env = Env::default();
+        let specs = vec![
+            "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHK3M.price=>[10, 20, 30]"
+                .to_string(),
+        ];
+        let mut registry = MockRegistry::from_cli_specs(&env, &specs).unwrap();
+
+        for _ in 0..5 {
+            registry.resolve_call(
+                "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHK3M",
+                "price",
+                vec![],
+            );
+        }
+
+        let returned: Vec<_> = registry
+            .calls()
+            .iter()
+            .map(|c| c.returned.clone().unwrap())
+            .collect();
+        assert_eq!(returned, vec!["10", "20", "30", "30", "30"]);
+    }
+
+    #[test]
+    fn assert_called_checks_call_count() {
+        let env = Env::default();
+        let contract = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHK3M";
+        let specs = vec![format!("{contract}.echo=42")];
+        let mut registry = MockRegistry::from_cli_specs(&env, &specs).unwrap();
+        registry.resolve_call(contract, "echo", vec![]);
+        registry.resolve_call(contract, "echo", vec![]);
+
+        assert!(registry.assert_called(contract, "echo", 2).is_ok());
+        assert!(registry.assert_called(contract, "other", 0).is_ok());
+        let err = registry.assert_called(contract, "echo", 1).unwrap_err();
+        assert!(err.to_string().contains("called 2 time(s)"));
+    }
+
+    #[test]
+    fn rejects_empty_or_malformed_sequences() {
+        let env = Env::default();
+        for spec in ["C1.price=>[]", "C1.price=>10"] {
+            assert!(MockRegistry::from_cli_specs(&env, &[spec.to_string()]).is_err());
+        }
+    }
+
+    #[test]
+    fn parses_injected_contract_errors_and_aborts() {
+        let env = Env::default();
+        let contract = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAHK3M";
+        let specs = vec![
+            format!("{contract}.price=>err:3"),
+            format!("{contract}.halt=>abort"),
+        ];
+        let mut registry = MockRegistry::from_cli_specs(&env, &specs).unwrap();
+
+        let price = registry.resolve_call(contract, "price", vec![]).unwrap();
+        assert_eq!(
+            Error::try_from(price).unwrap(),
+            Error::from_contract_error(3)
+        );
+        let halt = registry.resolve_call(contract, "halt", vec![]).unwrap();
+        assert!(!Error::try_from(halt)
+            .unwrap()
+            .is_type(ScErrorType::Contract));
+        assert_eq!(registry.calls()[0].returned.as_deref(), Some("err:3"));
+
+        assert!(MockRegistry::from_cli_specs(&env, &[format!("{contract}.price=>err:x")]).is_err());
+    }
 }
@@ -7,23 +7,39 @@
 //! - [`parser`]         — Argument parsing and type-aware JSON normalisation.
 //! - [`result`]         — Shared result types and formatting helpers.
 //! - [`env`]            — Debug environment utilities.
+//! - [`baseline`]       — `--baseline` per-function budget regression gate.
+//! - [`event_routes`]   — `--on-event` forwarding of events to another contract.
+//! - [`footprint`]      — Footprint export as stellar-cli compatible XDR.
+//! - [`function_guard`] — `--allow-functions`/`--deny-functions` invocation guard.
 //! - [`instruction`]    — WASM instruction parsing.
 //! - [`instrumentation`]— Instruction-level hooks for profiling.
+//! - [`invocation_log`] — `--log-json` audit log of every invocation.
+//! - [`metrics`]        — `--metrics-out` Prometheus metrics for a session.
 //! - [`mocking`]        — Mock contract registry and dispatcher.
+//! - [`recording`]      — Execution recordings for `run --record` / `replay`.
 
+pub mod baseline;
 pub mod env;
+pub mod event_routes;
 pub mod executor;
+pub mod footprint;
+pub mod function_guard;
 pub mod instruction;
 pub mod instrumentation;
+pub mod invocation_log;
 pub mod invoker;
 pub mod loader;
+pub mod metrics;
 pub mod mocking;
 pub mod parser;
+pub mod recording;
 pub mod result;
 
 // Top-level re-exports — public API is unchanged.
 pub use env::DebugEnv;
 pub use executor::ContractExecutor;
-pub use executor::{ExecutionRecord, InstructionCounts, MockCallEntry, StorageSnapshot};
+pub use executor::{
+    ExecutionRecord, InstructionCounts, MockCallEntry, StepRecord, StorageSnapshot,
+};
 pub use instruction::{Instruction, InstructionParser};
-pub use instrumentation::{InstructionHook, Instrumenter};
+pub use instrumentation::{InstructionHook, InstructionObserver, Instrumenter};
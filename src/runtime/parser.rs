@@ -8,7 +8,14 @@
 //! - Parse raw `--args` JSON into [`soroban_sdk::Val`] slices.
 //! - Normalise `Option<T>` and `Tuple<…>` arguments automatically so callers
 //!   do not need to spell out the annotation envelope themselves.
+//! - Wrap bare strings/numbers for `U256`, `I256`, `Timepoint` and `Duration`
+//!   parameters so large and temporal values need no annotation either.
+//! - Wrap bare hex strings for `Bytes` and `BytesN<N>` parameters.
+//! - Wrap bare objects and pair arrays for `Map<K, V>` parameters.
+//! - Reject bare numbers that do not fit the integer type of their parameter
+//!   before any host call is made.
 
+use crate::utils::wasm::ContractFunctionSignature;
 use crate::{DebuggerError, Result};
 use serde_json::Value as JsonValue;
 use soroban_sdk::{Env, Val};
@@ -23,27 +30,96 @@ pub fn parse_args(
     wasm_bytes: &[u8],
     function: &str,
     args_json: &str,
+) -> Result<Vec<Val>> {
+    let signatures = crate::utils::wasm::parse_function_signatures(wasm_bytes)?;
+    parse_args_with_signatures(env, &signatures, function, args_json)
+}
+
+/// Like [`parse_args`], but with signatures the caller has already parsed, so
+/// repeated invocations do not re-read the contract spec.
+pub fn parse_args_with_signatures(
+    env: &Env,
+    signatures: &[ContractFunctionSignature],
+    function: &str,
+    args_json: &str,
 ) -> Result<Vec<Val>> {
     let parser = crate::utils::ArgumentParser::new(env.clone());
-    let normalized = normalize_args_for_function(wasm_bytes, function, args_json)?;
+    let normalized = normalize_args_for_function(signatures, function, args_json)?;
     parser.parse_args_string(&normalized).map_err(|e| {
         warn!("Failed to parse arguments: {}", e);
         DebuggerError::InvalidArguments(e.to_string()).into()
     })
 }
 
+/// Like [`parse_args_with_signatures`], but check the argument count against the
+/// spec and parse one argument at a time, so an error names the parameter it
+/// came from. Used where the arguments are checked before anything runs.
+pub fn parse_args_checked(
+    env: &Env,
+    signatures: &[ContractFunctionSignature],
+    function: &str,
+    args_json: &str,
+) -> Result<Vec<Val>> {
+    let normalized = normalize_args_for_function(signatures, function, args_json)?;
+    let Ok(JsonValue::Array(values)) = serde_json::from_str::<JsonValue>(&normalized) else {
+        return Err(
+            DebuggerError::InvalidArguments("Arguments must be a JSON array".to_string()).into(),
+        );
+    };
+
+    let signature = signatures.iter().find(|sig| sig.name == function);
+    if let Some(signature) = signature {
+        if values.len() != signature.params.len() {
+            return Err(DebuggerError::InvalidArguments(format!(
+                "'{}' expects {} argument(s) ({}), got {}",
+                function,
+                signature.params.len(),
+                signature.params_display(),
+                values.len()
+            ))
+            .into());
+        }
+    }
+
+    let parser = crate::utils::ArgumentParser::new(env.clone());
+    let mut parsed = Vec::with_capacity(values.len());
+    for (index, value) in values.into_iter().enumerate() {
+        let single = JsonValue::Array(vec![value]).to_string();
+        let vals = parser.parse_args_string(&single).map_err(|e| {
+            let param = signature
+                .and_then(|sig| sig.params.get(index))
+                .map(|p| format!("'{}' ({})", p.name, p.type_name))
+                .unwrap_or_else(|| format!("#{}", index));
+            DebuggerError::InvalidArguments(format!("Argument {}: {}", param, e))
+        })?;
+        parsed.extend(vals);
+    }
+    Ok(parsed)
+}
+
 /// Normalise argument JSON against the contract's function signature.
 ///
 /// Wraps `Option<T>` arguments in `{"type":"option","value":…}` and
 /// `Tuple<…>` arguments in `{"type":"tuple","arity":N,"value":[…]}` so that
 /// the downstream [`ArgumentParser`] can handle them without caller involvement.
+/// Bare strings and numbers for 256-bit and temporal parameters get the matching
+/// `u256`/`i256`/`timepoint`/`duration` annotation, and bare hex strings for
+/// `Bytes`/`BytesN<N>` become `bytes`/`bytesn` annotations. `Map<K, V>`
+/// arguments given as a bare object or `[[k, v], ...]` array become `map`
+/// annotations with key/value types taken from the spec where possible.
 fn normalize_args_for_function(
-    wasm_bytes: &[u8],
+    signatures: &[ContractFunctionSignature],
     function: &str,
     args_json: &str,
 ) -> Result<String> {
-    let signatures = crate::utils::wasm::parse_function_signatures(wasm_bytes)?;
-    let Some(signature) = signatures.into_iter().find(|sig| sig.name == function) else {
+    let Some(signature) = signatures.iter().find(|sig| sig.name == function) else {
+        crate::output::WarningCollector::record(
+            "spec_function_missing",
+            format!(
+                "No spec entry found for '{}'; arguments were passed through without type normalisation",
+                function
+            ),
+        );
         return Ok(args_json.to_string());
     };
 
@@ -53,8 +129,50 @@ fn normalize_args_for_function(
     let JsonValue::Array(args) = &mut args_value else {
         return Ok(args_json.to_string());
     };
+    validate_arg_ranges(signature, args)?;
 
     for (arg, param) in args.iter_mut().zip(signature.params.iter()) {
+        if let Some(annotation) = scalar_annotation(&param.type_name) {
+            if matches!(arg, JsonValue::String(_) | JsonValue::Number(_)) {
+                *arg = serde_json::json!({"type": annotation, "value": arg.clone()});
+            }
+            continue;
+        }
+
+        if param.type_name == "Bytes" {
+            if arg.is_string() {
+                *arg = serde_json::json!({"type": "bytes", "value": arg.clone()});
+            }
+            continue;
+        }
+
+        if let Some(size) = bytesn_size_from_type_name(&param.type_name) {
+            if arg.is_string() {
+                *arg = serde_json::json!({"type": "bytesn", "size": size, "value": arg.clone()});
+            }
+            continue;
+        }
+
+        if let Some((key_type, value_type)) = map_types_from_type_name(&param.type_name) {
+            let pairs = match &*arg {
+                JsonValue::Object(obj) if !is_typed_annotation(arg) => obj
+                    .iter()
+                    .map(|(k, v)| JsonValue::Array(vec![JsonValue::String(k.clone()), v.clone()]))
+                    .collect(),
+                JsonValue::Array(pairs) => pairs.clone(),
+                _ => continue,
+            };
+            let mut annotation = serde_json::json!({"type": "map", "value": pairs});
+            if let Some(key_type) = element_annotation(key_type) {
+                annotation["key_type"] = JsonValue::from(key_type);
+            }
+            if let Some(value_type) = element_annotation(value_type) {
+                annotation["value_type"] = JsonValue::from(value_type);
+            }
+            *arg = annotation;
+            continue;
+        }
+
         if param.type_name.starts_with("Option<") {
             if !is_typed_annotation(arg) {
                 *arg = serde_json::json!({"type": "option", "value": arg.clone()});
@@ -82,7 +200,9 @@ fn normalize_args_for_function(
 
             if actual_arr.len() != arity {
                 return Err(DebuggerError::InvalidArguments(format!(
-                    "Tuple arity mismatch: expected {}, got {}",
+                    "Argument '{}' ({}): Tuple arity mismatch: expected {}, got {}",
+                    param.name,
+                    param.type_name,
                     arity,
                     actual_arr.len()
                 ))
@@ -99,6 +219,35 @@ fn normalize_args_for_function(
     })
 }
 
+/// Check that every bare JSON number fits the integer range of its parameter's
+/// spec type, naming the parameter and the accepted range when it does not.
+pub fn validate_arg_ranges(
+    signature: &ContractFunctionSignature,
+    args: &[JsonValue],
+) -> Result<()> {
+    for (arg, param) in args.iter().zip(signature.params.iter()) {
+        let JsonValue::Number(number) = arg else {
+            continue;
+        };
+        let Some((min, max)) = integer_range(&param.type_name) else {
+            continue;
+        };
+        let value = number
+            .as_i64()
+            .map(i128::from)
+            .or_else(|| number.as_u64().map(i128::from));
+        let in_range = value.is_some_and(|v| v >= min && (v < 0 || v as u128 <= max));
+        if !in_range {
+            return Err(DebuggerError::InvalidArguments(format!(
+                "Argument '{}' of type {} must be an integer from {} to {}, got {}",
+                param.name, param.type_name, min, max, number
+            ))
+            .into());
+        }
+    }
+    Ok(())
+}
+
 // ── helpers ──────────────────────────────────────────────────────────────────
 
 fn tuple_arity_from_type_name(type_name: &str) -> Option<usize> {
@@ -119,6 +268,72 @@ fn tuple_arity_from_type_name(type_name: &str) -> Option<usize> {
     Some(arity)
 }
 
+fn bytesn_size_from_type_name(type_name: &str) -> Option<usize> {
+    type_name
+        .strip_prefix("BytesN<")?
+        .strip_suffix('>')?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Split `Map<K, V>` into its key and value spec types.
+fn map_types_from_type_name(type_name: &str) -> Option<(&str, &str)> {
+    let inner = type_name.strip_prefix("Map<")?.strip_suffix('>')?;
+    let mut depth = 0usize;
+    for (i, ch) in inner.char_indices() {
+        match ch {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => return Some((inner[..i].trim(), inner[i + 1..].trim())),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Annotation name used for map keys and values of a simple spec type.
+fn element_annotation(type_name: &str) -> Option<&'static str> {
+    match type_name {
+        "Bool" => Some("bool"),
+        "U32" => Some("u32"),
+        "I32" => Some("i32"),
+        "U64" => Some("u64"),
+        "I64" => Some("i64"),
+        "U128" => Some("u128"),
+        "I128" => Some("i128"),
+        "Symbol" => Some("symbol"),
+        "String" => Some("string"),
+        "Address" => Some("address"),
+        "Bytes" => Some("bytes"),
+        _ => scalar_annotation(type_name),
+    }
+}
+
+/// Inclusive bounds of the integer spec types a bare JSON number can encode.
+fn integer_range(type_name: &str) -> Option<(i128, u128)> {
+    match type_name {
+        "U32" => Some((0, u32::MAX.into())),
+        "I32" => Some((i32::MIN.into(), i32::MAX as u128)),
+        "U64" | "Timepoint" | "Duration" => Some((0, u64::MAX.into())),
+        "I64" => Some((i64::MIN.into(), i64::MAX as u128)),
+        "U128" => Some((0, u128::MAX)),
+        "I128" => Some((i128::MIN, i128::MAX as u128)),
+        _ => None,
+    }
+}
+
+/// Annotation name for spec types whose bare JSON form is ambiguous.
+fn scalar_annotation(type_name: &str) -> Option<&'static str> {
+    match type_name {
+        "U256" => Some("u256"),
+        "I256" => Some("i256"),
+        "Timepoint" => Some("timepoint"),
+        "Duration" => Some("duration"),
+        _ => None,
+    }
+}
+
 fn is_typed_annotation(value: &JsonValue) -> bool {
     matches!(
         value,
@@ -141,7 +356,22 @@ fn json_type_name(value: &JsonValue) -> &'static str {
 
 #[cfg(test)]
 mod tests {
-    use super::tuple_arity_from_type_name;
+    use super::*;
+    use crate::utils::wasm::FunctionParam;
+
+    fn signature(params: &[(&str, &str)]) -> ContractFunctionSignature {
+        ContractFunctionSignature {
+            name: "f".to_string(),
+            params: params
+                .iter()
+                .map(|(name, type_name)| FunctionParam {
+                    name: name.to_string(),
+                    type_name: type_name.to_string(),
+                })
+                .collect(),
+            return_type: None,
+        }
+    }
 
     #[test]
     fn tuple_arity_counts_top_level_types() {
@@ -152,14 +382,112 @@ mod tests {
         );
     }
 
+    #[test]
+    fn checked_parse_names_the_offending_parameter() {
+        let env = Env::default();
+        let sigs = [signature(&[("amount", "U32"), ("pair", "Tuple<U32, U32>")])];
+
+        let parsed = parse_args_checked(&env, &sigs, "f", "[7, [1, 2]]").unwrap();
+        assert_eq!(parsed.len(), 2);
+
+        let err = parse_args_checked(&env, &sigs, "f", "[7, [1, 2, 3]]")
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains(
+                "Argument 'pair' (Tuple<U32, U32>): Tuple arity mismatch: expected 2, got 3"
+            ),
+            "{err}"
+        );
+
+        let err = parse_args_checked(&env, &sigs, "f", "[7]")
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("'f' expects 2 argument(s) (amount: U32, pair: Tuple<U32, U32>), got 1"),
+            "{err}"
+        );
+    }
+
     #[test]
     fn tuple_arity_zero_for_empty() {
         assert_eq!(tuple_arity_from_type_name("Tuple<>"), Some(0));
         assert_eq!(tuple_arity_from_type_name("Tuple<  >"), Some(0));
     }
 
+    #[test]
+    fn scalar_annotation_covers_wide_and_temporal_types() {
+        assert_eq!(scalar_annotation("U256"), Some("u256"));
+        assert_eq!(scalar_annotation("I256"), Some("i256"));
+        assert_eq!(scalar_annotation("Timepoint"), Some("timepoint"));
+        assert_eq!(scalar_annotation("Duration"), Some("duration"));
+        assert_eq!(scalar_annotation("U128"), None);
+    }
+
+    #[test]
+    fn bytesn_size_parsed_from_spec_type() {
+        assert_eq!(bytesn_size_from_type_name("BytesN<32>"), Some(32));
+        assert_eq!(bytesn_size_from_type_name("Bytes"), None);
+        assert_eq!(bytesn_size_from_type_name("Vec<BytesN<32>>"), None);
+    }
+
+    #[test]
+    fn map_types_split_at_top_level_comma() {
+        assert_eq!(
+            map_types_from_type_name("Map<Symbol, I128>"),
+            Some(("Symbol", "I128"))
+        );
+        assert_eq!(
+            map_types_from_type_name("Map<Symbol, Map<U32, Bool>>"),
+            Some(("Symbol", "Map<U32, Bool>"))
+        );
+        assert_eq!(map_types_from_type_name("Vec<Symbol>"), None);
+        assert_eq!(element_annotation("Symbol"), Some("symbol"));
+        assert_eq!(element_annotation("I128"), Some("i128"));
+        assert_eq!(element_annotation("Map<U32, Bool>"), None);
+    }
+
     #[test]
     fn tuple_arity_returns_none_for_bad_prefix() {
         assert_eq!(tuple_arity_from_type_name("Vec<U32>"), None);
     }
+
+    #[test]
+    fn validate_arg_ranges_rejects_too_large_u32() {
+        let sig = signature(&[("count", "U32")]);
+        assert!(validate_arg_ranges(&sig, &[serde_json::json!(4294967295u64)]).is_ok());
+
+        let err = validate_arg_ranges(&sig, &[serde_json::json!(4294967296u64)])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'count'"), "{err}");
+        assert!(err.contains("from 0 to 4294967295"), "{err}");
+    }
+
+    #[test]
+    fn validate_arg_ranges_rejects_negative_u64() {
+        let sig = signature(&[("owner", "Address"), ("amount", "U64")]);
+        let err = validate_arg_ranges(&sig, &[serde_json::json!("GABC"), serde_json::json!(-5)])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'amount' of type U64"), "{err}");
+        assert!(err.contains("got -5"), "{err}");
+
+        // Signed types accept negatives, and unrecognised types are left alone.
+        let sig = signature(&[("delta", "I64"), ("price", "I128"), ("x", "Val")]);
+        let args = [
+            serde_json::json!(-5),
+            serde_json::json!(-7),
+            serde_json::json!(-1),
+        ];
+        assert!(validate_arg_ranges(&sig, &args).is_ok());
+    }
+
+    #[test]
+    fn normalize_rejects_out_of_range_argument_before_invocation() {
+        let err = normalize_args_for_function(&[signature(&[("n", "U32")])], "f", "[-1]")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'n' of type U32"), "{err}");
+    }
 }
@@ -0,0 +1,356 @@
+//! Execution recordings written by `run --record` and re-run by `replay`.
+//!
+//! A recording holds the [`ExecutionRecord`] of one invocation together with
+//! the contract's storage entries as they were before the call, encoded as
+//! XDR so they can be written back into a fresh host.
+//!
+//! [`RunComparison`] diffs two recordings for `compare --compare-runs`.
+
+use crate::analyzer::upgrade::ExecutionDiff;
+use crate::inspector::events::ContractEvent;
+use crate::inspector::storage::{StorageDiff, StorageInspector};
+use crate::runtime::result::{xdr_base64, ExecutionRecord};
+use crate::{DebuggerError, Result};
+use serde::{Deserialize, Serialize};
+use soroban_env_host::xdr::ScVal;
+use std::fs;
+use std::path::Path;
+
+/// Format version written to `recording_version`.
+pub const RECORDING_VERSION: u32 = 1;
+
+/// Storage tier a recorded entry is restored into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecordedDurability {
+    Instance,
+    Persistent,
+    Temporary,
+}
+
+/// A contract data entry captured before the recorded call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedEntry {
+    pub durability: RecordedDurability,
+    #[serde(with = "xdr_base64")]
+    pub key: ScVal,
+    #[serde(with = "xdr_base64")]
+    pub value: ScVal,
+    /// Last ledger the entry is live in; instance entries share the instance's.
+    #[serde(default)]
+    pub live_until: Option<u32>,
+}
+
+/// Everything needed to reproduce and check a single invocation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionRecording {
+    pub recording_version: u32,
+    /// WASM path the recording was made with.
+    #[serde(default)]
+    pub contract: Option<String>,
+    pub record: ExecutionRecord,
+    pub storage_diff: StorageDiff,
+    pub entries_before: Vec<RecordedEntry>,
+    /// Events the recorded call emitted; empty in recordings made before they were kept.
+    #[serde(default)]
+    pub events: Vec<ContractEvent>,
+}
+
+impl ExecutionRecording {
+    pub fn new(
+        contract: Option<String>,
+        record: ExecutionRecord,
+        entries_before: Vec<RecordedEntry>,
+    ) -> Self {
+        let storage_diff =
+            StorageInspector::compute_diff(&record.storage_before, &record.storage_after, &[]);
+        Self {
+            recording_version: RECORDING_VERSION,
+            contract,
+            record,
+            storage_diff,
+            entries_before,
+            events: Vec::new(),
+        }
+    }
+
+    pub fn with_events(mut self, events: Vec<ContractEvent>) -> Self {
+        self.events = events;
+        self
+    }
+
+    /// True when `value` looks like a recording rather than a compare trace.
+    pub fn is_recording(value: &serde_json::Value) -> bool {
+        value.get("recording_version").is_some() && value.get("record").is_some()
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to serialize execution recording: {}", e))
+        })?;
+        fs::write(path.as_ref(), json).map_err(|e| {
+            DebuggerError::FileError(format!(
+                "Failed to write recording {:?}: {}",
+                path.as_ref(),
+                e
+            ))
+        })?;
+        Ok(())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let contents = fs::read_to_string(path.as_ref()).map_err(|e| {
+            DebuggerError::FileError(format!(
+                "Failed to read recording {:?}: {}",
+                path.as_ref(),
+                e
+            ))
+        })?;
+        let recording: Self = serde_json::from_str(&contents).map_err(|e| {
+            DebuggerError::FileError(format!(
+                "Invalid execution recording {:?}: {}",
+                path.as_ref(),
+                e
+            ))
+        })?;
+        if recording.recording_version > RECORDING_VERSION {
+            return Err(DebuggerError::FileError(format!(
+                "Recording version {} is newer than supported version {}",
+                recording.recording_version, RECORDING_VERSION
+            ))
+            .into());
+        }
+        Ok(recording)
+    }
+
+    /// Compare the recorded result with that of a re-run.
+    pub fn diff_result(&self, replayed: &std::result::Result<ScVal, String>) -> ExecutionDiff {
+        let args = serde_json::Value::Array(
+            self.record
+                .args
+                .iter()
+                .map(crate::utils::scval::scval_to_json)
+                .collect(),
+        );
+        let old_result = render_result(&self.record.result);
+        let new_result = render_result(replayed);
+        ExecutionDiff {
+            function: self.record.function.clone(),
+            args: args.to_string(),
+            outputs_match: old_result == new_result,
+            old_result,
+            new_result,
+        }
+    }
+}
+
+/// An event that differs between two runs at the same position.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EventDifference {
+    pub index: usize,
+    pub a: Option<String>,
+    pub b: Option<String>,
+}
+
+/// What differs between two recorded runs, kept apart by result, storage and events.
+#[derive(Debug, Clone, Serialize)]
+pub struct RunComparison {
+    /// Run A's result as `old_result`, run B's as `new_result`.
+    pub result: ExecutionDiff,
+    /// Storage after run B relative to storage after run A.
+    pub storage: StorageDiff,
+    pub events: Vec<EventDifference>,
+}
+
+impl RunComparison {
+    pub fn compare(a: &ExecutionRecording, b: &ExecutionRecording) -> Self {
+        let event_line = |event: &ContractEvent| {
+            format!(
+                "{} [{}] => {}",
+                event.contract_id.as_deref().unwrap_or("<none>"),
+                event.topics.join(", "),
+                event.data
+            )
+        };
+        let events = (0..a.events.len().max(b.events.len()))
+            .filter_map(|index| {
+                let a = a.events.get(index).map(event_line);
+                let b = b.events.get(index).map(event_line);
+                (a != b).then_some(EventDifference { index, a, b })
+            })
+            .collect();
+
+        Self {
+            result: a.diff_result(&b.record.result),
+            storage: StorageInspector::compute_diff(
+                &a.record.storage_after,
+                &b.record.storage_after,
+                &[],
+            ),
+            events,
+        }
+    }
+
+    pub fn has_differences(&self) -> bool {
+        !self.result.outputs_match || !self.storage.is_empty() || !self.events.is_empty()
+    }
+
+    /// Names of the sections that differ, e.g. `["result", "events"]`.
+    pub fn differing_sections(&self) -> Vec<&'static str> {
+        let mut sections = Vec::new();
+        if !self.result.outputs_match {
+            sections.push("result");
+        }
+        if !self.storage.is_empty() {
+            sections.push("storage");
+        }
+        if !self.events.is_empty() {
+            sections.push("events");
+        }
+        sections
+    }
+
+    pub fn format_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if self.result.outputs_match {
+            lines.push(format!("Result matches: {}", self.result.old_result));
+        } else {
+            lines.push("Result differs:".to_string());
+            lines.push(format!("  A: {}", self.result.old_result));
+            lines.push(format!("  B: {}", self.result.new_result));
+        }
+
+        if self.storage.is_empty() {
+            lines.push("Storage matches".to_string());
+        } else {
+            lines.push("Storage differs (after B relative to after A):".to_string());
+            lines.extend(
+                self.storage
+                    .format_lines()
+                    .iter()
+                    .map(|l| format!("  {}", l)),
+            );
+        }
+
+        if self.events.is_empty() {
+            lines.push("Events match".to_string());
+        } else {
+            lines.push("Events differ:".to_string());
+            for diff in &self.events {
+                let side =
+                    |event: &Option<String>| event.as_deref().unwrap_or("(none)").to_string();
+                lines.push(format!("  #{} A: {}", diff.index, side(&diff.a)));
+                lines.push(format!("  #{} B: {}", diff.index, side(&diff.b)));
+            }
+        }
+        lines
+    }
+}
+
+fn render_result(result: &std::result::Result<ScVal, String>) -> String {
+    match result {
+        Ok(val) => crate::utils::scval::scval_to_json(val).to_string(),
+        Err(msg) => format!("Error: {}", msg),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspector::budget::BudgetInfo;
+    use soroban_env_host::xdr::{ScSymbol, StringM};
+    use std::collections::HashMap;
+
+    fn sym(s: &str) -> ScVal {
+        ScVal::Symbol(ScSymbol(StringM::try_from(s.as_bytes().to_vec()).unwrap()))
+    }
+
+    fn record(result: std::result::Result<ScVal, String>) -> ExecutionRecord {
+        ExecutionRecord {
+            function: "increment".to_string(),
+            args: vec![ScVal::U32(7), sym("XLM")],
+            result,
+            budget: BudgetInfo {
+                cpu_instructions: 1200,
+                cpu_limit: 100_000,
+                memory_bytes: 64,
+                memory_limit: 40_960,
+            },
+            storage_before: HashMap::from([("c".to_string(), "I64(41)".to_string())]),
+            storage_after: HashMap::from([("c".to_string(), "I64(42)".to_string())]),
+            duration: std::time::Duration::default(),
+            cpu_insns: 0,
+        }
+    }
+
+    #[test]
+    fn recording_round_trips_through_json() {
+        let recording = ExecutionRecording::new(
+            Some("counter.wasm".to_string()),
+            record(Ok(ScVal::I64(42))),
+            vec![RecordedEntry {
+                durability: RecordedDurability::Instance,
+                key: sym("c"),
+                value: ScVal::I64(41),
+                live_until: None,
+            }],
+        );
+
+        let json = serde_json::to_value(&recording).unwrap();
+        assert!(ExecutionRecording::is_recording(&json));
+        assert!(json["record"]["args"][0].is_string());
+        assert_eq!(json["entries_before"][0]["durability"], "instance");
+
+        let restored: ExecutionRecording = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.record.args, recording.record.args);
+        assert_eq!(restored.record.result, Ok(ScVal::I64(42)));
+        assert_eq!(restored.entries_before, recording.entries_before);
+        assert!(restored.storage_diff.modified.contains_key("c"));
+    }
+
+    #[test]
+    fn error_results_round_trip_as_messages() {
+        let original = record(Err("Contract execution was aborted.".to_string()));
+        let json = serde_json::to_value(&original).unwrap();
+        assert_eq!(json["result"]["err"], "Contract execution was aborted.");
+
+        let restored: ExecutionRecord = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.result, original.result);
+    }
+
+    #[test]
+    fn diff_result_flags_changed_output() {
+        let recording = ExecutionRecording::new(None, record(Ok(ScVal::I64(42))), vec![]);
+
+        let same = recording.diff_result(&Ok(ScVal::I64(42)));
+        assert!(same.outputs_match);
+        assert_eq!(same.args, r#"[7,"XLM"]"#);
+
+        let changed = recording.diff_result(&Ok(ScVal::I64(43)));
+        assert!(!changed.outputs_match);
+        assert_eq!(changed.old_result, "42");
+        assert_eq!(changed.new_result, "43");
+    }
+
+    #[test]
+    fn run_comparison_reports_only_the_result_when_that_is_all_that_differs() {
+        let a = ExecutionRecording::new(None, record(Ok(ScVal::I64(42))), vec![]);
+        let b = ExecutionRecording::new(None, record(Ok(ScVal::I64(43))), vec![]);
+
+        let comparison = RunComparison::compare(&a, &b);
+        assert!(comparison.has_differences());
+        assert_eq!(comparison.differing_sections(), vec!["result"]);
+        assert_eq!(
+            comparison.format_lines(),
+            vec![
+                "Result differs:",
+                "  A: 42",
+                "  B: 43",
+                "Storage matches",
+                "Events match"
+            ]
+        );
+
+        assert!(!RunComparison::compare(&a, &a).has_differences());
+    }
+}
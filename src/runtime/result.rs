@@ -5,23 +5,167 @@
 //! and instruction-level profiling data.
 
 use crate::inspector::budget::BudgetInfo;
+use crate::inspector::events::ContractEvent;
+use crate::inspector::storage::StorageDiff;
 use soroban_env_host::xdr::ScVal;
 use soroban_env_host::{ConversionError, TryFromVal};
 use soroban_sdk::{InvokeError, Val};
 use std::collections::HashMap;
+use std::time::Duration;
 
 /// Re-export for convenience.
 pub use crate::runtime::mocking::MockCallLogEntry as MockCallEntry;
 
 /// Represents a captured execution trace.
-#[derive(Debug, Clone)]
+///
+/// `ScVal`s are serialized as base64-encoded XDR.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ExecutionRecord {
     pub function: String,
+    #[serde(with = "xdr_base64::vec")]
     pub args: Vec<ScVal>,
+    #[serde(with = "xdr_base64::result")]
     pub result: std::result::Result<ScVal, String>,
     pub budget: BudgetInfo,
     pub storage_before: HashMap<String, String>,
     pub storage_after: HashMap<String, String>,
+    /// Wall-clock time spent inside the contract call.
+    #[serde(default)]
+    pub duration: Duration,
+    /// CPU instructions the host charged for the contract call.
+    #[serde(default)]
+    pub cpu_insns: u64,
+}
+
+impl ExecutionRecord {
+    /// One-line timing footer, e.g. `Time: 1.234 ms, CPU instructions: 56789`.
+    pub fn timing_summary(&self) -> String {
+        format!(
+            "Time: {:.3} ms, CPU instructions: {}",
+            self.duration.as_secs_f64() * 1000.0,
+            self.cpu_insns
+        )
+    }
+}
+
+/// Events and storage changes from one invocation in a session, keyed by its
+/// position in the session.
+///
+/// The host only exposes events once a call returns, so the feed has one entry
+/// per invocation rather than per instruction step. Complements
+/// [`ExecutionRecord`], which only keeps the latest invocation.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct StepRecord {
+    /// 1-based position of the invocation within the session.
+    pub step: usize,
+    pub function: String,
+    /// Events emitted by this invocation, in emission order.
+    pub events: Vec<ContractEvent>,
+    pub storage_diff: StorageDiff,
+}
+
+impl StepRecord {
+    /// Render the call as a feed entry: a header, then events, then storage changes.
+    pub fn format_lines(&self) -> Vec<String> {
+        let mut lines = vec![format!("[call {}] {}", self.step, self.function)];
+
+        for event in &self.events {
+            lines.push(format!(
+                "  event #{}: {} => {}",
+                event.index.unwrap_or_default(),
+                event.topics.join(", "),
+                event.data
+            ));
+        }
+
+        for line in self.storage_diff.format_lines() {
+            lines.push(format!("  {}", line));
+        }
+
+        if lines.len() == 1 {
+            lines.push("  (no events or storage changes)".to_string());
+        }
+        lines
+    }
+}
+
+/// Serde helpers that encode [`ScVal`] as base64 XDR strings.
+pub(crate) mod xdr_base64 {
+    use serde::{de::Error as _, ser::Error as _, Deserialize, Deserializer, Serializer};
+    use soroban_env_host::xdr::{Limits, ReadXdr, ScVal, WriteXdr};
+
+    fn encode(val: &ScVal) -> std::result::Result<String, String> {
+        val.to_xdr_base64(Limits::none())
+            .map_err(|e| format!("Failed to encode ScVal as XDR: {}", e))
+    }
+
+    fn decode(text: &str) -> std::result::Result<ScVal, String> {
+        ScVal::from_xdr_base64(text, Limits::none())
+            .map_err(|e| format!("Invalid base64 XDR ScVal '{}': {}", text, e))
+    }
+
+    pub fn serialize<S: Serializer>(val: &ScVal, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&encode(val).map_err(S::Error::custom)?)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ScVal, D::Error> {
+        decode(&String::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+
+    pub mod vec {
+        use super::*;
+        use serde::ser::SerializeSeq;
+
+        pub fn serialize<S: Serializer>(vals: &[ScVal], serializer: S) -> Result<S::Ok, S::Error> {
+            let mut seq = serializer.serialize_seq(Some(vals.len()))?;
+            for val in vals {
+                seq.serialize_element(&encode(val).map_err(S::Error::custom)?)?;
+            }
+            seq.end()
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<ScVal>, D::Error> {
+            Vec::<String>::deserialize(deserializer)?
+                .iter()
+                .map(|text| decode(text).map_err(D::Error::custom))
+                .collect()
+        }
+    }
+
+    /// `Ok` values become `{"ok": "<xdr>"}`, errors `{"err": "<message>"}`.
+    pub mod result {
+        use super::*;
+        use serde::Serialize;
+
+        #[derive(Serialize, Deserialize)]
+        #[serde(rename_all = "snake_case")]
+        enum Repr {
+            Ok(String),
+            Err(String),
+        }
+
+        pub fn serialize<S: Serializer>(
+            val: &std::result::Result<ScVal, String>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match val {
+                Ok(val) => Repr::Ok(encode(val).map_err(S::Error::custom)?),
+                Err(msg) => Repr::Err(msg.clone()),
+            }
+            .serialize(serializer)
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<std::result::Result<ScVal, String>, D::Error> {
+            match Repr::deserialize(deserializer)? {
+                Repr::Ok(text) => decode(&text).map(Ok).map_err(D::Error::custom),
+                Repr::Err(msg) => Ok(Err(msg)),
+            }
+        }
+    }
 }
 
 /// Storage snapshot for dry-run rollback.
@@ -78,24 +222,30 @@ pub(super) fn format_invocation_result(
                 Err(msg),
             )
         }
-        Err(Ok(inv_err)) => {
-            let msg = match inv_err {
-                InvokeError::Contract(code) => {
-                    warn!("Contract returned error code: {}", code);
-                    error_db.display_error(*code);
-                    format!(
-                        "The contract returned an error code: {}. This typically indicates \
-                         a business logic failure (e.g. `panic!` or `require!`).",
-                        code
-                    )
-                }
-                InvokeError::Abort => {
-                    warn!("Contract execution aborted");
-                    "Contract execution was aborted. This could be due to a trap, \
-                     budget exhaustion, or an explicit abort call."
-                        .to_string()
+        Err(Ok(InvokeError::Contract(code))) => {
+            warn!("Contract returned error code: {}", code);
+            error_db.display_error(*code);
+            let msg = format!(
+                "The contract returned error {}. This typically indicates \
+                 a business logic failure (e.g. `panic!` or `require!`).",
+                error_db.describe(*code)
+            );
+            let name = error_db.lookup(*code).map(|e| e.name.clone());
+            (
+                Err(crate::DebuggerError::ContractError {
+                    code: *code,
+                    name,
+                    message: msg.clone(),
                 }
-            };
+                .into()),
+                Err(msg),
+            )
+        }
+        Err(Ok(InvokeError::Abort)) => {
+            warn!("Contract execution aborted");
+            let msg = "Contract execution was aborted. This could be due to a trap, \
+                       budget exhaustion, or an explicit abort call."
+                .to_string();
             (
                 Err(crate::DebuggerError::ExecutionError(msg.clone()).into()),
                 Err(msg),
@@ -164,3 +314,74 @@ impl RuntimeError {
         matches!(self, Self::Cancelled { .. })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::debugger::error_db::{ErrorDatabase, ErrorExplanation};
+
+    #[test]
+    fn contract_error_carries_code_and_resolved_name() {
+        let mut error_db = ErrorDatabase::new();
+        error_db.add_custom_error(ErrorExplanation {
+            code: 1,
+            name: "AssetNotFound".to_string(),
+            description: "No price has been set for the asset".to_string(),
+            common_cause: String::new(),
+            suggested_fix: String::new(),
+        });
+        let host = soroban_env_host::Host::default();
+
+        let (display, record) =
+            format_invocation_result(&Err(Ok(InvokeError::Contract(1))), &host, &error_db);
+        assert!(record.is_err());
+
+        let report = display.unwrap_err();
+        let json = serde_json::to_value(crate::output::JsonError::from_report(&report)).unwrap();
+        assert_eq!(json["error_kind"], "ContractError");
+        assert_eq!(json["contract_code"], 1);
+        assert_eq!(json["contract_error"], "AssetNotFound");
+        assert!(json["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("The contract returned error AssetNotFound (code 1)"));
+    }
+
+    #[test]
+    fn step_record_lists_events_before_storage_changes() {
+        let event = |index: usize, topic: &str| ContractEvent {
+            index: Some(index),
+            contract_id: None,
+            topics: vec![topic.to_string()],
+            data: "Void".to_string(),
+        };
+        let mut storage_diff = StorageDiff::default();
+        storage_diff
+            .modified
+            .insert("price".to_string(), ("1".to_string(), "2".to_string()));
+        let step = StepRecord {
+            step: 2,
+            function: "setprice".to_string(),
+            events: vec![event(0, "price_set"), event(1, "audit")],
+            storage_diff,
+        };
+
+        assert_eq!(
+            step.format_lines(),
+            vec![
+                "[call 2] setprice",
+                "  event #0: price_set => Void",
+                "  event #1: audit => Void",
+                "  ~ price: 1 -> 2",
+            ]
+        );
+
+        let empty = StepRecord {
+            step: 1,
+            function: "get".to_string(),
+            events: Vec::new(),
+            storage_diff: StorageDiff::default(),
+        };
+        assert_eq!(empty.format_lines()[1], "  (no events or storage changes)");
+    }
+}
@@ -47,6 +47,8 @@ pub struct ScenarioStep {
     /// Later steps can reference the value using `{{var_name}}` in their `args` or
     /// `expected_return` fields.
     pub capture: Option<String>,
+    /// Seconds to move the ledger clock forward before this step runs.
+    pub ledger_advance: Option<u64>,
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
@@ -167,6 +169,19 @@ pub fn run_scenario(args: ScenarioArgs, _verbosity: Verbosity) -> Result<()> {
             Formatter::info(format!("Step {}: {}", i + 1, step_label))
         );
 
+        let advance = resolve_ledger_advance(i, step.ledger_advance, args.step_advance_secs);
+        if advance > 0 {
+            engine.executor_mut().advance_ledger_time(advance);
+            println!(
+                "  {}",
+                Formatter::info(format!(
+                    "Ledger time advanced by {}s to {}",
+                    advance,
+                    engine.executor().ledger_timestamp()
+                ))
+            );
+        }
+
         let resolved_args = if let Some(args_json) = &step.args {
             Some(interpolate_variables(args_json, &variables)?)
         } else {
@@ -506,6 +521,22 @@ fn resolve_step_timeout(
         .unwrap_or(DEFAULT_EXECUTION_TIMEOUT_SECS)
 }
 
+/// Seconds to advance the ledger clock before step `step_index` (0-based).
+///
+/// A step's own `ledger_advance` wins; otherwise `--step-advance-secs` applies
+/// to every step but the first.
+fn resolve_ledger_advance(
+    step_index: usize,
+    step_advance_secs: Option<u64>,
+    cli_advance_secs: Option<u64>,
+) -> u64 {
+    match (step_advance_secs, cli_advance_secs) {
+        (Some(secs), _) => secs,
+        (None, Some(secs)) if step_index > 0 => secs,
+        _ => 0,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -886,6 +917,15 @@ function = "increment"
         );
     }
 
+    #[test]
+    fn test_ledger_advance_step_overrides_global() {
+        assert_eq!(resolve_ledger_advance(0, None, Some(60)), 0);
+        assert_eq!(resolve_ledger_advance(1, None, Some(60)), 60);
+        assert_eq!(resolve_ledger_advance(1, Some(0), Some(60)), 0);
+        assert_eq!(resolve_ledger_advance(0, Some(30), None), 30);
+        assert_eq!(resolve_ledger_advance(2, None, None), 0);
+    }
+
     #[test]
     fn test_event_assertion_passes_for_exact_match() {
         let expected = vec![ScenarioEventAssertion {
@@ -894,6 +934,7 @@ function = "increment"
             data: "payload".to_string(),
         }];
         let actual = vec![ContractEvent {
+            index: Some(0),
             contract_id: None,
             topics: vec!["topic".to_string()],
             data: "payload".to_string(),
@@ -906,6 +947,7 @@ function = "increment"
     fn test_event_assertion_fails_for_unexpected_event() {
         let expected = vec![];
         let actual = vec![ContractEvent {
+            index: Some(0),
             contract_id: None,
             topics: vec!["topic".to_string()],
             data: "payload".to_string(),
@@ -0,0 +1,324 @@
+//! Multi-call scripts for `soroban-debug script`.
+//!
+//! A script lists one invocation per line as `function [json-args]`; blank
+//! lines and lines starting with `#` are skipped. Every line runs against the
+//! same executor, so storage written by one call is visible to the next.
+//!
+//! `assert function [json-args] == <json>` also invokes the function, then
+//! compares its spec-decoded result with the expected JSON value.
+
+use crate::cli::args::{ScriptArgs, Verbosity};
+use crate::debugger::engine::DebuggerEngine;
+use crate::inspector::storage::StorageInspector;
+use crate::logging;
+use crate::runtime::executor::ContractExecutor;
+use crate::ui::formatter::Formatter;
+use crate::{DebuggerError, Result};
+use std::fs;
+
+/// A single invocation read from a script file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptStep {
+    /// 1-based line number in the script file.
+    pub line: usize,
+    pub function: String,
+    /// JSON argument array exactly as written in the script.
+    pub args: Option<String>,
+    /// Expected decoded result, for `assert` lines.
+    pub expected: Option<serde_json::Value>,
+}
+
+/// Parse script text into steps, rejecting lines whose arguments are not valid JSON.
+pub fn parse_script(content: &str) -> Result<Vec<ScriptStep>> {
+    let mut steps = Vec::new();
+    for (index, raw) in content.lines().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (function, rest) = match line.split_once(char::is_whitespace) {
+            Some((function, rest)) => (function, rest.trim()),
+            None => (line, ""),
+        };
+        if function == "assert" {
+            steps.push(parse_assert(rest, index + 1)?);
+            continue;
+        }
+        let args = if rest.is_empty() {
+            None
+        } else {
+            serde_json::from_str::<serde_json::Value>(rest).map_err(|e| {
+                DebuggerError::InvalidArguments(format!(
+                    "Line {}: invalid JSON arguments for '{}': {}",
+                    index + 1,
+                    function,
+                    e
+                ))
+            })?;
+            Some(rest.to_string())
+        };
+
+        steps.push(ScriptStep {
+            line: index + 1,
+            function: function.to_string(),
+            args,
+            expected: None,
+        });
+    }
+    Ok(steps)
+}
+
+/// Parse the part of an `assert` line after the keyword: `function [json-args] == <json>`.
+fn parse_assert(rest: &str, line: usize) -> Result<ScriptStep> {
+    let invalid = |detail: &str| -> miette::Report {
+        DebuggerError::InvalidArguments(format!(
+            "Line {}: {}; expected `assert function [json-args] == <json>`",
+            line, detail
+        ))
+        .into()
+    };
+
+    let (function, rest) = match rest.split_once(char::is_whitespace) {
+        Some((function, rest)) => (function, rest.trim()),
+        None => (rest, ""),
+    };
+    if function.is_empty() || function == "==" {
+        return Err(invalid("missing function name"));
+    }
+
+    // `==` may also appear inside JSON strings, so take the first split where
+    // both sides parse.
+    for (pos, _) in rest.match_indices("==") {
+        let args = rest[..pos].trim();
+        let Ok(expected) = serde_json::from_str(rest[pos + 2..].trim()) else {
+            continue;
+        };
+        if args.is_empty() {
+            return Ok(ScriptStep {
+                line,
+                function: function.to_string(),
+                args: None,
+                expected: Some(expected),
+            });
+        }
+        if serde_json::from_str::<serde_json::Value>(args).is_ok() {
+            return Ok(ScriptStep {
+                line,
+                function: function.to_string(),
+                args: Some(args.to_string()),
+                expected: Some(expected),
+            });
+        }
+    }
+    Err(invalid(&format!(
+        "could not read arguments and expected value for '{}'",
+        function
+    )))
+}
+
+/// Compare the last call's decoded result with an `assert` line's expected value.
+fn check_assertion(
+    executor: &ContractExecutor,
+    wasm: &[u8],
+    step: &ScriptStep,
+    expected: &serde_json::Value,
+) -> std::result::Result<(), String> {
+    let actual = executor
+        .last_execution()
+        .and_then(|record| record.result.as_ref().ok())
+        .map(|val| crate::utils::scval::decode_return_value(wasm, &step.function, val).into())
+        .ok_or_else(|| format!("Line {}: no result to assert on", step.line))?;
+
+    if crate::runtime::mocking::json_matches(expected, &actual) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Assertion failed on line {}: {} returned {}, expected {}",
+            step.line, step.function, actual, expected
+        ))
+    }
+}
+
+pub fn run_script(args: ScriptArgs, _verbosity: Verbosity) -> Result<()> {
+    let content = fs::read_to_string(&args.script).map_err(|e| {
+        DebuggerError::FileError(format!(
+            "Failed to read script file {:?}: {}",
+            args.script, e
+        ))
+    })?;
+    let steps = parse_script(&content)?;
+    if steps.is_empty() {
+        return Err(DebuggerError::InvalidArguments(format!(
+            "Script {:?} contains no invocations",
+            args.script
+        ))
+        .into());
+    }
+
+    println!(
+        "{}",
+        Formatter::info(format!("Loading contract: {:?}", args.contract))
+    );
+    logging::log_loading_contract(&args.contract.to_string_lossy());
+    let wasm_file = crate::utils::wasm::load_wasm(&args.contract)?;
+
+    let mut executor = ContractExecutor::new(wasm_file.bytes.clone())?;
+    // Like the REPL, a script walks through a session, so admin calls after
+    // `initialize` must not need signatures.
+    executor.enable_mock_all_auths();
+    executor.set_timeout(args.timeout);
+    if let Some(log_path) = &args.log_json {
+        executor.set_invocation_log(log_path)?;
+    }
+    if let Some(metrics_path) = &args.metrics_out {
+        executor.set_metrics_out(metrics_path)?;
+    }
+    executor.set_function_guard(crate::runtime::function_guard::FunctionGuard::new(
+        args.allow_functions.clone(),
+        args.deny_functions.clone(),
+    ));
+    if let Some(storage) = &args.storage {
+        executor.set_initial_storage(storage.clone())?;
+    }
+    let storage_before = executor.get_storage_snapshot()?;
+
+    let mut engine = DebuggerEngine::new(executor, vec![]);
+    let mut failed_lines = Vec::new();
+
+    for (i, step) in steps.iter().enumerate() {
+        println!(
+            "{}",
+            Formatter::info(format!(
+                "Step {}/{} (line {}): {}",
+                i + 1,
+                steps.len(),
+                step.line,
+                step.function
+            ))
+        );
+
+        let parsed_args = step
+            .args
+            .as_deref()
+            .map(crate::cli::commands::parse_args)
+            .transpose()
+            .map_err(|e| format!("Invalid arguments: {}", e));
+        let outcome = match parsed_args.and_then(|parsed| {
+            engine
+                .execute(&step.function, parsed.as_deref())
+                .map_err(|e| format!("Execution failed: {}", e))
+        }) {
+            Ok(result) => {
+                println!("  Result: {}", result);
+                match &step.expected {
+                    Some(expected) => {
+                        check_assertion(engine.executor(), &wasm_file.bytes, step, expected)
+                            .map(|()| println!("  Assertion passed"))
+                    }
+                    None => Ok(()),
+                }
+            }
+            Err(message) => Err(message),
+        };
+        if let Err(message) = outcome {
+            failed_lines.push(step.line.to_string());
+            println!("  {}", Formatter::error(message));
+            if !args.continue_on_error {
+                println!(
+                    "{}",
+                    Formatter::warning(format!(
+                        "Stopping at line {}; pass --continue-on-error to run the remaining lines",
+                        step.line
+                    ))
+                );
+                break;
+            }
+        }
+    }
+
+    let storage_after = engine.executor().get_storage_snapshot()?;
+    let diff = StorageInspector::compute_diff(&storage_before, &storage_after, &[]);
+    println!("\n{}", Formatter::info("--- Storage Changes ---"));
+    if diff.is_empty() {
+        println!("(no changes)");
+    }
+    for line in diff.format_lines() {
+        println!("{}", line);
+    }
+
+    if failed_lines.is_empty() {
+        println!(
+            "\n{}",
+            Formatter::success(format!("All {} script steps succeeded", steps.len()))
+        );
+        Ok(())
+    } else {
+        Err(DebuggerError::ExecutionError(format!(
+            "{} of {} script step(s) failed at line(s) {}",
+            failed_lines.len(),
+            steps.len(),
+            failed_lines.join(", ")
+        ))
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_script_skips_comments_and_keeps_line_numbers() {
+        let steps = parse_script(
+            "# set up the oracle\ninitialize [\"GADMIN\"]\n\nset_price [\"BTC\", 100]\nget_price [\"BTC\"]\n",
+        )
+        .unwrap();
+
+        assert_eq!(steps.len(), 3);
+        assert_eq!(steps[0].line, 2);
+        assert_eq!(steps[0].function, "initialize");
+        assert_eq!(steps[1].args.as_deref(), Some("[\"BTC\", 100]"));
+        assert_eq!(steps[2].line, 5);
+    }
+
+    #[test]
+    fn parse_script_allows_calls_without_arguments() {
+        let steps = parse_script("increment\n  get  \n").unwrap();
+        assert_eq!(steps[0].args, None);
+        assert_eq!(steps[1].function, "get");
+    }
+
+    #[test]
+    fn parse_script_reads_assert_lines() {
+        let steps = parse_script(
+            "assert get_price [\"XLM\"] == 1100000\nassert get == 2\nassert echo [\"a==b\"] == \"a==b\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(steps[0].function, "get_price");
+        assert_eq!(steps[0].args.as_deref(), Some("[\"XLM\"]"));
+        assert_eq!(steps[0].expected, Some(serde_json::json!(1100000)));
+        assert_eq!(steps[1].args, None);
+        assert_eq!(steps[1].expected, Some(serde_json::json!(2)));
+        assert_eq!(steps[2].args.as_deref(), Some("[\"a==b\"]"));
+        assert_eq!(steps[2].expected, Some(serde_json::json!("a==b")));
+    }
+
+    #[test]
+    fn parse_script_rejects_assert_without_expected_value() {
+        let err = parse_script("get\nassert get [1]\n")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Line 2"), "{err}");
+    }
+
+    #[test]
+    fn parse_script_reports_line_of_invalid_json() {
+        let err = parse_script("increment\nset_price [\"BTC\", \n")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Line 2"), "{err}");
+        assert!(err.contains("set_price"), "{err}");
+    }
+}
@@ -121,6 +121,55 @@ impl DebugServer {
         Ok(())
     }
 
+    /// Run `function` `repeat_count` times and summarise the runs, when the
+    /// server was started with a repeat count above one.
+    fn repeat_response(&self, function: &str, args: Option<&str>) -> Option<DebugResponse> {
+        let count = self.repeat_count.filter(|count| *count > 1)?;
+        let wasm = self.contract_wasm.as_ref()?;
+        let breakpoints = self
+            .engine
+            .as_ref()
+            .map(|engine| engine.breakpoints().list())
+            .unwrap_or_default();
+        let initial_storage = self
+            .engine
+            .as_ref()
+            .and_then(|engine| engine.executor().get_storage_snapshot().ok())
+            .and_then(|storage| serde_json::to_string(&storage).ok());
+        let runner = crate::repeat::RepeatRunner::new(wasm.clone(), breakpoints, initial_storage);
+        let response = match runner.run(function, args, count) {
+            Ok(stats) => DebugResponse::ExecutionResult {
+                success: true,
+                output: format!(
+                    "--- Repeat Execution ({} runs) ---\n\nDuration:\n  Min: {:.2}ms, Max: {:.2}ms, Avg: {:.2}ms\n\nCPU Instructions:\n  Min: {}, Max: {}, Avg: {}\n\nMemory (bytes):\n  Min: {}, Max: {}, Avg: {}\n\nResults: {}",
+                    count,
+                    stats.min_duration.as_secs_f64() * 1000.0,
+                    stats.max_duration.as_secs_f64() * 1000.0,
+                    stats.avg_duration.as_secs_f64() * 1000.0,
+                    stats.min_cpu,
+                    stats.max_cpu,
+                    stats.avg_cpu,
+                    stats.min_memory,
+                    stats.max_memory,
+                    stats.avg_memory,
+                    if stats.inconsistent_results {
+                        "INCONSISTENT"
+                    } else {
+                        "CONSISTENT"
+                    }
+                ),
+                error: None,
+                paused: false,
+                completed: true,
+                source_location: None,
+            },
+            Err(e) => DebugResponse::Error {
+                message: e.to_string(),
+            },
+        };
+        Some(response)
+    }
+
     async fn handle_single_connection<S>(&mut self, stream: S) -> Result<()>
     where
         S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin + Send + 'static,
@@ -472,38 +521,11 @@ impl DebugServer {
                     },
                 },
                 DebugRequest::Execute { function, args } => {
-                    if let Some(count) = self.repeat_count {
-                        if count > 1 {
-                            if let Some(wasm) = &self.contract_wasm {
-                                let breakpoints = self.engine.as_ref().map(|e| e.breakpoints().list().into_iter().map(|b| b.function).collect()).unwrap_or_default();
-                                let initial_storage = self.engine.as_ref().and_then(|e| e.executor().get_storage_snapshot().ok()).and_then(|s| serde_json::to_string(&s).ok());
-                                let runner = crate::repeat::RepeatRunner::new(wasm.clone(), breakpoints, initial_storage);
-                                match runner.run(&function, args.as_deref(), count) {
-                                    Ok(stats) => {
-                                        let output = format!("--- Repeat Execution ({} runs) ---\n\nDuration:\n  Min: {:.2}ms, Max: {:.2}ms, Avg: {:.2}ms\n\nCPU Instructions:\n  Min: {}, Max: {}, Avg: {}\n\nMemory (bytes):\n  Min: {}, Max: {}, Avg: {}\n\nResults: {}", 
-                                            count, 
-                                            stats.min_duration.as_secs_f64()*1000.0, stats.max_duration.as_secs_f64()*1000.0, stats.avg_duration.as_secs_f64()*1000.0,
-                                            stats.min_cpu, stats.max_cpu, stats.avg_cpu,
-                                            stats.min_memory, stats.max_memory, stats.avg_memory,
-                                            if stats.inconsistent_results { "INCONSISTENT" } else { "CONSISTENT" }
-                                        );
-                                        return Ok(DebugResponse::ExecutionResult {
-                                            success: true,
-                                            output,
-                                            error: None,
-                                            paused: false,
-                                            completed: true,
-                                            source_location: None,
-                                        });
-                                    }
-                                    Err(e) => return Ok(DebugResponse::Error { message: e.to_string() }),
-                                }
-                            }
-                        }
-                    }
-
+                    if let Some(response) = self.repeat_response(&function, args.as_deref()) {
+                        response
+                    } else {
                     match self.engine.as_mut() {
-                    Some(engine) if engine.breakpoints().should_break(&function) => {
+                    Some(engine) if engine.breakpoints().get(&function).is_some() => {
                         match current_storage(engine) {
                             Ok(storage) => match engine.breakpoints_mut().on_hit(
                                 &function,
@@ -564,7 +586,9 @@ impl DebugServer {
                     None => DebugResponse::Error {
                         message: "No contract loaded".to_string(),
                     },
-                },
+                    }
+                    }
+                }
                 DebugRequest::Step | DebugRequest::StepIn => match self.engine.as_mut() {
                     Some(engine) => match engine.step_into() {
                         Ok(_) => {
@@ -706,6 +730,28 @@ impl DebugServer {
                         message: "No contract loaded".to_string(),
                     },
                 },
+                DebugRequest::StepInto => match self.engine.as_mut() {
+                    Some(engine) => step_call(
+                        engine,
+                        self.pending_execution.take(),
+                        false,
+                        &is_executing,
+                    ),
+                    None => DebugResponse::Error {
+                        message: "No contract loaded".to_string(),
+                    },
+                },
+                DebugRequest::StepOver => match self.engine.as_mut() {
+                    Some(engine) => step_call(
+                        engine,
+                        self.pending_execution.take(),
+                        true,
+                        &is_executing,
+                    ),
+                    None => DebugResponse::Error {
+                        message: "No contract loaded".to_string(),
+                    },
+                },
                 DebugRequest::Continue => match self.engine.as_mut() {
                     Some(engine) => {
                         if let Some(pending) = self.pending_execution.take() {
@@ -865,7 +911,7 @@ impl DebugServer {
                         let condition = match condition {
                             Some(condition) => match BreakpointManager::parse_condition(&condition)
                             {
-                                Ok(condition) => Some(condition),
+                                Ok(condition) => Some(condition.to_string()),
                                 Err(e) => {
                                     let response = DebugMessage::response(
                                         message.id,
@@ -1087,6 +1133,54 @@ fn execute_without_breakpoints(
     }
 }
 
+/// Cross-contract `StepInto`/`StepOver`. A call still paused at its function
+/// breakpoint runs first so that its calls are recorded.
+fn step_call(
+    engine: &mut DebuggerEngine,
+    pending: Option<PendingExecution>,
+    over: bool,
+    is_executing: &std::sync::atomic::AtomicBool,
+) -> DebugResponse {
+    let executed = pending.map(|pending| {
+        is_executing.store(true, std::sync::atomic::Ordering::SeqCst);
+        let r = engine.execute_without_breakpoints(&pending.function, pending.args.as_deref());
+        is_executing.store(false, std::sync::atomic::Ordering::SeqCst);
+        r
+    });
+    let stepped = if over {
+        engine.step_over_call()
+    } else {
+        engine.step_into_call()
+    };
+    match stepped {
+        Ok(boundary) => {
+            let (current_function, step_count) = engine
+                .state()
+                .lock()
+                .map(|state| {
+                    (
+                        state.current_function().map(|s| s.to_string()),
+                        state.step_count() as u64,
+                    )
+                })
+                .unwrap_or((None, 0));
+            DebugResponse::CallStepResult {
+                paused: engine.is_paused(),
+                current_function,
+                step_count,
+                boundary,
+            }
+        }
+        // Without any recorded calls, a failed execution explains more than the step error.
+        Err(e) => DebugResponse::Error {
+            message: match executed {
+                Some(Err(exec_error)) => exec_error.to_string(),
+                _ => e.to_string(),
+            },
+        },
+    }
+}
+
 fn current_storage(engine: &DebuggerEngine) -> Result<std::collections::HashMap<String, String>> {
     engine.executor().get_storage_snapshot()
 }
@@ -1188,7 +1282,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_graceful_shutdown_on_signal() {
-        let server = DebugServer::new(None, None, None).expect("Failed to create server");
+        let server =
+            DebugServer::new(None, None, None, None, Vec::new()).expect("Failed to create server");
         let shutdown = server.shutdown.clone();
 
         let local = tokio::task::LocalSet::new();
@@ -1211,7 +1306,8 @@ mod tests {
 
     #[test]
     fn test_server_initialization() {
-        let server = DebugServer::new(None, None, None).expect("Failed to create server");
+        let server =
+            DebugServer::new(None, None, None, None, Vec::new()).expect("Failed to create server");
         assert!(server.engine.is_none());
         assert!(server.token.is_none());
         assert!(server.tls_config.is_none());
@@ -1220,8 +1316,8 @@ mod tests {
     #[test]
     fn test_server_with_token() {
         let token = "test-token-12345678".to_string();
-        let server =
-            DebugServer::new(Some(token.clone()), None, None).expect("Failed to create server");
+        let server = DebugServer::new(Some(token.clone()), None, None, None, Vec::new())
+            .expect("Failed to create server");
         assert_eq!(server.token, Some(token));
     }
 }
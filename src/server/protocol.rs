@@ -73,7 +73,7 @@ pub fn negotiate_protocol_version(
     Ok(negotiated_max)
 }
 
-use crate::debugger::SourceBreakpointResolution;
+use crate::debugger::{CallBoundary, SourceBreakpointResolution};
 
 /// Structured event category used by dynamic security analysis.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
@@ -181,6 +181,12 @@ pub enum DebugRequest {
     /// Step over to next source line in the same frame
     StepOverLine,
 
+    /// Step to the next contract call boundary, breaking at a cross-contract callee's entry
+    StepInto,
+
+    /// Step to the next contract call boundary, running a cross-contract call as one step
+    StepOver,
+
     /// Continue execution
     Continue,
 
@@ -294,6 +300,15 @@ pub enum DebugResponse {
         source_location: Option<SourceLocation>,
     },
 
+    /// Cross-contract step result
+    CallStepResult {
+        paused: bool,
+        current_function: Option<String>,
+        step_count: u64,
+        /// Boundary execution stopped at; `None` once the last one was passed.
+        boundary: Option<CallBoundary>,
+    },
+
     /// Source-level step-over result
     StepOverLineResult {
         paused: bool,
@@ -0,0 +1,48 @@
+use crate::{DebuggerError, Result};
+use rustyline::history::FileHistory;
+use rustyline::{Editor, Helper};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding where interactive command history is stored.
+pub const HISTORY_PATH_ENV: &str = "SOROBAN_DEBUG_COMMAND_HISTORY";
+
+/// Resolve the command history file: `$SOROBAN_DEBUG_COMMAND_HISTORY`, or
+/// `~/.config/soroban-debug/history` when unset.
+pub fn default_history_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(HISTORY_PATH_ENV).filter(|p| !p.is_empty()) {
+        return Some(PathBuf::from(path));
+    }
+    dirs::home_dir().map(|home| home.join(".config").join("soroban-debug").join("history"))
+}
+
+/// Create a line editor, loading previously saved commands from `path` if it exists.
+pub fn open_editor<H: Helper>(path: Option<&Path>) -> Result<Editor<H, FileHistory>> {
+    let mut editor = Editor::new().map_err(|e| {
+        DebuggerError::FileError(format!("Failed to initialize line editor: {}", e))
+    })?;
+    if let Some(path) = path.filter(|p| p.exists()) {
+        editor.load_history(path).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to load history {:?}: {}", path, e))
+        })?;
+    }
+    Ok(editor)
+}
+
+/// Write the editor's history to `path`, creating parent directories as needed.
+pub fn save_history<H: Helper>(editor: &mut Editor<H, FileHistory>, path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() && !parent.exists() {
+            fs::create_dir_all(parent).map_err(|e| {
+                DebuggerError::FileError(format!(
+                    "Failed to create history directory {:?}: {}",
+                    parent, e
+                ))
+            })?;
+        }
+    }
+    editor.save_history(path).map_err(|e| {
+        DebuggerError::FileError(format!("Failed to save history {:?}: {}", path, e))
+    })?;
+    Ok(())
+}
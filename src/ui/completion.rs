@@ -0,0 +1,171 @@
+use rustyline::completion::{Completer, Pair};
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::{Context, Helper};
+
+/// Commands understood by the interactive debugger prompt.
+pub const COMMANDS: &[&str] = &[
+    "step",
+    "continue",
+    "inspect",
+    "run",
+    "run-to",
+    "execute-with",
+    "storage",
+    "events",
+    "stack",
+    "budget",
+    "break",
+    "watch",
+    "list-breaks",
+    "clear",
+    "help",
+    "quit",
+];
+
+/// Commands whose first argument is a contract function name.
+const FUNCTION_COMMANDS: &[&str] = &["run", "run-to", "break", "clear"];
+
+/// Tab completion for the interactive debugger.
+///
+/// Matching is case-sensitive so completions are always exact WASM exports.
+#[derive(Debug, Clone, Default)]
+pub struct DebuggerCompleter {
+    functions: Vec<String>,
+    storage_keys: Vec<String>,
+}
+
+impl DebuggerCompleter {
+    pub fn new(functions: Vec<String>) -> Self {
+        Self {
+            functions,
+            storage_keys: Vec::new(),
+        }
+    }
+
+    /// Replace the storage keys offered after `storage`, e.g. from the latest snapshot.
+    pub fn set_storage_keys(&mut self, mut keys: Vec<String>) {
+        keys.sort();
+        self.storage_keys = keys;
+    }
+
+    fn complete_from<'a>(candidates: impl IntoIterator<Item = &'a str>, prefix: &str) -> Vec<Pair> {
+        candidates
+            .into_iter()
+            .filter(|candidate| candidate.starts_with(prefix))
+            .map(|candidate| Pair {
+                display: candidate.to_string(),
+                replacement: candidate.to_string(),
+            })
+            .collect()
+    }
+
+    /// Completion start offset and candidates for the word ending at `pos`.
+    pub fn complete_for_input(&self, line: &str, pos: usize) -> (usize, Vec<Pair>) {
+        let input = &line[..pos];
+        let mut tokens: Vec<&str> = input.split_whitespace().collect();
+        let prefix = if input.is_empty() || input.ends_with(char::is_whitespace) {
+            ""
+        } else {
+            tokens.pop().unwrap_or("")
+        };
+        let start = pos - prefix.len();
+
+        let candidates = match tokens.as_slice() {
+            [] => Self::complete_from(COMMANDS.iter().copied(), prefix),
+            [command] if FUNCTION_COMMANDS.contains(command) => {
+                Self::complete_from(self.functions.iter().map(String::as_str), prefix)
+            }
+            ["storage"] | ["watch"] => {
+                Self::complete_from(self.storage_keys.iter().map(String::as_str), prefix)
+            }
+            _ => Vec::new(),
+        };
+        (start, candidates)
+    }
+}
+
+impl Completer for DebuggerCompleter {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Self::Candidate>)> {
+        Ok(self.complete_for_input(line, pos))
+    }
+}
+
+impl Hinter for DebuggerCompleter {
+    type Hint = String;
+}
+
+impl Highlighter for DebuggerCompleter {}
+
+impl Validator for DebuggerCompleter {}
+
+impl Helper for DebuggerCompleter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn oracle() -> DebuggerCompleter {
+        let mut completer = DebuggerCompleter::new(vec![
+            "get_price".to_string(),
+            "init".to_string(),
+            "set_admin".to_string(),
+            "set_price".to_string(),
+        ]);
+        completer.set_storage_keys(vec![
+            r#"Price("XLM")"#.to_string(),
+            "Admin".to_string(),
+            r#"Price("BTC")"#.to_string(),
+        ]);
+        completer
+    }
+
+    fn replacements(line: &str) -> (usize, Vec<String>) {
+        let (start, pairs) = oracle().complete_for_input(line, line.len());
+        (start, pairs.into_iter().map(|p| p.replacement).collect())
+    }
+
+    #[test]
+    fn completes_oracle_functions_after_run() {
+        assert_eq!(
+            replacements("run set_"),
+            (4, vec!["set_admin".to_string(), "set_price".to_string()])
+        );
+        assert_eq!(replacements("break ").1.len(), 4);
+        assert_eq!(replacements("run-to g").1, vec!["get_price"]);
+    }
+
+    #[test]
+    fn completion_is_case_sensitive() {
+        assert!(replacements("run Set").1.is_empty());
+    }
+
+    #[test]
+    fn completes_commands_at_line_start() {
+        assert_eq!(
+            replacements("st"),
+            (0, vec!["step".into(), "storage".into(), "stack".into()])
+        );
+        assert_eq!(replacements("ev").1, vec!["events"]);
+    }
+
+    #[test]
+    fn completes_storage_keys_from_snapshot() {
+        assert_eq!(
+            replacements("storage Pr"),
+            (
+                8,
+                vec![r#"Price("BTC")"#.to_string(), r#"Price("XLM")"#.to_string()]
+            )
+        );
+        assert!(replacements("step ").1.is_empty());
+    }
+}
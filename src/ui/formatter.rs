@@ -243,6 +243,19 @@ impl Formatter {
         }
     }
 
+    /// Cut `text` to `max_chars` characters, marking how long it was, so a huge
+    /// result does not flood the terminal. `None` leaves it untouched.
+    pub fn truncate_output(text: &str, max_chars: Option<usize>) -> String {
+        let total = text.chars().count();
+        match max_chars {
+            Some(max_chars) if total > max_chars => {
+                let kept: String = text.chars().take(max_chars).collect();
+                format!("{}… (truncated, {} total)", kept, total)
+            }
+            _ => text.to_string(),
+        }
+    }
+
     /// Render a sparkline for a numeric series (downsampled to `width`).
     pub fn sparkline(values: &[u64], width: usize) -> String {
         if values.is_empty() || width == 0 {
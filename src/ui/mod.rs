@@ -1,3 +1,5 @@
+pub mod command_history;
+pub mod completion;
 pub mod dashboard;
 pub mod formatter;
 pub mod tui;
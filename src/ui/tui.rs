@@ -1,7 +1,13 @@
 use crate::debugger::engine::DebuggerEngine;
+use crate::inspector::decoded_storage::DecodedValue;
+use crate::inspector::events::EventInspector;
 use crate::inspector::{BudgetInspector, StorageInspector};
+use crate::ui::command_history;
+use crate::ui::completion::DebuggerCompleter;
 use crate::Result;
-use std::io::{self, Write};
+use rustyline::error::ReadlineError;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 
 #[derive(Debug, Clone)]
 struct PendingExecution {
@@ -14,8 +20,13 @@ pub struct DebuggerUI {
     engine: DebuggerEngine,
     storage_inspector: StorageInspector,
     pending_execution: Option<PendingExecution>,
+    last_execution: Option<PendingExecution>,
     last_output: Option<String>,
     last_error: Option<String>,
+    /// Function `run-to` is waiting for; cleared once it is reached.
+    run_to_target: Option<String>,
+    /// File the command history is loaded from and saved to; `None` disables persistence.
+    history_path: Option<PathBuf>,
 }
 
 impl DebuggerUI {
@@ -24,8 +35,11 @@ impl DebuggerUI {
             engine,
             storage_inspector: StorageInspector::new(),
             pending_execution: None,
+            last_execution: None,
             last_output: None,
             last_error: None,
+            run_to_target: None,
+            history_path: None,
         })
     }
 
@@ -47,39 +61,73 @@ impl DebuggerUI {
         self.last_error.as_deref()
     }
 
+    pub fn engine(&self) -> &DebuggerEngine {
+        &self.engine
+    }
+
+    /// Persist command history to `path` across sessions (`None` keeps it in memory only).
+    pub fn set_history_path(&mut self, path: Option<PathBuf>) {
+        self.history_path = path;
+    }
+
     /// Run the interactive UI loop.
     pub fn run(&mut self) -> Result<()> {
         self.print_help();
 
-        loop {
-            print!("\n(debug) ");
-            io::stdout().flush().map_err(|e| {
-                crate::DebuggerError::FileError(format!("Failed to flush stdout: {}", e))
-            })?;
+        let mut editor = command_history::open_editor(self.history_path.as_deref())?;
+        let functions = self
+            .engine
+            .executor()
+            .exported_functions()
+            .unwrap_or_default();
+        editor.set_helper(Some(DebuggerCompleter::new(functions)));
 
-            let mut input = String::new();
-            io::stdin().read_line(&mut input).map_err(|e| {
-                crate::DebuggerError::FileError(format!("Failed to read line: {}", e))
-            })?;
+        let result = loop {
+            if let Some(helper) = editor.helper_mut() {
+                helper.set_storage_keys(self.storage_keys());
+            }
+            println!();
+            let input = match editor.readline("(debug) ") {
+                Ok(line) => line,
+                Err(ReadlineError::Interrupted) => {
+                    tracing::info!("Use 'quit' or Ctrl+D to exit");
+                    continue;
+                }
+                Err(ReadlineError::Eof) => break Ok(()),
+                Err(e) => {
+                    break Err(crate::DebuggerError::FileError(format!(
+                        "Failed to read line: {}",
+                        e
+                    ))
+                    .into())
+                }
+            };
 
             let command = input.trim();
             if command.is_empty() {
                 continue;
             }
+            let _ = editor.add_history_entry(command);
 
             match self.handle_command(command) {
                 Ok(should_exit) => {
                     if should_exit {
-                        break;
+                        break Ok(());
                     }
                 }
                 Err(e) => {
                     tracing::error!(error = %e, "Command execution error");
                 }
             }
+        };
+
+        if let Some(path) = &self.history_path {
+            if let Err(e) = command_history::save_history(&mut editor, path) {
+                tracing::warn!(error = %e, "Failed to save command history");
+            }
         }
 
-        Ok(())
+        result
     }
 
     pub fn handle_command(&mut self, command: &str) -> Result<bool> {
@@ -97,32 +145,36 @@ impl DebuggerUI {
             }
             "c" | "continue" => {
                 if let Some(pending) = self.pending_execution.take() {
-                    match self
-                        .engine
-                        .execute_without_breakpoints(&pending.function, pending.args.as_deref())
-                    {
-                        Ok(output) => {
-                            self.last_error = None;
-                            self.last_output = Some(output.clone());
-                            crate::logging::log_display(
-                                format!("Result: {}", output),
-                                crate::logging::LogLevel::Info,
-                            );
-                        }
-                        Err(e) => {
-                            self.last_output = None;
-                            self.last_error = Some(e.to_string());
-                            crate::logging::log_display(
-                                format!("Error: {}", e),
-                                crate::logging::LogLevel::Error,
-                            );
-                        }
-                    }
+                    self.run_execution(pending);
                 } else {
                     self.engine.continue_execution()?;
                     tracing::info!("Execution continuing");
                 }
             }
+            "execute-with" => {
+                if parts.len() < 2 {
+                    tracing::warn!("execute-with command missing overrides");
+                } else {
+                    let pending = self.override_last_args(&parts[1..])?;
+                    self.run_execution(pending);
+                }
+            }
+            "run-to" => {
+                if parts.len() < 2 {
+                    tracing::warn!("run-to command missing function name");
+                } else {
+                    self.run_to_target = Some(parts[1].to_string());
+                    if let Some(pending) = self.pending_execution.take() {
+                        self.run_execution(pending);
+                    }
+                    if let Some(target) = &self.run_to_target {
+                        crate::logging::log_display(
+                            format!("Will pause when {} is reached", target),
+                            crate::logging::LogLevel::Info,
+                        );
+                    }
+                }
+            }
             "i" | "inspect" => {
                 self.inspect();
             }
@@ -140,7 +192,30 @@ impl DebuggerUI {
                 }
             }
             "storage" => {
-                self.storage_inspector.display();
+                if parts.len() < 2 {
+                    self.storage_inspector.display();
+                } else {
+                    let key = parts[1..].join(" ");
+                    match self.decoded_storage().get(&key) {
+                        Some(value) => crate::logging::log_display(
+                            format!("{} = {}", key, value.rendered),
+                            crate::logging::LogLevel::Info,
+                        ),
+                        None => tracing::warn!(key, "No storage entry for key"),
+                    }
+                }
+            }
+            "events" => {
+                let events = self.engine.executor().get_events()?;
+                if events.is_empty() {
+                    crate::logging::log_display(
+                        "No events emitted",
+                        crate::logging::LogLevel::Info,
+                    );
+                }
+                for line in EventInspector::format_events(&events) {
+                    crate::logging::log_display(line, crate::logging::LogLevel::Info);
+                }
             }
             "stack" => {
                 if let Ok(state) = self.engine.state().lock() {
@@ -158,6 +233,30 @@ impl DebuggerUI {
                     crate::logging::log_breakpoint_set(parts[1]);
                 }
             }
+            "watch" => {
+                if parts.len() < 2 {
+                    let watches = self.engine.breakpoints().watches();
+                    if watches.is_empty() {
+                        crate::logging::log_display(
+                            "No watchpoints set",
+                            crate::logging::LogLevel::Info,
+                        );
+                    }
+                    for watch in watches {
+                        crate::logging::log_display(
+                            format!("- {}", watch),
+                            crate::logging::LogLevel::Info,
+                        );
+                    }
+                } else {
+                    let key = parts[1..].join(" ");
+                    self.engine.breakpoints_mut().add_watch(&key);
+                    crate::logging::log_display(
+                        format!("Watching storage key {}", key),
+                        crate::logging::LogLevel::Info,
+                    );
+                }
+            }
             "list-breaks" => {
                 let breakpoints = self.engine.breakpoints_mut().list_detailed();
                 if breakpoints.is_empty() {
@@ -167,13 +266,8 @@ impl DebuggerUI {
                     );
                 } else {
                     for bp in breakpoints {
-                        let cond_str = bp
-                            .condition
-                            .clone()
-                            .map(|c| format!(" (if {:?})", c))
-                            .unwrap_or_default();
                         crate::logging::log_display(
-                            format!("- {}{}", bp.function, cond_str),
+                            format!("- {}", bp),
                             crate::logging::LogLevel::Info,
                         );
                     }
@@ -199,6 +293,106 @@ impl DebuggerUI {
         Ok(false)
     }
 
+    /// Keys of the current storage as the spec decodes them, e.g. `Price("XLM")`,
+    /// offered as `storage` and `watch` completions.
+    pub fn storage_keys(&self) -> Vec<String> {
+        self.decoded_storage().into_keys().collect()
+    }
+
+    /// Contract storage keyed by decoded key, the form users type after `storage`.
+    fn decoded_storage(&self) -> BTreeMap<String, DecodedValue> {
+        let executor = self.engine.executor();
+        executor
+            .contract_data_entries()
+            .map(|entries| executor.storage_decoder().decode_entries(&entries))
+            .unwrap_or_default()
+    }
+
+    fn run_execution(&mut self, pending: PendingExecution) {
+        let result = if self.run_to_target.as_deref() == Some(pending.function.as_str()) {
+            self.run_to_target = None;
+            self.engine
+                .run_to(&pending.function, pending.args.as_deref())
+        } else {
+            self.engine
+                .execute_without_breakpoints(&pending.function, pending.args.as_deref())
+        };
+        match result {
+            Ok(output) => {
+                self.last_error = None;
+                self.last_output = Some(output.clone());
+                crate::logging::log_display(
+                    format!("Result: {}", output),
+                    crate::logging::LogLevel::Info,
+                );
+            }
+            Err(e) => {
+                self.last_output = None;
+                self.last_error = Some(e.to_string());
+                crate::logging::log_display(
+                    format!("Error: {}", e),
+                    crate::logging::LogLevel::Error,
+                );
+            }
+        }
+        self.last_execution = Some(pending);
+    }
+
+    /// Apply `arg.<path>=<value>` overrides to the last invocation's arguments.
+    ///
+    /// `arg` is the argument list as passed to `run`, so positional calls use
+    /// `arg[0].price`. Values are parsed as JSON and fall back to a string.
+    fn override_last_args(&self, overrides: &[&str]) -> Result<PendingExecution> {
+        let last = self.last_execution.as_ref().ok_or_else(|| {
+            crate::DebuggerError::InvalidArguments(
+                "execute-with needs a previous execution to reuse".to_string(),
+            )
+        })?;
+        let args: serde_json::Value = serde_json::from_str(last.args.as_deref().unwrap_or("[]"))
+            .map_err(|e| {
+                crate::DebuggerError::InvalidArguments(format!(
+                    "Last arguments are not valid JSON: {}",
+                    e
+                ))
+            })?;
+
+        let mut root = serde_json::json!({ "arg": args });
+        for assignment in overrides {
+            let (path, raw) = assignment.split_once('=').ok_or_else(|| {
+                crate::DebuggerError::InvalidArguments(format!(
+                    "Expected <path>=<value>, got '{}'",
+                    assignment
+                ))
+            })?;
+            let path = match path.strip_prefix("args") {
+                Some(rest) => format!("arg{}", rest),
+                None => path.to_string(),
+            };
+            if path == "arg" || !(path.starts_with("arg.") || path.starts_with("arg[")) {
+                return Err(crate::DebuggerError::InvalidArguments(format!(
+                    "Override path must start with 'arg.' or 'arg[': {}",
+                    path
+                ))
+                .into());
+            }
+            let value = serde_json::from_str(raw)
+                .unwrap_or_else(|_| serde_json::Value::String(raw.to_string()));
+            crate::debugger::breakpoint::set_json_path(&mut root, &path, value).ok_or_else(
+                || {
+                    crate::DebuggerError::InvalidArguments(format!(
+                        "Argument field '{}' does not exist in the last invocation",
+                        path
+                    ))
+                },
+            )?;
+        }
+
+        Ok(PendingExecution {
+            function: last.function.clone(),
+            args: Some(root["arg"].to_string()),
+        })
+    }
+
     fn inspect(&self) {
         crate::logging::log_display("\n=== Current State ===", crate::logging::LogLevel::Info);
         if let Ok(state) = self.engine.state().lock() {
@@ -258,7 +452,19 @@ impl DebuggerUI {
             crate::logging::LogLevel::Info,
         );
         crate::logging::log_display(
-            "  storage            Show tracked storage view",
+            "  run-to <func>      Continue until <func> is reached, then pause there",
+            crate::logging::LogLevel::Info,
+        );
+        crate::logging::log_display(
+            "  execute-with arg.<path>=<value>  Re-run the last call with an argument overridden",
+            crate::logging::LogLevel::Info,
+        );
+        crate::logging::log_display(
+            "  storage [key]      Show tracked storage view, or one entry",
+            crate::logging::LogLevel::Info,
+        );
+        crate::logging::log_display(
+            "  events             Show events emitted so far",
             crate::logging::LogLevel::Info,
         );
         crate::logging::log_display(
@@ -273,6 +479,10 @@ impl DebuggerUI {
             "  break <func> [cond] Set breakpoint with optional condition",
             crate::logging::LogLevel::Info,
         );
+        crate::logging::log_display(
+            "  watch [key]        Pause when a storage key changes (`*` globs); list watches",
+            crate::logging::LogLevel::Info,
+        );
         crate::logging::log_display(
             "  list-breaks        List breakpoints",
             crate::logging::LogLevel::Info,
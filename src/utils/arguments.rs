@@ -18,10 +18,21 @@
 //! | `i64`    | `{"type": "i64", "value": -999}`         | Signed 64-bit integer          |
 //! | `u128`   | `{"type": "u128", "value": 100}`         | Unsigned 128-bit integer       |
 //! | `i128`   | `{"type": "i128", "value": 100}`         | Signed 128-bit integer         |
+//! | `u256`   | `{"type": "u256", "value": "0xff"}`      | Unsigned 256-bit integer       |
+//! | `i256`   | `{"type": "i256", "value": "-5"}`        | Signed 256-bit integer         |
+//! | `timepoint` | `{"type": "timepoint", "value": "1700000000"}` | Ledger timestamp (u64) |
+//! | `duration` | `{"type": "duration", "value": "3600"}` | Time span in seconds (u64)     |
+//! | `bytes`  | `{"type": "bytes", "value": "deadbeef"}` | Hex (`0x` optional) or `base64:` |
+//! | `bytesn` | `{"type": "bytesn", "size": 32, "value": "..."}` | Fixed-length bytes      |
+//! | `map`    | `{"type": "map", "value": [["XLM", 1]]}` | Map from `[key, value]` pairs    |
 //! | `bool`   | `{"type": "bool", "value": true}`        | Boolean                        |
 //! | `symbol` | `{"type": "symbol", "value": "hello"}`   | Soroban Symbol (≤32 chars)     |
 //! | `string` | `{"type": "string", "value": "long..."}`  | Soroban String (any length)    |
 //!
+//! 256-bit and temporal values take a decimal string or a `0x`-prefixed hex string.
+//! A `bytes`/`bytesn` value of `"@path"` is replaced by the contents of that file
+//! (see [`resolve_file_references`]).
+//!
 //! Bare values (without type annotation) still work:
 //! - Numbers → `i128`
 //! - Strings → `Symbol`
@@ -29,10 +40,12 @@
 
 use hex;
 use serde_json::Value;
+use soroban_sdk::xdr::{Duration, Int256Parts, ScVal, TimePoint, UInt256Parts};
 use soroban_sdk::{
     Address, Env, Map, String as SorobanString, Symbol, TryFromVal, Val, Vec as SorobanVec,
 };
 use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::Path;
 use thiserror::Error;
 use tracing::{debug, warn};
 
@@ -42,7 +55,7 @@ pub enum ArgumentParseError {
     #[error("Invalid argument: {0}")]
     InvalidArgument(String),
 
-    #[error("Unsupported type: {0}. Supported types: u32, i32, u64, u128, i128, bool, string, symbol, address, option, tuple, vec, bytes, bytesn")]
+    #[error("Unsupported type: {0}. Supported types: u32, i32, u64, u128, i128, u256, i256, timepoint, duration, bool, string, symbol, address, option, tuple, vec, map, bytes, bytesn")]
     UnsupportedType(String),
 
     #[error("Failed to convert value: {0}")]
@@ -160,15 +173,16 @@ impl ArgumentParser {
             }
 
             let type_name = obj["type"].as_str().unwrap_or_default();
-            let allowed_extra = match type_name {
-                "tuple" => Some("arity"),
-                "vec" => Some("element_type"),
-                "bytesn" => Some("length"),
-                _ => None,
+            let allowed_extra: &[&str] = match type_name {
+                "tuple" => &["arity"],
+                "vec" => &["element_type"],
+                "map" => &["key_type", "value_type"],
+                "bytesn" => &["length", "size"],
+                _ => &[],
             };
 
             obj.keys()
-                .all(|k| k == "type" || k == "value" || Some(k.as_str()) == allowed_extra)
+                .all(|k| k == "type" || k == "value" || allowed_extra.contains(&k.as_str()))
         } else {
             false
         }
@@ -193,6 +207,10 @@ impl ArgumentParser {
             "i64" => self.convert_i64(val),
             "u128" => self.convert_u128(val),
             "i128" => self.convert_i128(val),
+            "u256" => self.convert_u256(val),
+            "i256" => self.convert_i256(val),
+            "timepoint" => self.convert_timepoint(val),
+            "duration" => self.convert_duration(val),
             "bool" => self.convert_bool(val),
             "string" => self.convert_string(val),
             "symbol" => self.convert_symbol(val),
@@ -200,6 +218,7 @@ impl ArgumentParser {
             "option" => self.convert_option(val),
             "tuple" => self.convert_tuple(val, obj),
             "vec" => self.convert_vec(val, obj),
+            "map" => self.convert_map(val, obj),
             "bytes" => self.convert_bytes(val),
             "bytesn" => self.convert_bytesn(val, obj),
             other => Err(ArgumentParseError::UnsupportedType(other.to_string())),
@@ -280,34 +299,113 @@ impl ArgumentParser {
         })
     }
 
-    /// Convert a JSON number to u128 Val
+    /// Convert a JSON number or decimal string to u128 Val
     fn convert_u128(&self, value: &Value) -> Result<Val, ArgumentParseError> {
-        let n = value
-            .as_u64()
-            .ok_or_else(|| ArgumentParseError::TypeMismatch {
-                expected: "u128 (non-negative integer)".to_string(),
-                actual: format!("{}", value),
-            })?;
+        let n = match value {
+            Value::String(s) => s.trim().parse::<u128>().ok(),
+            _ => value.as_u64().map(u128::from),
+        }
+        .ok_or_else(|| ArgumentParseError::TypeMismatch {
+            expected: "u128 (non-negative integer)".to_string(),
+            actual: format!("{}", value),
+        })?;
 
-        Val::try_from_val(&self.env, &(n as u128)).map_err(|e| {
+        Val::try_from_val(&self.env, &n).map_err(|e| {
             ArgumentParseError::ConversionError(format!("Failed to convert u128 to Val: {:?}", e))
         })
     }
 
-    /// Convert a JSON number to i128 Val
+    /// Convert a JSON number or decimal string to i128 Val
     fn convert_i128(&self, value: &Value) -> Result<Val, ArgumentParseError> {
-        let n = value
-            .as_i64()
-            .ok_or_else(|| ArgumentParseError::TypeMismatch {
-                expected: "i128 (integer)".to_string(),
-                actual: format!("{}", value),
-            })?;
+        let n = match value {
+            Value::String(s) => s.trim().parse::<i128>().ok(),
+            _ => value.as_i64().map(i128::from),
+        }
+        .ok_or_else(|| ArgumentParseError::TypeMismatch {
+            expected: "i128 (integer)".to_string(),
+            actual: format!("{}", value),
+        })?;
 
-        Val::try_from_val(&self.env, &(n as i128)).map_err(|e| {
+        Val::try_from_val(&self.env, &n).map_err(|e| {
             ArgumentParseError::ConversionError(format!("Failed to convert i128 to Val: {:?}", e))
         })
     }
 
+    /// Convert a decimal or `0x` hex string to a U256 Val
+    fn convert_u256(&self, value: &Value) -> Result<Val, ArgumentParseError> {
+        let text = big_int_text(value, "u256")?;
+        let (hi, lo) = parse_u256_str(&text, "u256")?;
+        let parts = UInt256Parts {
+            hi_hi: (hi >> 64) as u64,
+            hi_lo: hi as u64,
+            lo_hi: (lo >> 64) as u64,
+            lo_lo: lo as u64,
+        };
+        self.scval_to_val(&ScVal::U256(parts), "u256")
+    }
+
+    /// Convert a decimal or `0x` hex string (optionally negative) to an I256 Val
+    fn convert_i256(&self, value: &Value) -> Result<Val, ArgumentParseError> {
+        let text = big_int_text(value, "i256")?;
+        let (negative, magnitude) = match text.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, text.as_str()),
+        };
+        let (hi, lo) = parse_u256_str(magnitude, "i256")?;
+
+        // |i256::MIN| is 2^255, one more than i256::MAX
+        let sign_bit = 1u128 << 127;
+        let fits = if negative {
+            hi < sign_bit || (hi == sign_bit && lo == 0)
+        } else {
+            hi < sign_bit
+        };
+        if !fits {
+            return Err(ArgumentParseError::OutOfRange {
+                type_name: "i256".to_string(),
+                value: text.clone(),
+                min: I256_MIN.to_string(),
+                max: I256_MAX.to_string(),
+            });
+        }
+
+        let (hi, lo) = if negative {
+            let lo = (!lo).wrapping_add(1);
+            let hi = (!hi).wrapping_add(u128::from(lo == 0));
+            (hi, lo)
+        } else {
+            (hi, lo)
+        };
+        let parts = Int256Parts {
+            hi_hi: (hi >> 64) as i64,
+            hi_lo: hi as u64,
+            lo_hi: (lo >> 64) as u64,
+            lo_lo: lo as u64,
+        };
+        self.scval_to_val(&ScVal::I256(parts), "i256")
+    }
+
+    /// Convert a number or decimal/hex string to a Timepoint Val
+    fn convert_timepoint(&self, value: &Value) -> Result<Val, ArgumentParseError> {
+        let n = parse_u64_text(value, "timepoint")?;
+        self.scval_to_val(&ScVal::Timepoint(TimePoint(n)), "timepoint")
+    }
+
+    /// Convert a number or decimal/hex string to a Duration Val
+    fn convert_duration(&self, value: &Value) -> Result<Val, ArgumentParseError> {
+        let n = parse_u64_text(value, "duration")?;
+        self.scval_to_val(&ScVal::Duration(Duration(n)), "duration")
+    }
+
+    fn scval_to_val(&self, scval: &ScVal, type_name: &str) -> Result<Val, ArgumentParseError> {
+        Val::try_from_val(&self.env, scval).map_err(|e| {
+            ArgumentParseError::ConversionError(format!(
+                "Failed to convert {} to Val: {:?}",
+                type_name, e
+            ))
+        })
+    }
+
     /// Convert a JSON boolean to Bool Val
     fn convert_bool(&self, value: &Value) -> Result<Val, ArgumentParseError> {
         let b = value
@@ -410,6 +508,71 @@ impl ArgumentParser {
         Ok(soroban_vec.into())
     }
 
+    /// Convert `[[key, value], ...]` pairs to a Soroban Map.
+    ///
+    /// `key_type`/`value_type` force the annotation used for each side, like
+    /// `element_type` does for vec. Entries end up key-ordered, as with
+    /// `soroban_sdk::Map`; a key given twice is rejected.
+    fn convert_map(
+        &self,
+        value: &Value,
+        obj: &serde_json::Map<String, Value>,
+    ) -> Result<Val, ArgumentParseError> {
+        let pairs = value
+            .as_array()
+            .ok_or_else(|| ArgumentParseError::TypeMismatch {
+                expected: "array of [key, value] pairs for map".to_string(),
+                actual: format!("{}", value),
+            })?;
+
+        let key_type = obj.get("key_type").and_then(|v| v.as_str());
+        let value_type = obj.get("value_type").and_then(|v| v.as_str());
+        let mut soroban_map = Map::<Val, Val>::new(&self.env);
+
+        for (i, pair) in pairs.iter().enumerate() {
+            let [key, val] = pair.as_array().map(Vec::as_slice).unwrap_or_default() else {
+                return Err(ArgumentParseError::InvalidArgument(format!(
+                    "Map entry {} must be a [key, value] pair, got {}",
+                    i, pair
+                )));
+            };
+
+            let key_val = self.parse_with_type(key, key_type).map_err(|e| {
+                ArgumentParseError::ConversionError(format!("Map key {}: {}", i, e))
+            })?;
+            if soroban_map.contains_key(key_val) {
+                return Err(ArgumentParseError::InvalidArgument(format!(
+                    "Duplicate map key {} at entry {}",
+                    key, i
+                )));
+            }
+            let val_val = self.parse_with_type(val, value_type).map_err(|e| {
+                ArgumentParseError::ConversionError(format!("Map value for key {}: {}", key, e))
+            })?;
+
+            soroban_map.set(key_val, val_val);
+        }
+
+        Ok(soroban_map.into())
+    }
+
+    /// Parse `item` as the named annotation type, or as a bare value when `None`.
+    fn parse_with_type(
+        &self,
+        item: &Value,
+        type_name: Option<&str>,
+    ) -> Result<Val, ArgumentParseError> {
+        match type_name {
+            Some(type_name) => {
+                let mut typed_item = serde_json::Map::new();
+                typed_item.insert("type".to_string(), Value::String(type_name.to_string()));
+                typed_item.insert("value".to_string(), item.clone());
+                self.parse_typed_value(&Value::Object(typed_item))
+            }
+            None => self.json_to_soroban_val(item),
+        }
+    }
+
     /// Convert a JSON array to a Soroban tuple (fixed length array)
     fn convert_tuple(
         &self,
@@ -451,21 +614,33 @@ impl ArgumentParser {
         Ok(soroban_vec.into())
     }
 
+    /// Decode hex (with or without `0x`) or `base64:`-prefixed text into bytes
     fn decode_bytes_string(&self, s: &str) -> Result<Vec<u8>, ArgumentParseError> {
-        if let Some(hex_part) = s.strip_prefix("0x") {
-            hex::decode(hex_part).map_err(|e| {
-                ArgumentParseError::InvalidArgument(format!("Invalid hex string: {}", e))
-            })
-        } else if let Some(b64_part) = s.strip_prefix("base64:") {
+        if let Some(b64_part) = s.strip_prefix("base64:") {
             use base64::{engine::general_purpose, Engine};
-            general_purpose::STANDARD.decode(b64_part).map_err(|e| {
+            return general_purpose::STANDARD.decode(b64_part).map_err(|e| {
                 ArgumentParseError::InvalidArgument(format!("Invalid base64 string: {}", e))
-            })
-        } else {
-            Err(ArgumentParseError::InvalidArgument(
-                "Bytes must start with '0x' or 'base64:'".to_string(),
-            ))
+            });
+        }
+
+        let hex_part = match s.strip_prefix("0x") {
+            Some(hex_part) => hex_part,
+            None if s.bytes().all(|b| b.is_ascii_hexdigit()) => s,
+            None => {
+                return Err(ArgumentParseError::InvalidArgument(
+                    "Bytes must be hex (optionally '0x'-prefixed) or start with 'base64:'"
+                        .to_string(),
+                ))
+            }
+        };
+        if hex_part.len() % 2 != 0 {
+            return Err(ArgumentParseError::InvalidArgument(format!(
+                "Invalid hex string: odd number of digits ({})",
+                hex_part.len()
+            )));
         }
+        hex::decode(hex_part)
+            .map_err(|e| ArgumentParseError::InvalidArgument(format!("Invalid hex string: {}", e)))
     }
 
     fn convert_bytes(&self, value: &Value) -> Result<Val, ArgumentParseError> {
@@ -494,9 +669,15 @@ impl ArgumentParser {
                 actual: format!("{}", value),
             })?;
         let bytes = self.decode_bytes_string(s)?;
-        let expected_length = obj.get("length").and_then(|l| l.as_u64()).ok_or_else(|| {
-            ArgumentParseError::InvalidArgument("BytesN requires a 'length' field".to_string())
-        })? as usize;
+        let expected_length = obj
+            .get("length")
+            .or_else(|| obj.get("size"))
+            .and_then(|l| l.as_u64())
+            .ok_or_else(|| {
+                ArgumentParseError::InvalidArgument(
+                    "BytesN requires a 'length' field (alias: 'size')".to_string(),
+                )
+            })? as usize;
 
         if bytes.len() != expected_length {
             return Err(ArgumentParseError::InvalidArgument(format!(
@@ -717,11 +898,335 @@ impl ArgumentParser {
     }
 }
 
+const U256_MAX: &str =
+    "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+const I256_MIN: &str =
+    "-57896044618658097711785492504343953926634992332820282019728792003956564819968";
+const I256_MAX: &str =
+    "57896044618658097711785492504343953926634992332820282019728792003956564819967";
+
+/// Text form of a 256-bit value; JSON numbers are accepted for convenience.
+fn big_int_text(value: &Value, type_name: &str) -> Result<String, ArgumentParseError> {
+    match value {
+        Value::String(s) => Ok(s.trim().to_string()),
+        Value::Number(n) if n.is_i64() || n.is_u64() => Ok(n.to_string()),
+        _ => Err(ArgumentParseError::TypeMismatch {
+            expected: format!("{} (decimal or 0x-prefixed hex string)", type_name),
+            actual: format!("{}", value),
+        }),
+    }
+}
+
+/// Parse an unsigned decimal or `0x` hex string into 256 bits as `(hi, lo)` halves.
+fn parse_u256_str(text: &str, type_name: &str) -> Result<(u128, u128), ArgumentParseError> {
+    let mismatch = || ArgumentParseError::TypeMismatch {
+        expected: format!("{} (decimal or 0x-prefixed hex string)", type_name),
+        actual: format!("\"{}\"", text),
+    };
+    let out_of_range = || ArgumentParseError::OutOfRange {
+        type_name: type_name.to_string(),
+        value: text.to_string(),
+        min: "0".to_string(),
+        max: U256_MAX.to_string(),
+    };
+
+    if let Some(hex_digits) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        if hex_digits.is_empty() || !hex_digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(mismatch());
+        }
+        let digits = hex_digits.trim_start_matches('0');
+        if digits.len() > 64 {
+            return Err(out_of_range());
+        }
+        let split = digits.len().saturating_sub(32);
+        let hi = if split == 0 {
+            0
+        } else {
+            u128::from_str_radix(&digits[..split], 16).map_err(|_| mismatch())?
+        };
+        let lo = if digits.is_empty() {
+            0
+        } else {
+            u128::from_str_radix(&digits[split..], 16).map_err(|_| mismatch())?
+        };
+        return Ok((hi, lo));
+    }
+
+    if text.is_empty() || !text.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(mismatch());
+    }
+
+    // (hi, lo) = (hi, lo) * 10 + digit, carrying through 64-bit limbs of `lo`
+    let (mut hi, mut lo) = (0u128, 0u128);
+    for digit in text.bytes().map(|b| u128::from(b - b'0')) {
+        let low = (lo & u128::from(u64::MAX)) * 10 + digit;
+        let mid = (lo >> 64) * 10 + (low >> 64);
+        lo = ((mid & u128::from(u64::MAX)) << 64) | (low & u128::from(u64::MAX));
+        hi = hi
+            .checked_mul(10)
+            .and_then(|h| h.checked_add(mid >> 64))
+            .ok_or_else(out_of_range)?;
+    }
+    Ok((hi, lo))
+}
+
+/// Parse a timepoint/duration from a JSON number or a decimal/hex string.
+fn parse_u64_text(value: &Value, type_name: &str) -> Result<u64, ArgumentParseError> {
+    if let Some(n) = value.as_u64() {
+        return Ok(n);
+    }
+    let text = big_int_text(value, type_name)?;
+    let (hi, lo) = parse_u256_str(&text, type_name)?;
+    if hi != 0 || lo > u128::from(u64::MAX) {
+        return Err(ArgumentParseError::OutOfRange {
+            type_name: type_name.to_string(),
+            value: text,
+            min: "0".to_string(),
+            max: u64::MAX.to_string(),
+        });
+    }
+    Ok(lo as u64)
+}
+
+/// Argument JSON as `run` executes it, from `--args-file` or inline `--args`:
+/// `${VAR}` is expanded first when `expand_env` is set, then `@path` byte
+/// references are resolved.
+pub fn prepare_args(
+    inline: Option<&str>,
+    file: Option<&Path>,
+    expand_env: bool,
+) -> crate::Result<Option<String>> {
+    let expand = |text: &str| {
+        if expand_env {
+            expand_env_vars(text)
+        } else {
+            Ok(text.to_string())
+        }
+    };
+    match (file, inline) {
+        (Some(path), _) => read_args_file(path, expand).map(Some),
+        (None, Some(args_json)) => {
+            resolve_file_references(&expand(args_json)?, Path::new(".")).map(Some)
+        }
+        (None, None) => Ok(None),
+    }
+}
+
+/// Read a JSON argument array from `path`, as for `run --args-file`.
+///
+/// `expand` rewrites the raw text first. The result must be a well-formed JSON
+/// array; `@path` byte references in it resolve relative to the file's directory.
+pub fn read_args_file(
+    path: &Path,
+    expand: impl Fn(&str) -> crate::Result<String>,
+) -> crate::Result<String> {
+    let contents = std::fs::read_to_string(path).map_err(|e| {
+        crate::DebuggerError::FileError(format!("Failed to read args file {:?}: {}", path, e))
+    })?;
+    let contents = expand(&contents)?;
+    let value: Value = serde_json::from_str(&contents).map_err(|e| {
+        crate::DebuggerError::InvalidArguments(format!(
+            "Args file {:?} is not valid JSON: {}",
+            path, e
+        ))
+    })?;
+    if !value.is_array() {
+        return Err(crate::DebuggerError::InvalidArguments(format!(
+            "Args file {:?} must contain a JSON array of arguments",
+            path
+        ))
+        .into());
+    }
+    let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+    resolve_file_references(&contents, base_dir)
+}
+
+/// Replace `{"type": "bytes", "value": "@blob.bin"}` (or `bytesn`) with the
+/// file's contents as hex, so large blobs need not be inlined.
+///
+/// Relative paths resolve against `base_dir`. Input that is not valid JSON is
+/// returned unchanged for the argument parser to report.
+pub fn resolve_file_references(args_json: &str, base_dir: &Path) -> crate::Result<String> {
+    let Ok(mut value) = serde_json::from_str::<Value>(args_json) else {
+        return Ok(args_json.to_string());
+    };
+    if !replace_file_references(&mut value, base_dir)? {
+        return Ok(args_json.to_string());
+    }
+    serde_json::to_string(&value).map_err(|e| {
+        crate::DebuggerError::InvalidArguments(format!("Failed to serialize arguments: {}", e))
+            .into()
+    })
+}
+
+/// Returns whether any reference was replaced.
+fn replace_file_references(value: &mut Value, base_dir: &Path) -> crate::Result<bool> {
+    match value {
+        Value::Object(obj) => {
+            let is_bytes = matches!(
+                obj.get("type").and_then(Value::as_str),
+                Some("bytes" | "bytesn")
+            );
+            if let (true, Some(Value::String(text))) = (is_bytes, obj.get("value")) {
+                if let Some(reference) = text.strip_prefix('@') {
+                    let path = base_dir.join(reference);
+                    let bytes = std::fs::read(&path).map_err(|e| {
+                        crate::DebuggerError::FileError(format!(
+                            "Failed to read bytes argument {:?}: {}",
+                            path, e
+                        ))
+                    })?;
+                    obj.insert("value".to_string(), Value::String(hex::encode(bytes)));
+                    return Ok(true);
+                }
+            }
+            let mut replaced = false;
+            for child in obj.values_mut() {
+                replaced |= replace_file_references(child, base_dir)?;
+            }
+            Ok(replaced)
+        }
+        Value::Array(items) => {
+            let mut replaced = false;
+            for item in items {
+                replaced |= replace_file_references(item, base_dir)?;
+            }
+            Ok(replaced)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Expand `${VAR}` and `${VAR:-default}` from the process environment, as for
+/// `run --expand-env`. A `$` not followed by `{` is kept as is.
+///
+/// Variable values are JSON-escaped, so they can sit inside a JSON string or,
+/// for numbers, stand bare; defaults are inserted as written.
+pub fn expand_env_vars(input: &str) -> crate::Result<String> {
+    expand_vars_with(input, |name| std::env::var(name).ok())
+}
+
+fn expand_vars_with(input: &str, lookup: impl Fn(&str) -> Option<String>) -> crate::Result<String> {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(start) = rest.find("${") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after.find('}').ok_or_else(|| {
+            crate::DebuggerError::InvalidArguments(format!(
+                "Unclosed '${{' in arguments: {}",
+                &rest[start..]
+            ))
+        })?;
+        let expr = &after[..end];
+        let (name, default) = match expr.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (expr, None),
+        };
+        let value = match (lookup(name), default) {
+            (Some(value), _) => {
+                let quoted = serde_json::Value::String(value).to_string();
+                quoted[1..quoted.len() - 1].to_string()
+            }
+            (None, Some(default)) => default.to_string(),
+            (None, None) => {
+                let message = format!(
+                    "Environment variable '{}' is not set; use ${{{}:-default}} for a fallback",
+                    name, name
+                );
+                return Err(crate::DebuggerError::InvalidArguments(message).into());
+            }
+        };
+        out.push_str(&value);
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use soroban_sdk::Env;
 
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "ADMIN" => Some("GADMIN".to_string()),
+            "MEMO" => Some(r#"say "hi" \ bye"#.to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_expand_env_defined_variable() {
+        let expanded = expand_vars_with(r#"["${ADMIN}", "$5"]"#, lookup).unwrap();
+        assert_eq!(expanded, r#"["GADMIN", "$5"]"#);
+    }
+
+    #[test]
+    fn test_expand_env_default_for_unset_variable() {
+        let expanded = expand_vars_with(r#"[${TTL:-60}, "${ADMIN:-x}"]"#, lookup).unwrap();
+        assert_eq!(expanded, r#"[60, "GADMIN"]"#);
+    }
+
+    #[test]
+    fn test_expand_env_escapes_values_for_json() {
+        let expanded = expand_vars_with(r#"["${MEMO}"]"#, lookup).unwrap();
+        let value: Value = serde_json::from_str(&expanded).unwrap();
+        assert_eq!(value[0], r#"say "hi" \ bye"#);
+    }
+
+    #[test]
+    fn test_args_file_is_expanded_before_it_is_validated_and_resolved() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("blob.bin"), [0xca, 0xfe]).unwrap();
+        let args_path = dir.path().join("args.json");
+        std::fs::write(
+            &args_path,
+            r#"[${AMOUNT}, {"type": "bytes", "value": "@${BLOB}"}]"#,
+        )
+        .unwrap();
+        let lookup = |name: &str| match name {
+            "AMOUNT" => Some("100".to_string()),
+            "BLOB" => Some("blob.bin".to_string()),
+            _ => None,
+        };
+
+        let resolved = read_args_file(&args_path, |text| expand_vars_with(text, lookup)).unwrap();
+        let value: Value = serde_json::from_str(&resolved).unwrap();
+        assert_eq!(value[0], 100);
+        assert_eq!(value[1]["value"], "cafe");
+    }
+
+    #[test]
+    fn test_expand_env_undefined_variable_is_an_error() {
+        let err = expand_vars_with(r#"["${MISSING}"]"#, lookup)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("MISSING"), "{err}");
+        assert!(expand_vars_with("[\"${ADMIN\"]", lookup).is_err());
+    }
+
+    #[test]
+    fn test_bytes_file_reference_is_inlined_as_hex() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("blob.bin"), [0xde, 0xad, 0xbe, 0xef]).unwrap();
+        let args_path = dir.path().join("args.json");
+        std::fs::write(
+            &args_path,
+            r#"["@handle", {"type": "bytes", "value": "@blob.bin"}]"#,
+        )
+        .unwrap();
+
+        let resolved = read_args_file(&args_path, |text| Ok(text.to_string())).unwrap();
+        let value: Value = serde_json::from_str(&resolved).unwrap();
+        assert_eq!(value[0], "@handle");
+        assert_eq!(value[1]["value"], "deadbeef");
+
+        std::fs::write(&args_path, r#"{"not": "an array"}"#).unwrap();
+        assert!(read_args_file(&args_path, |text| Ok(text.to_string())).is_err());
+    }
+
     fn create_parser() -> ArgumentParser {
         ArgumentParser::new(Env::default())
     }
@@ -1396,6 +1901,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_typed_i128_accepts_decimal_string_beyond_i64() {
+        let parser = create_parser();
+        let result = parser.parse_args_string(
+            r#"[{"type": "i128", "value": "-170141183460469231731687303715884105728"},
+                {"type": "u128", "value": "340282366920938463463374607431768211455"}]"#,
+        );
+        assert!(result.is_ok(), "128-bit strings failed: {:?}", result.err());
+    }
+
     #[test]
     fn test_typed_i128_type_mismatch() {
         let parser = create_parser();
@@ -1627,7 +2142,46 @@ mod tests {
         assert!(result
             .unwrap_err()
             .to_string()
-            .contains("must start with '0x' or 'base64:'"));
+            .contains("must be hex (optionally '0x'-prefixed) or start with 'base64:'"));
+    }
+
+    #[test]
+    fn test_bytes_bare_hex_without_prefix() {
+        let parser = create_parser();
+        let result = parser.parse_args_string(r#"[{"type": "bytes", "value": "deadbeef"}]"#);
+        assert!(result.is_ok(), "bare hex bytes failed: {:?}", result.err());
+    }
+
+    #[test]
+    fn test_bytes_odd_length_hex() {
+        let parser = create_parser();
+        let result = parser.parse_args_string(r#"[{"type": "bytes", "value": "abc"}]"#);
+        let err = result.unwrap_err();
+        assert!(err.to_string().contains("odd number of digits"));
+    }
+
+    #[test]
+    fn test_bytesn_32_byte_hash_with_size() {
+        let parser = create_parser();
+        let hash = "ab".repeat(32);
+        let json = format!(r#"[{{"type": "bytesn", "size": 32, "value": "{}"}}]"#, hash);
+        let vals = parser.parse_args_string(&json).unwrap();
+
+        let bytes = soroban_sdk::BytesN::<32>::try_from_val(&parser.env, &vals[0]).unwrap();
+        assert_eq!(bytes.to_array(), [0xab; 32]);
+    }
+
+    #[test]
+    fn test_bytesn_size_mismatch() {
+        let parser = create_parser();
+        let json = format!(
+            r#"[{{"type": "bytesn", "size": 32, "value": "{}"}}]"#,
+            "ab".repeat(31)
+        );
+        let err = parser.parse_args_string(&json).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("length mismatch: expected 32, got 31"));
     }
 
     #[test]
@@ -1713,6 +2267,150 @@ mod tests {
         assert!(result.is_ok(), "Empty vec failed: {:?}", result.err());
     }
 
+    // ── 256-bit and temporal types ───────────────────────────────────
+
+    fn to_scval(parser: &ArgumentParser, val: &Val) -> ScVal {
+        ScVal::try_from_val(&parser.env, val).unwrap()
+    }
+
+    #[test]
+    fn test_typed_u256_max_round_trips() {
+        let parser = create_parser();
+        let json = format!(r#"[{{"type": "u256", "value": "{}"}}]"#, U256_MAX);
+        let vals = parser.parse_args_string(&json).unwrap();
+
+        let expected = ScVal::U256(UInt256Parts {
+            hi_hi: u64::MAX,
+            hi_lo: u64::MAX,
+            lo_hi: u64::MAX,
+            lo_lo: u64::MAX,
+        });
+        assert_eq!(to_scval(&parser, &vals[0]), expected);
+
+        let hex = format!(r#"[{{"type": "u256", "value": "0x{}"}}]"#, "f".repeat(64));
+        let hex_vals = parser.parse_args_string(&hex).unwrap();
+        assert_eq!(to_scval(&parser, &hex_vals[0]), expected);
+    }
+
+    #[test]
+    fn test_typed_u256_overflow_rejected() {
+        let parser = create_parser();
+        let too_big =
+            "115792089237316195423570985008687907853269984665640564039457584007913129639936";
+        let json = format!(r#"[{{"type": "u256", "value": "{}"}}]"#, too_big);
+        let err = parser.parse_args_string(&json).unwrap_err();
+        assert!(err.to_string().contains("out of range for type u256"));
+
+        let hex = format!(r#"[{{"type": "u256", "value": "0x1{}"}}]"#, "0".repeat(64));
+        let err = parser.parse_args_string(&hex).unwrap_err();
+        assert!(err.to_string().contains("out of range for type u256"));
+    }
+
+    #[test]
+    fn test_typed_i256_bounds() {
+        let parser = create_parser();
+        let min = format!(r#"[{{"type": "i256", "value": "{}"}}]"#, I256_MIN);
+        let vals = parser.parse_args_string(&min).unwrap();
+        assert_eq!(
+            to_scval(&parser, &vals[0]),
+            ScVal::I256(Int256Parts {
+                hi_hi: i64::MIN,
+                hi_lo: 0,
+                lo_hi: 0,
+                lo_lo: 0,
+            })
+        );
+
+        let minus_one = parser
+            .parse_args_string(r#"[{"type": "i256", "value": "-1"}]"#)
+            .unwrap();
+        assert_eq!(
+            to_scval(&parser, &minus_one[0]),
+            ScVal::I256(Int256Parts {
+                hi_hi: -1,
+                hi_lo: u64::MAX,
+                lo_hi: u64::MAX,
+                lo_lo: u64::MAX,
+            })
+        );
+
+        let above_max = format!(r#"[{{"type": "i256", "value": "{}"}}]"#, &I256_MIN[1..]);
+        assert!(parser.parse_args_string(&above_max).is_err());
+    }
+
+    #[test]
+    fn test_typed_timepoint_and_duration() {
+        let parser = create_parser();
+        let vals = parser
+            .parse_args_string(
+                r#"[{"type": "timepoint", "value": "1700000000"}, {"type": "duration", "value": "0xe10"}]"#,
+            )
+            .unwrap();
+        assert_eq!(
+            to_scval(&parser, &vals[0]),
+            ScVal::Timepoint(TimePoint(1_700_000_000))
+        );
+        assert_eq!(to_scval(&parser, &vals[1]), ScVal::Duration(Duration(3600)));
+
+        let overflow = parser
+            .parse_args_string(r#"[{"type": "duration", "value": "18446744073709551616"}]"#)
+            .unwrap_err();
+        assert!(overflow
+            .to_string()
+            .contains("out of range for type duration"));
+    }
+
+    #[test]
+    fn test_typed_u256_rejects_garbage() {
+        let parser = create_parser();
+        assert!(parser
+            .parse_args_string(r#"[{"type": "u256", "value": "12ab"}]"#)
+            .is_err());
+        assert!(parser
+            .parse_args_string(r#"[{"type": "u256", "value": "0xzz"}]"#)
+            .is_err());
+    }
+
+    // ── Map edge cases ───────────────────────────────────────────────
+
+    #[test]
+    fn test_typed_map_symbol_to_i128() {
+        let parser = create_parser();
+        let vals = parser
+            .parse_args_string(
+                r#"[{"type": "map", "key_type": "symbol", "value_type": "i128", "value": [["XLM", 1100000], ["BTC", -5]]}]"#,
+            )
+            .unwrap();
+
+        let map = Map::<Symbol, i128>::try_from_val(&parser.env, &vals[0]).unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(Symbol::new(&parser.env, "XLM")), Some(1_100_000));
+        assert_eq!(map.get(Symbol::new(&parser.env, "BTC")), Some(-5));
+        // soroban Map iterates in key order regardless of input order
+        assert_eq!(map.keys().first(), Some(Symbol::new(&parser.env, "BTC")));
+    }
+
+    #[test]
+    fn test_typed_map_duplicate_key_rejected() {
+        let parser = create_parser();
+        let err = parser
+            .parse_args_string(
+                r#"[{"type": "map", "key_type": "symbol", "value": [["XLM", 1], ["XLM", 2]]}]"#,
+            )
+            .unwrap_err();
+        assert!(err.to_string().contains("Duplicate map key \"XLM\""));
+    }
+
+    #[test]
+    fn test_typed_map_entry_must_be_pair() {
+        let parser = create_parser();
+        let result = parser.parse_args_string(r#"[{"type": "map", "value": [["XLM"]]}]"#);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("[key, value] pair"));
+    }
+
     // ── Address edge cases ───────────────────────────────────────────
 
     #[test]
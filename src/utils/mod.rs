@@ -1,4 +1,6 @@
 pub mod arguments;
+pub mod rpc;
+pub mod scval;
 pub mod wasm;
 
 pub use arguments::ArgumentParser;
@@ -0,0 +1,182 @@
+//! Download deployed contract code from a Soroban RPC endpoint.
+//!
+//! `inspect --rpc URL --contract-id ID` makes two `getLedgerEntries` calls:
+//! one for the contract instance, which names the WASM hash, and one for the
+//! contract code under that hash. Downloads are cached in the system temp dir
+//! keyed by hash, so inspecting the same code again skips the second call.
+
+use crate::runtime::loader::contract_sc_address;
+use crate::utils::wasm::compute_wasm_sha256;
+use crate::{DebuggerError, Result};
+use soroban_env_host::xdr::{
+    ContractDataDurability, ContractExecutable, Hash, LedgerEntryData, LedgerKey,
+    LedgerKeyContractCode, LedgerKeyContractData, Limits, ReadXdr, ScVal, WriteXdr,
+};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// How long a single RPC request may take end to end.
+const RPC_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Directory under [`std::env::temp_dir`] that holds downloaded contract code.
+const CACHE_DIR: &str = "soroban-debug-wasm";
+
+/// Fetch the WASM of `contract_id` from `rpc_url` and return the path of the
+/// cached copy.
+pub fn fetch_contract_wasm(rpc_url: &str, contract_id: &str) -> Result<PathBuf> {
+    let agent = ureq::AgentBuilder::new().timeout(RPC_TIMEOUT).build();
+    let contract = contract_sc_address(contract_id)?;
+
+    let instance_key = LedgerKey::ContractData(LedgerKeyContractData {
+        contract,
+        key: ScVal::LedgerKeyContractInstance,
+        durability: ContractDataDurability::Persistent,
+    });
+    let hash = match get_ledger_entry(&agent, rpc_url, &instance_key)? {
+        Some(LedgerEntryData::ContractData(entry)) => match entry.val {
+            ScVal::ContractInstance(instance) => match instance.executable {
+                ContractExecutable::Wasm(hash) => hash,
+                ContractExecutable::StellarAsset => {
+                    return Err(DebuggerError::WasmLoadError(format!(
+                        "Contract {} is a Stellar Asset Contract and has no WASM to inspect",
+                        contract_id
+                    ))
+                    .into())
+                }
+            },
+            other => return Err(unexpected_entry(rpc_url, other.name())),
+        },
+        Some(other) => return Err(unexpected_entry(rpc_url, other.name())),
+        None => {
+            return Err(DebuggerError::WasmLoadError(format!(
+                "Contract {} was not found on {}; check the contract id and that the RPC serves the right network",
+                contract_id, rpc_url
+            ))
+            .into())
+        }
+    };
+
+    let hex_hash = hex::encode(hash.0);
+    let path = std::env::temp_dir()
+        .join(CACHE_DIR)
+        .join(format!("{}.wasm", hex_hash));
+    if let Ok(bytes) = fs::read(&path) {
+        if compute_wasm_sha256(&bytes) == hex_hash {
+            return Ok(path);
+        }
+    }
+
+    let code = fetch_contract_code(&agent, rpc_url, hash)?;
+    if compute_wasm_sha256(&code) != hex_hash {
+        return Err(DebuggerError::WasmLoadError(format!(
+            "Contract code from {} does not match its hash {}",
+            rpc_url, hex_hash
+        ))
+        .into());
+    }
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| {
+            DebuggerError::FileError(format!("Failed to create cache dir {:?}: {}", dir, e))
+        })?;
+    }
+    fs::write(&path, &code).map_err(|e| {
+        DebuggerError::FileError(format!(
+            "Failed to cache contract code at {:?}: {}",
+            path, e
+        ))
+    })?;
+    Ok(path)
+}
+
+fn fetch_contract_code(agent: &ureq::Agent, rpc_url: &str, hash: Hash) -> Result<Vec<u8>> {
+    let hex_hash = hex::encode(hash.0);
+    let code_key = LedgerKey::ContractCode(LedgerKeyContractCode { hash });
+    match get_ledger_entry(agent, rpc_url, &code_key)? {
+        Some(LedgerEntryData::ContractCode(entry)) => Ok(entry.code.to_vec()),
+        Some(other) => Err(unexpected_entry(rpc_url, other.name())),
+        None => Err(DebuggerError::WasmLoadError(format!(
+            "Contract code {} was not found on {}; it may have been archived",
+            hex_hash, rpc_url
+        ))
+        .into()),
+    }
+}
+
+/// Look up one ledger entry; `None` when the RPC has no such entry.
+fn get_ledger_entry(
+    agent: &ureq::Agent,
+    rpc_url: &str,
+    key: &LedgerKey,
+) -> Result<Option<LedgerEntryData>> {
+    let key = key
+        .to_xdr_base64(Limits::none())
+        .map_err(|e| DebuggerError::NetworkError(format!("Failed to encode ledger key: {}", e)))?;
+    let request = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "getLedgerEntries",
+        "params": { "keys": [key] },
+    });
+
+    let body = agent
+        .post(rpc_url)
+        .set("Content-Type", "application/json")
+        .send_string(&request.to_string())
+        .map_err(|e| match e {
+            ureq::Error::Status(status, _) => {
+                DebuggerError::NetworkError(format!("RPC {} returned HTTP {}", rpc_url, status))
+            }
+            ureq::Error::Transport(e) => {
+                DebuggerError::NetworkError(format!("Failed to reach RPC {}: {}", rpc_url, e))
+            }
+        })?
+        .into_string()
+        .map_err(|e| {
+            DebuggerError::NetworkError(format!("Failed to read response from {}: {}", rpc_url, e))
+        })?;
+    let response: serde_json::Value = serde_json::from_str(&body).map_err(|e| {
+        DebuggerError::NetworkError(format!("RPC {} returned invalid JSON: {}", rpc_url, e))
+    })?;
+
+    if let Some(error) = response.get("error") {
+        let message = error
+            .get("message")
+            .and_then(|m| m.as_str())
+            .map(str::to_string)
+            .unwrap_or_else(|| error.to_string());
+        return Err(DebuggerError::NetworkError(format!(
+            "RPC {} returned an error: {}",
+            rpc_url, message
+        ))
+        .into());
+    }
+
+    let Some(entry) = response
+        .pointer("/result/entries")
+        .and_then(|entries| entries.as_array())
+        .and_then(|entries| entries.first())
+    else {
+        return Ok(None);
+    };
+    let xdr = entry.get("xdr").and_then(|x| x.as_str()).ok_or_else(|| {
+        DebuggerError::NetworkError(format!("RPC {} returned an entry without xdr", rpc_url))
+    })?;
+    LedgerEntryData::from_xdr_base64(xdr, Limits::none())
+        .map(Some)
+        .map_err(|e| {
+            DebuggerError::NetworkError(format!(
+                "RPC {} returned an undecodable ledger entry: {}",
+                rpc_url, e
+            ))
+            .into()
+        })
+}
+
+fn unexpected_entry(rpc_url: &str, kind: &str) -> miette::Report {
+    DebuggerError::NetworkError(format!(
+        "RPC {} returned an unexpected ledger entry ({})",
+        rpc_url, kind
+    ))
+    .into()
+}
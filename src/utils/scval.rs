@@ -0,0 +1,377 @@
+//! Conversion of Soroban [`ScVal`] values into JSON.
+//!
+//! Values are decoded structurally by default. When the contract spec is
+//! available, `#[contracttype]` structs are rendered as named objects whose
+//! fields follow the struct's declaration order rather than the sorted key
+//! order Soroban uses on the wire.
+
+use crate::utils::wasm::{parse_contract_structs, parse_function_signatures, ContractStruct};
+use indexmap::IndexMap;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use soroban_env_host::xdr::{ScMap, ScVal};
+use std::fmt;
+
+/// A value decoded with the contract spec.
+///
+/// `serde_json::Value` objects sort their keys, so struct fields are kept in an
+/// ordered map and serialize in declaration order.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Decoded {
+    Json(Value),
+    Struct(IndexMap<String, Decoded>),
+    List(Vec<Decoded>),
+}
+
+impl Decoded {
+    /// True for structs, lists and JSON objects/arrays.
+    pub fn is_compound(&self) -> bool {
+        match self {
+            Decoded::Json(value) => value.is_object() || value.is_array(),
+            Decoded::Struct(_) | Decoded::List(_) => true,
+        }
+    }
+}
+
+impl From<Value> for Decoded {
+    fn from(value: Value) -> Self {
+        Decoded::Json(value)
+    }
+}
+
+impl From<Decoded> for Value {
+    fn from(decoded: Decoded) -> Self {
+        match decoded {
+            Decoded::Json(value) => value,
+            Decoded::Struct(fields) => Value::Object(
+                fields
+                    .into_iter()
+                    .map(|(name, field)| (name, field.into()))
+                    .collect(),
+            ),
+            Decoded::List(items) => Value::Array(items.into_iter().map(Value::from).collect()),
+        }
+    }
+}
+
+impl fmt::Display for Decoded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = serde_json::to_string(self).map_err(|_| fmt::Error)?;
+        f.write_str(&text)
+    }
+}
+
+/// Decode an `ScVal` into JSON without any type information.
+///
+/// 128-bit integers are rendered as decimal strings and 256-bit integers as
+/// hex strings so no precision is lost in JSON consumers.
+pub fn scval_to_json(val: &ScVal) -> Value {
+    match val {
+        ScVal::Bool(b) => Value::Bool(*b),
+        ScVal::Void => Value::Null,
+        ScVal::U32(n) => json!(n),
+        ScVal::I32(n) => json!(n),
+        ScVal::U64(n) => json!(n),
+        ScVal::I64(n) => json!(n),
+        ScVal::Timepoint(t) => json!(t.0),
+        ScVal::Duration(d) => json!(d.0),
+        ScVal::U128(parts) => {
+            Value::String((((parts.hi as u128) << 64) | parts.lo as u128).to_string())
+        }
+        ScVal::I128(parts) => {
+            Value::String((((parts.hi as i128) << 64) | parts.lo as i128).to_string())
+        }
+        ScVal::U256(parts) => Value::String(format!(
+            "0x{:016x}{:016x}{:016x}{:016x}",
+            parts.hi_hi, parts.hi_lo, parts.lo_hi, parts.lo_lo
+        )),
+        ScVal::I256(parts) => Value::String(format!(
+            "0x{:016x}{:016x}{:016x}{:016x}",
+            parts.hi_hi as u64, parts.hi_lo, parts.lo_hi, parts.lo_lo
+        )),
+        ScVal::Bytes(bytes) => Value::String(hex::encode(bytes.0.as_slice())),
+        ScVal::String(s) => Value::String(String::from_utf8_lossy(s.0.as_slice()).into_owned()),
+        ScVal::Symbol(s) => Value::String(String::from_utf8_lossy(s.0.as_slice()).into_owned()),
+        ScVal::Vec(Some(items)) => Value::Array(items.0.iter().map(scval_to_json).collect()),
+        ScVal::Vec(None) => Value::Array(Vec::new()),
+        ScVal::Map(Some(map)) => map_to_json(map),
+        ScVal::Map(None) => Value::Object(Map::new()),
+        ScVal::Address(address) => Value::String(address.to_string()),
+        other => Value::String(format!("{:?}", other)),
+    }
+}
+
+/// Decode an `ScVal` using a spec type name (as produced by the WASM spec parser).
+///
+/// Struct types found in `structs` are decoded as named objects in declaration
+/// order; anything that does not match its declared shape falls back to
+/// [`scval_to_json`].
+pub fn scval_to_json_with_type(
+    val: &ScVal,
+    type_name: &str,
+    structs: &[ContractStruct],
+) -> Decoded {
+    if let Some(inner) = strip_generic(type_name, "Option") {
+        return match val {
+            ScVal::Void => Value::Null.into(),
+            other => scval_to_json_with_type(other, inner, structs),
+        };
+    }
+
+    if let Some(inner) = strip_generic(type_name, "Vec") {
+        if let ScVal::Vec(Some(items)) = val {
+            return Decoded::List(
+                items
+                    .0
+                    .iter()
+                    .map(|item| scval_to_json_with_type(item, inner, structs))
+                    .collect(),
+            );
+        }
+    }
+
+    if let Some(def) = structs.iter().find(|s| s.name == type_name) {
+        if let Some(decoded) = struct_to_json(val, def, structs) {
+            return decoded;
+        }
+    }
+
+    scval_to_json(val).into()
+}
+
+/// Decode the return value of `function` using the contract spec embedded in `wasm_bytes`.
+///
+/// Falls back to plain structural decoding when the spec is missing or the
+/// function has no declared return type.
+pub fn decode_return_value(wasm_bytes: &[u8], function: &str, val: &ScVal) -> Decoded {
+    decode_typed_return_value(wasm_bytes, function, val)
+        .unwrap_or_else(|| scval_to_json(val).into())
+}
+
+/// Like [`decode_return_value`], but `None` unless the spec declares a return
+/// type for `function`.
+pub fn decode_typed_return_value(
+    wasm_bytes: &[u8],
+    function: &str,
+    val: &ScVal,
+) -> Option<Decoded> {
+    let type_name = parse_function_signatures(wasm_bytes)
+        .ok()?
+        .into_iter()
+        .find(|sig| sig.name == function)?
+        .return_type?;
+    let structs = parse_contract_structs(wasm_bytes).unwrap_or_default();
+    Some(scval_to_json_with_type(val, &type_name, &structs))
+}
+
+/// Render a decoded value for display. 128- and 256-bit integers, which JSON
+/// carries as decimal strings, are shown bare: `1100000`, not `"1100000"`.
+pub fn display_decoded(val: &ScVal, decoded: &Decoded) -> String {
+    match (val, decoded) {
+        (
+            ScVal::I128(_) | ScVal::U128(_) | ScVal::I256(_) | ScVal::U256(_),
+            Decoded::Json(Value::String(digits)),
+        ) => digits.clone(),
+        _ => decoded.to_string(),
+    }
+}
+
+/// Decode call arguments of `function` with the parameter types from the contract spec.
+///
+/// Arguments beyond the declared parameters, or all of them when the spec is
+/// missing, use plain structural decoding.
+pub fn decode_args(wasm_bytes: &[u8], function: &str, args: &[ScVal]) -> Vec<Decoded> {
+    let params = parse_function_signatures(wasm_bytes)
+        .ok()
+        .and_then(|sigs| sigs.into_iter().find(|sig| sig.name == function))
+        .map(|sig| sig.params)
+        .unwrap_or_default();
+    let structs = if params.is_empty() {
+        Vec::new()
+    } else {
+        parse_contract_structs(wasm_bytes).unwrap_or_default()
+    };
+
+    args.iter()
+        .enumerate()
+        .map(|(i, arg)| match params.get(i) {
+            Some(param) => scval_to_json_with_type(arg, &param.type_name, &structs),
+            None => scval_to_json(arg).into(),
+        })
+        .collect()
+}
+
+fn struct_to_json(
+    val: &ScVal,
+    def: &ContractStruct,
+    structs: &[ContractStruct],
+) -> Option<Decoded> {
+    match val {
+        ScVal::Map(Some(entries)) => {
+            let mut obj = IndexMap::new();
+            for field in &def.fields {
+                let entry = entries.0.iter().find(|entry| {
+                    matches!(&entry.key, ScVal::Symbol(sym) if sym.0.as_slice() == field.name.as_bytes())
+                })?;
+                obj.insert(
+                    field.name.clone(),
+                    scval_to_json_with_type(&entry.val, &field.type_name, structs),
+                );
+            }
+            Some(Decoded::Struct(obj))
+        }
+        // Tuple structs (`struct Pair(u32, u32)`) are encoded as vectors.
+        ScVal::Vec(Some(items)) if items.0.len() == def.fields.len() => Some(Decoded::List(
+            def.fields
+                .iter()
+                .zip(items.0.iter())
+                .map(|(field, item)| scval_to_json_with_type(item, &field.type_name, structs))
+                .collect(),
+        )),
+        _ => None,
+    }
+}
+
+/// Maps keyed entirely by symbols/strings become objects; anything else becomes
+/// a list of `{key, value}` pairs.
+fn map_to_json(map: &ScMap) -> Value {
+    let text_key = |key: &ScVal| match key {
+        ScVal::Symbol(s) => Some(String::from_utf8_lossy(s.0.as_slice()).into_owned()),
+        ScVal::String(s) => Some(String::from_utf8_lossy(s.0.as_slice()).into_owned()),
+        _ => None,
+    };
+
+    if map.0.iter().all(|entry| text_key(&entry.key).is_some()) {
+        let obj = map
+            .0
+            .iter()
+            .filter_map(|entry| Some((text_key(&entry.key)?, scval_to_json(&entry.val))))
+            .collect();
+        return Value::Object(obj);
+    }
+
+    Value::Array(
+        map.0
+            .iter()
+            .map(|entry| json!({"key": scval_to_json(&entry.key), "value": scval_to_json(&entry.val)}))
+            .collect(),
+    )
+}
+
+fn strip_generic<'a>(type_name: &'a str, outer: &str) -> Option<&'a str> {
+    type_name
+        .strip_prefix(outer)?
+        .strip_prefix('<')?
+        .strip_suffix('>')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::wasm::StructField;
+    use soroban_env_host::xdr::{Int128Parts, ScMapEntry, ScString, ScSymbol, StringM};
+
+    fn symbol(s: &str) -> ScVal {
+        ScVal::Symbol(ScSymbol(StringM::try_from(s.as_bytes().to_vec()).unwrap()))
+    }
+
+    fn price_data_struct() -> ContractStruct {
+        let field = |name: &str, type_name: &str| StructField {
+            name: name.to_string(),
+            type_name: type_name.to_string(),
+        };
+        ContractStruct {
+            name: "PriceData".to_string(),
+            fields: vec![
+                field("timestamp", "U64"),
+                field("price", "I128"),
+                field("asset", "String"),
+            ],
+        }
+    }
+
+    /// Soroban encodes struct fields as a map sorted by key.
+    fn price_data_value() -> ScVal {
+        let entry = |key: &str, val: ScVal| ScMapEntry {
+            key: symbol(key),
+            val,
+        };
+        ScVal::Map(Some(ScMap(
+            vec![
+                entry(
+                    "asset",
+                    ScVal::String(ScString(StringM::try_from(b"XLM".to_vec()).unwrap())),
+                ),
+                entry(
+                    "price",
+                    ScVal::I128(Int128Parts {
+                        hi: 0,
+                        lo: 1_500_000,
+                    }),
+                ),
+                entry("timestamp", ScVal::U64(1_700_000_000)),
+            ]
+            .try_into()
+            .unwrap(),
+        )))
+    }
+
+    #[test]
+    fn struct_fields_follow_declaration_order() {
+        let decoded =
+            scval_to_json_with_type(&price_data_value(), "PriceData", &[price_data_struct()]);
+        assert_eq!(
+            serde_json::to_string(&decoded).unwrap(),
+            r#"{"timestamp":1700000000,"price":"1500000","asset":"XLM"}"#
+        );
+    }
+
+    #[test]
+    fn unknown_struct_falls_back_to_raw_map() {
+        let decoded = scval_to_json_with_type(&price_data_value(), "PriceData", &[]);
+        assert_eq!(
+            serde_json::to_string(&decoded).unwrap(),
+            r#"{"asset":"XLM","price":"1500000","timestamp":1700000000}"#
+        );
+    }
+
+    #[test]
+    fn option_and_vec_types_are_unwrapped() {
+        let structs = [price_data_struct()];
+        assert_eq!(
+            scval_to_json_with_type(&ScVal::Void, "Option<PriceData>", &structs),
+            Decoded::Json(Value::Null)
+        );
+
+        let list = ScVal::Vec(Some(vec![price_data_value()].try_into().unwrap()));
+        let decoded = Value::from(scval_to_json_with_type(&list, "Vec<PriceData>", &structs));
+        assert_eq!(decoded[0]["asset"], "XLM");
+    }
+
+    #[test]
+    fn negative_i128_decodes_as_decimal_string() {
+        let val = ScVal::I128(Int128Parts {
+            hi: -1,
+            lo: u64::MAX,
+        });
+        assert_eq!(scval_to_json(&val), Value::String("-1".to_string()));
+    }
+
+    #[test]
+    fn i128_return_displays_as_bare_number() {
+        let val = ScVal::I128(Int128Parts {
+            hi: 0,
+            lo: 1_100_000,
+        });
+        let decoded = scval_to_json_with_type(&val, "i128", &[]);
+        assert_eq!(display_decoded(&val, &decoded), "1100000");
+
+        let text = ScVal::String(ScString(StringM::try_from(b"XLM".to_vec()).unwrap()));
+        assert_eq!(
+            display_decoded(&text, &scval_to_json(&text).into()),
+            "\"XLM\""
+        );
+        assert!(decode_typed_return_value(b"not wasm", "get_price", &val).is_none());
+    }
+}
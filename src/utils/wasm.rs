@@ -2,9 +2,11 @@ use crate::analyzer::upgrade::WasmType;
 use crate::{DebuggerError, Result};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
-use std::collections::{BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fs;
+use std::io::Read;
 use std::path::Path;
+use std::time::Duration;
 use wasmparser::{Operator, Parser, Payload, ValType};
 
 // Re-export FunctionSignature for convenience
@@ -483,6 +485,146 @@ pub fn parse_functions(wasm_bytes: &[u8]) -> Result<Vec<String>> {
     Ok(functions)
 }
 
+/// Host imports that write contract storage: `put_contract_data` and
+/// `del_contract_data` (exported by the Soroban env as `l._` and `l.2`).
+fn is_storage_write_host_fn(module: &str, name: &str) -> bool {
+    matches!((module, name), ("l", "_") | ("l", "2"))
+        || matches!(name, "put_contract_data" | "del_contract_data")
+}
+
+/// Direct-call graph of a module.
+///
+/// Function indices follow the WASM index space: imported functions first,
+/// then local functions in code order. Calls made through `call_indirect` are
+/// not followed.
+#[derive(Debug, Clone, Default)]
+pub struct CallGraph {
+    /// Imported functions as `(module, name)`, by index.
+    pub imports: Vec<(String, String)>,
+    /// Exported functions as `(name, index)`, in export order.
+    pub exports: Vec<(String, u32)>,
+    /// Direct callees of each local function, in code order.
+    pub callees: Vec<Vec<u32>>,
+}
+
+impl CallGraph {
+    pub fn parse(wasm_bytes: &[u8]) -> Result<Self> {
+        let parse_err = |e: wasmparser::BinaryReaderError| {
+            DebuggerError::WasmLoadError(format!("Failed to parse WASM: {}", e))
+        };
+
+        let mut graph = Self::default();
+        for payload in Parser::new(0).parse_all(wasm_bytes) {
+            match payload.map_err(parse_err)? {
+                Payload::ImportSection(reader) => {
+                    for import in reader {
+                        let import = import.map_err(parse_err)?;
+                        if let wasmparser::TypeRef::Func(_) = import.ty {
+                            graph
+                                .imports
+                                .push((import.module.to_string(), import.name.to_string()));
+                        }
+                    }
+                }
+                Payload::ExportSection(reader) => {
+                    for export in reader {
+                        let export = export.map_err(parse_err)?;
+                        if matches!(export.kind, wasmparser::ExternalKind::Func) {
+                            graph.exports.push((export.name.to_string(), export.index));
+                        }
+                    }
+                }
+                Payload::CodeSectionEntry(body) => {
+                    let mut calls = Vec::new();
+                    let mut operators = body.get_operators_reader().map_err(parse_err)?;
+                    while !operators.eof() {
+                        if let Operator::Call { function_index } =
+                            operators.read().map_err(parse_err)?
+                        {
+                            calls.push(function_index);
+                        }
+                    }
+                    graph.callees.push(calls);
+                }
+                _ => {}
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Index of the first local function.
+    pub fn imported_count(&self) -> u32 {
+        self.imports.len() as u32
+    }
+
+    /// Function index of the export called `name`.
+    pub fn export_index(&self, name: &str) -> Option<u32> {
+        self.exports
+            .iter()
+            .find(|(export, _)| export == name)
+            .map(|(_, index)| *index)
+    }
+
+    /// Direct callees of function `index`; empty for imports.
+    pub fn callees_of(&self, index: u32) -> &[u32] {
+        index
+            .checked_sub(self.imported_count())
+            .and_then(|local| self.callees.get(local as usize))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Local functions `entry` reaches through direct calls, `entry` included.
+    pub fn reachable_from(&self, entry: u32) -> BTreeSet<u32> {
+        let mut reachable = BTreeSet::from([entry]);
+        let mut pending = vec![entry];
+        while let Some(index) = pending.pop() {
+            for &callee in self.callees_of(index) {
+                if callee >= self.imported_count() && reachable.insert(callee) {
+                    pending.push(callee);
+                }
+            }
+        }
+        reachable
+    }
+}
+
+/// Exported functions that cannot reach a storage write through direct calls.
+///
+/// This is a static approximation: calls made through `call_indirect` are not
+/// followed.
+pub fn read_only_functions(wasm_bytes: &[u8]) -> Result<BTreeSet<String>> {
+    let graph = CallGraph::parse(wasm_bytes)?;
+    let mut writers: std::collections::HashSet<u32> = graph
+        .imports
+        .iter()
+        .enumerate()
+        .filter(|(_, (module, name))| is_storage_write_host_fn(module, name))
+        .map(|(index, _)| index as u32)
+        .collect();
+
+    // A function writes if it calls anything that writes; iterate to a fixed point.
+    loop {
+        let mut changed = false;
+        for (i, calls) in graph.callees.iter().enumerate() {
+            let index = graph.imported_count() + i as u32;
+            if !writers.contains(&index) && calls.iter().any(|c| writers.contains(c)) {
+                writers.insert(index);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    Ok(graph
+        .exports
+        .into_iter()
+        .filter(|(_, index)| !writers.contains(index))
+        .map(|(name, _)| name)
+        .collect())
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CrossContractCall {
     pub caller: String,
@@ -490,7 +632,16 @@ pub struct CrossContractCall {
     pub host_function: String,
 }
 
+/// Placeholder node for the contract a static call site reaches; the real
+/// address is only known at runtime.
+pub const EXTERNAL_CONTRACT_TARGET: &str = "external_contract";
+
 fn is_cross_contract_import(module: &str, name: &str) -> bool {
+    // soroban-sdk links `call` and `try_call` as `d._` and `d.0`.
+    if module == "d" && (name == "_" || name == "0") {
+        return true;
+    }
+
     let module = module.to_ascii_lowercase();
     let name = name.to_ascii_lowercase();
 
@@ -500,10 +651,22 @@ fn is_cross_contract_import(module: &str, name: &str) -> bool {
             || name.contains("try_call"))
 }
 
+fn cross_contract_host_fn_name(module: &str, name: &str) -> String {
+    match (module, name) {
+        ("d", "_") => "call".to_string(),
+        ("d", "0") => "try_call".to_string(),
+        _ => name.to_string(),
+    }
+}
+
 fn map_import_to_target(import_name: &str) -> String {
     let import_name = import_name.to_ascii_lowercase();
-    if import_name.contains("invoke_contract") || import_name.contains("call_contract") {
-        "external_contract".to_string()
+    if import_name.contains("invoke_contract")
+        || import_name.contains("call_contract")
+        || import_name == "call"
+        || import_name == "try_call"
+    {
+        EXTERNAL_CONTRACT_TARGET.to_string()
     } else {
         format!("external::{}", import_name)
     }
@@ -534,7 +697,10 @@ pub fn parse_cross_contract_calls(wasm_bytes: &[u8]) -> Result<Vec<CrossContract
                         let current_index = imported_func_count;
                         imported_func_count += 1;
                         if is_cross_contract_import(import.module, import.name) {
-                            cross_contract_imports.insert(current_index, import.name.to_string());
+                            cross_contract_imports.insert(
+                                current_index,
+                                cross_contract_host_fn_name(import.module, import.name),
+                            );
                         }
                     }
                 }
@@ -688,19 +854,141 @@ pub fn compute_wasm_sha256(wasm_bytes: &[u8]) -> String {
     hex::encode(hasher.finalize())
 }
 
-/// Reads a WASM file from disk and computes its SHA-256 checksum.
+/// How long a `--contract https://...` download may take end to end.
+const WASM_FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Downloads larger than this are refused; deployed contract code is far smaller.
+const MAX_WASM_DOWNLOAD_BYTES: u64 = 16 * 1024 * 1024;
+
+/// Reads a WASM module and computes its SHA-256 checksum.
+///
+/// `path` is a file on disk, `-` for stdin, or an `http://`/`https://` URL.
 pub fn load_wasm<P: AsRef<Path>>(path: P) -> Result<WasmFile> {
     let path_ref = path.as_ref();
-    let bytes = fs::read(path_ref).map_err(|e| {
-        crate::DebuggerError::WasmLoadError(format!(
-            "Failed to read WASM file at {:?}: {}",
-            path_ref, e
-        ))
-    })?;
+    let source = path_ref.to_string_lossy();
+    let (bytes, label) = if source == "-" {
+        let mut bytes = Vec::new();
+        std::io::stdin().read_to_end(&mut bytes).map_err(|e| {
+            DebuggerError::WasmLoadError(format!("Failed to read WASM from stdin: {}", e))
+        })?;
+        (bytes, "stdin".to_string())
+    } else if source.starts_with("http://") || source.starts_with("https://") {
+        (fetch_wasm(&source, WASM_FETCH_TIMEOUT)?, source.to_string())
+    } else {
+        let bytes = fs::read(path_ref).map_err(|e| {
+            crate::DebuggerError::WasmLoadError(format!(
+                "Failed to read WASM file at {:?}: {}",
+                path_ref, e
+            ))
+        })?;
+        (bytes, format!("{:?}", path_ref))
+    };
+    validate_wasm_magic(&bytes, &label)?;
     let sha256_hash = compute_wasm_sha256(&bytes);
     Ok(WasmFile { bytes, sha256_hash })
 }
 
+fn fetch_wasm(url: &str, timeout: Duration) -> Result<Vec<u8>> {
+    let agent = ureq::AgentBuilder::new().timeout(timeout).build();
+    let response = agent.get(url).call().map_err(|e| {
+        DebuggerError::WasmLoadError(format!("Failed to download WASM from {}: {}", url, e))
+    })?;
+
+    let mut bytes = Vec::new();
+    response
+        .into_reader()
+        .take(MAX_WASM_DOWNLOAD_BYTES + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|e| {
+            DebuggerError::WasmLoadError(format!("Failed to download WASM from {}: {}", url, e))
+        })?;
+    if bytes.len() as u64 > MAX_WASM_DOWNLOAD_BYTES {
+        return Err(DebuggerError::WasmLoadError(format!(
+            "WASM at {} is larger than {} bytes",
+            url, MAX_WASM_DOWNLOAD_BYTES
+        ))
+        .into());
+    }
+    Ok(bytes)
+}
+
+/// Rejects payloads that do not start with the `\0asm` magic bytes, such as an
+/// HTML error page or a path to the contract source.
+pub fn validate_wasm_magic(bytes: &[u8], source: &str) -> Result<()> {
+    if bytes.starts_with(b"\0asm") {
+        return Ok(());
+    }
+    let message = if bytes.is_empty() {
+        format!("{} is empty; expected a WASM module", source)
+    } else {
+        format!(
+            "{} is not a WASM module: expected the \\0asm magic bytes, found {:02x?}",
+            source,
+            &bytes[..bytes.len().min(4)]
+        )
+    };
+    Err(DebuggerError::WasmLoadError(message).into())
+}
+
+/// Check that `wasm_bytes` is a Soroban contract before it reaches the host,
+/// which fails opaquely on anything else.
+///
+/// The module must be valid WASM, carry the `contractenvmetav0` and
+/// `contractspecv0` sections the SDK embeds, and export at least one function
+/// declared in the spec.
+pub fn validate_soroban_contract(wasm_bytes: &[u8]) -> Result<()> {
+    validate_wasm_magic(wasm_bytes, "Contract")?;
+    wasmparser::Validator::new()
+        .validate_all(wasm_bytes)
+        .map_err(|e| {
+            DebuggerError::WasmLoadError(format!(
+                "Malformed WASM module: {} — is the file truncated or corrupted?",
+                e
+            ))
+        })?;
+
+    let mut has_env_meta = false;
+    let mut has_spec = false;
+    for payload in Parser::new(0).parse_all(wasm_bytes) {
+        if let Payload::CustomSection(reader) = payload
+            .map_err(|e| DebuggerError::WasmLoadError(format!("Failed to parse WASM: {}", e)))?
+        {
+            match reader.name() {
+                "contractenvmetav0" => has_env_meta = true,
+                "contractspecv0" => has_spec = true,
+                _ => {}
+            }
+        }
+    }
+    if !has_spec {
+        return Err(DebuggerError::WasmLoadError(
+            "missing contractspecv0 — is this a Soroban contract?".to_string(),
+        )
+        .into());
+    }
+    if !has_env_meta {
+        return Err(DebuggerError::WasmLoadError(
+            "missing contractenvmetav0 — was the contract built with soroban-sdk?".to_string(),
+        )
+        .into());
+    }
+
+    let exports = parse_functions(wasm_bytes)?;
+    let spec_functions = parse_function_signatures(wasm_bytes)?;
+    if !spec_functions
+        .iter()
+        .any(|function| exports.contains(&function.name))
+    {
+        return Err(DebuggerError::WasmLoadError(
+            "contractspecv0 declares no exported function — are the #[contractimpl] \
+             functions missing from the build?"
+                .to_string(),
+        )
+        .into());
+    }
+    Ok(())
+}
+
 /// Verifies that the computed hash matches the expected hash, if one is provided.
 pub fn verify_wasm_hash(computed_hash: &str, expected_hash: Option<&String>) -> Result<()> {
     if let Some(expected) = expected_hash {
@@ -888,6 +1176,55 @@ pub fn extract_contract_metadata(wasm_bytes: &[u8]) -> Result<ContractMetadata>
     Ok(metadata)
 }
 
+/// Parse the key/value pairs from the `contractmetav0` custom section.
+///
+/// The Soroban SDK writes entries such as `rsver` (rustc version) and
+/// `rssdkver` (soroban-sdk version) here. Contracts without the section yield
+/// an empty map.
+pub fn parse_metadata(wasm_bytes: &[u8]) -> Result<BTreeMap<String, String>> {
+    use stellar_xdr::curr::{Limited, Limits, ReadXdr, ScMetaEntry};
+
+    let mut entries = BTreeMap::new();
+    let parser = Parser::new(0);
+
+    for payload in parser.parse_all(wasm_bytes) {
+        let Payload::CustomSection(reader) = payload
+            .map_err(|e| DebuggerError::WasmLoadError(format!("Failed to parse WASM: {}", e)))?
+        else {
+            continue;
+        };
+
+        if reader.name() != "contractmetav0" {
+            continue;
+        }
+
+        let cursor = std::io::Cursor::new(reader.data());
+        let mut limited = Limited::new(cursor, Limits::none());
+
+        while let Ok(ScMetaEntry::ScMetaV0(meta)) = ScMetaEntry::read_xdr(&mut limited) {
+            entries.insert(
+                stringm_to_string(meta.key.as_slice()),
+                stringm_to_string(meta.val.as_slice()),
+            );
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Major version of the soroban-sdk recorded under `rssdkver`, if present.
+///
+/// The value looks like `22.0.11#<commit>`.
+pub fn sdk_major_version(metadata: &BTreeMap<String, String>) -> Option<u64> {
+    metadata
+        .get("rssdkver")?
+        .split(['.', '#'])
+        .next()?
+        .trim()
+        .parse()
+        .ok()
+}
+
 // ─── contract spec / function signatures ─────────────────────────────────────
 
 /// A single function parameter: name and its Soroban type as a display string.
@@ -906,6 +1243,17 @@ pub struct ContractFunctionSignature {
     pub return_type: Option<String>,
 }
 
+impl ContractFunctionSignature {
+    /// Parameters as `name: Type, ...`, for messages about the expected arguments.
+    pub fn params_display(&self) -> String {
+        self.params
+            .iter()
+            .map(|p| format!("{}: {}", p.name, p.type_name))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
 /// A custom error definition extracted from a contract spec.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct CustomError {
@@ -914,6 +1262,35 @@ pub struct CustomError {
     pub doc: String,
 }
 
+/// A single named field of a `#[contracttype]` struct.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StructField {
+    pub name: String,
+    pub type_name: String,
+}
+
+/// A `#[contracttype]` struct definition, with fields in declaration order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractStruct {
+    pub name: String,
+    pub fields: Vec<StructField>,
+}
+
+/// A single case of a `#[contracttype]` enum with data, e.g. `DataKey::Price(String)`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnionCase {
+    pub name: String,
+    /// Type names of the tuple payload; empty for unit cases.
+    pub fields: Vec<String>,
+}
+
+/// A `#[contracttype]` enum definition (spec union), typically a storage key type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractUnion {
+    pub name: String,
+    pub cases: Vec<UnionCase>,
+}
+
 /// Convert an XDR `ScSpecTypeDef` into a human-readable type string.
 fn spec_type_to_string(ty: &stellar_xdr::curr::ScSpecTypeDef) -> String {
     use stellar_xdr::curr::ScSpecTypeDef as T;
@@ -972,12 +1349,29 @@ fn stringm_to_string(bytes: &[u8]) -> String {
 /// this keeps callers simple and backward-compatible with contracts that
 /// pre-date the spec section.
 pub fn parse_function_signatures(wasm_bytes: &[u8]) -> Result<Vec<ContractFunctionSignature>> {
-    use stellar_xdr::curr::{Limited, Limits, ReadXdr, ScSpecEntry};
+    use stellar_xdr::curr::ScSpecEntry;
 
     let mut signatures = Vec::new();
-    let parser = Parser::new(0);
+    for_each_spec_entry(wasm_bytes, |entry| {
+        if let ScSpecEntry::FunctionV0(func) = entry {
+            signatures.push(function_signature(&func));
+        }
+    })?;
+    Ok(signatures)
+}
 
-    for payload in parser.parse_all(wasm_bytes) {
+/// Call `visit` with each `contractspecv0` entry in section order.
+///
+/// The section is a packed sequence of XDR-encoded `ScSpecEntry` values; the
+/// walk stops at its end or at the first entry that does not decode, which is
+/// reported as a `contract_spec_unreadable` warning.
+fn for_each_spec_entry(
+    wasm_bytes: &[u8],
+    mut visit: impl FnMut(stellar_xdr::curr::ScSpecEntry),
+) -> Result<()> {
+    use stellar_xdr::curr::{Limited, Limits, ReadXdr, ScSpecEntry};
+
+    for payload in Parser::new(0).parse_all(wasm_bytes) {
         let Payload::CustomSection(reader) = payload
             .map_err(|e| DebuggerError::WasmLoadError(format!("Failed to parse WASM: {}", e)))?
         else {
@@ -989,43 +1383,92 @@ pub fn parse_function_signatures(wasm_bytes: &[u8]) -> Result<Vec<ContractFuncti
         }
 
         let data = reader.data();
-        let cursor = std::io::Cursor::new(data);
-        let mut limited = Limited::new(cursor, Limits::none());
-
-        // The section is a packed sequence of XDR-encoded ScSpecEntry values.
-        loop {
+        let mut limited = Limited::new(std::io::Cursor::new(data), Limits::none());
+        while (limited.inner.position() as usize) < data.len() {
+            let offset = limited.inner.position();
             match ScSpecEntry::read_xdr(&mut limited) {
-                Ok(ScSpecEntry::FunctionV0(func)) => {
-                    let name = stringm_to_string(func.name.0.as_slice());
-
-                    let params = func
-                        .inputs
-                        .iter()
-                        .map(|input| FunctionParam {
-                            name: stringm_to_string(input.name.as_slice()),
-                            type_name: spec_type_to_string(&input.type_),
-                        })
-                        .collect();
-
-                    let return_type = func.outputs.first().map(spec_type_to_string);
-
-                    signatures.push(ContractFunctionSignature {
-                        name,
-                        params,
-                        return_type,
-                    });
-                }
-                Ok(_) => {
-                    // UDT definitions, events, etc. — skip
+                Ok(entry) => visit(entry),
+                Err(e) => {
+                    crate::output::WarningCollector::record(
+                        "contract_spec_unreadable",
+                        format!(
+                            "Contract spec entry at byte {} could not be decoded ({}); \
+                             the spec entries from there on are ignored",
+                            offset, e
+                        ),
+                    );
+                    break;
                 }
-                Err(_) => break, // end of section or corrupt data
             }
         }
 
         break; // only one contractspecv0 section exists per contract
     }
 
-    Ok(signatures)
+    Ok(())
+}
+
+fn function_signature(func: &stellar_xdr::curr::ScSpecFunctionV0) -> ContractFunctionSignature {
+    ContractFunctionSignature {
+        name: stringm_to_string(func.name.0.as_slice()),
+        params: func
+            .inputs
+            .iter()
+            .map(|input| FunctionParam {
+                name: stringm_to_string(input.name.as_slice()),
+                type_name: spec_type_to_string(&input.type_),
+            })
+            .collect(),
+        return_type: func.outputs.first().map(spec_type_to_string),
+    }
+}
+
+fn custom_errors(err_enum: &stellar_xdr::curr::ScSpecUdtErrorEnumV0) -> Vec<CustomError> {
+    err_enum
+        .cases
+        .iter()
+        .map(|case| CustomError {
+            code: case.value,
+            name: stringm_to_string(case.name.as_slice()),
+            doc: stringm_to_string(case.doc.as_slice()),
+        })
+        .collect()
+}
+
+fn contract_struct(udt: &stellar_xdr::curr::ScSpecUdtStructV0) -> ContractStruct {
+    ContractStruct {
+        name: stringm_to_string(udt.name.as_slice()),
+        fields: udt
+            .fields
+            .iter()
+            .map(|field| StructField {
+                name: stringm_to_string(field.name.as_slice()),
+                type_name: spec_type_to_string(&field.type_),
+            })
+            .collect(),
+    }
+}
+
+fn contract_union(udt: &stellar_xdr::curr::ScSpecUdtUnionV0) -> ContractUnion {
+    use stellar_xdr::curr::ScSpecUdtUnionCaseV0;
+
+    ContractUnion {
+        name: stringm_to_string(udt.name.as_slice()),
+        cases: udt
+            .cases
+            .iter()
+            .map(|case| match case {
+                ScSpecUdtUnionCaseV0::VoidV0(void) => UnionCase {
+                    name: stringm_to_string(void.name.as_slice()),
+                    fields: Vec::new(),
+                },
+                ScSpecUdtUnionCaseV0::TupleV0(tuple) => UnionCase {
+                    name: stringm_to_string(tuple.name.as_slice()),
+                    fields: tuple.type_.iter().map(spec_type_to_string).collect(),
+                },
+            })
+            .collect(),
+    }
 }
 
 #[allow(dead_code)]
@@ -1047,56 +1490,155 @@ fn val_type_to_wasm_type(vt: &ValType) -> WasmType {
         }
     }
 }
+/// Exported functions that have no `contractspecv0` function entry.
+///
+/// These are callable but not part of the declared contract interface. The
+/// SDK's `_` export is ignored, and contracts without a spec report nothing.
+pub fn exports_missing_from_spec(wasm_bytes: &[u8]) -> Result<Vec<String>> {
+    let spec: BTreeSet<String> = parse_function_signatures(wasm_bytes)?
+        .into_iter()
+        .map(|sig| sig.name)
+        .collect();
+    if spec.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    Ok(parse_functions(wasm_bytes)?
+        .into_iter()
+        .filter(|name| name != "_" && !spec.contains(name))
+        .collect())
+}
+
 /// Parse custom error definitions from the WASM `contractspecv0` custom section.
 pub fn parse_custom_errors(wasm_bytes: &[u8]) -> Result<Vec<CustomError>> {
-    use stellar_xdr::curr::{Limited, Limits, ReadXdr, ScSpecEntry};
+    use stellar_xdr::curr::ScSpecEntry;
 
     let mut errors = Vec::new();
-    let parser = Parser::new(0);
+    for_each_spec_entry(wasm_bytes, |entry| {
+        if let ScSpecEntry::UdtErrorEnumV0(err_enum) = entry {
+            errors.extend(custom_errors(&err_enum));
+        }
+    })?;
+    Ok(errors)
+}
 
-    for payload in parser.parse_all(wasm_bytes) {
-        let Payload::CustomSection(reader) = payload
-            .map_err(|e| DebuggerError::WasmLoadError(format!("Failed to parse WASM: {}", e)))?
-        else {
-            continue;
-        };
+/// Parse `#[contracttype]` struct definitions from the WASM `contractspecv0` custom section.
+pub fn parse_contract_structs(wasm_bytes: &[u8]) -> Result<Vec<ContractStruct>> {
+    use stellar_xdr::curr::ScSpecEntry;
 
-        if reader.name() != "contractspecv0" {
-            continue;
+    let mut structs = Vec::new();
+    for_each_spec_entry(wasm_bytes, |entry| {
+        if let ScSpecEntry::UdtStructV0(udt) = entry {
+            structs.push(contract_struct(&udt));
         }
+    })?;
+    Ok(structs)
+}
 
-        let data = reader.data();
-        let cursor = std::io::Cursor::new(data);
-        let mut limited = Limited::new(cursor, Limits::none());
+/// Parse `#[contracttype]` enum (union) definitions from the WASM `contractspecv0` custom section.
+pub fn parse_contract_unions(wasm_bytes: &[u8]) -> Result<Vec<ContractUnion>> {
+    use stellar_xdr::curr::ScSpecEntry;
 
-        loop {
-            match ScSpecEntry::read_xdr(&mut limited) {
-                Ok(ScSpecEntry::UdtErrorEnumV0(err_enum)) => {
-                    for case in err_enum.cases.iter() {
-                        errors.push(CustomError {
-                            code: case.value,
-                            name: stringm_to_string(case.name.as_slice()),
-                            doc: stringm_to_string(case.doc.as_slice()),
-                        });
-                    }
-                }
-                Ok(_) => {
-                    // Other spec entries — skip
-                }
-                Err(_) => break, // end of section or corrupt data
-            }
+    let mut unions = Vec::new();
+    for_each_spec_entry(wasm_bytes, |entry| {
+        if let ScSpecEntry::UdtUnionV0(udt) = entry {
+            unions.push(contract_union(&udt));
         }
+    })?;
+    Ok(unions)
+}
 
-        break;
-    }
+/// A `#[contracttype]` enum with integer values, e.g. `enum Level { Low = 1 }`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractEnum {
+    pub name: String,
+    pub cases: Vec<EnumCase>,
+}
 
-    Ok(errors)
+/// A single case of an integer-valued contract enum.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnumCase {
+    pub name: String,
+    pub value: u32,
+}
+
+/// A user-defined type from the contract spec.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum UdtDef {
+    Struct(ContractStruct),
+    Union(ContractUnion),
+    Enum(ContractEnum),
+}
+
+/// A `#[contracterror]` enum with its error codes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ErrorDef {
+    pub name: String,
+    pub cases: Vec<CustomError>,
+}
+
+/// A spec entry kind this version does not model, kept as base64 XDR.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RawSpecEntry {
+    pub kind: String,
+    pub xdr: String,
+}
+
+/// The full `contractspecv0` interface plus `contractmetav0` metadata, for `inspect --spec`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ContractSpec {
+    pub functions: Vec<ContractFunctionSignature>,
+    pub types: Vec<UdtDef>,
+    pub errors: Vec<ErrorDef>,
+    /// Entries of other kinds (e.g. events), passed through rather than dropped.
+    pub other: Vec<RawSpecEntry>,
+    pub metadata: BTreeMap<String, String>,
+}
+
+/// Parse every `contractspecv0` entry, in section order.
+pub fn parse_contract_spec(wasm_bytes: &[u8]) -> Result<ContractSpec> {
+    use stellar_xdr::curr::{Limits, ScSpecEntry, WriteXdr};
+
+    let mut spec = ContractSpec {
+        functions: Vec::new(),
+        types: Vec::new(),
+        errors: Vec::new(),
+        other: Vec::new(),
+        metadata: parse_metadata(wasm_bytes).unwrap_or_default(),
+    };
+    for_each_spec_entry(wasm_bytes, |entry| match entry {
+        ScSpecEntry::FunctionV0(func) => spec.functions.push(function_signature(&func)),
+        ScSpecEntry::UdtStructV0(udt) => spec.types.push(UdtDef::Struct(contract_struct(&udt))),
+        ScSpecEntry::UdtUnionV0(udt) => spec.types.push(UdtDef::Union(contract_union(&udt))),
+        ScSpecEntry::UdtEnumV0(udt) => spec.types.push(UdtDef::Enum(ContractEnum {
+            name: stringm_to_string(udt.name.as_slice()),
+            cases: udt
+                .cases
+                .iter()
+                .map(|case| EnumCase {
+                    name: stringm_to_string(case.name.as_slice()),
+                    value: case.value,
+                })
+                .collect(),
+        })),
+        ScSpecEntry::UdtErrorEnumV0(err_enum) => spec.errors.push(ErrorDef {
+            name: stringm_to_string(err_enum.name.as_slice()),
+            cases: custom_errors(&err_enum),
+        }),
+        #[allow(unreachable_patterns)]
+        entry => spec.other.push(RawSpecEntry {
+            kind: entry.name().to_string(),
+            xdr: entry.to_xdr_base64(Limits::none()).unwrap_or_default(),
+        }),
+    })?;
+    Ok(spec)
 }
 
 // ─── tests ────────────────────────────────────────────────────────────────────
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
 
     // ── SHA-256 tests ─────────────────────────────────────────────────────────
@@ -1188,7 +1730,7 @@ mod tests {
     ///
     /// Uses proper ULEB128 encoding so it works for payloads of any size,
     /// unlike a naïve single-byte length which panics above 127 bytes.
-    fn make_custom_section_wasm(name: &str, payload: &[u8]) -> Vec<u8> {
+    pub(crate) fn make_custom_section_wasm(name: &str, payload: &[u8]) -> Vec<u8> {
         let mut bytes = Vec::new();
 
         // WASM magic number and version.
@@ -1267,6 +1809,41 @@ mod tests {
         module
     }
 
+    fn with_spec_function(mut module: Vec<u8>, name: &str) -> Vec<u8> {
+        use stellar_xdr::curr::{ScSpecEntry, ScSpecFunctionV0, ScSymbol, StringM, WriteXdr};
+
+        let entry = ScSpecEntry::FunctionV0(ScSpecFunctionV0 {
+            doc: StringM::default(),
+            name: ScSymbol(StringM::try_from(name.as_bytes().to_vec()).unwrap()),
+            inputs: Default::default(),
+            outputs: Default::default(),
+        });
+        let mut section = Vec::new();
+        encode_string(&mut section, "contractspecv0");
+        section.extend(entry.to_xdr(stellar_xdr::curr::Limits::none()).unwrap());
+        append_section(&mut module, 0, &section);
+        module
+    }
+
+    #[test]
+    fn export_without_spec_entry_is_reported() {
+        let wasm = with_spec_function(make_wasm_with_cross_contract_call(), "other");
+        assert_eq!(
+            exports_missing_from_spec(&wasm).unwrap(),
+            vec!["entrypoint".to_string()]
+        );
+
+        let wasm = with_spec_function(make_wasm_with_cross_contract_call(), "entrypoint");
+        assert!(exports_missing_from_spec(&wasm).unwrap().is_empty());
+
+        // Without a spec there is no interface to compare against.
+        assert!(
+            exports_missing_from_spec(&make_wasm_with_cross_contract_call())
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     // ── metadata-present tests ────────────────────────────────────────────────
 
     #[test]
@@ -1390,6 +1967,33 @@ implementation_notes=Line-based format
         assert!(!meta.is_empty());
     }
 
+    #[test]
+    fn parse_metadata_reads_contractmetav0_entries() {
+        use stellar_xdr::curr::{ScMetaEntry, ScMetaV0, StringM, WriteXdr};
+
+        let mut payload = Vec::new();
+        for (key, val) in [("rsver", "1.92.0"), ("rssdkver", "22.0.11#34f7f53")] {
+            let entry = ScMetaEntry::ScMetaV0(ScMetaV0 {
+                key: StringM::try_from(key.as_bytes().to_vec()).unwrap(),
+                val: StringM::try_from(val.as_bytes().to_vec()).unwrap(),
+            });
+            payload.extend(entry.to_xdr(stellar_xdr::curr::Limits::none()).unwrap());
+        }
+        let wasm = make_custom_section_wasm("contractmetav0", &payload);
+
+        let meta = parse_metadata(&wasm).unwrap();
+        assert_eq!(meta.get("rsver").map(String::as_str), Some("1.92.0"));
+        assert_eq!(sdk_major_version(&meta), Some(22));
+    }
+
+    #[test]
+    fn parse_metadata_without_section_is_empty() {
+        let wasm = make_custom_section_wasm("other", b"");
+        let meta = parse_metadata(&wasm).unwrap();
+        assert!(meta.is_empty());
+        assert_eq!(sdk_major_version(&meta), None);
+    }
+
     // ── error extraction tests ────────────────────────────────────────────────
 
     #[test]
@@ -1429,4 +2033,88 @@ implementation_notes=Line-based format
         assert_eq!(errors[1].name, "ErrorTwo");
         assert_eq!(errors[1].doc, "My Error 2");
     }
+
+    #[test]
+    fn extract_contract_structs_in_declaration_order() {
+        use stellar_xdr::curr::{
+            ScSpecEntry, ScSpecTypeDef, ScSpecUdtStructFieldV0, ScSpecUdtStructV0, StringM,
+            WriteXdr,
+        };
+
+        let field = |name: &str, type_: ScSpecTypeDef| ScSpecUdtStructFieldV0 {
+            doc: StringM::default(),
+            name: StringM::try_from(name.as_bytes().to_vec()).unwrap(),
+            type_,
+        };
+        let udt = ScSpecUdtStructV0 {
+            doc: StringM::default(),
+            lib: StringM::default(),
+            name: StringM::try_from("PriceData".as_bytes().to_vec()).unwrap(),
+            fields: vec![
+                field("timestamp", ScSpecTypeDef::U64),
+                field("price", ScSpecTypeDef::I128),
+            ]
+            .try_into()
+            .unwrap(),
+        };
+
+        let payload = ScSpecEntry::UdtStructV0(udt)
+            .to_xdr(stellar_xdr::curr::Limits::none())
+            .unwrap();
+        let wasm = make_custom_section_wasm("contractspecv0", &payload);
+
+        let structs = parse_contract_structs(&wasm).expect("parsing should succeed");
+        assert_eq!(structs.len(), 1);
+        assert_eq!(structs[0].name, "PriceData");
+        let names: Vec<&str> = structs[0].fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["timestamp", "price"]);
+        assert_eq!(structs[0].fields[1].type_name, "I128");
+    }
+
+    fn with_custom_section(mut module: Vec<u8>, name: &str) -> Vec<u8> {
+        let mut section = Vec::new();
+        encode_string(&mut section, name);
+        append_section(&mut module, 0, &section);
+        module
+    }
+
+    #[test]
+    fn validate_soroban_contract_explains_what_is_missing() {
+        let error = |wasm: &[u8]| validate_soroban_contract(wasm).unwrap_err().to_string();
+
+        let truncated = &make_wasm_with_cross_contract_call()[..20];
+        assert!(error(truncated).contains("Malformed WASM module"));
+
+        let module = make_wasm_with_cross_contract_call();
+        assert!(error(&module).contains("missing contractspecv0"));
+
+        let spec_only = with_spec_function(module.clone(), "entrypoint");
+        assert!(error(&spec_only).contains("missing contractenvmetav0"));
+
+        let unexported = with_custom_section(
+            with_spec_function(module.clone(), "other"),
+            "contractenvmetav0",
+        );
+        assert!(error(&unexported).contains("declares no exported function"));
+
+        let contract = with_custom_section(
+            with_spec_function(module, "entrypoint"),
+            "contractenvmetav0",
+        );
+        assert!(validate_soroban_contract(&contract).is_ok());
+    }
+
+    #[test]
+    fn validate_wasm_magic_rejects_non_wasm_payloads() {
+        assert!(validate_wasm_magic(b"\0asm\x01\0\0\0", "stdin").is_ok());
+
+        let err = validate_wasm_magic(b"<!DOCTYPE html>", "https://example.com/c.wasm")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("https://example.com/c.wasm is not a WASM module"));
+        assert!(err.contains("[3c, 21, 44, 4f]"));
+
+        let err = validate_wasm_magic(b"", "stdin").unwrap_err().to_string();
+        assert!(err.contains("stdin is empty"));
+    }
 }
@@ -22,6 +22,7 @@ fn make_node(function: &str, contract_id: &str, status: AuthStatus) -> AuthNode
     AuthNode {
         address: "GABC123".to_string(),
         function: function.to_string(),
+        args: vec![],
         contract_id: contract_id.to_string(),
         status,
         sub_invocations: vec![],
@@ -84,6 +85,7 @@ fn test_has_failures_false_when_all_authorized() {
     let node = AuthNode {
         address: "GABC".to_string(),
         function: "transfer".to_string(),
+        args: vec![],
         contract_id: "C1".to_string(),
         status: AuthStatus::Authorized,
         sub_invocations: vec![make_node("inner", "C2", AuthStatus::Authorized)],
@@ -108,6 +110,7 @@ fn test_has_failures_propagates_from_child() {
     let parent = AuthNode {
         address: "GABC".to_string(),
         function: "transfer".to_string(),
+        args: vec![],
         contract_id: "C1".to_string(),
         status: AuthStatus::Authorized,
         sub_invocations: vec![make_node("inner", "C2", AuthStatus::Missing)],
@@ -121,6 +124,7 @@ fn test_has_failures_deep_nested() {
     let mid = AuthNode {
         address: "G1".to_string(),
         function: "mid".to_string(),
+        args: vec![],
         contract_id: "C2".to_string(),
         status: AuthStatus::Authorized,
         sub_invocations: vec![leaf],
@@ -128,6 +132,7 @@ fn test_has_failures_deep_nested() {
     let root = AuthNode {
         address: "G1".to_string(),
         function: "root".to_string(),
+        args: vec![],
         contract_id: "C1".to_string(),
         status: AuthStatus::Authorized,
         sub_invocations: vec![mid],
@@ -142,6 +147,7 @@ fn test_to_json_includes_address_and_status() {
     let node = AuthNode {
         address: "GABC123".to_string(),
         function: "transfer".to_string(),
+        args: vec![],
         contract_id: "CTOKEN".to_string(),
         status: AuthStatus::Authorized,
         sub_invocations: vec![],
@@ -165,6 +171,7 @@ fn test_to_json_nested_sub_invocations() {
     let child = AuthNode {
         address: "G2".to_string(),
         function: "inner_fn".to_string(),
+        args: vec![],
         contract_id: "CINNER".to_string(),
         status: AuthStatus::Authorized,
         sub_invocations: vec![],
@@ -172,6 +179,7 @@ fn test_to_json_nested_sub_invocations() {
     let parent = AuthNode {
         address: "G1".to_string(),
         function: "outer_fn".to_string(),
+        args: vec![],
         contract_id: "COUTER".to_string(),
         status: AuthStatus::Authorized,
         sub_invocations: vec![child],
@@ -222,6 +230,7 @@ fn test_to_json_roundtrip() {
     let node = AuthNode {
         address: "GABC".to_string(),
         function: "transfer".to_string(),
+        args: vec![],
         contract_id: "CTOKEN".to_string(),
         status: AuthStatus::Authorized,
         sub_invocations: vec![make_node("inner", "C2", AuthStatus::Missing)],
@@ -278,6 +287,7 @@ fn test_display_with_summary_all_pass_no_panic() {
     let node = AuthNode {
         address: "GABC".to_string(),
         function: "transfer".to_string(),
+        args: vec![],
         contract_id: "C1".to_string(),
         status: AuthStatus::Authorized,
         sub_invocations: vec![make_node("inner", "C2", AuthStatus::Authorized)],
@@ -290,6 +300,7 @@ fn test_display_with_summary_with_failure_no_panic() {
     let node = AuthNode {
         address: "GABC".to_string(),
         function: "transfer".to_string(),
+        args: vec![],
         contract_id: "C1".to_string(),
         status: AuthStatus::Authorized,
         sub_invocations: vec![make_node("inner", "C2", AuthStatus::Missing)],
@@ -303,6 +314,7 @@ fn test_display_nested_three_levels_no_panic() {
     let mid = AuthNode {
         address: "G2".to_string(),
         function: "mid_fn".to_string(),
+        args: vec![],
         contract_id: "CMID".to_string(),
         status: AuthStatus::Authorized,
         sub_invocations: vec![leaf],
@@ -310,6 +322,7 @@ fn test_display_nested_three_levels_no_panic() {
     let root = AuthNode {
         address: "G1".to_string(),
         function: "root_fn".to_string(),
+        args: vec![],
         contract_id: "CROOT".to_string(),
         status: AuthStatus::Authorized,
         sub_invocations: vec![mid],
@@ -332,11 +345,13 @@ fn test_auth_node_serialization_legacy_compat() {
     let node = AuthNode {
         address: "GABC".to_string(),
         function: "transfer".to_string(),
+        args: vec![],
         contract_id: "C123".to_string(),
         status: AuthStatus::Authorized,
         sub_invocations: vec![AuthNode {
             address: "GDEF".to_string(),
             function: "inner".to_string(),
+            args: vec![],
             contract_id: "C456".to_string(),
             status: AuthStatus::Authorized,
             sub_invocations: vec![],
@@ -370,6 +385,7 @@ fn test_auth_inspector_node_from_sdk_types() {
     let nodes = vec![AuthNode {
         address: format!("{:?}", contract_id),
         function: format!("{:?}({:?})", function_name, args),
+        args: vec![],
         contract_id: format!("{:?}", contract_id),
         status: AuthStatus::Authorized,
         sub_invocations: vec![],
@@ -445,3 +461,76 @@ fn test_show_auth_with_json_flag_accepted() {
         "--show-auth --json should be recognised: {stderr}"
     );
 }
+
+// ── Oracle set_price auth ─────────────────────────────────────────────────
+
+mod oracle {
+    use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+    #[contract]
+    pub struct Oracle;
+
+    #[contractimpl]
+    impl Oracle {
+        pub fn set_price(env: Env, admin: Address, asset: Symbol, price: i128) {
+            admin.require_auth();
+            env.storage().persistent().set(&asset, &price);
+        }
+    }
+}
+
+#[test]
+fn test_oracle_set_price_produces_single_admin_node() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let oracle_id = env.register(oracle::Oracle, ());
+    let admin = Address::generate(&env);
+
+    oracle::OracleClient::new(&env, &oracle_id).set_price(
+        &admin,
+        &Symbol::new(&env, "XLM"),
+        &1_100_000,
+    );
+
+    let nodes = AuthInspector::get_auth_tree(&env).unwrap();
+    assert_eq!(nodes.len(), 1);
+    let node = &nodes[0];
+    assert_eq!(node.function, "set_price");
+    assert!(node.sub_invocations.is_empty());
+    assert_eq!(node.contract_id.len(), 56);
+    assert_eq!(node.args[0], serde_json::json!(node.address));
+    assert_eq!(node.args[1], serde_json::json!("XLM"));
+    assert_eq!(node.args[2], serde_json::json!("1100000"));
+
+    let lines = AuthInspector::format_tree(&nodes);
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0], format!("Signer: {}", node.address));
+    assert!(lines[1].starts_with("[OK] set_price("));
+    assert!(lines[1].contains("\"XLM\", \"1100000\")"));
+
+    let json = AuthInspector::to_json_value(&nodes);
+    assert_eq!(json[0]["args"][1], "XLM");
+    assert_eq!(json[0]["sub_invocations"], serde_json::json!([]));
+}
+
+#[test]
+fn test_format_tree_indents_deeply_nested_invocations() {
+    let mut leaf = make_node("leaf", "C4", AuthStatus::Authorized);
+    leaf.args = vec![serde_json::json!(7)];
+    let mut mid = make_node("mid", "C2", AuthStatus::Authorized);
+    mid.sub_invocations = vec![leaf];
+    let sibling = make_node("sibling", "C3", AuthStatus::Missing);
+    let mut root = make_node("root", "C1", AuthStatus::Authorized);
+    root.sub_invocations = vec![mid, sibling];
+
+    assert_eq!(
+        AuthInspector::format_tree(&[root]),
+        vec![
+            "Signer: GABC123",
+            "[OK] root() [Contract: C1]",
+            "├── [OK] mid() [Contract: C2]",
+            "│   └── [OK] leaf(7) [Contract: C4]",
+            "└── [MISSING] sibling() [Contract: C3]",
+        ]
+    );
+}
@@ -171,7 +171,8 @@ fn test_run_with_invalid_json_args() {
     let mut cmd = assert_cmd::Command::cargo_bin("soroban-debug").expect("Failed to find binary");
     let temp_dir = TempDir::new().expect("Failed to create temp dir");
     let contract_file = temp_dir.path().join("contract.wasm");
-    std::fs::write(&contract_file, b"dummy").expect("Failed to write temp file");
+    // An empty module passes the magic-byte check, so the argument error is reached.
+    std::fs::write(&contract_file, b"\0asm\x01\0\0\0").expect("Failed to write temp file");
 
     cmd.args([
         "run",
@@ -196,7 +197,8 @@ fn test_run_with_invalid_json_storage() {
     let mut cmd = assert_cmd::Command::cargo_bin("soroban-debug").expect("Failed to find binary");
     let temp_dir = TempDir::new().expect("Failed to create temp dir");
     let contract_file = temp_dir.path().join("contract.wasm");
-    std::fs::write(&contract_file, b"dummy").expect("Failed to write temp file");
+    // An empty module passes the magic-byte check, so the argument error is reached.
+    std::fs::write(&contract_file, b"\0asm\x01\0\0\0").expect("Failed to write temp file");
 
     cmd.args([
         "run",
@@ -271,7 +273,8 @@ fn test_run_with_invalid_batch_file() {
     let mut cmd = assert_cmd::Command::cargo_bin("soroban-debug").expect("Failed to find binary");
     let temp_dir = TempDir::new().expect("Failed to create temp dir");
     let contract_file = temp_dir.path().join("contract.wasm");
-    std::fs::write(&contract_file, b"dummy").expect("Failed to write temp file");
+    // An empty module passes the magic-byte check, so the argument error is reached.
+    std::fs::write(&contract_file, b"\0asm\x01\0\0\0").expect("Failed to write temp file");
 
     cmd.args([
         "run",
@@ -254,6 +254,95 @@ expected_return = "I64(1)"
         ));
 }
 
+#[test]
+fn scenario_advances_ledger_clock_between_steps() {
+    let wasm = fixture_wasm("counter");
+    let scenario = NamedTempFile::new().unwrap();
+    fs::write(
+        scenario.path(),
+        r#"
+[[steps]]
+function = "increment"
+
+[[steps]]
+function = "increment"
+
+[[steps]]
+function = "get"
+ledger_advance = 5
+expected_return = "I64(2)"
+"#,
+    )
+    .unwrap();
+
+    base_cmd()
+        .args([
+            "scenario",
+            "--scenario",
+            scenario.path().to_str().unwrap(),
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--step-advance-secs",
+            "60",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Ledger time advanced by 60s").count(1))
+        .stdout(predicate::str::contains("Ledger time advanced by 5s"));
+}
+
+#[test]
+fn scenario_ledger_advance_flips_oracle_staleness_at_expected_step() {
+    let wasm = fixture_wasm(fixtures::names::ORACLE);
+    let scenario = NamedTempFile::new().unwrap();
+    // With a stale TTL of 100s the price is fresh 60s after it was set and
+    // stale another 60s later.
+    fs::write(
+        scenario.path(),
+        r#"
+[[steps]]
+function = "set_price"
+args = '[{"type": "string", "value": "XLM"}, {"type": "i128", "value": 1100000}]'
+
+[[steps]]
+function = "is_stale"
+args = '[{"type": "string", "value": "XLM"}]'
+expected_return = "False"
+
+[[steps]]
+function = "is_stale"
+args = '[{"type": "string", "value": "XLM"}]'
+expected_return = "True"
+"#,
+    )
+    .unwrap();
+
+    base_cmd()
+        .args([
+            "scenario",
+            "--scenario",
+            scenario.path().to_str().unwrap(),
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--storage",
+            r#"[{"key": {"type": "vec", "value": [{"type": "symbol", "value": "StaleTtl"}]},
+                 "value": {"type": "u64", "value": 100}, "durability": "instance"}]"#,
+            "--step-advance-secs",
+            "60",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Ledger time advanced by 60s to 60",
+        ))
+        .stdout(predicate::str::contains(
+            "Ledger time advanced by 60s to 120",
+        ))
+        .stdout(predicate::str::contains(
+            "All scenario steps passed successfully!",
+        ));
+}
+
 #[test]
 fn scenario_passes_when_no_events_are_expected() {
     let wasm = fixture_wasm("counter");
@@ -662,6 +751,106 @@ fn repl_seeds_initial_storage() {
     );
 }
 
+#[test]
+fn repl_let_binds_result_for_later_calls() {
+    let wasm = fixture_wasm("echo");
+    let output = Command::new(env!("CARGO_BIN_EXE_soroban-debug"))
+        .env("NO_COLOR", "1")
+        .args(["repl", "--contract", wasm.to_str().unwrap()])
+        .write_stdin("let v = echo 42\ncall echo $v\ncall echo $missing\nexit\n")
+        .output()
+        .unwrap();
+
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert!(combined.contains("v = 42"), "{}", combined);
+    assert_eq!(combined.matches("Result:").count(), 2, "{}", combined);
+    assert!(
+        combined.contains("Unknown variable $missing"),
+        "{}",
+        combined
+    );
+}
+
+#[test]
+fn repl_print_decodes_storage_value() {
+    let wasm = fixture_wasm("counter");
+    let output = Command::new(env!("CARGO_BIN_EXE_soroban-debug"))
+        .env("NO_COLOR", "1")
+        .args(["repl", "--contract", wasm.to_str().unwrap()])
+        .write_stdin(concat!(
+            "call increment\n",
+            "call increment\n",
+            "print storage[c]\n",
+            "print storage[{\"type\":\"symbol\",\"value\":\"c\"}]\n",
+            "print storage[missing]\n",
+            "exit\n",
+        ))
+        .output()
+        .unwrap();
+
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert!(
+        combined.contains("storage[c] = 2 (i64)"),
+        "decoded value missing\n{}",
+        combined
+    );
+    assert!(
+        combined.contains(r#"storage[{"type":"symbol","value":"c"}] = 2 (i64)"#),
+        "typed key lookup failed\n{}",
+        combined
+    );
+    assert!(
+        combined.contains("storage[missing] = <not set>"),
+        "missing key should print <not set>\n{}",
+        combined
+    );
+}
+
+#[test]
+fn repl_account_seed_makes_aliases_reproducible() {
+    let wasm = fixture_wasm("cross_contract");
+    let aliases = |seed: &str| {
+        let output = Command::new(env!("CARGO_BIN_EXE_soroban-debug"))
+            .env("NO_COLOR", "1")
+            .args([
+                "repl",
+                "--contract",
+                wasm.to_str().unwrap(),
+                "--account-seed",
+                seed,
+            ])
+            .write_stdin("call call acc1 ping []\ncall call acc2 ping []\nexit\n")
+            .output()
+            .unwrap();
+        let combined = format!(
+            "{}{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        combined
+            .lines()
+            .filter_map(|line| line.split_once("Address alias ").map(|(_, rest)| rest))
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+
+    let first = aliases("42");
+    assert_eq!(first.len(), 2, "expected two alias lines: {:?}", first);
+    assert!(first[0].starts_with("'acc1' -> G"), "{:?}", first);
+    assert_eq!(first, aliases("42"));
+    assert_ne!(first, aliases("43"));
+}
+
 #[test]
 fn repl_supports_conditional_breakpoints() {
     let wasm = fixture_wasm("counter");
@@ -691,3 +880,2065 @@ fn repl_supports_conditional_breakpoints() {
         combined
     );
 }
+
+#[test]
+fn run_fails_with_budget_exceeded_when_max_insns_is_tiny() {
+    let wasm = fixture_wasm("budget_heavy");
+
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "heavy",
+            "--args",
+            "[200]",
+            "--max-insns",
+            "1000",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Budget exceeded"))
+        .stderr(predicate::str::contains(
+            "CPU instruction limit of 1000 exceeded",
+        ));
+}
+
+#[test]
+fn run_checks_expected_mock_call_arguments() {
+    let wasm = fixture_wasm("cross_contract");
+    let token = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
+    let holder = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+    let call_args = format!(
+        r#"["{token}", "transfer", {{"type": "vec", "value": [{{"type": "address", "value": "{holder}"}}, {{"type": "address", "value": "{token}"}}, {{"type": "i128", "value": 100}}]}}]"#
+    );
+    let run = |expectation: String| {
+        let mut cmd = base_cmd();
+        cmd.args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "call",
+            "--args",
+            &call_args,
+            "--mock",
+            &format!("{token}.transfer=true"),
+            "--expect-mock-call",
+            &expectation,
+        ]);
+        cmd.assert()
+    };
+
+    run(format!("{token}:transfer(to=@acc0, amount=100)"))
+        .success()
+        .stdout(predicate::str::contains("expected mock call(s) matched"));
+
+    run(format!("{token}:transfer(to=@acc0, amount=250)"))
+        .failure()
+        .stderr(predicate::str::contains("did not match"))
+        .stderr(predicate::str::contains(format!("transfer({holder},")))
+        .stderr(predicate::str::contains(format!("{token}, 100)")));
+}
+
+#[test]
+fn run_dumps_footprint_as_decodable_xdr() {
+    use soroban_env_host::xdr::{LedgerFootprint, LedgerKey, Limits, ReadXdr, ScVal};
+
+    let wasm = fixture_wasm("counter");
+    let output = base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "increment",
+            "--dump-footprint-xdr",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let xdr = stdout
+        .lines()
+        .skip_while(|line| !line.contains("--- Ledger Footprint (XDR) ---"))
+        .nth(1)
+        .expect("footprint XDR line after header");
+    let footprint = LedgerFootprint::from_xdr_base64(xdr.trim(), Limits::none()).unwrap();
+
+    let writes_instance = footprint.read_write.iter().any(|key| {
+        matches!(key, LedgerKey::ContractData(data) if data.key == ScVal::LedgerKeyContractInstance)
+    });
+    assert!(
+        writes_instance,
+        "increment writes instance storage: {footprint:?}"
+    );
+    assert!(footprint
+        .read_only
+        .iter()
+        .chain(footprint.read_write.iter())
+        .any(|key| matches!(key, LedgerKey::ContractCode(_))));
+}
+
+#[test]
+fn run_surfaces_error_injected_by_mock() {
+    let wasm = fixture_wasm("cross_contract");
+    let oracle = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
+    let call_args = format!(r#"["{oracle}", "price", []]"#);
+
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "call",
+            "--args",
+            &call_args,
+            "--mock",
+            &format!("{oracle}.price=>err:3"),
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("error code: 3"));
+}
+
+#[test]
+fn check_interface_distinguishes_conforming_and_drifted_contracts() {
+    let counter = fixture_wasm("counter");
+    let echo = fixture_wasm("echo");
+
+    // Use the counter's own exported signatures as the shared interface.
+    let inspect = base_cmd()
+        .args([
+            "inspect",
+            "--contract",
+            counter.to_str().unwrap(),
+            "--functions",
+            "--format",
+            "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(inspect.status.success());
+    let inspected: serde_json::Value = serde_json::from_slice(&inspect.stdout).unwrap();
+    let interface = NamedTempFile::new().unwrap();
+    fs::write(
+        interface.path(),
+        inspected["result"]["exported_functions"].to_string(),
+    )
+    .unwrap();
+
+    let contracts = format!("{},{}", counter.display(), echo.display());
+    let output = base_cmd()
+        .args([
+            "check-interface",
+            "--interface",
+            interface.path().to_str().unwrap(),
+            "--contracts",
+            &contracts,
+            "--format",
+            "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let results = report["result"]["contracts"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0]["conforms"], true);
+    assert!(results[0]["violations"].as_array().unwrap().is_empty());
+    assert_eq!(results[1]["conforms"], false);
+    assert!(results[1]["violations"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|v| v["type"] == "FunctionRemoved" && v["name"] == "increment"));
+}
+
+#[test]
+fn run_quiet_budget_hides_only_budget_output() {
+    let wasm = fixture_wasm("counter");
+    let run = |extra: &[&str]| {
+        let output = base_cmd()
+            .args([
+                "run",
+                "--contract",
+                wasm.to_str().unwrap(),
+                "--function",
+                "increment",
+                "--show-events",
+            ])
+            .args(extra)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        format!(
+            "{}{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        )
+    };
+
+    let noisy = run(&[]);
+    assert!(noisy.contains("Resource budget"), "{noisy}");
+
+    let quiet = run(&["--quiet-budget"]);
+    assert!(!quiet.contains("Resource budget"), "{quiet}");
+    assert!(!quiet.contains("Memory Allocation Summary"), "{quiet}");
+    assert!(quiet.contains("Result"), "{quiet}");
+    assert!(quiet.contains("--- Events ---"), "{quiet}");
+}
+
+#[test]
+fn run_decode_storage_shows_typed_values_and_delta() {
+    let wasm = fixture_wasm("counter");
+    let output = base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "increment",
+            "--storage",
+            r#"{"c": 41}"#,
+            "--decode-storage",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert!(
+        combined.contains("--- Storage Changes (decoded) ---"),
+        "{combined}"
+    );
+    assert!(combined.contains("41 (i64)"), "{combined}");
+    assert!(combined.contains("42 (i64)"), "{combined}");
+    assert!(combined.contains("[+1]"), "{combined}");
+}
+
+#[test]
+fn json_errors_prints_error_kind_on_failure() {
+    let wasm = fixture_wasm("counter");
+    let output = base_cmd()
+        .args([
+            "--quiet",
+            "--json-errors",
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "missing_fn",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let last_line = stdout.lines().rev().find(|l| !l.trim().is_empty()).unwrap();
+    let json: serde_json::Value = serde_json::from_str(last_line).unwrap();
+    assert_eq!(json["error_kind"], "InvalidFunction");
+    assert!(json["message"].as_str().unwrap().contains("missing_fn"));
+}
+
+#[test]
+fn inspect_functions_marks_read_only_entry_points() {
+    let counter = fixture_wasm("counter");
+
+    base_cmd()
+        .args([
+            "inspect",
+            "--contract",
+            counter.to_str().unwrap(),
+            "--functions",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("get() -> "))
+        .stdout(predicate::str::contains("[read-only]"))
+        .stdout(predicate::str::contains("increment"));
+
+    let output = base_cmd()
+        .args([
+            "--quiet",
+            "inspect",
+            "--contract",
+            counter.to_str().unwrap(),
+            "--functions",
+            "--format",
+            "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let functions = json["result"]["exported_functions"].as_array().unwrap();
+    let read_only = |name: &str| {
+        functions
+            .iter()
+            .find(|f| f["name"] == name)
+            .map(|f| f["read_only"].clone())
+            .unwrap()
+    };
+    assert_eq!(read_only("get"), true);
+    assert_eq!(read_only("increment"), false);
+}
+
+#[test]
+fn run_reports_an_unreadable_contract_spec_as_a_warning() {
+    // Custom sections may sit anywhere, so a copy of the counter's spec with
+    // an undecodable entry appended, placed right after the header, is the
+    // `contractspecv0` the spec readers find first.
+    let counter = fs::read(fixture_wasm("counter")).unwrap();
+    let mut spec = wasmparser::Parser::new(0)
+        .parse_all(&counter)
+        .find_map(|payload| match payload.unwrap() {
+            wasmparser::Payload::CustomSection(reader) if reader.name() == "contractspecv0" => {
+                Some(reader.data().to_vec())
+            }
+            _ => None,
+        })
+        .unwrap();
+    spec.extend_from_slice(&[0xff; 4]);
+    let uleb = |mut n: usize| {
+        let mut out = Vec::new();
+        loop {
+            let byte = (n & 0x7f) as u8;
+            n >>= 7;
+            if n == 0 {
+                out.push(byte);
+                return out;
+            }
+            out.push(byte | 0x80);
+        }
+    };
+    let name = b"contractspecv0";
+    let mut section = uleb(name.len());
+    section.extend_from_slice(name);
+    section.extend_from_slice(&spec);
+    let mut wasm = counter[..8].to_vec();
+    wasm.push(0x00);
+    wasm.extend_from_slice(&uleb(section.len()));
+    wasm.extend_from_slice(&section);
+    wasm.extend_from_slice(&counter[8..]);
+    let contract = NamedTempFile::new().unwrap();
+    fs::write(contract.path(), &wasm).unwrap();
+    let run = |extra: &[&str]| {
+        let mut cmd = base_cmd();
+        cmd.args([
+            "run",
+            "--contract",
+            contract.path().to_str().unwrap(),
+            "--function",
+            "increment",
+        ])
+        .args(extra);
+        cmd.output().unwrap()
+    };
+
+    let output = run(&["--quiet", "--output", "json"]);
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let warnings = json["warnings"].as_array().unwrap();
+    assert_eq!(warnings.len(), 1, "{warnings:?}");
+    assert_eq!(warnings[0]["code"], "contract_spec_unreadable");
+
+    let output = run(&[]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("--- Warnings ---"), "{stdout}");
+    assert!(stdout.contains("[contract_spec_unreadable]"), "{stdout}");
+}
+
+#[test]
+fn run_budget_breakdown_sums_to_total() {
+    let wasm = fixture_wasm("counter");
+    let output = base_cmd()
+        .args([
+            "--quiet",
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "increment",
+            "--output",
+            "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let budget = &json["result"]["budget"];
+    let rows = budget["breakdown"].as_array().unwrap();
+    assert!(!rows.is_empty());
+
+    let cpu: u64 = rows
+        .iter()
+        .map(|r| r["cpu_instructions"].as_u64().unwrap())
+        .sum();
+    let mem: u64 = rows
+        .iter()
+        .map(|r| r["memory_bytes"].as_u64().unwrap())
+        .sum();
+    assert_eq!(cpu, budget["cpu_instructions"].as_u64().unwrap());
+    assert_eq!(mem, budget["memory_bytes"].as_u64().unwrap());
+
+    // Sorted by CPU, most expensive first.
+    let cpus: Vec<u64> = rows
+        .iter()
+        .map(|r| r["cpu_instructions"].as_u64().unwrap())
+        .collect();
+    assert!(cpus.windows(2).all(|w| w[0] >= w[1]));
+
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "increment",
+            "--verbose",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--- Budget Breakdown ---"))
+        .stdout(predicate::str::contains("Cost type"));
+}
+
+#[test]
+fn optimize_strip_custom_sections_writes_smaller_wasm() {
+    let wasm = fixture_wasm("counter");
+    let stripped = NamedTempFile::new().unwrap();
+    let report = NamedTempFile::new().unwrap();
+
+    base_cmd()
+        .args([
+            "optimize",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "get",
+            "--output",
+            report.path().to_str().unwrap(),
+            "--strip-custom-sections",
+            stripped.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("contractmetav0"));
+
+    let original = fs::read(&wasm).unwrap();
+    let stripped = fs::read(stripped.path()).unwrap();
+    assert!(stripped.len() < original.len());
+    assert!(soroban_debugger::utils::wasm::parse_functions(&stripped)
+        .unwrap()
+        .contains(&"increment".to_string()));
+
+    let report = fs::read_to_string(report.path()).unwrap();
+    assert!(report.contains("## WASM Size"), "{report}");
+    assert!(
+        report.contains("Export `increment` is not in the entry set"),
+        "{report}"
+    );
+}
+
+#[test]
+fn optimize_flags_exports_missing_from_spec() {
+    let wasm = fixture_wasm(fixtures::names::EXTRA_EXPORT);
+    let report = NamedTempFile::new().unwrap();
+
+    base_cmd()
+        .args([
+            "optimize",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "value",
+            "--output",
+            report.path().to_str().unwrap(),
+            "--fail-on-extra-exports",
+        ])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(
+            "Export `helper` is not part of the contract interface",
+        ))
+        .stderr(predicate::str::contains(
+            "1 exported function(s) are not part of the contract interface: helper",
+        ));
+
+    base_cmd()
+        .args([
+            "inspect",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--functions",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Exported but not part of the contract interface: helper",
+        ));
+}
+
+#[test]
+fn optimize_fail_on_extra_exports_passes_for_declared_interface() {
+    let wasm = fixture_wasm("counter");
+    let report = NamedTempFile::new().unwrap();
+
+    base_cmd()
+        .args([
+            "optimize",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--output",
+            report.path().to_str().unwrap(),
+            "--fail-on-extra-exports",
+        ])
+        .assert()
+        .success();
+}
+
+#[test]
+fn run_show_diagnostics_explains_a_failed_call() {
+    let wasm = fixture_wasm("always_panic");
+    let run = |extra: &[&str]| {
+        let mut cmd = base_cmd();
+        cmd.args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "panic",
+            "--show-diagnostics",
+        ])
+        .args(extra);
+        cmd.assert().failure()
+    };
+
+    run(&[])
+        .stdout(predicate::str::contains("--- Diagnostic Events ---"))
+        .stdout(predicate::str::contains("#0 fn_call panic()"));
+
+    run(&["--diagnostics-level", "error"])
+        .stdout(predicate::str::contains("--- Diagnostic Events ---"))
+        .stdout(predicate::str::contains("fn_call").not())
+        .stdout(predicate::str::is_match(r"#\d+ error ").unwrap());
+}
+
+#[test]
+fn inspect_shows_toolchain_from_contract_meta() {
+    let counter = fixture_wasm("counter");
+
+    base_cmd()
+        .args(["inspect", "--contract", counter.to_str().unwrap()])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Built with: soroban-sdk 22."))
+        .stdout(predicate::str::contains("rustc "));
+}
+
+#[test]
+fn inspect_opcodes_counts_instructions_in_fixture() {
+    let counter = fixture_wasm("counter");
+
+    base_cmd()
+        .args([
+            "inspect",
+            "--contract",
+            counter.to_str().unwrap(),
+            "--opcodes",
+            "--opcode-limit",
+            "5",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("(top 5 shown)"));
+
+    let output = base_cmd()
+        .args([
+            "--quiet",
+            "inspect",
+            "--contract",
+            counter.to_str().unwrap(),
+            "--opcodes",
+            "--format",
+            "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let opcodes = json["result"]["opcodes"].as_object().unwrap();
+    let count = |name: &str| opcodes.get(name).and_then(|c| c.as_u64()).unwrap_or(0);
+    // `get` and `increment` both reach storage through imported host functions,
+    // and every function body ends with `end`.
+    assert!(count("call") >= 2, "{:?}", opcodes);
+    assert!(count("end") >= 2, "{:?}", opcodes);
+    assert!(count("i64.const") >= 1, "{:?}", opcodes);
+    let total: u64 = opcodes.values().filter_map(|c| c.as_u64()).sum();
+    assert_eq!(json["result"]["total_instructions"], total);
+}
+
+#[test]
+fn call_graph_shows_static_and_runtime_cross_contract_edges() {
+    let wasm = fixture_wasm("cross_contract");
+    let oracle = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
+
+    base_cmd()
+        .args([
+            "inspect",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--call-graph",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("digraph contract_dependencies"))
+        .stdout(predicate::str::contains(
+            "\"call\" -> \"external_contract\";",
+        ));
+
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "call",
+            "--args",
+            &format!(r#"["{oracle}", "get_price", []]"#),
+            "--mock",
+            &format!("{oracle}.get_price=100"),
+            "--call-graph",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "\"call\" -> \"{oracle}::get_price\";"
+        )));
+}
+
+#[test]
+fn contract_can_be_piped_through_stdin() {
+    let wasm = fs::read(fixture_wasm("counter")).unwrap();
+
+    base_cmd()
+        .args(["inspect", "--contract", "-", "--functions"])
+        .write_stdin(wasm.clone())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("increment"));
+
+    base_cmd()
+        .args(["run", "--contract", "-", "--function", "increment"])
+        .write_stdin(wasm)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Result:"));
+
+    base_cmd()
+        .args(["inspect", "--contract", "-"])
+        .write_stdin("not a contract")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("stdin is not a WASM module"));
+}
+
+#[test]
+fn run_seeds_ledger_from_flags() {
+    let counter = fixture_wasm("counter");
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            counter.to_str().unwrap(),
+            "--function",
+            "increment",
+            "--ledger-timestamp",
+            "1700000000",
+            "--ledger-sequence",
+            "42",
+            "--ledger-network-id",
+            "Test SDF Network ; September 2015",
+            "--verbose",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "Ledger: timestamp=1700000000, sequence=42",
+        ));
+
+    let clock = fixture_wasm(fixtures::names::LEDGER_CLOCK);
+    for _ in 0..2 {
+        base_cmd()
+            .args([
+                "run",
+                "--contract",
+                clock.to_str().unwrap(),
+                "--function",
+                "get_timestamp",
+                "--ledger-timestamp",
+                "1700000000",
+            ])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("Result: 1700000000"));
+    }
+}
+
+#[test]
+fn run_event_filter_matches_the_event_name_only() {
+    let wasm = fixture_wasm(fixtures::names::ORACLE);
+    let set_price = |filter: &[&str]| {
+        base_cmd()
+            .args([
+                "run",
+                "--contract",
+                wasm.to_str().unwrap(),
+                "--function",
+                "set_price",
+                "--args",
+                r#"[{"type": "string", "value": "XLM"}, {"type": "i128", "value": 105}]"#,
+            ])
+            .args(filter)
+            .assert()
+            .success()
+    };
+
+    set_price(&["--event-filter", "set*"]).stdout(predicate::str::contains("StringM(setprice)"));
+    set_price(&["--event-filter", "price"]).stdout(predicate::str::contains("No events captured."));
+    // The deprecated --filter-topic keeps its substring matching.
+    set_price(&["--filter-topic", "price"]).stdout(predicate::str::contains("StringM(setprice)"));
+}
+
+#[test]
+fn run_snapshot_in_keeps_the_snapshot_ledger_timestamp() {
+    let clock = fixture_wasm(fixtures::names::LEDGER_CLOCK);
+    let snapshot = NamedTempFile::new().unwrap();
+    let get_timestamp = |extra: &[&str]| {
+        base_cmd()
+            .args([
+                "run",
+                "--contract",
+                clock.to_str().unwrap(),
+                "--function",
+                "get_timestamp",
+            ])
+            .args(extra)
+            .assert()
+            .success()
+    };
+
+    get_timestamp(&[
+        "--ledger-timestamp",
+        "1700000000",
+        "--snapshot-out",
+        snapshot.path().to_str().unwrap(),
+    ])
+    .stdout(predicate::str::contains("Result: 1700000000"));
+    get_timestamp(&["--snapshot-in", snapshot.path().to_str().unwrap()])
+        .stdout(predicate::str::contains("Result: 1700000000"));
+    get_timestamp(&[
+        "--snapshot-in",
+        snapshot.path().to_str().unwrap(),
+        "--ledger-timestamp",
+        "1800000000",
+    ])
+    .stdout(predicate::str::contains("Result: 1800000000"));
+}
+
+#[test]
+fn script_runs_lines_against_shared_storage() {
+    let wasm = fixture_wasm(fixtures::names::ORACLE);
+    let script = NamedTempFile::new().unwrap();
+    fs::write(
+        script.path(),
+        concat!(
+            "# set up the oracle, then read the price back\n",
+            r#"initialize ["GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF", {"type": "u64", "value": 3600}]"#,
+            "\n",
+            r#"set_price [{"type": "string", "value": "XLM"}, {"type": "i128", "value": 1100000}]"#,
+            "\n",
+            r#"get_price [{"type": "string", "value": "XLM"}]"#,
+            "\n",
+        ),
+    )
+    .unwrap();
+
+    base_cmd()
+        .args([
+            "script",
+            script.path().to_str().unwrap(),
+            "--contract",
+            wasm.to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Step 3/3 (line 4): get_price"))
+        .stdout(predicate::str::contains("1100000"))
+        .stdout(predicate::str::contains("--- Storage Changes ---"))
+        .stdout(predicate::str::contains("All 3 script steps succeeded"));
+}
+
+#[test]
+fn script_stops_on_first_error_unless_told_to_continue() {
+    let wasm = fixture_wasm(fixtures::names::ORACLE);
+    let script = NamedTempFile::new().unwrap();
+    fs::write(
+        script.path(),
+        concat!(
+            r#"set_price [{"type": "string", "value": "XLM"}, {"type": "i128", "value": 1100000}]"#,
+            "\n",
+            "set_price [{\"type\": \"i128\", \"value\": \"abc\"}]\n",
+            "missing_fn\n",
+            r#"get_price [{"type": "string", "value": "XLM"}]"#,
+            "\n",
+        ),
+    )
+    .unwrap();
+    let run = |extra: &[&str]| {
+        let mut cmd = base_cmd();
+        cmd.args([
+            "script",
+            script.path().to_str().unwrap(),
+            "--contract",
+            wasm.to_str().unwrap(),
+        ])
+        .args(extra);
+        cmd.assert()
+    };
+
+    run(&[])
+        .failure()
+        .stdout(predicate::str::contains("Invalid arguments"))
+        .stdout(predicate::str::contains("Stopping at line 2"))
+        .stdout(predicate::str::contains("Step 3/4").not());
+
+    run(&["--continue-on-error"])
+        .failure()
+        .stdout(predicate::str::contains("Step 4/4 (line 4): get_price"))
+        .stdout(predicate::str::contains("1100000"))
+        .stderr(predicate::str::contains(
+            "2 of 4 script step(s) failed at line(s) 2, 3",
+        ));
+}
+
+#[test]
+fn script_assert_lines_compare_decoded_results() {
+    let wasm = fixture_wasm("counter");
+    let script = NamedTempFile::new().unwrap();
+    fs::write(
+        script.path(),
+        "increment\nassert get == 1\nassert increment == 5\nget\n",
+    )
+    .unwrap();
+
+    base_cmd()
+        .args([
+            "script",
+            script.path().to_str().unwrap(),
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--continue-on-error",
+        ])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("Assertion passed"))
+        .stdout(predicate::str::contains(
+            "Assertion failed on line 3: increment returned 2, expected 5",
+        ))
+        .stderr(predicate::str::contains(
+            "1 of 4 script step(s) failed at line(s) 3",
+        ));
+
+    let oracle = fixture_wasm(fixtures::names::ORACLE);
+    // i128 results decode to decimal strings but compare against plain numbers.
+    fs::write(
+        script.path(),
+        concat!(
+            r#"set_price [{"type": "string", "value": "XLM"}, {"type": "i128", "value": 1100000}]"#,
+            "\n",
+            r#"assert get_price [{"type": "string", "value": "XLM"}] == 1100000"#,
+            "\n",
+        ),
+    )
+    .unwrap();
+    base_cmd()
+        .args([
+            "script",
+            script.path().to_str().unwrap(),
+            "--contract",
+            oracle.to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Assertion passed"));
+}
+
+#[test]
+fn run_set_instance_rejects_keys_missing_from_spec() {
+    let wasm = fixture_wasm("counter");
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "get",
+            "--set-instance",
+            "StaleTtl=60",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("StaleTtl"));
+
+    let oracle = fixtures::fixtures_root()
+        .join("wasm")
+        .join(format!("{}.wasm", fixtures::names::ORACLE));
+    if !oracle.exists() {
+        eprintln!(
+            "Skipping oracle checks: fixture not found at {}. Run tests/fixtures/build.sh to build fixtures.",
+            oracle.display()
+        );
+        return;
+    }
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            oracle.to_str().unwrap(),
+            "--function",
+            "get_stale_ttl",
+            "--set-instance",
+            "StaleTtl=60",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Result: 60"));
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            oracle.to_str().unwrap(),
+            "--function",
+            "get_stale_ttl",
+            "--set-instance",
+            "Missing=1",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("known: StaleTtl, Admin"));
+}
+
+#[test]
+fn run_dry_run_rolls_back_storage_before_export() {
+    let wasm = fixture_wasm("counter");
+    let dry_export = NamedTempFile::new().unwrap();
+    let baseline_export = NamedTempFile::new().unwrap();
+
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "increment",
+            "--dry-run",
+            "--export-storage",
+            dry_export.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Result: 1"))
+        .stdout(predicate::str::contains("DRY RUN — no state committed"));
+
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "get",
+            "--export-storage",
+            baseline_export.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    let dry: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(dry_export.path()).unwrap()).unwrap();
+    let baseline: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(baseline_export.path()).unwrap()).unwrap();
+    assert_eq!(dry, baseline, "dry run must leave storage as it was");
+}
+
+#[test]
+fn run_typed_storage_export_includes_instance_entries() {
+    let wasm = fixture_wasm("counter");
+    let export = NamedTempFile::new().unwrap();
+    let storage = r#"[
+        {"key":{"type":"symbol","value":"c"},"value":5,"durability":"instance"},
+        {"key":{"type":"symbol","value":"c"},"value":9,"durability":"temporary"}
+    ]"#;
+
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "increment",
+            "--storage",
+            storage,
+            "--export-storage",
+            export.path().to_str().unwrap(),
+            "--export-storage-format",
+            "typed",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Result: 6"));
+
+    let typed: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(export.path()).unwrap()).unwrap();
+    let mut values: Vec<_> = ["c", "c#2"].iter().map(|key| typed[key].clone()).collect();
+    values.sort_by_key(|v| v.as_u64());
+    assert_eq!(values, [serde_json::json!(6), serde_json::json!(9)]);
+}
+
+#[test]
+fn run_dry_run_set_price_leaves_oracle_without_a_price() {
+    let wasm = fixture_wasm(fixtures::names::ORACLE);
+    let snapshot = NamedTempFile::new().unwrap();
+    let set_price = |extra: &[&str]| {
+        base_cmd()
+            .args([
+                "run",
+                "--contract",
+                wasm.to_str().unwrap(),
+                "--function",
+                "set_price",
+                "--args",
+                r#"[{"type": "string", "value": "XLM"}, {"type": "i128", "value": 120000}]"#,
+                "--snapshot-out",
+                snapshot.path().to_str().unwrap(),
+            ])
+            .args(extra)
+            .assert()
+            .success()
+    };
+    let get_price = || {
+        base_cmd()
+            .args([
+                "run",
+                "--contract",
+                wasm.to_str().unwrap(),
+                "--function",
+                "get_price",
+                "--args",
+                r#"[{"type": "string", "value": "XLM"}]"#,
+                "--snapshot-in",
+                snapshot.path().to_str().unwrap(),
+            ])
+            .assert()
+    };
+
+    set_price(&["--dry-run"]).stdout(predicate::str::contains("DRY RUN — no state committed"));
+    get_price()
+        .failure()
+        .stderr(predicate::str::contains("AssetNotFound"));
+
+    set_price(&[]);
+    get_price()
+        .success()
+        .stdout(predicate::str::contains("120000"));
+}
+
+#[test]
+fn run_snapshot_out_then_snapshot_in_keeps_counter_state() {
+    let wasm = fixture_wasm("counter");
+    let snapshot = NamedTempFile::new().unwrap();
+
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "increment",
+            "--snapshot-out",
+            snapshot.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Result: 1"))
+        .stdout(predicate::str::contains("Ledger snapshot written to"));
+
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "increment",
+            "--snapshot-in",
+            snapshot.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Result: 2"));
+
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "get",
+            "--snapshot-in",
+            snapshot.path().to_str().unwrap(),
+            "--ledger-network-id",
+            "Some Other Network",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Ledger snapshot was taken on network",
+        ));
+}
+
+#[test]
+fn fuzz_with_fixed_seed_reports_the_same_findings() {
+    let oracle = fixtures::fixtures_root()
+        .join("wasm")
+        .join(format!("{}.wasm", fixtures::names::ORACLE));
+    if !oracle.exists() {
+        eprintln!(
+            "Skipping test: fixture not found at {}. Run tests/fixtures/build.sh to build fixtures.",
+            oracle.display()
+        );
+        return;
+    }
+
+    let fuzz = || {
+        let output = base_cmd()
+            .args([
+                "fuzz",
+                "--contract",
+                oracle.to_str().unwrap(),
+                "--function",
+                "set_price",
+                "--iterations",
+                "40",
+                "--seed",
+                "42",
+                "--format",
+                "json",
+            ])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let json_start = stdout.find('{').unwrap();
+        serde_json::from_str::<serde_json::Value>(&stdout[json_start..]).unwrap()
+    };
+
+    let first = fuzz();
+    assert_eq!(first, fuzz(), "a fixed seed must replay the same findings");
+
+    let findings = first["result"]["findings"].as_array().unwrap();
+    let invalid_price = findings
+        .iter()
+        .find(|f| f["code"] == 2)
+        .expect("non-positive prices should be found");
+    assert_eq!(invalid_price["name"], "InvalidPrice");
+    assert_eq!(invalid_price["kind"], "contract_error");
+
+    base_cmd()
+        .args([
+            "fuzz",
+            "--contract",
+            oracle.to_str().unwrap(),
+            "--function",
+            "set_price",
+            "--iterations",
+            "40",
+            "--seed",
+            "42",
+            "--expect-code",
+            "2",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Findings: 0"));
+}
+
+#[test]
+fn script_log_json_writes_one_line_per_invocation() {
+    let wasm = fixture_wasm("counter");
+    let script = NamedTempFile::new().unwrap();
+    fs::write(script.path(), "increment\nget\n").unwrap();
+    let log = NamedTempFile::new().unwrap();
+
+    base_cmd()
+        .args([
+            "script",
+            script.path().to_str().unwrap(),
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--log-json",
+            log.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    let contents = fs::read_to_string(log.path()).unwrap();
+    let lines: Vec<serde_json::Value> = contents
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2, "{contents}");
+    assert_eq!(lines[0]["function"], "increment");
+    assert_eq!(lines[1]["function"], "get");
+    assert_eq!(lines[1]["result"]["ok"], true);
+    assert_eq!(lines[1]["result"]["json"], 1);
+    assert!(lines[1]["result"]["xdr"].is_string());
+    assert!(lines[1]["budget"]["cpu_instructions"].as_u64().unwrap() > 0);
+    assert!(lines[1]["memory"]["peak_memory"].is_u64());
+    assert!(lines[1]["started_at"].is_string());
+}
+
+#[test]
+fn inspect_spec_dumps_types_errors_and_functions() {
+    let oracle = fixtures::fixtures_root()
+        .join("wasm")
+        .join(format!("{}.wasm", fixtures::names::ORACLE));
+    if !oracle.exists() {
+        eprintln!(
+            "Skipping: fixture not found at {}. Run tests/fixtures/build.sh to build fixtures.",
+            oracle.display()
+        );
+        return;
+    }
+
+    let output = base_cmd()
+        .args(["inspect", "--contract", oracle.to_str().unwrap(), "--spec"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let spec: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    let functions: Vec<&str> = spec["functions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|f| f["name"].as_str().unwrap())
+        .collect();
+    for name in ["initialize", "set_price", "get_price", "is_stale"] {
+        assert!(
+            functions.contains(&name),
+            "{name} missing from {functions:?}"
+        );
+    }
+
+    let data_key = spec["types"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|t| t["name"] == "DataKey")
+        .expect("DataKey type");
+    assert_eq!(data_key["kind"], "union");
+
+    let oracle_error = spec["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|e| e["name"] == "OracleError")
+        .expect("OracleError enum");
+    let variants: Vec<&str> = oracle_error["cases"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["name"].as_str().unwrap())
+        .collect();
+    assert!(variants.contains(&"AssetNotFound"), "{variants:?}");
+    assert!(variants.contains(&"InvalidPrice"), "{variants:?}");
+}
+
+#[test]
+fn run_reads_arguments_from_args_file() {
+    let oracle = fixture_wasm(fixtures::names::ORACLE);
+    let args_file = NamedTempFile::new().unwrap();
+    fs::write(
+        args_file.path(),
+        r#"[{"type": "string", "value": "XLM"}, {"type": "i128", "value": 1100000}]"#,
+    )
+    .unwrap();
+
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            oracle.to_str().unwrap(),
+            "--function",
+            "set_price",
+            "--args-file",
+            args_file.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Execution Complete"));
+
+    fs::write(args_file.path(), r#"["XLM", 1100000"#).unwrap();
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            oracle.to_str().unwrap(),
+            "--function",
+            "set_price",
+            "--args-file",
+            args_file.path().to_str().unwrap(),
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not valid JSON"));
+}
+
+#[test]
+fn run_expand_env_substitutes_and_rejects_unset_variables() {
+    let oracle = fixture_wasm(fixtures::names::ORACLE);
+
+    base_cmd()
+        .env("ORACLE_ASSET", "XLM")
+        .env_remove("ORACLE_PRICE")
+        .args([
+            "run",
+            "--contract",
+            oracle.to_str().unwrap(),
+            "--function",
+            "set_price",
+            "--args",
+            r#"[{"type": "string", "value": "${ORACLE_ASSET}"}, {"type": "i128", "value": ${ORACLE_PRICE:-1100000}}]"#,
+            "--expand-env",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(r#""value": "XLM""#))
+        .stdout(predicate::str::contains(r#""value": 1100000"#));
+
+    base_cmd()
+        .env_remove("ORACLE_ASSET")
+        .args([
+            "run",
+            "--contract",
+            oracle.to_str().unwrap(),
+            "--function",
+            "set_price",
+            "--args",
+            r#"[{"type": "string", "value": "${ORACLE_ASSET}"}, {"type": "i128", "value": 1}]"#,
+            "--expand-env",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("ORACLE_ASSET"));
+}
+
+#[test]
+fn run_auth_allows_only_declared_signers() {
+    let oracle = fixture_wasm(fixtures::names::ORACLE);
+    let admin = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+    let other = "GD7777777777777777777777777777777777777777777777777773DB";
+    let run_with_signer = |signer: &str| {
+        let mut cmd = base_cmd();
+        cmd.args([
+            "run",
+            "--contract",
+            oracle.to_str().unwrap(),
+            "--function",
+            "set_price",
+            "--args",
+            r#"[{"type": "string", "value": "XLM"}, {"type": "i128", "value": 1100000}]"#,
+            "--set-instance",
+            &format!("Admin={}", admin),
+            "--auth",
+            signer,
+        ]);
+        cmd
+    };
+
+    run_with_signer(admin)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Execution Complete"));
+
+    // The rejected call is still logged, as a failure.
+    let log = NamedTempFile::new().unwrap();
+    run_with_signer(other)
+        .args(["--log-json", log.path().to_str().unwrap()])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(format!(
+            "missing auth for {}",
+            admin
+        )));
+    let entry: serde_json::Value =
+        serde_json::from_str(fs::read_to_string(log.path()).unwrap().trim()).unwrap();
+    assert_eq!(entry["function"], "set_price");
+    assert_eq!(entry["result"]["ok"], false);
+    assert_eq!(
+        entry["result"]["error"],
+        format!("missing auth for {}", admin)
+    );
+}
+
+#[test]
+fn run_on_event_forwards_setprice_to_downstream_mock() {
+    let oracle = fixture_wasm(fixtures::names::ORACLE);
+    let sink = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
+
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            oracle.to_str().unwrap(),
+            "--function",
+            "set_price",
+            "--args",
+            r#"[{"type": "string", "value": "XLM"}, {"type": "i128", "value": 1100000}]"#,
+            "--mock",
+            &format!("{sink}.notify=true"),
+            "--on-event",
+            &format!("setprice => {sink}.notify"),
+            "--expect-mock-call",
+            &format!("{sink}:notify(XLM, _)"),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--- Forwarded Events ---"))
+        .stdout(predicate::str::contains(format!(
+            "setprice -> {sink}.notify(\"XLM\""
+        )))
+        .stdout(predicate::str::contains("expected mock call(s) matched"));
+}
+
+/// `wasm` with a `name` section naming every local function `counter_fn_<index>`.
+fn with_function_names(wasm: &[u8]) -> Vec<u8> {
+    fn leb(mut value: usize, out: &mut Vec<u8>) {
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                out.push(byte);
+                return;
+            }
+            out.push(byte | 0x80);
+        }
+    }
+
+    let (mut imported, mut local) = (0usize, 0usize);
+    for payload in wasmparser::Parser::new(0).parse_all(wasm) {
+        match payload.unwrap() {
+            wasmparser::Payload::ImportSection(reader) => {
+                imported += reader
+                    .into_iter()
+                    .filter(|import| {
+                        matches!(import.as_ref().unwrap().ty, wasmparser::TypeRef::Func(_))
+                    })
+                    .count();
+            }
+            wasmparser::Payload::FunctionSection(reader) => local = reader.count() as usize,
+            _ => {}
+        }
+    }
+
+    let mut names = Vec::new();
+    leb(local, &mut names);
+    for index in imported..imported + local {
+        let name = format!("counter_fn_{}", index);
+        leb(index, &mut names);
+        leb(name.len(), &mut names);
+        names.extend_from_slice(name.as_bytes());
+    }
+    let mut body = vec![4];
+    body.extend_from_slice(b"name");
+    body.push(1);
+    leb(names.len(), &mut body);
+    body.extend(names);
+
+    let mut out = wasm.to_vec();
+    out.push(0);
+    leb(body.len(), &mut out);
+    out.extend(body);
+    out
+}
+
+#[test]
+fn run_profile_lists_named_hotspots() {
+    let wasm = fs::read(fixture_wasm("counter")).expect("counter fixture");
+    let named = NamedTempFile::new().unwrap();
+    fs::write(named.path(), with_function_names(&wasm)).unwrap();
+
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            named.path().to_str().unwrap(),
+            "--function",
+            "increment",
+            "--profile",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Hotspots (by code size)"))
+        .stdout(predicate::str::contains("counter_fn_"));
+}
+
+#[test]
+fn run_max_output_len_truncates_only_the_displayed_result() {
+    let wasm = fixture_wasm("echo");
+    let items: Vec<u32> = (0..300).collect();
+    let call_args = serde_json::json!([items]).to_string();
+    let saved = NamedTempFile::new().unwrap();
+    let run = |extra: &[&str]| {
+        let output = base_cmd()
+            .args([
+                "run",
+                "--contract",
+                wasm.to_str().unwrap(),
+                "--function",
+                "echo",
+                "--args",
+                &call_args,
+            ])
+            .args(extra)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stdout).unwrap()
+    };
+    let result_line = |text: &str| {
+        text.lines()
+            .find(|line| line.starts_with("Result: "))
+            .expect("result line")
+            .to_string()
+    };
+
+    let stdout = run(&[
+        "--max-output-len",
+        "40",
+        "--save-output",
+        saved.path().to_str().unwrap(),
+    ]);
+    let shown = result_line(&stdout);
+    let full = result_line(&fs::read_to_string(saved.path()).unwrap());
+    assert!(full.chars().count() > 40, "{full}");
+    assert_eq!(
+        shown,
+        format!(
+            "{}… (truncated, {} total)",
+            full.chars().take(40).collect::<String>(),
+            full.chars().count()
+        )
+    );
+
+    // Piped output has no default limit, and JSON always carries the full value.
+    assert_eq!(result_line(&run(&[])), full);
+    assert!(!run(&["--max-output-len", "40", "--json"]).contains("(truncated, "));
+}
+
+#[test]
+fn script_metrics_out_writes_prometheus_text() {
+    let wasm = fixture_wasm("counter");
+    let script = NamedTempFile::new().unwrap();
+    fs::write(script.path(), "increment\nincrement\nget\nmissing_fn\n").unwrap();
+    let metrics = NamedTempFile::new().unwrap();
+
+    base_cmd()
+        .args([
+            "script",
+            script.path().to_str().unwrap(),
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--continue-on-error",
+            "--metrics-out",
+            metrics.path().to_str().unwrap(),
+        ])
+        .assert()
+        .code(1);
+
+    let text = fs::read_to_string(metrics.path()).unwrap();
+    let sample =
+        regex::Regex::new(r#"^[a-zA-Z_:][a-zA-Z0-9_:]*(\{[a-z_]+="(\\.|[^"\\])*"\})? [0-9.]+$"#)
+            .unwrap();
+    for line in text.lines().filter(|line| !line.starts_with('#')) {
+        assert!(sample.is_match(line), "malformed metric line: {line}");
+    }
+    assert!(text.contains("# TYPE soroban_debug_invocations_total counter"));
+    assert!(
+        text.contains("\nsoroban_debug_invocations_total 4\n"),
+        "{text}"
+    );
+    assert!(text.contains("soroban_debug_failures_total{kind=\"InvalidFunction\"} 1\n"));
+    assert!(text.contains("soroban_debug_function_cpu_insns_avg{function=\"increment\"} "));
+    assert!(text.contains("soroban_debug_function_cpu_insns_avg{function=\"get\"} "));
+}
+
+#[test]
+fn run_function_guard_applies_allow_and_deny_lists() {
+    let wasm = fixture_wasm("counter");
+    let run = |function: &str, guard: &[&str]| {
+        base_cmd()
+            .args([
+                "run",
+                "--contract",
+                wasm.to_str().unwrap(),
+                "--function",
+                function,
+            ])
+            .args(guard)
+            .assert()
+    };
+
+    // Allow-only: listed functions run, anything else is rejected.
+    run("get", &["--allow-functions", "get"]).success();
+    run("increment", &["--allow-functions", "get"])
+        .failure()
+        .stderr(predicate::str::contains("not in --allow-functions"));
+
+    // Deny-only: everything but the listed functions runs.
+    run("get", &["--deny-functions", "increment"]).success();
+    run("increment", &["--deny-functions", "increment"])
+        .failure()
+        .stderr(predicate::str::contains("blocked by --deny-functions"));
+
+    // Conflicting: deny wins over allow.
+    run(
+        "increment",
+        &[
+            "--allow-functions",
+            "get,increment",
+            "--deny-functions",
+            "increment",
+        ],
+    )
+    .failure()
+    .stderr(predicate::str::contains("blocked by --deny-functions"));
+}
+
+#[test]
+fn script_deny_functions_rejects_denied_lines() {
+    let wasm = fixture_wasm("counter");
+    let script = NamedTempFile::new().unwrap();
+    fs::write(script.path(), "get\nincrement\n").unwrap();
+
+    base_cmd()
+        .args([
+            "script",
+            script.path().to_str().unwrap(),
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--deny-functions",
+            "increment",
+        ])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("Result: I64(0)"))
+        .stdout(predicate::str::contains("blocked by --deny-functions"));
+}
+
+#[test]
+fn run_advance_ledgers_reports_expired_temporary_entries() {
+    let wasm = fixture_wasm("counter");
+    let storage =
+        r#"[{"key":{"type":"symbol","value":"nonce"},"value":1,"durability":"temporary"}]"#;
+    let run = |ledgers: &str| {
+        base_cmd()
+            .args([
+                "run",
+                "--contract",
+                wasm.to_str().unwrap(),
+                "--function",
+                "increment",
+                "--storage",
+                storage,
+                "--advance-ledgers",
+                ledgers,
+            ])
+            .assert()
+            .success()
+    };
+
+    run("0")
+        .stdout(predicate::str::contains("--- Storage TTL"))
+        .stdout(predicate::str::contains(
+            "nonce [Temporary] live until ledger",
+        ))
+        .stdout(predicate::str::contains("EXPIRED").not());
+
+    // Temporary entries get a far shorter minimum TTL than the contract instance.
+    run("1000")
+        .stdout(predicate::str::contains(
+            "nonce [Temporary] EXPIRED at ledger",
+        ))
+        .stdout(predicate::str::contains("(deleted)"))
+        .stdout(predicate::str::contains("c [Instance] live until ledger"));
+}
+
+#[test]
+fn run_advance_ledgers_flags_entries_read_after_expiry() {
+    let wasm = fixture_wasm("counter");
+    let storage = r#"[
+        {"key":{"type":"symbol","value":"c"},"value":5,"durability":"instance"},
+        {"key":{"type":"symbol","value":"nonce"},"value":1,"durability":"temporary"}
+    ]"#;
+
+    // Past the instance's TTL the call itself reads the archived instance and
+    // fails; the TTL report still names the entry it read.
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "increment",
+            "--storage",
+            storage,
+            "--advance-ledgers",
+            "5000",
+        ])
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("c [Instance] EXPIRED at ledger"))
+        .stdout(predicate::str::contains("(archived); READ after expiry"))
+        .stdout(predicate::str::contains("(deleted); READ").not());
+}
+
+#[test]
+fn diff_reports_removed_functions_without_executing() {
+    let counter = fixture_wasm("counter");
+    let echo = fixture_wasm("echo");
+
+    base_cmd()
+        .args([
+            "diff",
+            "--old",
+            counter.to_str().unwrap(),
+            "--new",
+            echo.to_str().unwrap(),
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("[REMOVED] increment"));
+
+    let output = base_cmd()
+        .args([
+            "diff",
+            "--old",
+            counter.to_str().unwrap(),
+            "--new",
+            echo.to_str().unwrap(),
+            "--format",
+            "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(report["result"]["breaking_changes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|c| c["type"] == "FunctionRemoved" && c["name"] == "increment"));
+}
+
+#[test]
+fn run_baseline_records_then_fails_on_budget_regression() {
+    let wasm = fixture_wasm("counter");
+    let dir = tempfile::tempdir().unwrap();
+    let baseline = dir.path().join("baseline.json");
+    let run = || {
+        let mut cmd = base_cmd();
+        cmd.args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "increment",
+            "--baseline",
+            baseline.to_str().unwrap(),
+            "--tolerance",
+            "5",
+        ]);
+        cmd
+    };
+
+    run().assert().success().stdout(predicate::str::contains(
+        "Recorded budget baseline for 'increment'",
+    ));
+    let recorded: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&baseline).unwrap()).unwrap();
+    let cpu = recorded["functions"]["increment"]["cpu_insns"]
+        .as_u64()
+        .unwrap();
+    assert!(cpu > 0);
+
+    run()
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "--- Budget Baseline (increment) ---",
+        ))
+        .stdout(predicate::str::contains("cpu-insns:"));
+
+    // Pretend the function used to be half as expensive.
+    let cheaper = serde_json::json!({
+        "functions": {
+            "increment": {
+                "cpu_insns": cpu / 2,
+                "mem_bytes": recorded["functions"]["increment"]["mem_bytes"],
+            }
+        }
+    });
+    std::fs::write(&baseline, cheaper.to_string()).unwrap();
+    run()
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("REGRESSION"))
+        .stderr(predicate::str::contains("regressed against baseline"));
+}
+
+#[test]
+fn run_constructor_args_initialise_contract_at_registration() {
+    base_cmd()
+        .args([
+            "run",
+            "--contract",
+            fixture_wasm("counter").to_str().unwrap(),
+            "--function",
+            "get",
+            "--constructor-args",
+            "[1]",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--constructor-args was given"));
+
+    let wasm = fixture_wasm(fixtures::names::CONSTRUCTOR);
+    let admin = "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF";
+    let run = |constructor_args: Option<&str>| {
+        let mut cmd = base_cmd();
+        cmd.args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "get",
+        ]);
+        if let Some(constructor_args) = constructor_args {
+            cmd.args(["--constructor-args", constructor_args]);
+        }
+        cmd
+    };
+
+    let constructor_args = format!(r#"["{}", {{"type": "i64", "value": 41}}]"#, admin);
+    run(Some(&constructor_args))
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Result: 41"));
+    run(None)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "pass them with --constructor-args",
+        ));
+    run(Some("[41]"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("expects 2 argument(s)"));
+}
+
+#[test]
+fn validate_args_reports_tuple_arity_mismatch_without_executing() {
+    let wasm = fixture_wasm("echo");
+    let validate = |args: &str| {
+        let mut cmd = base_cmd();
+        cmd.args([
+            "validate-args",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "echo",
+            "--args",
+            args,
+        ]);
+        cmd
+    };
+
+    validate("[42]")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("v (Val) = 42"))
+        .stdout(predicate::str::contains("1 argument(s) valid for 'echo'"));
+
+    validate(r#"[{"type": "tuple", "arity": 2, "value": [1, 2, 3]}]"#)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("Result").not())
+        .stderr(predicate::str::contains("Argument 'v' (Val)"))
+        .stderr(predicate::str::contains(
+            "Tuple arity mismatch: expected 2, got 3",
+        ));
+
+    validate("[1, 2]")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "'echo' expects 1 argument(s) (v: Val), got 2",
+        ));
+}
+
+#[test]
+fn validate_args_expands_env_like_run() {
+    let wasm = fixture_wasm("echo");
+    let validate = |extra: &[&str]| {
+        base_cmd()
+            .env("VALIDATE_ARGS_VALUE", "42")
+            .args([
+                "validate-args",
+                "--contract",
+                wasm.to_str().unwrap(),
+                "--function",
+                "echo",
+                "--args",
+                "[${VALIDATE_ARGS_VALUE}]",
+            ])
+            .args(extra)
+            .assert()
+    };
+
+    validate(&["--expand-env"])
+        .success()
+        .stdout(predicate::str::contains("v (Val) = 42"));
+    validate(&[]).failure();
+}
+
+#[test]
+fn run_trace_host_functions_lists_calls_in_order() {
+    let wasm = fixture_wasm("cross_contract");
+    let oracle = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
+    let call_args = format!(r#"["{oracle}", "price", []]"#);
+    let run = |structured: bool| {
+        let mut cmd = base_cmd();
+        if structured {
+            cmd.arg("--quiet");
+        }
+        cmd.args([
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "call",
+            "--args",
+            &call_args,
+            "--mock",
+            &format!("{oracle}.price=42"),
+            "--trace-host-functions",
+        ]);
+        if structured {
+            cmd.args(["--output", "json"]);
+        }
+        cmd.output().unwrap()
+    };
+
+    let output = run(true);
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let calls = json["result"]["host_calls"].as_array().unwrap();
+    let order: Vec<(&str, u64)> = calls
+        .iter()
+        .map(|c| {
+            (
+                c["function"].as_str().unwrap(),
+                c["depth"].as_u64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(order, vec![("call", 0), ("price", 1)]);
+    assert_eq!(calls[1]["contract_id"], oracle);
+    assert_eq!(calls[1]["index"], 2);
+
+    let output = run(false);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let caller = stdout.find("   1. call(").expect("caller entry");
+    let callee = stdout.find("   2.   price() -> ").expect("callee entry");
+    assert!(caller < callee, "{stdout}");
+}
+
+#[test]
+fn run_trace_writes_chrome_trace_events() {
+    let wasm = fixture_wasm("counter");
+    let trace = NamedTempFile::new().unwrap();
+
+    base_cmd()
+        .args([
+            "--quiet",
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "increment",
+            "--trace",
+            trace.path().to_str().unwrap(),
+        ])
+        .assert()
+        .success();
+
+    let json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(trace.path()).unwrap()).unwrap();
+    let events = json["traceEvents"].as_array().unwrap();
+    assert!(!events.is_empty());
+    assert!(events.iter().all(|e| e["ph"] == "X"));
+    assert!(events
+        .iter()
+        .any(|e| e["cat"] == "contract_call" && e["name"] == "increment"));
+    assert!(events
+        .iter()
+        .any(|e| e["args"]["cpu_instructions"].as_u64().unwrap_or_default() > 0));
+}
+
+#[test]
+fn run_trace_is_written_when_contract_fails() {
+    let wasm = fixture_wasm("always_panic");
+    let trace = NamedTempFile::new().unwrap();
+    fs::write(trace.path(), "").unwrap();
+
+    base_cmd()
+        .args([
+            "--quiet",
+            "run",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "panic",
+            "--trace",
+            trace.path().to_str().unwrap(),
+        ])
+        .assert()
+        .failure();
+
+    let json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(trace.path()).unwrap()).unwrap();
+    let events = json["traceEvents"].as_array().unwrap();
+    assert!(!events.is_empty());
+}
@@ -0,0 +1,85 @@
+#[path = "fixtures/mod.rs"]
+mod fixtures;
+
+use soroban_debugger::debugger::engine::DebuggerEngine;
+use soroban_debugger::debugger::CallBoundaryKind;
+use soroban_debugger::runtime::executor::ContractExecutor;
+
+const ORACLE: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
+
+/// Engine for the `cross_contract` caller with `ORACLE.price` mocked as the second contract.
+fn caller_engine() -> DebuggerEngine {
+    let wasm = std::fs::read(fixtures::get_fixture_path(fixtures::names::CROSS_CONTRACT)).unwrap();
+    let mut executor = ContractExecutor::new(wasm).unwrap();
+    executor
+        .set_mock_specs(&[format!("{ORACLE}.price=42")])
+        .unwrap();
+    DebuggerEngine::new(executor, vec![])
+}
+
+fn call_oracle(engine: &mut DebuggerEngine) {
+    let args = format!(r#"["{ORACLE}", "price", []]"#);
+    engine
+        .execute_without_breakpoints("call", Some(&args))
+        .unwrap();
+}
+
+#[test]
+fn step_into_breaks_at_mocked_callee_entry() {
+    let mut engine = caller_engine();
+    call_oracle(&mut engine);
+
+    let entry = engine
+        .step_into_call()
+        .unwrap()
+        .expect("callee entry boundary");
+    assert_eq!(entry.kind, CallBoundaryKind::Enter);
+    assert_eq!(entry.function, "price");
+    assert_eq!(entry.contract_id.as_deref(), Some(ORACLE));
+    assert_eq!(entry.mocked_response.as_deref(), Some("42"));
+    assert!(engine.is_paused());
+
+    let state = engine.state();
+    let state = state.lock().unwrap();
+    assert_eq!(state.current_function(), Some("price"));
+}
+
+#[test]
+fn step_over_runs_cross_contract_call_as_one_step() {
+    let mut engine = caller_engine();
+    call_oracle(&mut engine);
+
+    let exit = engine
+        .step_over_call()
+        .unwrap()
+        .expect("callee return boundary");
+    assert_eq!(exit.kind, CallBoundaryKind::Exit);
+    assert_eq!(exit.function, "price");
+    assert_eq!(exit.mocked_response.as_deref(), Some("42"));
+
+    let state = engine.state();
+    let state = state.lock().unwrap();
+    assert_eq!(state.current_function(), Some("call"));
+    assert_eq!(state.step_count(), 1);
+}
+
+#[test]
+fn caller_registered_at_fixed_id_reaches_mock_by_hex_id() {
+    let caller_id = "CAAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQC526";
+    let wasm = std::fs::read(fixtures::get_fixture_path(fixtures::names::CROSS_CONTRACT)).unwrap();
+    let mut executor = ContractExecutor::new_at(wasm, caller_id).unwrap();
+    assert_eq!(
+        soroban_env_host::xdr::ScAddress::from(executor.contract_address()).to_string(),
+        caller_id
+    );
+
+    // The callee is mocked by its hex hash and called by its strkey.
+    let callee = "CABAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAFNSZ";
+    executor
+        .set_mock_specs(&[format!("{}.price=42", "02".repeat(32))])
+        .unwrap();
+    let result = executor
+        .execute("call", Some(&format!(r#"["{callee}", "price", []]"#)))
+        .unwrap();
+    assert!(result.contains("42"), "{result}");
+}
@@ -0,0 +1,74 @@
+use serde_json::{json, Value};
+use soroban_debugger::protocol::server::{read_message, write_message, DapServer};
+use std::io::Cursor;
+
+fn framed(messages: &[Value]) -> Vec<u8> {
+    let mut out = Vec::new();
+    for message in messages {
+        write_message(&mut out, message).unwrap();
+    }
+    out
+}
+
+fn read_all(bytes: Vec<u8>) -> Vec<Value> {
+    let mut reader = Cursor::new(bytes);
+    let mut messages = Vec::new();
+    while let Some(message) = read_message(&mut reader).unwrap() {
+        messages.push(message);
+    }
+    messages
+}
+
+#[test]
+fn initialize_returns_capabilities() {
+    let input = framed(&[
+        json!({
+            "seq": 1,
+            "type": "request",
+            "command": "initialize",
+            "arguments": {"adapterID": "soroban", "linesStartAt1": true},
+        }),
+        json!({"seq": 2, "type": "request", "command": "disconnect"}),
+    ]);
+
+    let mut output = Vec::new();
+    DapServer::new(None)
+        .serve(Cursor::new(input), &mut output)
+        .expect("DAP session should complete");
+
+    let messages = read_all(output);
+    let init = &messages[0];
+    assert_eq!(init["type"], "response");
+    assert_eq!(init["command"], "initialize");
+    assert_eq!(init["request_seq"], 1);
+    assert_eq!(init["success"], true);
+    assert_eq!(init["body"]["supportsConfigurationDoneRequest"], true);
+    assert_eq!(init["body"]["supportsFunctionBreakpoints"], true);
+
+    let disconnect = &messages[1];
+    assert_eq!(disconnect["command"], "disconnect");
+    assert_eq!(disconnect["success"], true);
+}
+
+#[test]
+fn unsupported_request_returns_error_instead_of_panicking() {
+    let input = framed(&[json!({
+        "seq": 7,
+        "type": "request",
+        "command": "setExceptionBreakpoints",
+        "arguments": {"filters": []},
+    })]);
+
+    let mut output = Vec::new();
+    DapServer::new(None)
+        .serve(Cursor::new(input), &mut output)
+        .unwrap();
+
+    let messages = read_all(output);
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0]["success"], false);
+    assert!(messages[0]["body"]["error"]["format"]
+        .as_str()
+        .unwrap()
+        .contains("setExceptionBreakpoints"));
+}
@@ -39,6 +39,25 @@ fn test_fixture_counter_parsing() {
     );
 }
 
+#[test]
+fn test_fixture_counter_contract_meta() {
+    let fixture_path = fixtures::get_fixture_path(fixtures::names::COUNTER);
+    if !fixture_path.exists() {
+        return;
+    }
+
+    let wasm_bytes = fs::read(&fixture_path).expect("Failed to read counter fixture");
+    let meta = wasm::parse_metadata(&wasm_bytes).expect("Failed to parse contractmetav0");
+
+    // The SDK records the rustc and soroban-sdk versions used for the build.
+    assert!(meta.contains_key("rsver"), "Found: {:?}", meta);
+    assert!(
+        wasm::sdk_major_version(&meta).is_some(),
+        "Found: {:?}",
+        meta
+    );
+}
+
 #[test]
 fn test_fixture_echo_parsing() {
     let fixture_path = fixtures::get_fixture_path(fixtures::names::ECHO);
@@ -151,3 +170,168 @@ fn test_fixture_registration_and_invocation() {
     );
     assert_eq!(second, 2);
 }
+
+#[test]
+fn test_fixture_passes_contract_validation_and_random_bytes_do_not() {
+    use soroban_debugger::runtime::executor::ContractExecutor;
+
+    let fixture_path = fixtures::get_fixture_path(fixtures::names::COUNTER);
+    if !fixture_path.exists() {
+        return;
+    }
+    let wasm_bytes = fs::read(fixture_path).unwrap();
+    wasm::validate_soroban_contract(&wasm_bytes).expect("counter is a Soroban contract");
+
+    // Deterministic "random" bytes behind a valid header.
+    let mut garbage = b"\0asm\x01\0\0\0".to_vec();
+    garbage.extend((0u32..256).map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8));
+    let err = ContractExecutor::new(garbage)
+        .err()
+        .expect("garbage must not load")
+        .to_string();
+    assert!(err.contains("Malformed WASM module"), "{err}");
+
+    let err = ContractExecutor::new(vec![0x7f; 64])
+        .err()
+        .expect("random bytes must not load")
+        .to_string();
+    assert!(err.contains("not a WASM module"), "{err}");
+}
+
+#[test]
+fn test_fixture_cross_contract_call_site() {
+    let fixture_path = fixtures::get_fixture_path(fixtures::names::CROSS_CONTRACT);
+    if !fixture_path.exists() {
+        return;
+    }
+
+    let wasm_bytes = fs::read(&fixture_path).expect("Failed to read cross_contract fixture");
+    let calls = wasm::parse_cross_contract_calls(&wasm_bytes).expect("Failed to parse calls");
+
+    // `env.invoke_contract` links against the SDK's short `d._` import.
+    assert!(
+        calls
+            .iter()
+            .any(|c| c.caller == "call" && c.host_function == "call"),
+        "Found: {:?}",
+        calls
+    );
+}
+
+#[test]
+fn test_fixture_counter_execution_timing() {
+    let fixture_path = fixtures::get_fixture_path(fixtures::names::COUNTER);
+    if !fixture_path.exists() {
+        return;
+    }
+
+    let wasm_bytes = fs::read(&fixture_path).expect("Failed to read counter fixture");
+    let mut executor =
+        soroban_debugger::runtime::executor::ContractExecutor::new(wasm_bytes).unwrap();
+    executor.execute("increment", None).unwrap();
+
+    let record = executor.last_execution().expect("execution record");
+    assert!(record.cpu_insns > 0, "{:?}", record.cpu_insns);
+    assert!(record.timing_summary().contains("CPU instructions"));
+}
+
+#[test]
+fn test_fixture_counter_instruction_hook() {
+    use soroban_debugger::runtime::{Instruction, InstructionObserver, InstructionParser};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Counts the instructions it is shown and the invocations it brackets.
+    struct CountingHook {
+        instructions: Arc<AtomicUsize>,
+        invocations: Arc<AtomicUsize>,
+    }
+
+    impl InstructionObserver for CountingHook {
+        fn on_instruction(&mut self, _index: usize, _instruction: &Instruction) -> bool {
+            self.instructions.fetch_add(1, Ordering::SeqCst);
+            false
+        }
+
+        fn on_invocation_end(&mut self, _function: &str) {
+            self.invocations.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    let fixture_path = fixtures::get_fixture_path(fixtures::names::COUNTER);
+    if !fixture_path.exists() {
+        return;
+    }
+
+    let wasm_bytes = fs::read(&fixture_path).expect("Failed to read counter fixture");
+    let module_size = InstructionParser::new().parse(&wasm_bytes).unwrap().len();
+    let mut executor =
+        soroban_debugger::runtime::executor::ContractExecutor::new(wasm_bytes).unwrap();
+    let instructions = Arc::new(AtomicUsize::new(0));
+    let invocations = Arc::new(AtomicUsize::new(0));
+    executor
+        .add_instruction_hook(Box::new(CountingHook {
+            instructions: Arc::clone(&instructions),
+            invocations: Arc::clone(&invocations),
+        }))
+        .unwrap();
+
+    executor.execute("increment", None).unwrap();
+
+    // Only the code `increment` can reach is visited, not the whole module.
+    let observed = instructions.load(Ordering::SeqCst);
+    assert!(observed > 0);
+    assert!(observed < module_size, "{observed} of {module_size}");
+    assert_eq!(invocations.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_fixture_counter_instruction_hook_pauses_engine() {
+    use soroban_debugger::debugger::{DebuggerEngine, PauseReason};
+    use soroban_debugger::runtime::Instruction;
+
+    let fixture_path = fixtures::get_fixture_path(fixtures::names::COUNTER);
+    let wasm_bytes = fs::read(&fixture_path).expect("Failed to read counter fixture");
+    let mut executor =
+        soroban_debugger::runtime::executor::ContractExecutor::new(wasm_bytes).unwrap();
+    executor
+        .add_instruction_hook(Box::new(|_index: usize, _instruction: &Instruction| true))
+        .unwrap();
+
+    let mut engine = DebuggerEngine::new(executor, vec![]);
+    engine.execute("increment", None).unwrap();
+
+    assert!(engine.is_paused());
+    let state = engine.state();
+    let state = state.lock().unwrap();
+    assert!(matches!(
+        state.paused_reason(),
+        Some(PauseReason::InstructionHook { .. })
+    ));
+}
+
+#[test]
+fn test_fixture_oracle_storage_keys_decode_to_data_key_variants() {
+    use soroban_debugger::inspector::storage::StorageInspector;
+    use soroban_env_host::xdr::{ScString, ScSymbol, ScVal, ScVec};
+
+    let fixture_path = fixtures::get_fixture_path(fixtures::names::ORACLE);
+    let wasm_bytes = fs::read(&fixture_path).expect("Failed to read oracle fixture");
+    let spec = wasm::parse_contract_unions(&wasm_bytes).expect("Failed to parse unions");
+
+    let data_key = |variant: &str, args: Vec<ScVal>| {
+        let mut items = vec![ScVal::Symbol(ScSymbol(variant.try_into().unwrap()))];
+        items.extend(args);
+        ScVal::Vec(Some(ScVec(items.try_into().unwrap())))
+    };
+    let xlm = ScVal::String(ScString("XLM".try_into().unwrap()));
+
+    assert_eq!(
+        StorageInspector::decode_key(&spec, &data_key("Price", vec![xlm])),
+        r#"Price("XLM")"#
+    );
+    assert_eq!(
+        StorageInspector::decode_key(&spec, &data_key("Admin", vec![])),
+        "Admin"
+    );
+}
@@ -19,6 +19,10 @@ function Get-FixtureExports {
         "cross_contract" { return @("call") }
         "echo" { return @("echo") }
         "same_return" { return @("same") }
+        "extra_export" { return @("helper", "value") }
+        "ledger_clock" { return @("get_sequence", "get_timestamp") }
+        "oracle" { return @("get_price", "get_stale_ttl", "initialize", "is_stale", "set_price") }
+        "constructor" { return @("__constructor", "get") }
         default { throw "Unknown fixture export set for '$Name'" }
     }
 }
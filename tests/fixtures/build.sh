@@ -18,6 +18,10 @@ fixture_exports_json() {
         cross_contract) printf '["call"]' ;;
         echo) printf '["echo"]' ;;
         same_return) printf '["same"]' ;;
+        extra_export) printf '["helper","value"]' ;;
+        ledger_clock) printf '["get_sequence","get_timestamp"]' ;;
+        oracle) printf '["get_price","get_stale_ttl","initialize","is_stale","set_price"]' ;;
+        constructor) printf '["__constructor","get"]' ;;
         *)
             echo "Unknown fixture export set for '$1'" >&2
             exit 1
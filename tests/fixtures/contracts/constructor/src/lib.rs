@@ -0,0 +1,20 @@
+#![no_std]
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+#[contract]
+pub struct Constructor;
+
+#[contractimpl]
+impl Constructor {
+    pub fn __constructor(env: Env, admin: Address, start: i64) {
+        env.storage().instance().set(&symbol_short!("admin"), &admin);
+        env.storage().instance().set(&symbol_short!("c"), &start);
+    }
+
+    pub fn get(env: Env) -> i64 {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("c"))
+            .unwrap_or(0)
+    }
+}
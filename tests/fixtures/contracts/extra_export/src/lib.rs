@@ -0,0 +1,20 @@
+#![no_std]
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contract]
+pub struct ExtraExport;
+
+#[contractimpl]
+impl ExtraExport {
+    pub fn value(_env: Env) -> u32 {
+        7
+    }
+}
+
+// Exported from the module but never declared in the contract spec.
+// Used to test detection of accidentally-public internals.
+#[no_mangle]
+pub extern "C" fn helper() -> u64 {
+    // Encodes Val::VOID so the export is well-formed if it is ever called.
+    2
+}
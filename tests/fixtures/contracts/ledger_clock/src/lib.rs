@@ -0,0 +1,16 @@
+#![no_std]
+use soroban_sdk::{contract, contractimpl, Env};
+
+#[contract]
+pub struct LedgerClock;
+
+#[contractimpl]
+impl LedgerClock {
+    pub fn get_timestamp(env: Env) -> u64 {
+        env.ledger().timestamp()
+    }
+
+    pub fn get_sequence(env: Env) -> u32 {
+        env.ledger().sequence()
+    }
+}
@@ -0,0 +1,88 @@
+#![no_std]
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, String,
+};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Price(String),
+    Timestamp(String),
+    StaleTtl,
+    Admin,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum OracleError {
+    AssetNotFound = 1,
+    InvalidPrice = 2,
+    AlreadyInitialized = 4,
+    InvalidTtl = 5,
+}
+
+#[contract]
+pub struct Oracle;
+
+#[contractimpl]
+impl Oracle {
+    pub fn initialize(env: Env, admin: Address, stale_ttl: u64) -> Result<(), OracleError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(OracleError::AlreadyInitialized);
+        }
+        if stale_ttl == 0 {
+            return Err(OracleError::InvalidTtl);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::StaleTtl, &stale_ttl);
+        Ok(())
+    }
+
+    pub fn set_price(env: Env, asset: String, price: i128) -> Result<(), OracleError> {
+        if price <= 0 {
+            return Err(OracleError::InvalidPrice);
+        }
+        if let Some(admin) = env.storage().instance().get::<_, Address>(&DataKey::Admin) {
+            admin.require_auth();
+        }
+        let now = env.ledger().timestamp();
+        env.storage()
+            .persistent()
+            .set(&DataKey::Price(asset.clone()), &price);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Timestamp(asset.clone()), &now);
+        env.events()
+            .publish((symbol_short!("setprice"),), (asset, price));
+        Ok(())
+    }
+
+    pub fn get_price(env: Env, asset: String) -> Result<i128, OracleError> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Price(asset))
+            .ok_or(OracleError::AssetNotFound)
+    }
+
+    pub fn is_stale(env: Env, asset: String) -> Result<bool, OracleError> {
+        let last: u64 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Timestamp(asset))
+            .ok_or(OracleError::AssetNotFound)?;
+        let ttl: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::StaleTtl)
+            .unwrap_or(0);
+        Ok(env.ledger().timestamp().saturating_sub(last) > ttl)
+    }
+
+    pub fn get_stale_ttl(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::StaleTtl)
+            .unwrap_or(0)
+    }
+}
@@ -146,4 +146,8 @@ pub mod names {
     pub const BUDGET_HEAVY: &str = "budget_heavy";
     pub const CROSS_CONTRACT: &str = "cross_contract";
     pub const SAME_RETURN: &str = "same_return";
+    pub const EXTRA_EXPORT: &str = "extra_export";
+    pub const LEDGER_CLOCK: &str = "ledger_clock";
+    pub const ORACLE: &str = "oracle";
+    pub const CONSTRUCTOR: &str = "constructor";
 }
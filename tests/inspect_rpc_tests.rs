@@ -0,0 +1,174 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+use sha2::{Digest, Sha256};
+use soroban_env_host::xdr::{
+    ContractCodeEntry, ContractCodeEntryExt, ContractDataDurability, ContractDataEntry,
+    ContractExecutable, ExtensionPoint, Hash, LedgerEntryData, LedgerKey, Limits, ReadXdr,
+    ScContractInstance, ScVal, WriteXdr,
+};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+
+#[path = "fixtures/mod.rs"]
+mod fixtures;
+mod network;
+
+/// A contract id whose hash is 32 bytes of 0x01.
+const CONTRACT_ID: &str = "CAAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQC526";
+
+fn base_cmd() -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_soroban-debug"));
+    cmd.env("NO_COLOR", "1");
+    cmd.env("NO_BANNER", "1");
+    cmd
+}
+
+/// Serve `getLedgerEntries` for one deployed contract running `wasm`, or
+/// report every entry as missing when `wasm` is `None`. Returns the RPC URL.
+fn spawn_mock_rpc(wasm: Option<Vec<u8>>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind mock rpc");
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else { break };
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap_or(0);
+                    }
+                }
+            }
+            let mut body = vec![0; content_length];
+            if reader.read_exact(&mut body).is_err() {
+                continue;
+            }
+            let request: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            let key = request["params"]["keys"][0].as_str().unwrap();
+            let key = LedgerKey::from_xdr_base64(key, Limits::none()).unwrap();
+            let entry = wasm.as_deref().map(|wasm| ledger_entry(&key, wasm));
+            let entries: Vec<serde_json::Value> = entry
+                .into_iter()
+                .map(|data| {
+                    serde_json::json!({
+                        "key": request["params"]["keys"][0],
+                        "xdr": data.to_xdr_base64(Limits::none()).unwrap(),
+                        "lastModifiedLedgerSeq": 1,
+                    })
+                })
+                .collect();
+            let response = serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": request["id"],
+                "result": { "entries": entries, "latestLedger": 2 },
+            })
+            .to_string();
+            let _ = write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                response.len(),
+                response
+            );
+        }
+    });
+    url
+}
+
+fn ledger_entry(key: &LedgerKey, wasm: &[u8]) -> LedgerEntryData {
+    let hash = Hash(Sha256::digest(wasm).into());
+    match key {
+        LedgerKey::ContractData(key) => LedgerEntryData::ContractData(ContractDataEntry {
+            ext: ExtensionPoint::V0,
+            contract: key.contract.clone(),
+            key: ScVal::LedgerKeyContractInstance,
+            durability: ContractDataDurability::Persistent,
+            val: ScVal::ContractInstance(ScContractInstance {
+                executable: ContractExecutable::Wasm(hash),
+                storage: None,
+            }),
+        }),
+        LedgerKey::ContractCode(_) => LedgerEntryData::ContractCode(ContractCodeEntry {
+            ext: ContractCodeEntryExt::V0,
+            hash,
+            code: wasm.to_vec().try_into().unwrap(),
+        }),
+        other => panic!("unexpected ledger key {:?}", other),
+    }
+}
+
+#[test]
+fn inspect_rpc_fetches_deployed_contract_code() {
+    if !network::can_bind_loopback() {
+        eprintln!("Skipping inspect_rpc_fetches_deployed_contract_code: loopback unavailable");
+        return;
+    }
+    let wasm = std::fs::read(fixtures::fixtures_root().join("wasm").join("counter.wasm"))
+        .expect("counter fixture");
+    let rpc = spawn_mock_rpc(Some(wasm));
+
+    base_cmd()
+        .args([
+            "inspect",
+            "--rpc",
+            &rpc,
+            "--contract-id",
+            CONTRACT_ID,
+            "--functions",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(CONTRACT_ID))
+        .stdout(predicate::str::contains("increment"));
+}
+
+#[test]
+fn inspect_rpc_reports_unknown_contract() {
+    if !network::can_bind_loopback() {
+        eprintln!("Skipping inspect_rpc_reports_unknown_contract: loopback unavailable");
+        return;
+    }
+    let rpc = spawn_mock_rpc(None);
+
+    base_cmd()
+        .args(["inspect", "--rpc", &rpc, "--contract-id", CONTRACT_ID])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("was not found"));
+}
+
+#[test]
+fn inspect_rpc_reports_unreachable_endpoint() {
+    let port = match TcpListener::bind("127.0.0.1:0") {
+        Ok(listener) => listener.local_addr().unwrap().port(),
+        Err(_) => {
+            eprintln!("Skipping inspect_rpc_reports_unreachable_endpoint: loopback unavailable");
+            return;
+        }
+    };
+
+    base_cmd()
+        .args([
+            "inspect",
+            "--rpc",
+            &format!("http://127.0.0.1:{}", port),
+            "--contract-id",
+            CONTRACT_ID,
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to reach RPC"));
+}
+
+#[test]
+fn inspect_rpc_requires_contract_id() {
+    base_cmd()
+        .args(["inspect", "--rpc", "http://127.0.0.1:1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--contract-id"));
+}
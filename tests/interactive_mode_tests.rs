@@ -45,3 +45,287 @@ fn interactive_accepts_basic_commands_and_exits() {
         String::from_utf8_lossy(&output.stderr)
     );
 }
+
+#[test]
+fn execute_with_overrides_a_field_of_the_last_invocation() {
+    use soroban_debugger::debugger::engine::DebuggerEngine;
+    use soroban_debugger::runtime::executor::ContractExecutor;
+    use soroban_debugger::ui::DebuggerUI;
+
+    let wasm = fixture_wasm("echo");
+    if !wasm.exists() {
+        eprintln!(
+            "Skipping test: fixture not found at {}. Run tests/fixtures/build.sh to build fixtures.",
+            wasm.display()
+        );
+        return;
+    }
+
+    let executor = ContractExecutor::new(std::fs::read(&wasm).unwrap()).unwrap();
+    let mut ui = DebuggerUI::new(DebuggerEngine::new(executor, vec![])).unwrap();
+
+    ui.handle_command(r#"run echo {"price":100,"qty":3}"#)
+        .unwrap();
+    ui.handle_command("continue").unwrap();
+    assert!(ui.last_output().is_some(), "first execution should succeed");
+    let first = ui.engine().executor().last_result_json().unwrap();
+    assert_eq!(first["price"], "100");
+
+    ui.handle_command("execute-with arg.price=1200000").unwrap();
+    assert!(ui.last_output().is_some(), "re-execution should succeed");
+    let second = ui.engine().executor().last_result_json().unwrap();
+    assert_eq!(second["price"], "1200000");
+    assert_eq!(second["qty"], "3");
+
+    assert!(ui.handle_command("execute-with arg.amount=5").is_err());
+}
+
+#[test]
+fn run_to_pauses_at_target_function_exactly_once() {
+    use soroban_debugger::debugger::engine::DebuggerEngine;
+    use soroban_debugger::runtime::executor::ContractExecutor;
+    use soroban_debugger::ui::DebuggerUI;
+
+    let wasm = fixture_wasm("counter");
+    if !wasm.exists() {
+        eprintln!(
+            "Skipping test: fixture not found at {}. Run tests/fixtures/build.sh to build fixtures.",
+            wasm.display()
+        );
+        return;
+    }
+
+    let executor = ContractExecutor::new(std::fs::read(&wasm).unwrap()).unwrap();
+    let mut ui = DebuggerUI::new(DebuggerEngine::new(executor, vec![])).unwrap();
+
+    // The staged call is not the target, so it runs through without pausing.
+    ui.handle_command("run increment").unwrap();
+    ui.handle_command("run-to get").unwrap();
+    assert!(!ui.engine().is_paused());
+
+    let mut pauses = 0;
+    for function in ["increment", "get", "increment", "get"] {
+        ui.handle_command(&format!("run {function}")).unwrap();
+        ui.handle_command("continue").unwrap();
+        assert!(ui.last_error().is_none(), "{:?}", ui.last_error());
+        if ui.engine().is_paused() {
+            pauses += 1;
+            assert_eq!(function, "get");
+            assert!(ui.last_output().is_some_and(|out| out.contains('2')));
+        }
+    }
+
+    assert_eq!(pauses, 1);
+    assert!(ui.engine().breakpoints().is_empty());
+}
+
+#[test]
+fn command_history_round_trips_through_history_file() {
+    use soroban_debugger::ui::command_history::{open_editor, save_history};
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nested").join("history");
+
+    let mut editor = open_editor::<()>(Some(&path)).unwrap();
+    assert_eq!(editor.history().iter().count(), 0);
+    editor
+        .add_history_entry(r#"run set_price ["GADMIN", "XLM", 105]"#)
+        .unwrap();
+    editor.add_history_entry("inspect").unwrap();
+    save_history(&mut editor, &path).unwrap();
+
+    let reloaded = open_editor::<()>(Some(&path)).unwrap();
+    let entries: Vec<&String> = reloaded.history().iter().collect();
+    assert_eq!(
+        entries,
+        vec![r#"run set_price ["GADMIN", "XLM", 105]"#, "inspect"]
+    );
+}
+
+#[test]
+fn interactive_saves_history_when_input_ends_without_quit() {
+    let wasm = fixture_wasm("counter");
+    if !wasm.exists() {
+        eprintln!(
+            "Skipping test: fixture not found at {}. Run tests/fixtures/build.sh to build fixtures.",
+            wasm.display()
+        );
+        return;
+    }
+
+    let dir = tempfile::tempdir().unwrap();
+    let history = dir.path().join("history");
+
+    let output = base_cmd()
+        .env("SOROBAN_DEBUG_COMMAND_HISTORY", &history)
+        .args([
+            "interactive",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "get",
+        ])
+        .write_stdin("inspect\ncontinue\n")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let saved = std::fs::read_to_string(&history).unwrap();
+    assert!(saved.contains("inspect"), "{saved}");
+    assert!(saved.contains("continue"), "{saved}");
+
+    let output = base_cmd()
+        .env("SOROBAN_DEBUG_COMMAND_HISTORY", &history)
+        .args([
+            "interactive",
+            "--contract",
+            wasm.to_str().unwrap(),
+            "--function",
+            "get",
+            "--no-history",
+        ])
+        .write_stdin("budget\nquit\n")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(!std::fs::read_to_string(&history)
+        .unwrap()
+        .contains("budget"));
+}
+
+#[test]
+fn watch_pauses_only_when_watched_storage_changes() {
+    use soroban_debugger::debugger::engine::DebuggerEngine;
+    use soroban_debugger::debugger::PauseReason;
+    use soroban_debugger::runtime::executor::ContractExecutor;
+    use soroban_debugger::ui::DebuggerUI;
+
+    let wasm = fixture_wasm("oracle");
+    if !wasm.exists() {
+        eprintln!(
+            "Skipping test: fixture not found at {}. Run tests/fixtures/build.sh to build fixtures.",
+            wasm.display()
+        );
+        return;
+    }
+
+    let executor = ContractExecutor::new(std::fs::read(&wasm).unwrap()).unwrap();
+    executor.enable_mock_all_auths();
+    let mut ui = DebuggerUI::new(DebuggerEngine::new(executor, vec![])).unwrap();
+    ui.handle_command(
+        r#"run initialize ["GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF", {"type": "u64", "value": 3600}]"#,
+    )
+    .unwrap();
+    ui.handle_command("continue").unwrap();
+    assert!(ui.last_error().is_none(), "{:?}", ui.last_error());
+
+    // The pattern is matched against the spec-decoded key, not the raw XDR.
+    ui.handle_command("watch Price(*)").unwrap();
+
+    ui.handle_command(
+        r#"run set_price [{"type": "string", "value": "XLM"}, {"type": "i128", "value": 120000}]"#,
+    )
+    .unwrap();
+    ui.handle_command("continue").unwrap();
+    assert!(ui.last_error().is_none(), "{:?}", ui.last_error());
+    assert!(ui.engine().is_paused());
+    let reason = ui.engine().state().lock().unwrap().paused_reason().cloned();
+    match reason {
+        Some(PauseReason::Watchpoint { key, new_value, .. }) => {
+            assert_eq!(key, r#"Price("XLM")"#);
+            assert!(new_value.unwrap().starts_with("120000"));
+        }
+        other => panic!("expected a watchpoint pause, got {:?}", other),
+    }
+
+    // Reading the price back leaves storage untouched.
+    ui.handle_command(r#"run get_price [{"type": "string", "value": "XLM"}]"#)
+        .unwrap();
+    ui.handle_command("continue").unwrap();
+    assert!(ui.last_error().is_none(), "{:?}", ui.last_error());
+    assert!(!ui.engine().is_paused());
+}
+
+#[test]
+fn paused_reason_records_conditional_breakpoint() {
+    use soroban_debugger::debugger::breakpoint::Breakpoint;
+    use soroban_debugger::debugger::engine::DebuggerEngine;
+    use soroban_debugger::debugger::PauseReason;
+    use soroban_debugger::protocol::DebugResponse;
+    use soroban_debugger::runtime::executor::ContractExecutor;
+
+    let wasm = fixture_wasm("counter");
+    if !wasm.exists() {
+        eprintln!(
+            "Skipping test: fixture not found at {}. Run tests/fixtures/build.sh to build fixtures.",
+            wasm.display()
+        );
+        return;
+    }
+
+    let executor = ContractExecutor::new(std::fs::read(&wasm).unwrap()).unwrap();
+    let mut engine = DebuggerEngine::new(executor, vec![]);
+    engine.breakpoints_mut().set(Breakpoint::with_condition(
+        "increment".to_string(),
+        "storage[c] >= 0".to_string(),
+    ));
+
+    engine.execute("increment", None).unwrap();
+    assert!(engine.is_paused());
+
+    let state = engine.state().lock().unwrap().clone();
+    assert_eq!(
+        state.paused_reason(),
+        Some(&PauseReason::Breakpoint {
+            id: "increment".to_string(),
+            function: "increment".to_string(),
+            condition: Some("storage[c] >= 0".to_string()),
+        })
+    );
+
+    // GetState hands the same state to clients, reason included.
+    let json = serde_json::to_value(DebugResponse::State(state)).unwrap();
+    assert_eq!(json["State"]["paused_reason"]["kind"], "breakpoint");
+    assert_eq!(
+        json["State"]["paused_reason"]["condition"],
+        "storage[c] >= 0"
+    );
+
+    engine.continue_execution().unwrap();
+    assert!(engine.state().lock().unwrap().paused_reason().is_none());
+}
+
+#[test]
+fn storage_completions_use_decoded_oracle_keys() {
+    use soroban_debugger::debugger::engine::DebuggerEngine;
+    use soroban_debugger::runtime::executor::ContractExecutor;
+    use soroban_debugger::ui::completion::DebuggerCompleter;
+    use soroban_debugger::ui::DebuggerUI;
+
+    let wasm = fixture_wasm("oracle");
+    let executor = ContractExecutor::new(std::fs::read(&wasm).unwrap()).unwrap();
+    executor.enable_mock_all_auths();
+    let mut ui = DebuggerUI::new(DebuggerEngine::new(executor, vec![])).unwrap();
+    for command in [
+        r#"run initialize ["GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF", {"type": "u64", "value": 3600}]"#,
+        r#"run set_price [{"type": "string", "value": "XLM"}, {"type": "i128", "value": 120000}]"#,
+    ] {
+        ui.handle_command(command).unwrap();
+        ui.handle_command("continue").unwrap();
+        assert!(ui.last_error().is_none(), "{:?}", ui.last_error());
+    }
+
+    let keys = ui.storage_keys();
+    assert!(keys.contains(&r#"Price("XLM")"#.to_string()), "{keys:?}");
+    assert!(keys.contains(&"Admin".to_string()), "{keys:?}");
+
+    let mut completer = DebuggerCompleter::new(vec![]);
+    completer.set_storage_keys(keys);
+    let (_, pairs) = completer.complete_for_input("storage Pr", "storage Pr".len());
+    let completed: Vec<String> = pairs.into_iter().map(|p| p.replacement).collect();
+    assert_eq!(completed, vec![r#"Price("XLM")"#.to_string()]);
+
+    // The completed key is accepted back by `storage`.
+    ui.handle_command(r#"storage Price("XLM")"#).unwrap();
+    assert!(ui.last_error().is_none(), "{:?}", ui.last_error());
+}
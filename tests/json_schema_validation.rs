@@ -121,6 +121,50 @@ fn upgrade_check_json_output_matches_versioned_schema() {
     assert_schema_valid(&schema, &json_val, "Upgrade-check JSON");
 }
 
+#[test]
+fn upgrade_check_format_json_reports_compatibility() {
+    let wasm_path = "tests/fixtures/wasm/counter.wasm";
+    #[allow(deprecated)]
+    let output = Command::cargo_bin("soroban-debug")
+        .unwrap()
+        .arg("--quiet")
+        .arg("upgrade-check")
+        .arg("--old")
+        .arg(wasm_path)
+        .arg("--new")
+        .arg(wasm_path)
+        .arg("--format")
+        .arg("json")
+        .output()
+        .expect("Failed to execute upgrade-check command");
+
+    let json_val = parse_json_stdout(output);
+    assert_eq!(json_val["result"]["is_compatible"], Value::Bool(true));
+}
+
+#[test]
+fn upgrade_check_exits_non_zero_when_incompatible() {
+    #[allow(deprecated)]
+    let output = Command::cargo_bin("soroban-debug")
+        .unwrap()
+        .arg("--quiet")
+        .arg("upgrade-check")
+        .arg("--old")
+        .arg("tests/fixtures/wasm/counter.wasm")
+        .arg("--new")
+        .arg("tests/fixtures/wasm/echo.wasm")
+        .arg("--format")
+        .arg("json")
+        .output()
+        .expect("Failed to execute upgrade-check command");
+
+    assert!(!output.status.success());
+    let stdout = String::from_utf8(output.stdout).expect("Stdout is not valid UTF-8");
+    let json_val: Value = serde_json::from_str(&stdout)
+        .unwrap_or_else(|_| panic!("Failed to parse JSON output: {}", stdout));
+    assert_eq!(json_val["result"]["is_compatible"], Value::Bool(false));
+}
+
 #[test]
 fn schema_rejects_missing_schema_version() {
     let schema = compile_schema("tests/schemas/execution_output.json");
@@ -154,3 +198,56 @@ fn schema_rejects_invalid_envelope_structure() {
         "schema should reject invalid envelope fields"
     );
 }
+
+fn run_counter_with_output(format: &str) -> std::process::Output {
+    #[allow(deprecated)]
+    Command::cargo_bin("soroban-debug")
+        .unwrap()
+        .arg("--quiet")
+        .arg("run")
+        .arg("--contract")
+        .arg("tests/fixtures/wasm/counter.wasm")
+        .arg("--function")
+        .arg("increment")
+        .arg("--output")
+        .arg(format)
+        .arg("--show-events")
+        .output()
+        .expect("Failed to execute run command")
+}
+
+#[test]
+fn run_yaml_output_parses_to_same_structure_as_json() {
+    let mut json_val = parse_json_stdout(run_counter_with_output("json"));
+
+    let output = run_counter_with_output("yaml");
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).expect("Stdout is not valid UTF-8");
+    let mut yaml_val: Value = serde_norway::from_str(&stdout)
+        .unwrap_or_else(|e| panic!("Failed to parse YAML output ({}): {}", e, stdout));
+
+    // Wall-clock time differs between the two runs.
+    for val in [&mut json_val, &mut yaml_val] {
+        val["result"]
+            .as_object_mut()
+            .expect("result object")
+            .remove("duration_ms");
+    }
+    assert_eq!(yaml_val, json_val);
+}
+
+#[test]
+fn run_csv_output_flattens_storage_diff() {
+    let output = run_counter_with_output("csv");
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).expect("Stdout is not valid UTF-8");
+    let mut lines = stdout.lines();
+
+    assert_eq!(lines.next(), Some("section,key,before,after"));
+    assert!(lines.next().unwrap().starts_with("result,increment,,"));
+    assert!(
+        lines.any(|line| line.starts_with("storage,")),
+        "expected a storage row: {}",
+        stdout
+    );
+}
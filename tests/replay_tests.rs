@@ -300,3 +300,107 @@ fn test_replay_trace_with_budget() {
         "Expected some output from replay command"
     );
 }
+
+#[test]
+fn test_record_then_replay_round_trip() {
+    let wasm_path = "tests/fixtures/wasm/counter.wasm";
+    let recording = NamedTempFile::new().unwrap();
+
+    Command::new(env!("CARGO_BIN_EXE_soroban-debug"))
+        .arg("run")
+        .arg("--contract")
+        .arg(wasm_path)
+        .arg("--function")
+        .arg("increment")
+        .arg("--storage")
+        .arg(r#"{"c": 41}"#)
+        .arg("--record")
+        .arg(recording.path())
+        .assert()
+        .success();
+
+    let recorded: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(recording.path()).unwrap()).unwrap();
+    assert_eq!(recorded["record"]["function"], "increment");
+    assert!(!recorded["entries_before"].as_array().unwrap().is_empty());
+
+    Command::new(env!("CARGO_BIN_EXE_soroban-debug"))
+        .arg("replay")
+        .arg(recording.path())
+        .arg("--contract")
+        .arg(wasm_path)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Replayed result: 42"));
+
+    // Tamper with the recorded result so the replay no longer matches.
+    let mut tampered = recorded.clone();
+    tampered["record"]["result"] = json!({"err": "tampered"});
+    fs::write(recording.path(), tampered.to_string()).unwrap();
+
+    Command::new(env!("CARGO_BIN_EXE_soroban-debug"))
+        .arg("replay")
+        .arg(recording.path())
+        .arg("--contract")
+        .arg(wasm_path)
+        .assert()
+        .code(1);
+
+    // A replay that cannot run at all fails too, rather than printing a report.
+    let mut missing = recorded.clone();
+    missing["record"]["function"] = json!("no_such_function");
+    fs::write(recording.path(), missing.to_string()).unwrap();
+
+    Command::new(env!("CARGO_BIN_EXE_soroban-debug"))
+        .arg("replay")
+        .arg(recording.path())
+        .arg("--contract")
+        .arg(wasm_path)
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("Replayed result").not());
+}
+
+#[test]
+fn test_compare_runs_separates_a_differing_result() {
+    let wasm_path = "tests/fixtures/wasm/echo.wasm";
+    let record = |arg: &str| {
+        let recording = NamedTempFile::new().unwrap();
+        Command::new(env!("CARGO_BIN_EXE_soroban-debug"))
+            .arg("run")
+            .arg("--contract")
+            .arg(wasm_path)
+            .arg("--function")
+            .arg("echo")
+            .arg("--args")
+            .arg(arg)
+            .arg("--record")
+            .arg(recording.path())
+            .assert()
+            .success();
+        recording
+    };
+    let run_a = record("[1]");
+    let run_b = record("[2]");
+
+    Command::new(env!("CARGO_BIN_EXE_soroban-debug"))
+        .arg("compare")
+        .arg(run_a.path())
+        .arg(run_b.path())
+        .arg("--compare-runs")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("Result differs:"))
+        .stdout(predicate::str::contains("Storage matches"))
+        .stdout(predicate::str::contains("Events match"))
+        .stderr(predicate::str::contains("Runs differ in result"));
+
+    Command::new(env!("CARGO_BIN_EXE_soroban-debug"))
+        .arg("compare")
+        .arg(run_a.path())
+        .arg(run_a.path())
+        .arg("--compare-runs")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(r#"Result matches: "1""#));
+}
@@ -0,0 +1,84 @@
+#[path = "fixtures/mod.rs"]
+mod fixtures;
+
+use soroban_debugger::debugger::breakpoint::Breakpoint;
+use soroban_debugger::debugger::engine::DebuggerEngine;
+use soroban_debugger::runtime::executor::ContractExecutor;
+
+/// Engine for the oracle fixture, or `None` when the WASM has not been built.
+fn oracle_engine() -> Option<DebuggerEngine> {
+    let path = fixtures::fixtures_root()
+        .join("wasm")
+        .join(format!("{}.wasm", fixtures::names::ORACLE));
+    if !path.exists() {
+        eprintln!(
+            "Skipping: fixture not found at {}. Run tests/fixtures/build.sh to build fixtures.",
+            path.display()
+        );
+        return None;
+    }
+    let executor = ContractExecutor::new(std::fs::read(path).unwrap()).unwrap();
+    Some(DebuggerEngine::new(executor, vec![]))
+}
+
+/// Oracle engine with XLM priced above 1000000 and USDC below it.
+fn priced_oracle_engine() -> Option<DebuggerEngine> {
+    let mut engine = oracle_engine()?;
+    engine
+        .execute_without_breakpoints(
+            "set_price",
+            Some(r#"[{"type": "string", "value": "XLM"}, {"type": "i128", "value": 1100000}]"#),
+        )
+        .unwrap();
+    engine
+        .execute_without_breakpoints(
+            "set_price",
+            Some(r#"[{"type": "string", "value": "USDC"}, {"type": "i128", "value": 1000}]"#),
+        )
+        .unwrap();
+    Some(engine)
+}
+
+#[test]
+fn result_breakpoint_pauses_only_after_matching_return() {
+    let Some(mut engine) = priced_oracle_engine() else {
+        return;
+    };
+    engine.breakpoints_mut().set(Breakpoint::with_condition(
+        "get_price".to_string(),
+        "result > 1000000".to_string(),
+    ));
+
+    // The call completes either way; only a matching result pauses.
+    let low = engine
+        .execute(
+            "get_price",
+            Some(r#"[{"type": "string", "value": "USDC"}]"#),
+        )
+        .unwrap();
+    assert!(low.contains("1000"), "{low}");
+    assert!(!engine.is_paused());
+
+    let high = engine
+        .execute("get_price", Some(r#"[{"type": "string", "value": "XLM"}]"#))
+        .unwrap();
+    assert!(high.contains("1100000"), "{high}");
+    assert!(engine.is_paused());
+}
+
+#[test]
+fn result_log_point_logs_without_pausing() {
+    let Some(mut engine) = priced_oracle_engine() else {
+        return;
+    };
+    let mut log_point =
+        Breakpoint::with_condition("get_price".to_string(), "result > 1000000".to_string());
+    log_point.log_message = Some("price above 1 XLM".to_string());
+    engine.breakpoints_mut().set(log_point);
+
+    engine
+        .execute("get_price", Some(r#"[{"type": "string", "value": "XLM"}]"#))
+        .unwrap();
+    assert!(!engine.is_paused());
+    assert_eq!(engine.breakpoints().get("get_price").unwrap().hit_count, 1);
+}
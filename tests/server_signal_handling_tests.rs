@@ -5,14 +5,14 @@ use std::path::Path;
 
 #[test]
 fn test_server_creation_without_token() {
-    let server = DebugServer::new(None, None, None);
+    let server = DebugServer::new(None, None, None, None, Vec::new());
     assert!(server.is_ok(), "Server should be creatable without token");
 }
 
 #[test]
 fn test_server_creation_with_token() {
     let token = "valid-test-token-1234567890".to_string();
-    let server = DebugServer::new(Some(token.clone()), None, None)
+    let server = DebugServer::new(Some(token.clone()), None, None, None, Vec::new())
         .expect("Failed to create server with token");
 
     let _ = server;
@@ -21,7 +21,7 @@ fn test_server_creation_with_token() {
 #[test]
 fn test_server_rejects_tls_configuration() {
     let fake_cert = Path::new("tests/fixtures/cert.pem");
-    match DebugServer::new(None, Some(fake_cert), None) {
+    match DebugServer::new(None, Some(fake_cert), None, None, Vec::new()) {
         Ok(_) => panic!("expected TLS unsupported error"),
         Err(err) => {
             assert!(
@@ -62,3 +62,228 @@ fn storage_seed_rejects_malformed_json() {
         .to_string();
     assert!(err.contains("Failed to parse initial storage JSON"));
 }
+
+#[test]
+fn step_back_restores_storage_from_before_last_call() {
+    let wasm_path = fixture_wasm("counter");
+    if !wasm_path.exists() {
+        return;
+    }
+
+    let wasm = std::fs::read(&wasm_path).unwrap();
+    let mut executor = ContractExecutor::new(wasm).unwrap();
+
+    executor.execute("increment", None).unwrap();
+    let after_first = executor.get_storage_snapshot().unwrap();
+    executor.execute("increment", None).unwrap();
+    let after_second = executor.get_storage_snapshot().unwrap();
+    assert_ne!(after_first, after_second);
+
+    executor.step_back().unwrap();
+    assert_eq!(executor.get_storage_snapshot().unwrap(), after_first);
+
+    executor.step_forward().unwrap();
+    assert_eq!(executor.get_storage_snapshot().unwrap(), after_second);
+    assert!(executor.step_forward().is_err());
+
+    executor.step_back().unwrap();
+    executor.step_back().unwrap();
+    let err = executor.step_back().unwrap_err();
+    assert!(err.to_string().contains("No earlier storage state"));
+}
+
+#[test]
+fn failed_calls_leave_no_step_back_history() {
+    let wasm_path = fixture_wasm("counter");
+    if !wasm_path.exists() {
+        return;
+    }
+
+    let wasm = std::fs::read(&wasm_path).unwrap();
+    let mut executor = ContractExecutor::new(wasm).unwrap();
+    let before = executor.get_storage_snapshot().unwrap();
+
+    executor.execute("increment", None).unwrap();
+    assert_eq!(executor.storage_history_len(), 1);
+
+    executor.set_budget_limits(Some(1), None);
+    assert!(executor.execute("increment", None).is_err());
+    executor.set_budget_limits(None, None);
+    assert_eq!(executor.storage_history_len(), 1);
+
+    // Stepping back undoes the increment that ran, not the failed call.
+    executor.step_back().unwrap();
+    assert_eq!(executor.get_storage_snapshot().unwrap(), before);
+}
+
+#[test]
+fn step_feed_records_each_call_in_order() {
+    let wasm_path = fixture_wasm("oracle");
+    if !wasm_path.exists() {
+        eprintln!(
+            "Skipping test: fixture not found at {}. Run tests/fixtures/build.sh to build fixtures.",
+            wasm_path.display()
+        );
+        return;
+    }
+
+    let wasm = std::fs::read(&wasm_path).unwrap();
+    let mut executor = ContractExecutor::new(wasm).unwrap();
+    executor.enable_mock_all_auths();
+
+    executor
+        .execute(
+            "initialize",
+            Some(
+                r#"["GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF", {"type": "u64", "value": 3600}]"#,
+            ),
+        )
+        .unwrap();
+    executor
+        .execute(
+            "set_price",
+            Some(r#"[{"type": "string", "value": "XLM"}, {"type": "i128", "value": 120000}]"#),
+        )
+        .unwrap();
+
+    let feed = executor.step_feed();
+    let calls: Vec<(usize, &str)> = feed.iter().map(|s| (s.step, s.function.as_str())).collect();
+    assert_eq!(calls, vec![(1, "initialize"), (2, "set_price")]);
+
+    // initialize only writes instance storage; set_price also publishes its event.
+    assert!(feed[0].events.is_empty());
+    assert!(!feed[0].storage_diff.is_empty());
+    assert_eq!(feed[1].events.len(), 1);
+    assert!(feed[1].events[0].topics[0].contains("setprice"));
+    assert!(!feed[1].storage_diff.is_empty());
+
+    let lines = feed[1].format_lines();
+    assert_eq!(lines[0], "[call 2] set_price");
+    assert!(lines[1].starts_with("  event #") && lines[1].contains("setprice"));
+}
+
+#[test]
+fn contract_events_are_numbered_across_the_session() {
+    use soroban_debugger::inspector::events::EventFilter;
+
+    let wasm_path = fixture_wasm("oracle");
+    if !wasm_path.exists() {
+        eprintln!(
+            "Skipping test: fixture not found at {}. Run tests/fixtures/build.sh to build fixtures.",
+            wasm_path.display()
+        );
+        return;
+    }
+
+    let wasm = std::fs::read(&wasm_path).unwrap();
+    let mut executor = ContractExecutor::new(wasm).unwrap();
+    executor.enable_mock_all_auths();
+
+    executor
+        .execute(
+            "initialize",
+            Some(
+                r#"["GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF", {"type": "u64", "value": 3600}]"#,
+            ),
+        )
+        .unwrap();
+    for price in [120000, 121000, 122000] {
+        executor
+            .execute(
+                "set_price",
+                Some(&format!(
+                    r#"[{{"type": "string", "value": "XLM"}}, {{"type": "i128", "value": {}}}]"#,
+                    price
+                )),
+            )
+            .unwrap();
+    }
+
+    let indices: Vec<Option<usize>> = executor
+        .step_feed()
+        .iter()
+        .flat_map(|step| step.events.iter().map(|e| e.index))
+        .collect();
+    assert_eq!(indices, vec![Some(0), Some(1), Some(2)]);
+
+    // Diagnostic events of the last call are listed but not numbered.
+    let events = executor.get_events().unwrap();
+    let numbered: Vec<usize> = events.iter().filter_map(|e| e.index).collect();
+    assert_eq!(numbered, vec![2]);
+
+    let filter = EventFilter::new(&["setprice".to_string()]).unwrap();
+    let kept: Vec<Option<usize>> = events
+        .iter()
+        .filter(|e| filter.matches(e))
+        .map(|e| e.index)
+        .collect();
+    assert_eq!(kept, vec![Some(2)]);
+}
+
+#[test]
+fn cached_signatures_match_fresh_parse() {
+    let wasm_path = fixture_wasm("counter");
+    if !wasm_path.exists() {
+        eprintln!(
+            "Skipping test: fixture not found at {}. Run tests/fixtures/build.sh to build fixtures.",
+            wasm_path.display()
+        );
+        return;
+    }
+
+    let wasm = std::fs::read(&wasm_path).expect("read fixture wasm");
+    let fresh_signatures =
+        soroban_debugger::utils::wasm::parse_function_signatures(&wasm).expect("parse signatures");
+    let fresh_exports =
+        soroban_debugger::utils::wasm::parse_functions(&wasm).expect("parse exports");
+    let mut executor = ContractExecutor::new(wasm).expect("create executor");
+
+    for _ in 0..3 {
+        executor
+            .execute("increment", None)
+            .expect("execute increment");
+        assert_eq!(
+            executor.function_signatures().expect("cached signatures"),
+            fresh_signatures.as_slice()
+        );
+        assert_eq!(
+            executor.exported_functions().expect("cached exports"),
+            fresh_exports
+        );
+    }
+}
+
+#[test]
+fn ledger_snapshot_round_trip_keeps_oracle_price() {
+    let wasm_path = fixture_wasm("oracle");
+    if !wasm_path.exists() {
+        eprintln!(
+            "Skipping test: fixture not found at {}. Run tests/fixtures/build.sh to build fixtures.",
+            wasm_path.display()
+        );
+        return;
+    }
+
+    let wasm = std::fs::read(&wasm_path).expect("read fixture wasm");
+    let snapshot_file = tempfile::NamedTempFile::new().expect("temp file");
+
+    let mut executor = ContractExecutor::new(wasm.clone()).expect("create executor");
+    executor
+        .execute(
+            "set_price",
+            Some(r#"[{"type": "string", "value": "XLM"}, {"type": "i128", "value": 120000}]"#),
+        )
+        .expect("execute set_price");
+    executor
+        .save_ledger_snapshot(snapshot_file.path())
+        .expect("save snapshot");
+
+    let snapshot = soroban_debugger::runtime::loader::read_ledger_snapshot(snapshot_file.path())
+        .expect("read snapshot");
+    let mut reloaded =
+        ContractExecutor::from_ledger_snapshot(wasm, snapshot).expect("reload executor");
+    let price = reloaded
+        .execute("get_price", Some(r#"[{"type": "string", "value": "XLM"}]"#))
+        .expect("price survives the snapshot");
+    assert!(price.contains("120000"), "{price}");
+}
@@ -57,3 +57,37 @@ fn test_cancelled_fields() {
         _ => panic!("expected Cancelled variant"),
     }
 }
+
+#[path = "fixtures/mod.rs"]
+mod fixtures;
+
+#[test]
+fn test_runaway_execution_is_recoverable() {
+    use soroban_debugger::runtime::executor::ContractExecutor;
+
+    let fixture_path = fixtures::get_fixture_path(fixtures::names::BUDGET_HEAVY);
+    if !fixture_path.exists() {
+        return;
+    }
+    let wasm_bytes = std::fs::read(&fixture_path).unwrap();
+    let mut executor = ContractExecutor::new(wasm_bytes).unwrap();
+
+    // A tight host budget stands in for a contract that never stops looping.
+    executor
+        .host()
+        .budget_cloned()
+        .reset_limits(200_000, u64::MAX)
+        .unwrap();
+    let err = executor
+        .execute("heavy", Some(r#"[{"type": "u32", "value": 1000000}]"#))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("runaway execution"), "{err}");
+    assert!(!err.contains("timed out"), "{err}");
+
+    // The executor keeps working after the runaway call.
+    let result = executor
+        .execute("heavy", Some(r#"[{"type": "u32", "value": 1}]"#))
+        .unwrap();
+    assert!(!result.is_empty());
+}